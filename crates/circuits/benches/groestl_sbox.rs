@@ -0,0 +1,97 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_circuits::{
+	builder::{types::U, ConstraintSystemBuilder},
+	groestl::groestl_p_permutation_sbox,
+	unconstrained::unconstrained,
+};
+use binius_field::{
+	as_packed_field::PackedType, BinaryField128b, BinaryField8b, Field, PackedField,
+};
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion, Throughput};
+use rand::{rngs::StdRng, SeedableRng};
+
+const LOG_SIZE: usize = 20;
+
+/// Montgomery batch inversion of every scalar, mapping zero to zero.
+///
+/// This is the alternative to packed inversion that the S-box witness is compared against.
+fn montgomery_invert_or_zero<P: PackedField>(input: &[P], output: &mut [P]) {
+	let ones_for_zeros = |x: P| {
+		P::from_fn(|i| match x.get(i) {
+			scalar if scalar == P::Scalar::ZERO => P::Scalar::ONE,
+			scalar => scalar,
+		})
+	};
+
+	let mut product = P::one();
+	for (&x, out) in input.iter().zip(output.iter_mut()) {
+		product *= ones_for_zeros(x);
+		*out = product;
+	}
+	let mut inverse = product.invert_or_zero();
+	for i in (1..input.len()).rev() {
+		output[i] = inverse * output[i - 1];
+		inverse *= ones_for_zeros(input[i]);
+	}
+	output[0] = inverse;
+
+	for (&x, out) in input.iter().zip(output.iter_mut()) {
+		if x.iter().any(|scalar| scalar == P::Scalar::ZERO) {
+			*out = P::from_fn(|i| match x.get(i) {
+				scalar if scalar == P::Scalar::ZERO => P::Scalar::ZERO,
+				_ => out.get(i),
+			});
+		}
+	}
+}
+
+fn bench_inversion<P: PackedField>(c: &mut Criterion, name: &str) {
+	let mut rng = StdRng::seed_from_u64(0);
+	let input = (0..1 << (LOG_SIZE - P::LOG_WIDTH))
+		.map(|_| P::random(&mut rng))
+		.collect::<Vec<_>>();
+	let mut output = vec![P::default(); input.len()];
+
+	let mut group = c.benchmark_group(name);
+	group.throughput(Throughput::Elements(1 << LOG_SIZE));
+	group.sample_size(10);
+	group.bench_function("invert_or_zero", |bench| {
+		bench.iter(|| {
+			for (out, x) in output.iter_mut().zip(&input) {
+				*out = x.invert_or_zero();
+			}
+		});
+	});
+	group.bench_function("montgomery", |bench| {
+		bench.iter(|| montgomery_invert_or_zero(&input, &mut output));
+	});
+	group.finish();
+}
+
+fn bench_sbox_inversion(c: &mut Criterion) {
+	bench_inversion::<PackedType<U, BinaryField8b>>(c, "inversion_8b");
+	bench_inversion::<PackedType<U, BinaryField128b>>(c, "inversion_128b");
+}
+
+fn bench_sbox_witness(c: &mut Criterion) {
+	let mut group = c.benchmark_group("groestl_sbox");
+	group.throughput(Throughput::Elements(1 << LOG_SIZE));
+	group.sample_size(10);
+	group.bench_function("witness", |bench| {
+		bench.iter_batched(
+			bumpalo::Bump::new,
+			|allocator| {
+				let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+				let input =
+					unconstrained::<BinaryField8b>(&mut builder, "input", LOG_SIZE).unwrap();
+				groestl_p_permutation_sbox(&mut builder, "s_box", LOG_SIZE, input).unwrap();
+			},
+			BatchSize::PerIteration,
+		);
+	});
+	group.finish();
+}
+
+criterion_group!(groestl_sbox, bench_sbox_inversion, bench_sbox_witness);
+criterion_main!(groestl_sbox);
@@ -0,0 +1,78 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_circuits::{
+	arithmetic,
+	builder::{types::U, ConstraintSystemBuilder},
+	unconstrained::unconstrained,
+};
+use binius_core::{constraint_system, fiat_shamir::HasherChallenger};
+use binius_field::{tower::CanonicalTowerFamily, BinaryField1b};
+use binius_hal::make_portable_backend;
+use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+
+const N_PROOFS: usize = 64;
+const LOG_INV_RATE: usize = 1;
+const SECURITY_BITS: usize = 100;
+
+fn bench_verify_batch(c: &mut Criterion) {
+	let allocator = bumpalo::Bump::new();
+	let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+	let x = unconstrained::<BinaryField1b>(&mut builder, "x", 12).unwrap();
+	let y = unconstrained::<BinaryField1b>(&mut builder, "y", 12).unwrap();
+	arithmetic::u32::add(&mut builder, "sum", x, y, arithmetic::Flags::Unchecked).unwrap();
+
+	let witness = builder
+		.take_witness()
+		.expect("builder created with witness");
+	let constraint_system = builder.build().unwrap();
+
+	let backend = make_portable_backend();
+	let proof = constraint_system::prove::<
+		U,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+		_,
+	>(&constraint_system, LOG_INV_RATE, SECURITY_BITS, &[], witness, &backend)
+	.unwrap();
+
+	let mut group = c.benchmark_group("verify_batch");
+	group.throughput(Throughput::Elements(N_PROOFS as u64));
+	group.bench_function("verify", |bench| {
+		bench.iter(|| {
+			for _ in 0..N_PROOFS {
+				constraint_system::verify::<
+					U,
+					CanonicalTowerFamily,
+					Groestl256,
+					Groestl256ByteCompression,
+					HasherChallenger<Groestl256>,
+				>(&constraint_system, LOG_INV_RATE, SECURITY_BITS, &[], proof.clone())
+				.unwrap();
+			}
+		});
+	});
+	group.bench_function("verify_batch", |bench| {
+		bench.iter(|| {
+			constraint_system::verify_batch::<
+				U,
+				CanonicalTowerFamily,
+				Groestl256,
+				Groestl256ByteCompression,
+				HasherChallenger<Groestl256>,
+			>(
+				&constraint_system,
+				LOG_INV_RATE,
+				SECURITY_BITS,
+				(0..N_PROOFS).map(|_| (&[][..], proof.clone())),
+			)
+			.unwrap();
+		});
+	});
+	group.finish();
+}
+
+criterion_group!(verify_batch, bench_verify_batch);
+criterion_main!(verify_batch);
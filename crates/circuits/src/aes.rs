@@ -0,0 +1,205 @@
+// Copyright 2025 Irreducible Inc.
+
+//! AES gadgets built on the Rijndael S-box shared with Grøstl.
+
+use anyhow::Result;
+use binius_core::oracle::OracleId;
+use binius_field::{AESTowerField8b, BinaryField8b, Field};
+
+use crate::{
+	builder::{types::F, ConstraintSystemBuilder},
+	groestl::{aes_byte, groestl_p_permutation_sbox},
+	transparent,
+};
+
+/// Number of round keys produced by the AES-128 key schedule.
+pub const N_ROUND_KEYS: usize = 11;
+
+/// Round constants of the AES-128 key schedule, added to the first byte of the first word of
+/// every round key after the initial one.
+const RCON: [u8; N_ROUND_KEYS - 1] = [0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80, 0x1b, 0x36];
+
+/// AES-128 key expansion.
+///
+/// The key and every round key are 16 bytes in the standard AES byte order, so byte `4 * c + b`
+/// is byte `b` of word `c`. The first round key is the key itself. Every following round key
+/// applies RotWord and SubWord to the last word of the previous round key, using the same
+/// inversion-based S-box as Grøstl, adds the round constant and accumulates the previous round
+/// key words.
+pub fn key_schedule(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	log_size: usize,
+	key: [OracleId; 16],
+) -> Result<[[OracleId; 16]; N_ROUND_KEYS]> {
+	builder.push_namespace(name);
+
+	let rcon: [_; N_ROUND_KEYS - 1] = array_util::try_from_fn(|i| {
+		transparent::constant(builder, format!("rcon[{i}]"), log_size, aes_byte(RCON[i]))
+	})?;
+
+	let mut round_keys = Vec::with_capacity(N_ROUND_KEYS);
+	round_keys.push(key);
+	for round in 1..N_ROUND_KEYS {
+		builder.push_namespace(format!("round_keys[{round}]"));
+		let prev = round_keys[round - 1];
+
+		// SubWord(RotWord(w)) of the last word of the previous round key.
+		let sub_word: [_; 4] = array_util::try_from_fn(|b| {
+			groestl_p_permutation_sbox(
+				builder,
+				format!("s_box[{b}]"),
+				log_size,
+				prev[12 + (b + 1) % 4],
+			)
+		})?;
+
+		let mut round_key = [OracleId::default(); 16];
+		for b in 0..4 {
+			let terms = if b == 0 {
+				vec![prev[b], sub_word[b], rcon[round - 1]]
+			} else {
+				vec![prev[b], sub_word[b]]
+			};
+			round_key[b] = add_bytes(builder, format!("round_key[{b}]"), log_size, &terms)?;
+		}
+		for i in 4..16 {
+			round_key[i] = add_bytes(
+				builder,
+				format!("round_key[{i}]"),
+				log_size,
+				&[prev[i], round_key[i - 4]],
+			)?;
+		}
+
+		builder.pop_namespace();
+		round_keys.push(round_key);
+	}
+
+	let round_keys: [_; N_ROUND_KEYS] = round_keys
+		.try_into()
+		.expect("one round key is pushed per round");
+
+	#[cfg(debug_assertions)]
+	if let Some(witness) = builder.witness() {
+		use crate::groestl::witness_states;
+
+		let keys = witness_states(witness, &key)?;
+		let outputs = round_keys
+			.iter()
+			.map(|round_key| witness_states(witness, round_key))
+			.collect::<Result<Vec<_>>>()?;
+		for (z, key) in keys.into_iter().enumerate() {
+			let expected = expand_key(key.map(AESTowerField8b::new));
+			for (round, (expected, output)) in expected.iter().zip(&outputs).enumerate() {
+				assert_eq!(
+					expected.map(|byte| byte.val()),
+					output[z],
+					"round key {round} mismatch"
+				);
+			}
+		}
+	}
+
+	builder.pop_namespace();
+	Ok(round_keys)
+}
+
+/// Adds a column holding the sum of several byte columns.
+fn add_bytes(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	log_size: usize,
+	terms: &[OracleId],
+) -> Result<OracleId> {
+	let output =
+		builder.add_linear_combination(name, log_size, terms.iter().map(|&id| (id, F::ONE)))?;
+
+	if let Some(witness) = builder.witness() {
+		let terms = terms
+			.iter()
+			.map(|&id| witness.get::<BinaryField8b>(id))
+			.collect::<Result<Vec<_>, _>>()?;
+		let mut output = witness.new_column::<BinaryField8b>(output);
+		for (z, output) in output.packed().iter_mut().enumerate() {
+			*output = terms.iter().map(|term| term.packed()[z]).sum();
+		}
+	}
+
+	Ok(output)
+}
+
+/// Reference AES-128 key expansion used to check the witness.
+#[cfg(any(debug_assertions, test))]
+fn expand_key(key: [AESTowerField8b; 16]) -> [[AESTowerField8b; 16]; N_ROUND_KEYS] {
+	use crate::groestl::s_box;
+
+	let mut round_keys = [key; N_ROUND_KEYS];
+	for round in 1..N_ROUND_KEYS {
+		let prev = round_keys[round - 1];
+		let mut round_key = [AESTowerField8b::default(); 16];
+		for b in 0..4 {
+			round_key[b] = prev[b] + s_box(prev[12 + (b + 1) % 4]);
+		}
+		round_key[0] += AESTowerField8b::new(RCON[round - 1]);
+		for i in 4..16 {
+			round_key[i] = prev[i] + round_key[i - 4];
+		}
+		round_keys[round] = round_key;
+	}
+	round_keys
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_core::constraint_system::validate::validate_witness;
+	use binius_field::AESTowerField8b;
+
+	use super::{expand_key, key_schedule, N_ROUND_KEYS};
+	use crate::{
+		builder::ConstraintSystemBuilder,
+		groestl::{aes_byte, witness_states},
+		transparent,
+	};
+
+	// FIPS-197, Appendix A.1.
+	const KEY: [u8; 16] = [
+		0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6, 0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f,
+		0x3c,
+	];
+	const LAST_ROUND_KEY: [u8; 16] = [
+		0xd0, 0x14, 0xf9, 0xa8, 0xc9, 0xee, 0x25, 0x89, 0xe1, 0x3f, 0x0c, 0xc8, 0xb6, 0x63, 0x0c,
+		0xa6,
+	];
+
+	#[test]
+	fn test_expand_key_fips_197() {
+		let round_keys = expand_key(KEY.map(AESTowerField8b::new));
+		assert_eq!(round_keys[0], KEY.map(AESTowerField8b::new));
+		assert_eq!(round_keys[N_ROUND_KEYS - 1], LAST_ROUND_KEY.map(AESTowerField8b::new));
+	}
+
+	#[test]
+	fn test_key_schedule() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let log_size = 4;
+		let key: [_; 16] = array_util::try_from_fn(|i| {
+			transparent::constant(&mut builder, format!("key[{i}]"), log_size, aes_byte(KEY[i]))
+		})
+		.unwrap();
+		let round_keys = key_schedule(&mut builder, "key_schedule", log_size, key).unwrap();
+
+		let last_round_keys =
+			witness_states(builder.witness().unwrap(), &round_keys[N_ROUND_KEYS - 1]).unwrap();
+		assert_eq!(last_round_keys.len(), 1 << log_size);
+		assert!(last_round_keys
+			.iter()
+			.all(|round_key| *round_key == LAST_ROUND_KEY));
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		let boundaries = vec![];
+		validate_witness(&constraint_system, &boundaries, &witness).unwrap();
+	}
+}
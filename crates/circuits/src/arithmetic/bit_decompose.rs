@@ -0,0 +1,153 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_core::oracle::OracleId;
+use binius_field::{
+	as_packed_field::PackScalar,
+	packed::{get_packed_slice, set_packed_slice},
+	BinaryField128b, BinaryField16b, BinaryField1b, BinaryField2b, BinaryField32b, BinaryField4b,
+	BinaryField64b, BinaryField8b, ExtensionField, TowerField,
+};
+use binius_math::ArithCircuit;
+
+use crate::builder::{
+	types::{F, U},
+	witness, ConstraintSystemBuilder,
+};
+
+type B1 = BinaryField1b;
+
+/// Decomposes an `N`-bit value into its bits.
+///
+/// `value` is a column of the binary tower field with `N` bits, and `N` must be a power of two of
+/// at most 128. Returns the little-endian bit columns of `value`, constrained so that their linear
+/// combination with the tower basis reconstructs `value`. This is the inverse of packing bit
+/// columns with [`ConstraintSystemBuilder::add_linear_combination`].
+pub fn bit_decompose<const N: usize>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	value: OracleId,
+) -> Result<[OracleId; N], anyhow::Error> {
+	anyhow::ensure!(
+		N.is_power_of_two() && N <= 128,
+		"Cannot decompose a {N}-bit value, the width must be a power of two of at most 128"
+	);
+
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([value])?;
+	let bits: [OracleId; N] = builder.add_committed_multiple("bits", log_rows, B1::TOWER_LEVEL);
+
+	if let Some(witness) = builder.witness() {
+		match N {
+			1 => fill_bits::<B1>(witness, log_rows, value, &bits)?,
+			2 => fill_bits::<BinaryField2b>(witness, log_rows, value, &bits)?,
+			4 => fill_bits::<BinaryField4b>(witness, log_rows, value, &bits)?,
+			8 => fill_bits::<BinaryField8b>(witness, log_rows, value, &bits)?,
+			16 => fill_bits::<BinaryField16b>(witness, log_rows, value, &bits)?,
+			32 => fill_bits::<BinaryField32b>(witness, log_rows, value, &bits)?,
+			64 => fill_bits::<BinaryField64b>(witness, log_rows, value, &bits)?,
+			_ => fill_bits::<BinaryField128b>(witness, log_rows, value, &bits)?,
+		}
+	}
+
+	// $value = \sum_i \beta_i \cdot bit_i$, where $\beta_i$ is the $i$-th basis element over B1.
+	let reconstruction = (0..N)
+		.map(|i| {
+			let basis = <F as TowerField>::basis(0, i).expect("N is at most the degree of F");
+			ArithCircuit::constant(basis) * ArithCircuit::var(i)
+		})
+		.sum::<ArithCircuit<F>>();
+	builder.assert_zero(
+		"reconstruction",
+		bits.into_iter().chain([value]),
+		ArithCircuit::var(N) - reconstruction,
+	);
+
+	builder.pop_namespace();
+	Ok(bits)
+}
+
+fn fill_bits<FS>(
+	witness: &mut witness::Builder,
+	log_rows: usize,
+	value: OracleId,
+	bits: &[OracleId],
+) -> Result<(), anyhow::Error>
+where
+	FS: TowerField + ExtensionField<B1>,
+	U: PackScalar<FS>,
+	F: ExtensionField<FS>,
+{
+	let values = witness.get::<FS>(value)?.packed();
+
+	let mut bit_columns = bits
+		.iter()
+		.map(|&id| witness.new_column::<B1>(id))
+		.collect::<Vec<_>>();
+	let mut bit_columns = bit_columns
+		.iter_mut()
+		.map(|column| column.packed())
+		.collect::<Vec<_>>();
+
+	for z in 0..1 << log_rows {
+		let value = get_packed_slice(values, z);
+		for (bit_column, bit) in bit_columns
+			.iter_mut()
+			.zip(ExtensionField::<B1>::iter_bases(&value))
+		{
+			set_packed_slice(bit_column, z, bit);
+		}
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField1b, BinaryField32b, BinaryField8b};
+
+	use crate::{arithmetic, builder::test_utils::test_circuit, unconstrained::unconstrained};
+
+	#[test]
+	fn test_bit_decompose_u32() {
+		test_circuit(|builder| {
+			let log_size = 7;
+			let value = unconstrained::<BinaryField32b>(builder, "value", log_size)?;
+			let bits = arithmetic::bit_decompose::<32>(builder, "bit_decompose", value)?;
+
+			if let Some(witness) = builder.witness() {
+				let values = witness.get::<BinaryField32b>(value)?;
+				let bits = bits
+					.iter()
+					.map(|&bit| witness.get::<BinaryField1b>(bit))
+					.collect::<Result<Vec<_>, _>>()?;
+				for (z, &value) in values.as_slice::<u32>().iter().enumerate() {
+					for (i, bit) in bits.iter().enumerate() {
+						let bit = (bit.as_slice::<u8>()[z / 8] >> (z % 8)) & 1;
+						assert_eq!(bit as u32, (value >> i) & 1);
+					}
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_bit_decompose_u8() {
+		test_circuit(|builder| {
+			let value = unconstrained::<BinaryField8b>(builder, "value", 7)?;
+			arithmetic::bit_decompose::<8>(builder, "bit_decompose", value)?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_bit_decompose_invalid_width() {
+		test_circuit(|builder| {
+			let value = unconstrained::<BinaryField8b>(builder, "value", 7)?;
+			assert!(arithmetic::bit_decompose::<3>(builder, "bit_decompose", value).is_err());
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+}
@@ -1,9 +1,16 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+mod bit_decompose;
+mod mod_pow2;
+mod mod_reduce;
 pub mod mul;
 pub mod static_exp;
 pub mod u32;
 
+pub use bit_decompose::bit_decompose;
+pub use mod_pow2::mod_pow2;
+pub use mod_reduce::mod_reduce;
+
 /// Whether to allow or disallow arithmetic overflow
 #[derive(Debug, Clone, Copy)]
 pub enum Flags {
@@ -0,0 +1,127 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_core::oracle::OracleId;
+use binius_field::{BinaryField1b, TowerField};
+use binius_macros::arith_expr;
+
+use crate::builder::ConstraintSystemBuilder;
+
+type B1 = BinaryField1b;
+
+/// Reduces a value modulo `2^k`.
+///
+/// The value is given by its little-endian bit decomposition `x`, where `x[i]` is the column of
+/// the `i`-th bits. Returns the columns of the low `k` bits, each constrained to equal the
+/// corresponding bit of `x`. The high bits of `x` are not constrained by this gadget.
+pub fn mod_pow2(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	x: &[OracleId],
+	k: usize,
+) -> Result<Vec<OracleId>, anyhow::Error> {
+	anyhow::ensure!(k <= x.len(), "Cannot reduce a {}-bit value modulo 2^{k}", x.len());
+
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows(x.iter().copied())?;
+	let low_bits = (0..k)
+		.map(|i| builder.add_committed(format!("low_bits[{i}]"), log_rows, B1::TOWER_LEVEL))
+		.collect::<Vec<_>>();
+
+	if let Some(witness) = builder.witness() {
+		for (&bit, &low_bit) in x.iter().zip(&low_bits) {
+			witness
+				.new_column::<B1>(low_bit)
+				.as_mut_slice::<u8>()
+				.copy_from_slice(witness.get::<B1>(bit)?.as_slice::<u8>());
+		}
+	}
+
+	for (&bit, &low_bit) in x.iter().zip(&low_bits) {
+		builder.assert_zero(
+			"low_bit",
+			[bit, low_bit],
+			arith_expr!([bit, low_bit] = bit - low_bit).convert_field(),
+		);
+	}
+
+	builder.pop_namespace();
+	Ok(low_bits)
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_core::oracle::OracleId;
+	use binius_field::BinaryField1b;
+
+	use crate::{arithmetic, builder::test_utils::test_circuit, unconstrained::unconstrained};
+
+	#[test]
+	fn test_mod_pow2() {
+		test_circuit(|builder| {
+			let log_size = 7;
+			// The low byte of every row is 0b1011_0110 and the high byte is random.
+			let value = 0b1011_0110u8;
+			let mut x = (0..8)
+				.map(|i| builder.add_committed(format!("x[{i}]"), log_size, 0))
+				.collect::<Vec<_>>();
+			if let Some(witness) = builder.witness() {
+				for (i, &bit) in x.iter().enumerate() {
+					let fill = if (value >> i) & 1 == 1 { 0xff } else { 0x00 };
+					witness
+						.new_column::<BinaryField1b>(bit)
+						.as_mut_slice::<u8>()
+						.fill(fill);
+				}
+			}
+			for i in 8..16 {
+				x.push(unconstrained::<BinaryField1b>(builder, format!("x[{i}]"), log_size)?);
+			}
+
+			let low_bits = arithmetic::mod_pow2(builder, "mod_pow2", &x, 5)?;
+			assert_eq!(low_bits.len(), 5);
+
+			if let Some(witness) = builder.witness() {
+				for (i, &low_bit) in low_bits.iter().enumerate() {
+					let expected = if (value >> i) & 1 == 1 { 0xff } else { 0x00 };
+					let low_bit = witness.get::<BinaryField1b>(low_bit)?;
+					assert!(low_bit
+						.as_slice::<u8>()
+						.iter()
+						.all(|&byte| byte == expected));
+				}
+
+				// Rebuild each row's value from its bit columns: the reduction keeps the low bits
+				// of x, including the random ones, and leaves every bit from 2^5 upwards zero.
+				let row_values = |bits: &[OracleId]| -> Result<Vec<u16>, anyhow::Error> {
+					let mut values = vec![0u16; 1 << log_size];
+					for (i, &bit) in bits.iter().enumerate() {
+						let bit = witness.get::<BinaryField1b>(bit)?;
+						let bytes = bit.as_slice::<u8>();
+						for (row, value) in values.iter_mut().enumerate() {
+							*value |= (((bytes[row / 8] >> (row % 8)) & 1) as u16) << i;
+						}
+					}
+					Ok(values)
+				};
+				let x_values = row_values(&x)?;
+				assert!(x_values.iter().any(|&x| x >> 5 != 0));
+				for (reduced, x) in row_values(&low_bits)?.into_iter().zip(x_values) {
+					assert_eq!(reduced >> 5, 0);
+					assert_eq!(reduced, x % (1 << 5));
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_mod_pow2_too_many_bits() {
+		test_circuit(|builder| {
+			let x = unconstrained::<BinaryField1b>(builder, "x", 7)?;
+			assert!(arithmetic::mod_pow2(builder, "mod_pow2", &[x], 2).is_err());
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+}
@@ -0,0 +1,212 @@
+// Copyright 2025 Irreducible Inc.
+
+use anyhow::Error;
+use binius_core::oracle::OracleId;
+use binius_field::{BinaryField1b, Field, TowerField};
+use binius_macros::arith_expr;
+use binius_math::ArithCircuit;
+
+use super::mul::{add_bits, columns_to_numbers, numbers_to_columns};
+use crate::builder::{types::F, ConstraintSystemBuilder};
+
+type B1 = BinaryField1b;
+
+/// Reduces an `N`-bit value modulo a constant.
+///
+/// The value is given by its little-endian bit decomposition. The quotient `q` and remainder `r`
+/// are committed, and the gadget proves $value = q \cdot modulus + r$ and $r < modulus$. The
+/// multiplication by the constant modulus is a sum of shifted copies of `q`, and the range check
+/// commits $modulus - 1 - r$ and checks that it adds up with `r` to $modulus - 1$. The gadget
+/// commits an addition of `N`-bit values for every set bit of the modulus.
+///
+/// Returns the `M` bits of the remainder. `modulus - 1` must fit in `M` bits.
+pub fn mod_reduce<const N: usize, const M: usize>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	value: [OracleId; N],
+	modulus: u64,
+) -> Result<[OracleId; M], anyhow::Error> {
+	anyhow::ensure!(modulus != 0, "Cannot reduce modulo zero");
+	// Wide enough that $q \cdot modulus + r < 2^{N + 1} + 2^M$ cannot wrap around.
+	let sum_len = N.max(M) + 2;
+	anyhow::ensure!(sum_len <= 128, "Cannot reduce a {N}-bit value to {M} bits in 128-bit sums");
+	anyhow::ensure!(
+		u64::BITS - (modulus - 1).leading_zeros() <= M as u32,
+		"The remainder modulo {modulus} does not fit in {M} bits"
+	);
+
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows(value)?;
+
+	// $q < 2^N / modulus \le 2^{N + 1 - len(modulus)}$
+	let quotient_len = (N + 1).saturating_sub((u64::BITS - modulus.leading_zeros()) as usize);
+	let quotient = (0..quotient_len)
+		.map(|i| builder.add_committed(format!("quotient {i}"), log_rows, B1::TOWER_LEVEL))
+		.collect::<Vec<_>>();
+	let remainder: [OracleId; M] =
+		builder.add_committed_multiple("remainder", log_rows, B1::TOWER_LEVEL);
+	let remainder_gap: [OracleId; M] =
+		builder.add_committed_multiple("remainder_gap", log_rows, B1::TOWER_LEVEL);
+
+	if let Some(witness) = builder.witness() {
+		let value_columns = value
+			.iter()
+			.map(|&id| witness.get::<B1>(id).map(|x| x.packed()))
+			.collect::<Result<Vec<_>, Error>>()?;
+
+		let modulus = modulus as u128;
+		let values = columns_to_numbers(&value_columns);
+		for (bits, numbers) in [
+			(
+				&quotient[..],
+				values
+					.iter()
+					.map(|value| value / modulus)
+					.collect::<Vec<_>>(),
+			),
+			(&remainder[..], values.iter().map(|value| value % modulus).collect()),
+			(
+				&remainder_gap[..],
+				values
+					.iter()
+					.map(|value| modulus - 1 - value % modulus)
+					.collect(),
+			),
+		] {
+			let mut columns = bits
+				.iter()
+				.map(|&id| witness.new_column::<B1>(id))
+				.collect::<Vec<_>>();
+
+			let mut columns = columns
+				.iter_mut()
+				.map(|column| column.packed())
+				.collect::<Vec<_>>();
+
+			numbers_to_columns(&numbers, &mut columns);
+		}
+	}
+
+	// $r + (modulus - 1 - r) = modulus - 1$
+	let range = add_bits(builder, "range", &remainder, &remainder_gap, 0, M + 1)?;
+	assert_constant_bits(builder, "range", &range, (modulus - 1) as u128);
+
+	// $q \cdot modulus + r = value$
+	let mut sum = remainder.to_vec();
+	for shift in (0..64).filter(|shift| (modulus >> shift) & 1 == 1) {
+		sum = add_bits(builder, format!("product {shift}"), &sum, &quotient, shift, sum_len)?;
+	}
+	for (i, (&sum, &value)) in sum.iter().zip(&value).enumerate() {
+		builder.assert_zero(
+			format!("value {i}"),
+			[sum, value],
+			arith_expr!([sum, value] = sum - value).convert_field(),
+		);
+	}
+	assert_constant_bits(builder, "overflow", &sum[N..], 0);
+
+	builder.pop_namespace();
+	Ok(remainder)
+}
+
+/// Asserts that the bit columns are the little-endian bits of a constant.
+fn assert_constant_bits(
+	builder: &mut ConstraintSystemBuilder,
+	name: &str,
+	bits: &[OracleId],
+	constant: u128,
+) {
+	for (i, &bit) in bits.iter().enumerate() {
+		let expected = if (constant >> i) & 1 == 1 {
+			F::ONE
+		} else {
+			F::ZERO
+		};
+		builder.assert_zero(
+			format!("{name} {i}"),
+			[bit],
+			ArithCircuit::var(0) - ArithCircuit::constant(expected),
+		);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_core::oracle::OracleId;
+	use binius_field::BinaryField1b;
+
+	use super::super::mul::columns_to_numbers;
+	use crate::{
+		arithmetic,
+		builder::{test_utils::test_circuit, ConstraintSystemBuilder},
+		unconstrained::unconstrained,
+	};
+
+	fn check_remainders(
+		builder: &mut ConstraintSystemBuilder,
+		value: &[OracleId],
+		remainder: &[OracleId],
+		modulus: u64,
+	) -> Result<(), anyhow::Error> {
+		if let Some(witness) = builder.witness() {
+			let numbers = |bits: &[OracleId]| {
+				bits.iter()
+					.map(|&id| witness.get::<BinaryField1b>(id).map(|x| x.packed()))
+					.collect::<Result<Vec<_>, _>>()
+					.map(|columns| columns_to_numbers(&columns))
+			};
+			let values = numbers(value)?;
+			let remainders = numbers(remainder)?;
+			for (value, remainder) in values.into_iter().zip(remainders) {
+				assert_eq!(remainder, value % modulus as u128);
+			}
+		}
+		Ok(())
+	}
+
+	fn test_mod_reduce_u64<const MODULUS: u64>() {
+		test_circuit(|builder| {
+			let value: [_; 64] = array_util::try_from_fn(|i| {
+				unconstrained::<BinaryField1b>(builder, format!("value[{i}]"), 7)
+			})?;
+			let remainder =
+				arithmetic::mod_reduce::<64, 17>(builder, "mod_reduce", value, MODULUS)?;
+			check_remainders(builder, &value, &remainder, MODULUS)?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_mod_reduce() {
+		test_mod_reduce_u64::<3>();
+		test_mod_reduce_u64::<17>();
+		test_mod_reduce_u64::<257>();
+		test_mod_reduce_u64::<65537>();
+	}
+
+	#[test]
+	fn test_mod_reduce_small_value() {
+		test_circuit(|builder| {
+			// The modulus exceeds every value, so the quotient has no bits.
+			let value: [_; 8] = array_util::try_from_fn(|i| {
+				unconstrained::<BinaryField1b>(builder, format!("value[{i}]"), 7)
+			})?;
+			let remainder = arithmetic::mod_reduce::<8, 32>(builder, "mod_reduce", value, 65537)?;
+			check_remainders(builder, &value, &remainder, 65537)?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_mod_reduce_remainder_too_narrow() {
+		test_circuit(|builder| {
+			let value = unconstrained::<BinaryField1b>(builder, "value", 7)?;
+			assert!(arithmetic::mod_reduce::<1, 8>(builder, "mod_reduce", [value], 257).is_err());
+			assert!(arithmetic::mod_reduce::<1, 8>(builder, "mod_reduce", [value], 0).is_err());
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+}
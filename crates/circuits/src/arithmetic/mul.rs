@@ -18,6 +18,7 @@ use binius_field::{
 	BinaryField, BinaryField16b, BinaryField1b, BinaryField64b, Field, TowerField,
 };
 use binius_macros::arith_expr;
+use binius_math::ArithCircuit;
 use binius_maybe_rayon::iter::{
 	IndexedParallelIterator, IntoParallelRefMutIterator, ParallelIterator,
 };
@@ -365,6 +366,286 @@ pub fn u32_mul<const LOG_MAX_MULTIPLICITY: usize>(
 	Ok(cout_bits)
 }
 
+/// u64 Multiplication composed of four [`u32_mul`] instances
+///
+/// Splitting $x = x_{lo} + 2^{32} x_{hi}$ and $y = y_{lo} + 2^{32} y_{hi}$, the 128-bit product is
+///
+/// $x \cdot y = x_{lo} y_{lo} + 2^{32} (x_{lo} y_{hi} + x_{hi} y_{lo}) + 2^{64} x_{hi} y_{hi}$.
+///
+/// The outer partial products do not overlap, so the product is the sum of their concatenation and
+/// the shifted sum of the middle partial products, both computed with bitwise carry chains.
+///
+/// Returns the low and high 64 bits of the product.
+pub fn u64_mul<const LOG_MAX_MULTIPLICITY: usize>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	xin_bits: [OracleId; 64],
+	yin_bits: [OracleId; 64],
+) -> Result<([OracleId; 64], [OracleId; 64]), anyhow::Error> {
+	builder.push_namespace(name);
+
+	let [xin_low, xin_high]: [[OracleId; 32]; 2] =
+		array::from_fn(|i| array::from_fn(|j| xin_bits[32 * i + j]));
+	let [yin_low, yin_high]: [[OracleId; 32]; 2] =
+		array::from_fn(|i| array::from_fn(|j| yin_bits[32 * i + j]));
+
+	let low_low = u32_mul::<LOG_MAX_MULTIPLICITY>(builder, "low_low", xin_low, yin_low)?;
+	let low_high = u32_mul::<LOG_MAX_MULTIPLICITY>(builder, "low_high", xin_low, yin_high)?;
+	let high_low = u32_mul::<LOG_MAX_MULTIPLICITY>(builder, "high_low", xin_high, yin_low)?;
+	let high_high = u32_mul::<LOG_MAX_MULTIPLICITY>(builder, "high_high", xin_high, yin_high)?;
+
+	// The sum of the middle partial products takes 65 bits.
+	let middle = add_bits(builder, "middle", &low_high, &high_low, 0, 65)?;
+	let outer = [low_low, high_high].concat();
+	let cout = add_bits(builder, "cout", &outer, &middle, 32, 128)?;
+
+	builder.pop_namespace();
+	Ok((array::from_fn(|i| cout[i]), array::from_fn(|i| cout[64 + i])))
+}
+
+/// Signed i32 Multiplication built on [`u32_mul`]
+///
+/// Reading the two's-complement inputs as unsigned values $x_u = x + 2^{32} x_{31}$ and
+/// $y_u = y + 2^{32} y_{31}$, the signed product is
+///
+/// $x \cdot y \equiv x_u \cdot y_u - 2^{32} (x_{31} y_u + y_{31} x_u) \pmod{2^{64}}$,
+///
+/// so only the high half of the unsigned product needs correcting. The corrected high half is
+/// committed and checked by adding the correction terms back to it.
+///
+/// Returns the 64-bit two's-complement product.
+pub fn i32_mul<const LOG_MAX_MULTIPLICITY: usize>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	xin_bits: [OracleId; 32],
+	yin_bits: [OracleId; 32],
+) -> Result<[OracleId; 64], anyhow::Error> {
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows(xin_bits)?;
+
+	let unsigned = u32_mul::<LOG_MAX_MULTIPLICITY>(builder, "unsigned", xin_bits, yin_bits)?;
+
+	let x_correction = select_bits(builder, "x_correction", xin_bits[31], &yin_bits)?;
+	let y_correction = select_bits(builder, "y_correction", yin_bits[31], &xin_bits)?;
+
+	let high_bits: [OracleId; 32] =
+		builder.add_committed_multiple("high_bits", log_rows, BinaryField1b::TOWER_LEVEL);
+
+	if let Some(witness) = builder.witness() {
+		let xin_columns = xin_bits
+			.iter()
+			.map(|&id| witness.get::<BinaryField1b>(id).map(|x| x.packed()))
+			.collect::<Result<Vec<_>, Error>>()?;
+
+		let yin_columns = yin_bits
+			.iter()
+			.map(|&id| witness.get::<BinaryField1b>(id).map(|x| x.packed()))
+			.collect::<Result<Vec<_>, Error>>()?;
+
+		let high = columns_to_numbers(&xin_columns)
+			.into_iter()
+			.zip(columns_to_numbers(&yin_columns))
+			.map(|(x, y)| {
+				let product = (x as u32 as i32 as i64) * (y as u32 as i32 as i64);
+				(product as u64 >> 32) as u128
+			})
+			.collect::<Vec<_>>();
+
+		let mut high_columns = high_bits
+			.iter()
+			.map(|&id| witness.new_column::<BinaryField1b>(id))
+			.collect::<Vec<_>>();
+
+		let mut high_columns = high_columns
+			.iter_mut()
+			.map(|column| column.packed())
+			.collect::<Vec<_>>();
+
+		numbers_to_columns(&high, &mut high_columns);
+	}
+
+	// $high + x_{31} y_u + y_{31} x_u \equiv high_u \pmod{2^{32}}$
+	let partial = add_bits(builder, "partial", &high_bits, &x_correction, 0, 32)?;
+	let total = add_bits(builder, "total", &partial, &y_correction, 0, 32)?;
+	for (i, (&total, &unsigned_high)) in total.iter().zip(&unsigned[32..]).enumerate() {
+		builder.assert_zero(
+			format!("high {i}"),
+			[total, unsigned_high],
+			arith_expr!([total, unsigned_high] = total - unsigned_high).convert_field(),
+		);
+	}
+
+	builder.pop_namespace();
+	Ok(array::from_fn(|i| {
+		if i < 32 {
+			unsigned[i]
+		} else {
+			high_bits[i - 32]
+		}
+	}))
+}
+
+/// Adds two little-endian bit decompositions with a ripple carry chain.
+///
+/// Returns the low `n_bits` bits of $lhs + 2^{rhs\_offset} \cdot rhs$.
+pub(super) fn add_bits(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	lhs: &[OracleId],
+	rhs: &[OracleId],
+	rhs_offset: usize,
+	n_bits: usize,
+) -> Result<Vec<OracleId>, anyhow::Error> {
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows(lhs.iter().chain(rhs).copied())?;
+
+	let sum_bits = (0..n_bits)
+		.map(|i| builder.add_committed(format!("sum {i}"), log_rows, BinaryField1b::TOWER_LEVEL))
+		.collect::<Vec<_>>();
+	// The carry into each bit; there is no carry into the lowest bit.
+	let carry_bits = (1..n_bits)
+		.map(|i| builder.add_committed(format!("carry {i}"), log_rows, BinaryField1b::TOWER_LEVEL))
+		.collect::<Vec<_>>();
+
+	if let Some(witness) = builder.witness() {
+		let lhs_columns = lhs
+			.iter()
+			.map(|&id| witness.get::<BinaryField1b>(id).map(|x| x.packed()))
+			.collect::<Result<Vec<_>, Error>>()?;
+		let rhs_columns = rhs
+			.iter()
+			.map(|&id| witness.get::<BinaryField1b>(id).map(|x| x.packed()))
+			.collect::<Result<Vec<_>, Error>>()?;
+
+		// An operand without bits is zero in every row.
+		let lhs_numbers = columns_to_numbers(&lhs_columns);
+		let rhs_numbers = columns_to_numbers(&rhs_columns);
+		let (sums, carries): (Vec<_>, Vec<_>) = (0..lhs_numbers.len().max(rhs_numbers.len()))
+			.map(|i| {
+				let lhs = lhs_numbers.get(i).copied().unwrap_or_default();
+				let rhs = rhs_numbers.get(i).copied().unwrap_or_default() << rhs_offset;
+				let sum = lhs.wrapping_add(rhs);
+				// Each sum bit is the XOR of the operand bits and the carry into it.
+				(sum, (lhs ^ rhs ^ sum) >> 1)
+			})
+			.unzip();
+
+		let mut sum_columns = sum_bits
+			.iter()
+			.map(|&id| witness.new_column::<BinaryField1b>(id))
+			.collect::<Vec<_>>();
+		let mut sum_columns = sum_columns
+			.iter_mut()
+			.map(|column| column.packed())
+			.collect::<Vec<_>>();
+		numbers_to_columns(&sums, &mut sum_columns);
+
+		let mut carry_columns = carry_bits
+			.iter()
+			.map(|&id| witness.new_column::<BinaryField1b>(id))
+			.collect::<Vec<_>>();
+		let mut carry_columns = carry_columns
+			.iter_mut()
+			.map(|column| column.packed())
+			.collect::<Vec<_>>();
+		numbers_to_columns(&carries, &mut carry_columns);
+	}
+
+	for i in 0..n_bits {
+		let terms = [
+			lhs.get(i).copied(),
+			i.checked_sub(rhs_offset).and_then(|j| rhs.get(j).copied()),
+			i.checked_sub(1).map(|j| carry_bits[j]),
+		]
+		.into_iter()
+		.flatten()
+		.collect::<Vec<_>>();
+		let vars = (0..terms.len())
+			.map(ArithCircuit::<F>::var)
+			.collect::<Vec<_>>();
+		let out = ArithCircuit::<F>::var(terms.len());
+
+		// The sum bit is the XOR of the terms.
+		let xor = vars.iter().cloned().sum::<ArithCircuit<F>>();
+		builder.assert_zero(
+			format!("sum {i}"),
+			terms.iter().copied().chain([sum_bits[i]]),
+			xor - out.clone(),
+		);
+
+		// The carry out of the bit is the majority of the terms.
+		if i + 1 < n_bits {
+			let majority = match vars.as_slice() {
+				[a, b, c] => a.clone() * b.clone() + a.clone() * c.clone() + b.clone() * c.clone(),
+				[a, b] => a.clone() * b.clone(),
+				_ => ArithCircuit::<F>::zero(),
+			};
+			builder.assert_zero(
+				format!("carry {}", i + 1),
+				terms.iter().copied().chain([carry_bits[i]]),
+				majority - out,
+			);
+		}
+	}
+
+	builder.pop_namespace();
+	Ok(sum_bits)
+}
+
+/// Returns the bits of `bits` where the `select` bit is set, and zeroes otherwise.
+fn select_bits(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	select: OracleId,
+	bits: &[OracleId],
+) -> Result<Vec<OracleId>, anyhow::Error> {
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows(bits.iter().copied().chain([select]))?;
+
+	let selected_bits = (0..bits.len())
+		.map(|i| {
+			builder.add_committed(format!("selected {i}"), log_rows, BinaryField1b::TOWER_LEVEL)
+		})
+		.collect::<Vec<_>>();
+
+	if let Some(witness) = builder.witness() {
+		let select_column = witness.get::<BinaryField1b>(select)?.packed();
+		let columns = bits
+			.iter()
+			.map(|&id| witness.get::<BinaryField1b>(id).map(|x| x.packed()))
+			.collect::<Result<Vec<_>, Error>>()?;
+
+		let selected = columns_to_numbers(&[select_column])
+			.into_iter()
+			.zip(columns_to_numbers(&columns))
+			.map(|(select, value)| if select == 1 { value } else { 0 })
+			.collect::<Vec<_>>();
+
+		let mut selected_columns = selected_bits
+			.iter()
+			.map(|&id| witness.new_column::<BinaryField1b>(id))
+			.collect::<Vec<_>>();
+
+		let mut selected_columns = selected_columns
+			.iter_mut()
+			.map(|column| column.packed())
+			.collect::<Vec<_>>();
+
+		numbers_to_columns(&selected, &mut selected_columns);
+	}
+
+	for (i, (&bit, &selected)) in bits.iter().zip(&selected_bits).enumerate() {
+		builder.assert_zero(
+			format!("selected {i}"),
+			[select, bit, selected],
+			arith_expr!([select, bit, selected] = select * bit - selected).convert_field(),
+		);
+	}
+
+	builder.pop_namespace();
+	Ok(selected_bits)
+}
+
 fn exp_pow2<F: BinaryField>(mut g: F, log_exp: usize) -> F {
 	for _ in 0..log_exp {
 		g *= g
@@ -372,7 +653,7 @@ fn exp_pow2<F: BinaryField>(mut g: F, log_exp: usize) -> F {
 	g
 }
 
-fn columns_to_numbers(columns: &[&[PackedType<U, BinaryField1b>]]) -> Vec<u128> {
+pub(super) fn columns_to_numbers(columns: &[&[PackedType<U, BinaryField1b>]]) -> Vec<u128> {
 	let width = PackedType::<U, BinaryField1b>::WIDTH;
 	let mut numbers: Vec<u128> = vec![0; columns.first().map(|c| c.len() * width).unwrap_or(0)];
 
@@ -386,7 +667,10 @@ fn columns_to_numbers(columns: &[&[PackedType<U, BinaryField1b>]]) -> Vec<u128>
 	numbers
 }
 
-fn numbers_to_columns(numbers: &[u128], columns: &mut [&mut [PackedType<U, BinaryField1b>]]) {
+pub(super) fn numbers_to_columns(
+	numbers: &[u128],
+	columns: &mut [&mut [PackedType<U, BinaryField1b>]],
+) {
 	columns
 		.par_iter_mut()
 		.enumerate()
@@ -402,14 +686,18 @@ fn numbers_to_columns(numbers: &[u128], columns: &mut [&mut [PackedType<U, Binar
 #[cfg(test)]
 mod tests {
 	use binius_core::{
-		constraint_system::{self},
+		constraint_system::{self, exp, validate::validate_witness},
 		fiat_shamir::HasherChallenger,
+		oracle::OracleId,
 	};
-	use binius_field::{tower::CanonicalTowerFamily, BinaryField1b, BinaryField8b};
+	use binius_field::{tower::CanonicalTowerFamily, BinaryField1b, BinaryField8b, TowerField};
 	use binius_hal::make_portable_backend;
 	use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+	use rand::{rngs::StdRng, Rng, SeedableRng};
 
-	use super::mul;
+	use super::{
+		add_bits, columns_to_numbers, i32_mul, mul, numbers_to_columns, select_bits, u64_mul,
+	};
 	use crate::{
 		builder::{types::U, ConstraintSystemBuilder},
 		unconstrained::unconstrained,
@@ -464,4 +752,191 @@ mod tests {
 		>(&constraint_system, 1, 10, &[], proof)
 		.unwrap();
 	}
+
+	fn committed_bits<const N: usize>(
+		builder: &mut ConstraintSystemBuilder,
+		name: &str,
+		log_rows: usize,
+		numbers: &[u128],
+	) -> [OracleId; N] {
+		let bits = builder.add_committed_multiple(name, log_rows, BinaryField1b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			let mut columns = bits.map(|id| witness.new_column::<BinaryField1b>(id));
+			let mut columns = columns
+				.iter_mut()
+				.map(|column| column.packed())
+				.collect::<Vec<_>>();
+			numbers_to_columns(numbers, &mut columns);
+		}
+		bits
+	}
+
+	fn read_bits(builder: &mut ConstraintSystemBuilder, bits: &[OracleId]) -> Vec<u128> {
+		let witness = builder.witness().unwrap();
+		let columns = bits
+			.iter()
+			.map(|&id| witness.get::<BinaryField1b>(id).map(|x| x.packed()))
+			.collect::<Result<Vec<_>, _>>()
+			.unwrap();
+		columns_to_numbers(&columns)
+	}
+
+	/// Validates the witness of a circuit built on [`super::u32_mul`].
+	///
+	/// The dynamic exponentiation results of `u32_mul` are only filled in by the prover, so they
+	/// are generated here before the constraints are checked.
+	fn validate_mul_witness(mut builder: ConstraintSystemBuilder) {
+		let mut witness = builder
+			.take_witness()
+			.expect("builder created with witness");
+		let constraint_system = builder.build().unwrap();
+		exp::make_exp_witnesses::<U, CanonicalTowerFamily>(
+			&mut witness,
+			&constraint_system.oracles,
+			&constraint_system.exponents,
+		)
+		.unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+
+	#[test]
+	fn test_u64_mul() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+
+		let log_n_muls = 8;
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let mut operands = vec![
+			(u64::MAX, u64::MAX),
+			(0, 0x0123_4567_89ab_cdef),
+			(1, 0xfedc_ba98_7654_3210),
+			(u32::MAX as u64, u64::MAX),
+			(1 << 32, 1 << 32),
+		];
+		operands.resize_with(1 << log_n_muls, || (rng.gen(), rng.gen()));
+
+		let (xin, yin): (Vec<_>, Vec<_>) = operands
+			.iter()
+			.map(|&(x, y)| (x as u128, y as u128))
+			.unzip();
+		let xin_bits = committed_bits(&mut builder, "xin_bits", log_n_muls, &xin);
+		let yin_bits = committed_bits(&mut builder, "yin_bits", log_n_muls, &yin);
+
+		let cout = u64_mul::<13>(&mut builder, "u64_mul", xin_bits, yin_bits).unwrap();
+
+		let cout = read_bits(&mut builder, &<[_; 2]>::from(cout).concat());
+		for (cout, (x, y)) in cout.into_iter().zip(xin.into_iter().zip(yin)) {
+			assert_eq!(cout, x * y);
+		}
+
+		validate_mul_witness(builder);
+	}
+
+	#[test]
+	fn test_i32_mul() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+
+		let log_n_muls = 8;
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let mut operands = vec![
+			(3, 5),
+			(3, -5),
+			(-3, 5),
+			(-3, -5),
+			(i32::MIN, i32::MIN),
+			(i32::MIN, -1),
+			(i32::MAX, i32::MIN),
+			(0, -1),
+			(-1, -1),
+			(-1, i32::MAX),
+			(i32::MIN, 1),
+			(i32::MAX, -2),
+			(-65536, 65536),
+			(-65537, -65535),
+		];
+		operands.resize_with(1 << log_n_muls, || (rng.gen(), rng.gen()));
+
+		let (xin, yin): (Vec<_>, Vec<_>) = operands
+			.iter()
+			.map(|&(x, y)| (x as u32 as u128, y as u32 as u128))
+			.unzip();
+		let xin_bits = committed_bits(&mut builder, "xin_bits", log_n_muls, &xin);
+		let yin_bits = committed_bits(&mut builder, "yin_bits", log_n_muls, &yin);
+
+		let cout = i32_mul::<13>(&mut builder, "i32_mul", xin_bits, yin_bits).unwrap();
+
+		let cout = read_bits(&mut builder, &cout);
+		for (cout, (x, y)) in cout.into_iter().zip(operands) {
+			assert_eq!(cout, (x as i64 * y as i64) as u64 as u128);
+		}
+
+		validate_mul_witness(builder);
+	}
+
+	#[test]
+	fn test_add_bits() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+
+		let log_rows = 8;
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let mut operands = vec![(u64::MAX, u64::MAX), (0, u64::MAX), (u64::MAX, 1)];
+		operands.resize_with(1 << log_rows, || (rng.gen(), rng.gen()));
+
+		let (lhs, rhs): (Vec<_>, Vec<_>) = operands
+			.iter()
+			.map(|&(lhs, rhs)| (lhs as u128, rhs as u128))
+			.unzip();
+		let lhs_bits: [_; 64] = committed_bits(&mut builder, "lhs_bits", log_rows, &lhs);
+		let rhs_bits: [_; 64] = committed_bits(&mut builder, "rhs_bits", log_rows, &rhs);
+
+		let sum = add_bits(&mut builder, "sum", &lhs_bits, &rhs_bits, 0, 65).unwrap();
+		let shifted_sum =
+			add_bits(&mut builder, "shifted_sum", &lhs_bits, &rhs_bits, 32, 80).unwrap();
+
+		let sum = read_bits(&mut builder, &sum);
+		let shifted_sum = read_bits(&mut builder, &shifted_sum);
+		for (i, (lhs, rhs)) in lhs.into_iter().zip(rhs).enumerate() {
+			assert_eq!(sum[i], lhs + rhs);
+			assert_eq!(shifted_sum[i], (lhs + (rhs << 32)) & ((1 << 80) - 1));
+		}
+
+		let witness = builder
+			.take_witness()
+			.expect("builder created with witness");
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+
+	#[test]
+	fn test_select_bits() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+
+		let log_rows = 8;
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let (select, values): (Vec<_>, Vec<_>) = (0..1 << log_rows)
+			.map(|_| (rng.gen::<bool>() as u128, rng.gen::<u32>() as u128))
+			.unzip();
+		let [select_bit] = committed_bits(&mut builder, "select", log_rows, &select);
+		let value_bits: [_; 32] = committed_bits(&mut builder, "values", log_rows, &values);
+
+		let selected = select_bits(&mut builder, "selected", select_bit, &value_bits).unwrap();
+
+		let selected = read_bits(&mut builder, &selected);
+		for (i, (select, value)) in select.into_iter().zip(values).enumerate() {
+			assert_eq!(selected[i], select * value);
+		}
+
+		let witness = builder
+			.take_witness()
+			.expect("builder created with witness");
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
 }
@@ -1,6 +1,11 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use std::{
+	cell::RefCell,
+	collections::HashMap,
+	ops::{Deref, DerefMut},
+	rc::Rc,
+};
 
 use anyhow::{anyhow, ensure};
 use binius_core::{
@@ -12,13 +17,14 @@ use binius_core::{
 	oracle::{
 		ConstraintSetBuilder, Error as OracleError, MultilinearOracleSet, OracleId, ShiftVariant,
 	},
-	polynomial::MultivariatePoly,
+	polynomial::{ArithCircuitPoly, MultivariatePoly},
+	protocols::sumcheck::prove::zerocheck,
 	transparent::step_down::StepDown,
 	witness::MultilinearExtensionIndex,
 };
 use binius_field::{
 	as_packed_field::{PackScalar, PackedType},
-	BinaryField1b,
+	BinaryField1b, TowerField,
 };
 use binius_math::ArithCircuit;
 use binius_utils::bail;
@@ -39,6 +45,7 @@ pub struct ConstraintSystemBuilder<'arena> {
 	witness: Option<witness::Builder<'arena>>,
 	next_channel_id: ChannelId,
 	namespace_path: Vec<String>,
+	check_constraints: bool,
 }
 
 impl<'arena> ConstraintSystemBuilder<'arena> {
@@ -55,8 +62,26 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 		}
 	}
 
+	/// Creates a builder with a witness whose random data is generated from `seed`.
+	///
+	/// Building the same circuit with the same seed reproduces the same witness, while
+	/// [`Self::new_with_witness`] draws fresh randomness each time.
+	pub fn new_with_witness_seeded(allocator: &'arena bumpalo::Bump, seed: u64) -> Self {
+		let oracles = Rc::new(RefCell::new(MultilinearOracleSet::new()));
+		Self {
+			witness: Some(witness::Builder::new(allocator, oracles.clone()).with_seed(seed)),
+			oracles,
+			..Default::default()
+		}
+	}
+
 	#[allow(clippy::type_complexity)]
 	pub fn build(self) -> Result<ConstraintSystem<F>, anyhow::Error> {
+		ensure!(
+			self.namespace_path.is_empty(),
+			"Failed to build ConstraintSystem: namespace \"{}\" was pushed but never popped",
+			self.current_namespace()
+		);
 		let table_constraints = self.constraints.build(&self.oracles.borrow())?;
 		Ok(ConstraintSystem {
 			max_channel_id: self
@@ -77,6 +102,28 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 		})
 	}
 
+	/// Builds the constraint system alone, discarding the witness if there is one.
+	///
+	/// [`Self::build`] requires the witness to be taken first, while this drops it without building
+	/// it. This is useful for tooling that only needs the structure of the constraint system.
+	///
+	/// Example
+	/// ```
+	/// use binius_circuits::{builder::ConstraintSystemBuilder, unconstrained::unconstrained};
+	/// use binius_field::BinaryField1b;
+	///
+	/// let allocator = bumpalo::Bump::new();
+	/// let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+	/// let x = unconstrained::<BinaryField1b>(&mut builder, "x", 10).unwrap();
+	///
+	/// let system = builder.build_shape_only().unwrap();
+	/// assert_eq!(system.oracles.n_vars(x), 10);
+	/// ```
+	pub fn build_shape_only(mut self) -> Result<ConstraintSystem<F>, anyhow::Error> {
+		self.witness = None;
+		self.build()
+	}
+
 	pub const fn witness(&mut self) -> Option<&mut witness::Builder<'arena>> {
 		self.witness.as_mut()
 	}
@@ -209,16 +256,57 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 		self.flush(FlushDirection::Pull, channel_id, count, oracle_ids)
 	}
 
+	/// Enables checking every zero constraint against the witness as soon as it is added.
+	///
+	/// With checking enabled, [`Self::assert_zero`] evaluates the composition over the witness
+	/// columns and panics on the first row where it is nonzero. Constraints referring to columns
+	/// whose witness has not been filled yet are skipped, and are only caught by a later
+	/// `validate_witness`. Builders without a witness are unaffected.
+	pub fn enable_constraint_checking(&mut self) {
+		self.check_constraints = true;
+	}
+
 	pub fn assert_zero(
 		&mut self,
 		name: impl ToString,
 		oracle_ids: impl IntoIterator<Item = OracleId>,
 		composition: ArithCircuit<F>,
 	) {
+		let name = name.to_string();
+		let oracle_ids = oracle_ids.into_iter().collect::<Vec<_>>();
+		if self.check_constraints {
+			self.check_zero_constraint(&name, &oracle_ids, &composition);
+		}
 		self.constraints
 			.add_zerocheck(name, oracle_ids, composition);
 	}
 
+	fn check_zero_constraint(
+		&self,
+		name: &str,
+		oracle_ids: &[OracleId],
+		composition: &ArithCircuit<F>,
+	) {
+		let Some(witness) = &self.witness else {
+			return;
+		};
+		let Some(multilinears) = oracle_ids
+			.iter()
+			.map(|&id| witness.multilinear(id))
+			.collect::<Option<Vec<_>>>()
+		else {
+			return;
+		};
+
+		let composition = ArithCircuitPoly::with_n_vars(oracle_ids.len(), composition.clone())
+			.unwrap_or_else(|err| panic!("constraint {name} has an invalid composition: {err}"));
+		if let Err(err) =
+			zerocheck::validate_witness(&multilinears, &[(name.to_string(), composition)])
+		{
+			panic!("constraint {name} is not satisfied by the witness: {err}");
+		}
+	}
+
 	pub fn assert_not_zero(&mut self, oracle_id: OracleId) {
 		self.non_zero_oracle_ids.push(oracle_id);
 	}
@@ -296,6 +384,20 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 			.committed_multiple(n_vars, tower_level)
 	}
 
+	/// Like [`Self::add_committed_multiple`], but the `i`-th column has tower level
+	/// `tower_levels[i]`, so a single named group can mix column types.
+	pub fn add_committed_multiple_typed<const N: usize>(
+		&mut self,
+		name: impl ToString,
+		n_vars: usize,
+		tower_levels: [usize; N],
+	) -> [OracleId; N] {
+		self.oracles
+			.borrow_mut()
+			.add_named(self.scoped_name(name))
+			.committed_multiple_typed(n_vars, tower_levels)
+	}
+
 	pub fn add_linear_combination(
 		&mut self,
 		name: impl ToString,
@@ -308,6 +410,50 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 			.linear_combination(n_vars, inner)
 	}
 
+	/// Adds a linear combination like [`Self::add_linear_combination`], validating every term
+	/// first.
+	///
+	/// Each inner oracle must exist and have `n_vars` variables, and both the oracle and its
+	/// coefficient must fit in `tower_level`, which is typically the tower level of the field the
+	/// witness column is filled with. The returned error names the index of the offending term.
+	pub fn add_linear_combination_checked(
+		&mut self,
+		name: impl ToString,
+		n_vars: usize,
+		tower_level: usize,
+		inner: impl IntoIterator<Item = (OracleId, F)>,
+	) -> Result<OracleId, OracleError> {
+		if tower_level > F::TOWER_LEVEL {
+			return Err(OracleError::TowerLevelTooHigh { tower_level });
+		}
+		let inner = inner.into_iter().collect::<Vec<_>>();
+		{
+			let oracles = self.oracles.borrow();
+			for (index, &(id, coeff)) in inner.iter().enumerate() {
+				if !oracles.is_valid_oracle_id(id) {
+					return Err(OracleError::InvalidOracleId(id));
+				}
+				let term_n_vars = oracles.n_vars(id);
+				if term_n_vars != n_vars {
+					return Err(OracleError::LinearCombinationTermNvarsMismatch {
+						index,
+						n_vars: term_n_vars,
+						expected: n_vars,
+					});
+				}
+				let term_tower_level = oracles.tower_level(id).max(coeff.min_tower_level());
+				if term_tower_level > tower_level {
+					return Err(OracleError::LinearCombinationTermTowerLevelTooHigh {
+						index,
+						tower_level: term_tower_level,
+						max_tower_level: tower_level,
+					});
+				}
+			}
+		}
+		self.add_linear_combination(name, n_vars, inner)
+	}
+
 	pub fn add_linear_combination_with_offset(
 		&mut self,
 		name: impl ToString,
@@ -438,7 +584,7 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 		if self.namespace_path.is_empty() {
 			name
 		} else {
-			format!("{}::{name}", self.namespace_path.join("::"))
+			format!("{}::{name}", self.current_namespace())
 		}
 	}
 
@@ -476,6 +622,61 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 		self.namespace_path.pop();
 	}
 
+	/// Pushes `name` to the namespace and returns a guard that pops it when dropped.
+	///
+	/// The guard dereferences to the builder, so it can be used in its place. Unlike a manual
+	/// `push_namespace`/`pop_namespace` pair, the namespace is popped even on an early return.
+	///
+	/// Example
+	/// ```
+	/// use binius_circuits::builder::ConstraintSystemBuilder;
+	/// use binius_field::{BinaryField1b, TowerField};
+	///
+	/// fn gadget(builder: &mut ConstraintSystemBuilder, fail: bool) -> anyhow::Result<()> {
+	///     let mut builder = builder.namespace("gadget");
+	///     builder.add_committed("x", 4, BinaryField1b::TOWER_LEVEL);
+	///     anyhow::ensure!(!fail, "gadget failed");
+	///     Ok(())
+	/// }
+	///
+	/// let mut builder = ConstraintSystemBuilder::new();
+	/// assert!(gadget(&mut builder, true).is_err());
+	/// assert_eq!(builder.namespace_depth(), 0);
+	/// ```
+	pub fn namespace(&mut self, name: impl ToString) -> NamespaceGuard<'_, 'arena> {
+		self.push_namespace(name);
+		NamespaceGuard { builder: self }
+	}
+
+	/// Returns the current namespace stack joined with `::`, the same prefix that is applied to
+	/// oracle names. Returns an empty string at the root namespace.
+	///
+	/// Example
+	/// ```
+	/// use binius_circuits::builder::ConstraintSystemBuilder;
+	///
+	/// let mut builder = ConstraintSystemBuilder::new();
+	/// assert_eq!(builder.current_namespace(), "");
+	/// assert_eq!(builder.namespace_depth(), 0);
+	///
+	/// builder.push_namespace("round[0]");
+	/// builder.push_namespace("s_box[3]");
+	/// assert_eq!(builder.current_namespace(), "round[0]::s_box[3]");
+	/// assert_eq!(builder.namespace_depth(), 2);
+	///
+	/// builder.pop_namespace();
+	/// assert_eq!(builder.current_namespace(), "round[0]");
+	/// assert_eq!(builder.namespace_depth(), 1);
+	/// ```
+	pub fn current_namespace(&self) -> String {
+		self.namespace_path.join("::")
+	}
+
+	/// Returns the number of namespaces currently pushed.
+	pub fn namespace_depth(&self) -> usize {
+		self.namespace_path.len()
+	}
+
 	/// Returns the number of rows shared by a set of columns.
 	///
 	/// Fails if no columns are provided, or not all columns have the same number of rows.
@@ -498,3 +699,31 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 		Ok(log_rows)
 	}
 }
+
+/// A [`ConstraintSystemBuilder`] with a namespace pushed, which is popped when the guard is
+/// dropped.
+///
+/// Created by [`ConstraintSystemBuilder::namespace`].
+pub struct NamespaceGuard<'a, 'arena> {
+	builder: &'a mut ConstraintSystemBuilder<'arena>,
+}
+
+impl<'arena> Deref for NamespaceGuard<'_, 'arena> {
+	type Target = ConstraintSystemBuilder<'arena>;
+
+	fn deref(&self) -> &Self::Target {
+		self.builder
+	}
+}
+
+impl DerefMut for NamespaceGuard<'_, '_> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		self.builder
+	}
+}
+
+impl Drop for NamespaceGuard<'_, '_> {
+	fn drop(&mut self) {
+		self.builder.pop_namespace();
+	}
+}
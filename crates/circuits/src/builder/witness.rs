@@ -15,6 +15,7 @@ use binius_field::{
 use binius_math::MultilinearExtension;
 use binius_utils::bail;
 use bytemuck::{must_cast_slice, must_cast_slice_mut, Pod};
+use rand::{rngs::StdRng, Rng, SeedableRng};
 
 use super::types::{F, U};
 
@@ -25,6 +26,8 @@ pub struct Builder<'arena> {
 
 	#[allow(clippy::type_complexity)]
 	entries: Rc<RefCell<Vec<Option<WitnessBuilderEntry<'arena>>>>>,
+
+	rng: Option<StdRng>,
 }
 
 struct WitnessBuilderEntry<'arena> {
@@ -43,9 +46,27 @@ impl<'arena> Builder<'arena> {
 			bump: allocator,
 			oracles,
 			entries: Rc::new(RefCell::new(Vec::new())),
+			rng: None,
 		}
 	}
 
+	/// Seeds the randomness used to generate witness data, such as unconstrained columns.
+	pub fn with_seed(self, seed: u64) -> Self {
+		Self {
+			rng: Some(StdRng::seed_from_u64(seed)),
+			..self
+		}
+	}
+
+	/// Returns a new RNG derived from the witness seed, or `None` if the witness is not seeded.
+	///
+	/// Each call advances the seed, so the same sequence of calls reproduces the same RNGs.
+	pub fn seeded_rng(&mut self) -> Option<StdRng> {
+		self.rng
+			.as_mut()
+			.map(|rng| StdRng::seed_from_u64(rng.gen()))
+	}
+
 	pub fn new_column<FS: TowerField>(&self, id: OracleId) -> EntryBuilder<'arena, FS>
 	where
 		U: PackScalar<FS>,
@@ -165,6 +186,18 @@ impl<'arena> Builder<'arena> {
 		Ok(())
 	}
 
+	/// Returns the multilinear witness of an oracle, if its column has been filled.
+	pub(crate) fn multilinear(
+		&self,
+		id: OracleId,
+	) -> Option<MultilinearWitness<'arena, PackedType<U, F>>> {
+		self.entries
+			.borrow()
+			.get(id.index())
+			.and_then(|entry| entry.as_ref())
+			.and_then(|entry| entry.witness.as_ref().ok().cloned())
+	}
+
 	pub fn build(self) -> Result<MultilinearExtensionIndex<'arena, PackedType<U, F>>, Error> {
 		let mut result = MultilinearExtensionIndex::new();
 		let entries = Rc::into_inner(self.entries)
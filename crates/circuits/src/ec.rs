@@ -0,0 +1,360 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Gadgets for elliptic curves over binary fields.
+//!
+//! The curves have the short Weierstrass form `y^2 + xy = x^3 + ax^2 + b` with `b != 0`, which
+//! covers the binary Koblitz curves. Points are given in affine coordinates together with a flag
+//! marking the point at infinity, which is the group identity.
+
+use binius_core::oracle::OracleId;
+use binius_field::{
+	as_packed_field::PackScalar,
+	packed::{get_packed_slice, set_packed_slice},
+	BinaryField1b, ExtensionField, Field, TowerField,
+};
+use binius_math::ArithCircuit;
+use bytemuck::Pod;
+
+use crate::builder::{
+	types::{F, U},
+	ConstraintSystemBuilder,
+};
+
+type B1 = BinaryField1b;
+
+/// The coefficients of the binary curve `y^2 + xy = x^3 + ax^2 + b`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BinaryCurveParams<FS> {
+	pub a: FS,
+	pub b: FS,
+}
+
+impl<FS: Field> BinaryCurveParams<FS> {
+	/// Returns whether the affine point `(x, y)` lies on the curve.
+	pub fn contains(&self, x: FS, y: FS) -> bool {
+		y.square() + x * y == x.square() * x + self.a * x.square() + self.b
+	}
+}
+
+/// The columns of a curve point.
+///
+/// `is_identity` is a column of [`BinaryField1b`] flags marking the rows that hold the point at
+/// infinity. The identity is encoded with both coordinates set to zero, which is not an affine
+/// point of the curve because `b` is non-zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PointCols {
+	pub x: OracleId,
+	pub y: OracleId,
+	pub is_identity: OracleId,
+}
+
+/// Adds two columns of curve points row by row.
+///
+/// Returns the columns of the sums, which are constrained by the affine addition formulas. The
+/// slope of the chord through distinct points, or of the tangent when doubling, is committed
+/// along with flags for the special cases: either input being the identity, and the inputs being
+/// inverses of each other, in which case the sum is the identity. Doubling a point of order two
+/// falls in the latter case.
+///
+/// ## Preconditions
+///
+/// * every non-identity input point lies on the curve
+pub fn binary_curve_add<FS>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	p1: PointCols,
+	p2: PointCols,
+	curve_params: &BinaryCurveParams<FS>,
+) -> Result<PointCols, anyhow::Error>
+where
+	U: PackScalar<FS> + PackScalar<B1> + Pod,
+	F: ExtensionField<FS>,
+	FS: TowerField + Pod,
+{
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([p1.x, p1.y, p1.is_identity, p2.x, p2.y, p2.is_identity])?;
+
+	// Flag and inverse witnessing whether x1 + x2 == 0.
+	let same_x = builder.add_committed("same_x", log_rows, B1::TOWER_LEVEL);
+	let same_x_inv = builder.add_committed("same_x_inv", log_rows, FS::TOWER_LEVEL);
+	// Flag and inverse witnessing whether y1 + y2 + x2 == 0, which means p2 == -p1 when the x
+	// coordinates are equal.
+	let negated_y = builder.add_committed("negated_y", log_rows, B1::TOWER_LEVEL);
+	let negated_y_inv = builder.add_committed("negated_y_inv", log_rows, FS::TOWER_LEVEL);
+	// Flag for the rows where both points are affine and the sum is computed by the formulas.
+	let regular = builder.add_committed("regular", log_rows, B1::TOWER_LEVEL);
+	let lambda = builder.add_committed("lambda", log_rows, FS::TOWER_LEVEL);
+	let sum = PointCols {
+		x: builder.add_committed("x", log_rows, FS::TOWER_LEVEL),
+		y: builder.add_committed("y", log_rows, FS::TOWER_LEVEL),
+		is_identity: builder.add_committed("is_identity", log_rows, B1::TOWER_LEVEL),
+	};
+
+	if let Some(witness) = builder.witness() {
+		let x1 = witness.get::<FS>(p1.x)?.as_slice::<FS>();
+		let y1 = witness.get::<FS>(p1.y)?.as_slice::<FS>();
+		let i1 = witness.get::<B1>(p1.is_identity)?.packed();
+		let x2 = witness.get::<FS>(p2.x)?.as_slice::<FS>();
+		let y2 = witness.get::<FS>(p2.y)?.as_slice::<FS>();
+		let i2 = witness.get::<B1>(p2.is_identity)?.packed();
+
+		let mut same_x = witness.new_column::<B1>(same_x);
+		let mut same_x_inv = witness.new_column::<FS>(same_x_inv);
+		let mut negated_y = witness.new_column::<B1>(negated_y);
+		let mut negated_y_inv = witness.new_column::<FS>(negated_y_inv);
+		let mut regular = witness.new_column::<B1>(regular);
+		let mut lambda = witness.new_column::<FS>(lambda);
+		let mut x3 = witness.new_column::<FS>(sum.x);
+		let mut y3 = witness.new_column::<FS>(sum.y);
+		let mut i3 = witness.new_column::<B1>(sum.is_identity);
+
+		let same_x = same_x.packed();
+		let same_x_inv = same_x_inv.as_mut_slice::<FS>();
+		let negated_y = negated_y.packed();
+		let negated_y_inv = negated_y_inv.as_mut_slice::<FS>();
+		let regular = regular.packed();
+		let lambda = lambda.as_mut_slice::<FS>();
+		let x3 = x3.as_mut_slice::<FS>();
+		let y3 = y3.as_mut_slice::<FS>();
+		let i3 = i3.packed();
+
+		for row in 0..1 << log_rows {
+			let (x1, y1, x2, y2) = (x1[row], y1[row], x2[row], y2[row]);
+			let is_identity_1 = get_packed_slice(i1, row) == B1::ONE;
+			let is_identity_2 = get_packed_slice(i2, row) == B1::ONE;
+
+			let dx = x1 + x2;
+			let dy = y1 + y2;
+			same_x_inv[row] = dx.invert_or_zero();
+			negated_y_inv[row] = (dy + x2).invert_or_zero();
+			let is_same_x = dx == FS::ZERO;
+			let is_negated = is_same_x && dy + x2 == FS::ZERO;
+			set_packed_slice(same_x, row, B1::from(is_same_x));
+			set_packed_slice(negated_y, row, B1::from(dy + x2 == FS::ZERO));
+
+			let is_regular = !is_identity_1 && !is_identity_2 && !is_negated;
+			set_packed_slice(regular, row, B1::from(is_regular));
+			set_packed_slice(
+				i3,
+				row,
+				B1::from(
+					(is_identity_1 && is_identity_2)
+						|| (!is_identity_1 && !is_identity_2 && is_negated),
+				),
+			);
+
+			(x3[row], y3[row]) = if is_regular {
+				let slope = if is_same_x {
+					x1 + y1 * x1.invert_or_zero()
+				} else {
+					dy * dx.invert_or_zero()
+				};
+				lambda[row] = slope;
+				let x = slope.square() + slope + dx + curve_params.a;
+				(x, slope * (x1 + x) + x + y1)
+			} else if is_identity_1 {
+				(x2, y2)
+			} else if is_identity_2 {
+				(x1, y1)
+			} else {
+				(FS::ZERO, FS::ZERO)
+			};
+		}
+	}
+
+	let oracle_ids = [
+		p1.x,
+		p1.y,
+		p1.is_identity,
+		p2.x,
+		p2.y,
+		p2.is_identity,
+		same_x,
+		same_x_inv,
+		negated_y,
+		negated_y_inv,
+		regular,
+		lambda,
+		sum.x,
+		sum.y,
+		sum.is_identity,
+	];
+	let [x1, y1, i1, x2, y2, i2, same_x, same_x_inv, negated_y, negated_y_inv, regular, lambda, x3, y3, i3] =
+		[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14].map(ArithCircuit::<F>::var);
+	let one = ArithCircuit::<F>::one();
+	let dx = x1.clone() + x2.clone();
+	let dy = y1.clone() + y2.clone();
+	let not_identity_1 = one.clone() + i1.clone();
+	let not_identity_2 = one.clone() + i2.clone();
+	let is_negated = same_x.clone() * negated_y.clone();
+
+	let constraints = [
+		// The identity is encoded as (0, 0).
+		("p1_identity_x", i1.clone() * x1.clone()),
+		("p1_identity_y", i1.clone() * y1.clone()),
+		("p2_identity_x", i2.clone() * x2.clone()),
+		("p2_identity_y", i2.clone() * y2.clone()),
+		// same_x == 1 iff dx == 0
+		("same_x_inv", dx.clone() * same_x_inv + one.clone() + same_x.clone()),
+		("same_x", dx.clone() * same_x.clone()),
+		// negated_y == 1 iff dy + x2 == 0
+		(
+			"negated_y_inv",
+			(dy.clone() + x2.clone()) * negated_y_inv + one.clone() + negated_y.clone(),
+		),
+		("negated_y", (dy.clone() + x2.clone()) * negated_y),
+		// Affine points with the same x coordinate are either equal or inverses.
+		("same_x_points", regular.clone() * same_x.clone() * dy.clone()),
+		(
+			"regular",
+			regular.clone()
+				+ not_identity_1.clone() * not_identity_2.clone() * (one + is_negated.clone()),
+		),
+		// lambda * (x1 + x2) == y1 + y2 for distinct points and lambda * x1 == x1^2 + y1 when
+		// doubling.
+		(
+			"lambda",
+			regular.clone()
+				* (lambda.clone() * dx.clone()
+					+ dy + same_x * (lambda.clone() * x1.clone() + x1.clone().pow(2) + y1.clone())),
+		),
+		(
+			"is_identity",
+			i3 + i1.clone() * i2.clone() + not_identity_1 * not_identity_2 * is_negated,
+		),
+		(
+			"x",
+			x3.clone()
+				+ regular.clone()
+					* (lambda.clone().pow(2)
+						+ lambda.clone() + dx
+						+ ArithCircuit::constant(F::from(curve_params.a)))
+				+ i1.clone() * x2
+				+ i2.clone() * x1.clone(),
+		),
+		("y", y3 + regular * (lambda * (x1 + x3.clone()) + x3 + y1.clone()) + i1 * y2 + i2 * y1),
+	];
+	for (name, constraint) in constraints {
+		builder.assert_zero(name, oracle_ids, constraint);
+	}
+
+	builder.pop_namespace();
+	Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{as_packed_field::PackedType, BinaryField8b};
+
+	use super::*;
+	use crate::builder::test_utils::test_circuit;
+
+	type B8 = BinaryField8b;
+
+	const PARAMS: BinaryCurveParams<B8> = BinaryCurveParams {
+		a: B8::new(1),
+		b: B8::new(1),
+	};
+
+	fn curve_points() -> Vec<(B8, B8)> {
+		(0..=255u8)
+			.flat_map(|x| (0..=255u8).map(move |y| (B8::new(x), B8::new(y))))
+			.filter(|&(x, y)| PARAMS.contains(x, y))
+			.collect()
+	}
+
+	fn negate(point: Option<(B8, B8)>) -> Option<(B8, B8)> {
+		point.map(|(x, y)| (x, x + y))
+	}
+
+	fn point_cols(
+		builder: &mut ConstraintSystemBuilder,
+		name: &str,
+		log_size: usize,
+		points: &[Option<(B8, B8)>],
+	) -> PointCols {
+		let point = PointCols {
+			x: builder.add_committed(format!("{name}_x"), log_size, B8::TOWER_LEVEL),
+			y: builder.add_committed(format!("{name}_y"), log_size, B8::TOWER_LEVEL),
+			is_identity: builder.add_committed(
+				format!("{name}_is_identity"),
+				log_size,
+				B1::TOWER_LEVEL,
+			),
+		};
+		if let Some(witness) = builder.witness() {
+			let mut x = witness.new_column::<B8>(point.x);
+			let mut y = witness.new_column::<B8>(point.y);
+			let mut is_identity = witness.new_column::<B1>(point.is_identity);
+			let (x, y, is_identity) =
+				(x.as_mut_slice::<B8>(), y.as_mut_slice::<B8>(), is_identity.packed());
+			for (row, &point) in points.iter().enumerate() {
+				(x[row], y[row]) = point.unwrap_or_default();
+				set_packed_slice(is_identity, row, B1::from(point.is_none()));
+			}
+		}
+		point
+	}
+
+	fn read_points(
+		builder: &mut ConstraintSystemBuilder,
+		point: PointCols,
+	) -> Vec<Option<(B8, B8)>> {
+		let witness = builder.witness().unwrap();
+		let x = witness.get::<B8>(point.x).unwrap().as_slice::<B8>();
+		let y = witness.get::<B8>(point.y).unwrap().as_slice::<B8>();
+		let is_identity = witness.get::<B1>(point.is_identity).unwrap().packed();
+		(0..x.len())
+			.map(|row| (get_packed_slice(is_identity, row) == B1::ZERO).then_some((x[row], y[row])))
+			.collect()
+	}
+
+	#[test]
+	fn test_binary_curve_add() {
+		test_circuit(|builder| {
+			let log_size = PackedType::<U, B1>::LOG_WIDTH;
+			let points = curve_points();
+			let order_two = points
+				.iter()
+				.copied()
+				.find(|&(x, _)| x == B8::ZERO)
+				.unwrap();
+			let p = Some(points[1]);
+			let q = points.iter().copied().find(|&(x, _)| x != points[1].0);
+
+			// The edge cases come first, followed by sums of arbitrary pairs of points.
+			let mut rows = vec![
+				(p, q),
+				(p, p),
+				(p, negate(p)),
+				(Some(order_two), Some(order_two)),
+				(None, p),
+				(p, None),
+				(None, None),
+			];
+			rows.extend((0..(1 << log_size) - rows.len()).map(|i| {
+				(Some(points[i % points.len()]), Some(points[(3 * i + 1) % points.len()]))
+			}));
+
+			let lhs = rows.iter().map(|&(lhs, _)| lhs).collect::<Vec<_>>();
+			let rhs = rows.iter().map(|&(_, rhs)| rhs).collect::<Vec<_>>();
+			let neg_rhs = rhs.iter().copied().map(negate).collect::<Vec<_>>();
+			let p1 = point_cols(builder, "p1", log_size, &lhs);
+			let p2 = point_cols(builder, "p2", log_size, &rhs);
+			let neg_p2 = point_cols(builder, "neg_p2", log_size, &neg_rhs);
+
+			let sum = binary_curve_add(builder, "sum", p1, p2, &PARAMS)?;
+			let diff = binary_curve_add(builder, "diff", sum, neg_p2, &PARAMS)?;
+
+			if builder.witness().is_some() {
+				let sum = read_points(builder, sum);
+				assert!(sum.iter().flatten().all(|&(x, y)| PARAMS.contains(x, y)));
+				assert_eq!(&sum[2..5], &[None, None, p]);
+				assert_eq!(&sum[5..7], &[p, None]);
+				assert_eq!(read_points(builder, diff), lhs);
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+}
@@ -2,160 +2,577 @@
 
 use std::array;
 
-use anyhow::Result;
+use anyhow::{ensure, Result};
 use array_util::ArrayExt;
-use binius_core::oracle::OracleId;
+use binius_core::{composition::DisjunctionComposition, oracle::OracleId};
 use binius_field::{
-	as_packed_field::{PackScalar, PackedType},
 	packed::{get_packed_slice, set_packed_slice},
-	AESTowerField8b, BinaryField1b, BinaryField8b, ExtensionField, PackedField, TowerField,
+	AESTowerField8b, BinaryField1b, BinaryField8b, ExtensionField, Field, PackedField, TowerField,
 };
-use binius_math::ArithExpr;
+use binius_math::{ArithCircuit, ArithExpr, CompositionPoly};
 use binius_maybe_rayon::prelude::*;
-use bytemuck::Pod;
+use binius_utils::bail;
 
-use crate::{builder::ConstraintSystemBuilder, transparent, unconstrained::unconstrained};
+use crate::{
+	builder::{types::F, ConstraintSystemBuilder},
+	plain_lookup, transparent,
+	unconstrained::unconstrained,
+};
+
+/// Parameters of a Grøstl permutation.
+///
+/// The default parameters are those of the Grøstl-256 permutations. Other values can be used to
+/// instantiate the wide Grøstl-512 permutations, or reduced-round and narrower toy variants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GroestlParams {
+	n_rounds: usize,
+	state_cols: usize,
+}
+
+impl GroestlParams {
+	/// Creates the parameters of a permutation with `n_rounds` rounds over a state of
+	/// `state_cols` 8-byte columns.
+	///
+	/// ## Throws
+	///
+	/// * if `n_rounds` is zero or greater than 256, as the round index is a byte
+	/// * if `state_cols` is zero or greater than 16, as the column index is a nibble
+	pub fn new(n_rounds: usize, state_cols: usize) -> Result<Self> {
+		ensure!(
+			(1..=MAX_ROUNDS).contains(&n_rounds),
+			"number of rounds must be between 1 and {MAX_ROUNDS}, got {n_rounds}"
+		);
+		ensure!(
+			(1..=MAX_STATE_COLS).contains(&state_cols),
+			"number of state columns must be between 1 and {MAX_STATE_COLS}, got {state_cols}"
+		);
+		Ok(Self {
+			n_rounds,
+			state_cols,
+		})
+	}
+
+	/// Parameters of the Grøstl-512 permutations over the 1024-bit state.
+	pub fn groestl_512() -> Self {
+		Self {
+			n_rounds: N_ROUNDS_1024,
+			state_cols: STATE_SIZE_1024 / 8,
+		}
+	}
+
+	/// Number of permutation rounds.
+	pub fn n_rounds(&self) -> usize {
+		self.n_rounds
+	}
+
+	/// Number of 8-byte columns in the state.
+	pub fn state_cols(&self) -> usize {
+		self.state_cols
+	}
 
-pub fn groestl_p_permutation<U, F>(
-	builder: &mut ConstraintSystemBuilder<U, F>,
+	/// Number of bytes in the state.
+	pub fn state_size(&self) -> usize {
+		8 * self.state_cols
+	}
+
+	/// The ShiftBytes schedule of a permutation.
+	///
+	/// States of up to 8 columns use the Grøstl-256 schedule and wider states use the Grøstl-512
+	/// one. Shifts are taken modulo the number of columns.
+	fn shifts(&self, permutation: Permutation) -> [usize; 8] {
+		match (permutation, self.state_cols <= STATE_SIZE / 8) {
+			(Permutation::P, true) => P512_SHIFTS,
+			(Permutation::Q, true) => Q512_SHIFTS,
+			(Permutation::P, false) => P1024_SHIFTS,
+			(Permutation::Q, false) => Q1024_SHIFTS,
+		}
+	}
+}
+
+impl Default for GroestlParams {
+	fn default() -> Self {
+		Self {
+			n_rounds: N_ROUNDS,
+			state_cols: STATE_SIZE / 8,
+		}
+	}
+}
+
+pub fn groestl_p_permutation(
+	builder: &mut ConstraintSystemBuilder,
 	log_size: usize,
-) -> Result<[OracleId; STATE_SIZE]>
-where
-	U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<AESTowerField8b> + Pod,
-	F: TowerField + ExtensionField<AESTowerField8b>,
-	PackedType<U, F>: Pod,
-{
-	let p_in = array_util::try_from_fn(|i| {
-		unconstrained::<U, F, AESTowerField8b>(builder, format!("p_in[{i}]"), log_size)
-	})?;
-	let multiples_16: [_; 8] = array::from_fn(|i| {
-		transparent::constant(
-			builder,
-			format!("multiples_16[{i}]"),
-			log_size,
-			AESTowerField8b::new(i as u8 * 0x10),
-		)
-		.unwrap()
-	});
+	params: GroestlParams,
+) -> Result<Vec<OracleId>> {
+	let (p_out, _rounds) = groestl_p_permutation_with_rounds(builder, log_size, params)?;
+	Ok(p_out)
+}
 
-	let round_consts = permutation_round_consts(builder, log_size, 0, multiples_16, p_in)?;
-	let mut output =
-		groestl_p_permutation_round(builder, "round[0]", log_size, round_consts, p_in)?;
-	for round_index in 1..N_ROUNDS {
-		let round_consts =
-			permutation_round_consts(builder, log_size, round_index, multiples_16, output)?;
-		output = groestl_p_permutation_round(
+/// Grøstl P permutation that also returns the output state of every round.
+///
+/// The returned vector holds the round outputs in order, so its last entry is the permutation
+/// output.
+pub fn groestl_p_permutation_with_rounds(
+	builder: &mut ConstraintSystemBuilder,
+	log_size: usize,
+	params: GroestlParams,
+) -> Result<(Vec<OracleId>, Vec<Vec<OracleId>>)> {
+	let p_in = (0..params.state_size())
+		.map(|i| unconstrained::<BinaryField8b>(builder, format!("p_in[{i}]"), log_size))
+		.collect::<Result<Vec<_>>>()?;
+	groestl_permutation_of(builder, log_size, params, Permutation::P, &p_in)
+}
+
+/// The two permutations of Grøstl, which differ in their round constants and ShiftBytes
+/// schedules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Permutation {
+	P,
+	Q,
+}
+
+/// Applies a Grøstl permutation to existing state oracles, returning the output state along with
+/// the output state of every round.
+fn groestl_permutation_of(
+	builder: &mut ConstraintSystemBuilder,
+	log_size: usize,
+	params: GroestlParams,
+	permutation: Permutation,
+	input: &[OracleId],
+) -> Result<(Vec<OracleId>, Vec<Vec<OracleId>>)> {
+	ensure!(
+		input.len() == params.state_size(),
+		"{permutation:?} permutation input has {} oracles, expected {}",
+		input.len(),
+		params.state_size()
+	);
+	let multiples_16 = (0..params.state_cols)
+		.map(|i| {
+			transparent::constant(
+				builder,
+				format!("multiples_16[{i}]"),
+				log_size,
+				aes_byte(i as u8 * 0x10),
+			)
+		})
+		.collect::<Result<Vec<_>>>()?;
+	let shifts = params.shifts(permutation);
+
+	let mut rounds = Vec::with_capacity(params.n_rounds);
+	let mut output = input.to_vec();
+	for round_index in 0..params.n_rounds {
+		let round_input = match permutation {
+			Permutation::P => {
+				let round_consts = permutation_round_consts(
+					builder,
+					log_size,
+					round_index,
+					&multiples_16,
+					&output,
+				)?;
+				with_round_consts_p(&round_consts, &output)
+			}
+			Permutation::Q => {
+				permutation_round_consts_q(builder, log_size, round_index, &multiples_16, &output)?
+			}
+		};
+		output = groestl_permutation_round(
 			builder,
 			format!("rounds[{round_index}]"),
 			log_size,
-			round_consts,
-			output,
+			&round_input,
+			shifts,
 		)?;
+		rounds.push(output.clone());
 	}
-	let p_out = output;
 
+	// The reference implementation only covers the default parameters.
 	#[cfg(debug_assertions)]
-	if let Some(witness) = builder.witness() {
-		use binius_field::PackedAESBinaryField64x8b;
-		use binius_hash::Groestl256Core;
-
-		let inputs = p_in
-			.try_map_ext(|id| witness.get::<AESTowerField8b>(id))?
-			.map(|col| col.as_slice::<AESTowerField8b>());
-		let outputs = p_out
-			.try_map_ext(|id| witness.get::<AESTowerField8b>(id))?
-			.map(|col| col.as_slice::<AESTowerField8b>());
-
-		for z in 0..1 << log_size {
-			assert_eq!(
-				Groestl256Core.permutation_p(PackedAESBinaryField64x8b::from_fn(|i| inputs[i][z])),
-				PackedAESBinaryField64x8b::from_fn(|i| outputs[i][z])
-			);
+	if params == GroestlParams::default() {
+		if let Some(witness) = builder.witness() {
+			use binius_hash::groestl::{GroestlShortImpl, GroestlShortInternal};
+
+			let inputs = witness_states(witness, input.try_into()?)?;
+			let outputs = witness_states(witness, output.as_slice().try_into()?)?;
+			for (input, output) in inputs.iter().zip(&outputs) {
+				let mut state = GroestlShortImpl::state_from_bytes(input);
+				match permutation {
+					Permutation::P => GroestlShortImpl::p_perm(&mut state),
+					Permutation::Q => GroestlShortImpl::q_perm(&mut state),
+				}
+				assert_eq!(&GroestlShortImpl::state_to_bytes(&state), output);
+			}
 		}
 	}
 
-	Ok(p_out)
+	Ok((output, rounds))
 }
 
-fn groestl_p_permutation_round<U, F>(
-	builder: &mut ConstraintSystemBuilder<U, F>,
+/// Grøstl-256 output transformation `trunc(P(x) + x)`.
+///
+/// Takes the chaining state `x` after the last compression, applies the P permutation, adds the
+/// input state back and keeps the last 256 bits, i.e. the last four columns of the state, as the
+/// digest bytes.
+pub fn groestl_output_transform(
+	builder: &mut ConstraintSystemBuilder,
 	name: impl ToString,
 	log_size: usize,
-	round_consts: [OracleId; 8],
-	input: [OracleId; STATE_SIZE],
-) -> Result<[OracleId; STATE_SIZE]>
-where
-	U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<AESTowerField8b> + Pod,
-	F: TowerField + ExtensionField<AESTowerField8b>,
-{
+	state: [OracleId; STATE_SIZE],
+) -> Result<[OracleId; DIGEST_SIZE]> {
 	builder.push_namespace(name);
 
-	let p_sub_bytes_out: [OracleId; STATE_SIZE] = array_util::try_from_fn(|i| {
-		groestl_p_permutation_sbox(
-			builder,
-			format!("s_box[{i}]"),
+	let (p_out, _rounds) = groestl_permutation_of(
+		builder,
+		log_size,
+		GroestlParams::default(),
+		Permutation::P,
+		&state,
+	)?;
+
+	let output: [_; DIGEST_SIZE] = array_util::try_from_fn(|i| {
+		let ij = STATE_SIZE - DIGEST_SIZE + i;
+		builder.add_linear_combination(
+			format!("output[{i}]"),
 			log_size,
-			if i % 8 == 0 {
-				round_consts[i / 8]
-			} else {
-				input[i]
-			},
+			[(p_out[ij], F::ONE), (state[ij], F::ONE)],
 		)
 	})?;
 
+	if let Some(witness) = builder.witness() {
+		{
+			let mut output_witness = output.map(|id| witness.new_column::<BinaryField8b>(id));
+			let p_out = p_out[STATE_SIZE - DIGEST_SIZE..]
+				.iter()
+				.map(|&id| witness.get::<BinaryField8b>(id))
+				.collect::<Result<Vec<_>, _>>()?;
+			let state = state[STATE_SIZE - DIGEST_SIZE..]
+				.iter()
+				.map(|&id| witness.get::<BinaryField8b>(id))
+				.collect::<Result<Vec<_>, _>>()?;
+
+			output_witness
+				.iter_mut()
+				.map(|col| col.packed())
+				.collect::<Vec<_>>()
+				.into_par_iter()
+				.enumerate()
+				.for_each(|(i, output)| {
+					(output, p_out[i].packed(), state[i].packed())
+						.into_par_iter()
+						.for_each(|(output, p_out, state)| {
+							*output = (*p_out) + (*state);
+						});
+				});
+		}
+
+		#[cfg(debug_assertions)]
+		{
+			use binius_hash::groestl::{GroestlShortImpl, GroestlShortInternal};
+
+			let inputs = witness_states(witness, &state)?;
+			let outputs = witness_states(witness, &output)?;
+			for (input, output) in inputs.iter().zip(&outputs) {
+				let mut state = GroestlShortImpl::state_from_bytes(input);
+				let mut p_out = state;
+				GroestlShortImpl::p_perm(&mut p_out);
+				GroestlShortImpl::xor_state(&mut state, &p_out);
+				let expected = GroestlShortImpl::state_to_bytes(&state);
+				assert_eq!(
+					&expected[STATE_SIZE - DIGEST_SIZE..],
+					output,
+					"output transformation mismatch"
+				);
+			}
+		}
+	}
+
+	builder.pop_namespace();
+	Ok(output)
+}
+
+/// Grøstl-256 compression function `f(h, m) = P(h + m) + Q(m) + h`.
+///
+/// Returns the next chaining value given the current chaining value `h` and a 512-bit message
+/// block `m`.
+pub fn groestl_compression(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	log_size: usize,
+	chaining_value: [OracleId; STATE_SIZE],
+	message_block: [OracleId; STATE_SIZE],
+) -> Result<[OracleId; STATE_SIZE]> {
+	builder.push_namespace(name);
+
+	let p_in = xor_states(builder, "p_in", log_size, [&chaining_value, &message_block])?;
+
+	builder.push_namespace("p");
+	let (p_out, _rounds) =
+		groestl_permutation_of(builder, log_size, GroestlParams::default(), Permutation::P, &p_in)?;
+	builder.pop_namespace();
+
+	builder.push_namespace("q");
+	let (q_out, _rounds) = groestl_permutation_of(
+		builder,
+		log_size,
+		GroestlParams::default(),
+		Permutation::Q,
+		&message_block,
+	)?;
+	builder.pop_namespace();
+
+	let output = xor_states(builder, "output", log_size, [&p_out, &q_out, &chaining_value])?;
+
+	builder.pop_namespace();
+	Ok(output
+		.try_into()
+		.expect("xor of states has the same size as the state"))
+}
+
+/// Grøstl-256 hash of a message given as a sequence of already padded 512-bit blocks.
+///
+/// The chaining value starts at the Grøstl-256 IV, is threaded through
+/// [`groestl_compression`] once per block and is finally passed to
+/// [`groestl_output_transform`]. The circuit size grows linearly with the number of blocks.
+pub fn groestl_hash(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	log_size: usize,
+	message_blocks: &[[OracleId; STATE_SIZE]],
+) -> Result<[OracleId; DIGEST_SIZE]> {
+	ensure!(!message_blocks.is_empty(), "Grøstl message must have at least one block");
+
+	builder.push_namespace(name);
+
+	let mut chaining_value: [_; STATE_SIZE] = array_util::try_from_fn(|i| {
+		transparent::constant(builder, format!("iv[{i}]"), log_size, groestl_256_iv_byte(i))
+	})?;
+	for (block_index, &message_block) in message_blocks.iter().enumerate() {
+		chaining_value = groestl_compression(
+			builder,
+			format!("compression[{block_index}]"),
+			log_size,
+			chaining_value,
+			message_block,
+		)?;
+	}
+	let digest = groestl_output_transform(builder, "output_transform", log_size, chaining_value)?;
+
+	builder.pop_namespace();
+	Ok(digest)
+}
+
+/// Byte `i` of the Grøstl-256 initial chaining value, which encodes the digest bit length as a
+/// big-endian integer in the last 8 bytes.
+fn groestl_256_iv_byte(i: usize) -> BinaryField8b {
+	let digest_bits = ((8 * DIGEST_SIZE) as u64).to_be_bytes();
+	let value = i
+		.checked_sub(STATE_SIZE - digest_bits.len())
+		.map_or(0, |offset| digest_bits[offset]);
+	aes_byte(value)
+}
+
+/// Maps a byte of the Grøstl specification, which is an [`AESTowerField8b`] element, to its
+/// isomorphic [`BinaryField8b`] representation used by the gadgets.
+pub(crate) fn aes_byte(byte: u8) -> BinaryField8b {
+	BinaryField8b::from(AESTowerField8b::new(byte))
+}
+
+/// Reads the witness of a state as bytes of the Grøstl specification, one array per row.
+#[cfg(any(debug_assertions, test))]
+pub(crate) fn witness_states<const N: usize>(
+	witness: &crate::builder::witness::Builder,
+	state: &[OracleId; N],
+) -> Result<Vec<[u8; N]>> {
+	let entries = state.try_map_ext(|id| witness.get::<BinaryField8b>(id))?;
+	let n_rows = entries.first().map_or(0, |entry| 1 << entry.low_rows());
+	let columns = entries
+		.each_ref()
+		.map(|entry| entry.as_slice::<BinaryField8b>());
+	Ok((0..n_rows)
+		.map(|z| array::from_fn(|i| AESTowerField8b::from(columns[i][z]).val()))
+		.collect())
+}
+
+/// Adds the byte-wise sum of several states, committing it as linear combinations.
+fn xor_states<const N: usize>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	log_size: usize,
+	states: [&[OracleId]; N],
+) -> Result<Vec<OracleId>> {
+	let n = states[0].len();
+	ensure!(states.iter().all(|state| state.len() == n), "states must have equal sizes");
+
+	builder.push_namespace(name);
+	let output = (0..n)
+		.map(|i| {
+			builder.add_linear_combination(
+				format!("output[{i}]"),
+				log_size,
+				states.map(|state| (state[i], F::ONE)),
+			)
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+
+	if let Some(witness) = builder.witness() {
+		let mut output_witness = output
+			.iter()
+			.map(|&id| witness.new_column::<BinaryField8b>(id))
+			.collect::<Vec<_>>();
+		let states = states
+			.iter()
+			.map(|state| {
+				state
+					.iter()
+					.map(|&id| witness.get::<BinaryField8b>(id))
+					.collect::<Result<Vec<_>, _>>()
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+
+		output_witness
+			.iter_mut()
+			.map(|col| col.packed())
+			.collect::<Vec<_>>()
+			.into_par_iter()
+			.enumerate()
+			.for_each(|(i, output)| {
+				for (z, output) in output.iter_mut().enumerate() {
+					*output = states.iter().map(|state| state[i].packed()[z]).sum();
+				}
+			});
+	}
+
+	builder.pop_namespace();
+	Ok(output)
+}
+
+/// Grøstl-512 P permutation, the 1024-bit wide variant of [`groestl_p_permutation`].
+///
+/// The state is an 8x16 matrix of bytes, stored column-major like the 512-bit variant, and the
+/// permutation runs with [`GroestlParams::groestl_512`].
+pub fn groestl_p_permutation_512(
+	builder: &mut ConstraintSystemBuilder,
+	log_size: usize,
+) -> Result<[OracleId; STATE_SIZE_1024]> {
+	let p_out = groestl_p_permutation(builder, log_size, GroestlParams::groestl_512())?;
+	Ok(p_out
+		.try_into()
+		.expect("permutation output has the same size as the state"))
+}
+
+/// Grøstl Q permutation, the permutation applied to the message block in the compression
+/// function.
+///
+/// It shares the round structure of [`groestl_p_permutation`] but adds the Q round constants to
+/// every byte of the state and uses the Q ShiftBytes schedule.
+pub fn groestl_q_permutation(
+	builder: &mut ConstraintSystemBuilder,
+	log_size: usize,
+	params: GroestlParams,
+) -> Result<Vec<OracleId>> {
+	let q_in = (0..params.state_size())
+		.map(|i| unconstrained::<BinaryField8b>(builder, format!("q_in[{i}]"), log_size))
+		.collect::<Result<Vec<_>>>()?;
+	let (q_out, _rounds) =
+		groestl_permutation_of(builder, log_size, params, Permutation::Q, &q_in)?;
+	Ok(q_out)
+}
+
+/// Replaces the first row of the P permutation state with the round constants added to it.
+fn with_round_consts_p(round_consts: &[OracleId], input: &[OracleId]) -> Vec<OracleId> {
+	debug_assert_eq!(input.len(), 8 * round_consts.len());
+	input
+		.iter()
+		.enumerate()
+		.map(|(i, &id)| if i % 8 == 0 { round_consts[i / 8] } else { id })
+		.collect()
+}
+
+/// A single round of a Grøstl permutation over a state of 8-byte columns.
+///
+/// The `round_input` is the state after AddRoundConstant, and `shifts[i]` is the number of
+/// positions row `i` is rotated to the left during ShiftBytes.
+fn groestl_permutation_round(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	log_size: usize,
+	round_input: &[OracleId],
+	shifts: [usize; 8],
+) -> Result<Vec<OracleId>> {
+	let n = round_input.len();
+	let n_cols = n / 8;
+	debug_assert_eq!(n, 8 * n_cols);
+
+	builder.push_namespace(name);
+
+	let p_sub_bytes_out = round_input
+		.iter()
+		.enumerate()
+		.map(|(i, &input)| {
+			groestl_p_permutation_sbox(builder, format!("s_box[{i}]"), log_size, input)
+		})
+		.collect::<Result<Vec<_>>>()?;
+
 	// Shift and mix bytes using committed columns
-	let output = builder.add_committed_multiple("output", log_size, BinaryField8b::TOWER_LEVEL);
+	let output = (0..n)
+		.map(|ij| {
+			builder.add_committed(format!("output_{ij}"), log_size, BinaryField8b::TOWER_LEVEL)
+		})
+		.collect::<Vec<_>>();
 
 	if let Some(witness) = builder.witness() {
-		let p_sub_bytes_out =
-			p_sub_bytes_out.try_map_ext(|id| witness.get::<AESTowerField8b>(id))?;
-		let mut output = output.map(|id| witness.new_column::<AESTowerField8b>(id));
+		let p_sub_bytes_out = p_sub_bytes_out
+			.iter()
+			.map(|&id| witness.get::<BinaryField8b>(id))
+			.collect::<Result<Vec<_>, _>>()?;
 		let mut output = output
+			.iter()
+			.map(|&id| witness.new_column::<BinaryField8b>(id))
+			.collect::<Vec<_>>();
+		// Split every output column into the same disjoint ranges of rows, so that each task
+		// fills one range of rows across all columns.
+		let chunk_size = 1 << log_size.min(MIX_BYTES_WITNESS_LOG_CHUNK_SIZE);
+		let mut column_chunks = output
 			.iter_mut()
-			.map(|col| col.as_mut_slice::<AESTowerField8b>())
+			.map(|col| col.as_mut_slice::<BinaryField8b>().chunks_mut(chunk_size))
+			.collect::<Vec<_>>();
+		let output_chunks = (0..(1 << log_size) / chunk_size)
+			.map(|_| {
+				column_chunks
+					.iter_mut()
+					.map(|chunks| chunks.next().expect("columns have 1 << log_size rows"))
+					.collect::<Vec<_>>()
+			})
 			.collect::<Vec<_>>();
 
-		let two = AESTowerField8b::new(2);
-		for z in 0..1 << log_size {
-			for j in 0..8 {
-				let a_j: [_; 8] = array::from_fn(|i| {
-					let shift_p = ((i + j) % 8) * 8 + i; // ShiftBytes & MixBytes
-					get_packed_slice(p_sub_bytes_out[shift_p].packed(), z)
-				});
-				for i in 0..8 {
-					let ij = j * 8 + i;
-					let a_i: [AESTowerField8b; 8] = array::from_fn(|k| a_j[(i + k) % 8]);
-					// Here we are using an optimized matrix multiplication, as documented in
-					// section 4.4.2 of https://www.groestl.info/groestl-implementation-guide.pdf
-					let b_ij = two
-						* (two * (a_i[3] + a_i[4] + a_i[6] + a_i[7])
-							+ a_i[0] + a_i[1] + a_i[2]
-							+ a_i[5] + a_i[7])
-						+ a_i[2] + a_i[4] + a_i[5]
-						+ a_i[6] + a_i[7];
-
-					output[ij][z] = b_ij;
+		output_chunks
+			.into_par_iter()
+			.enumerate()
+			.for_each(|(chunk_index, mut output)| {
+				for offset in 0..chunk_size {
+					let z = chunk_index * chunk_size + offset;
+					for j in 0..n_cols {
+						let a_j: [_; 8] = array::from_fn(|i| {
+							let shift_p = ((j + shifts[i]) % n_cols) * 8 + i; // ShiftBytes & MixBytes
+							get_packed_slice(p_sub_bytes_out[shift_p].packed(), z)
+						});
+						for (i, b_ij) in mix_bytes(a_j).into_iter().enumerate() {
+							output[j * 8 + i][offset] = b_ij;
+						}
+					}
 				}
-			}
-		}
+			});
 	}
 
-	for ij in 0..STATE_SIZE {
-		let i = ij / 8;
-		let j = ij % 8;
-
-		let mut mix_shift_oracles = [OracleId::default(); 9];
-		mix_shift_oracles[0] = output[ij];
-		for k in 0..8 {
-			let j_prime = (j + k) % 8;
-			let i_prime = (i + j_prime) % 8;
-			mix_shift_oracles[k + 1] = p_sub_bytes_out[i_prime * 8 + j_prime];
-		}
-		// This is not required if the columns are virtual
+	// Each column is constrained at once, which requires F to have degree at least 8 over the
+	// bytes; this is not required if the columns are virtual.
+	let mix_column_block = MixColumnBlock::<F>::new()?;
+	for i in 0..n_cols {
+		let outputs = (0..8).map(|j| output[i * 8 + j]);
+		let inputs = (0..8).map(|j| p_sub_bytes_out[((i + shifts[j]) % n_cols) * 8 + j]);
 		builder.assert_zero(
-			"mix_column_{ij}",
-			mix_shift_oracles,
-			mix_column_expr().convert_field(),
+			format!("mix_column_{i}"),
+			outputs.chain(inputs),
+			CompositionPoly::<F>::expression(&mix_column_block),
 		);
 	}
 
@@ -163,17 +580,17 @@ where
 	Ok(output)
 }
 
-fn groestl_p_permutation_sbox<U, F>(
-	builder: &mut ConstraintSystemBuilder<U, F>,
+/// The Rijndael S-box shared by Grøstl and AES, proven with an inversion constraint.
+///
+/// The inverse of the input byte is committed bit by bit, and the output is the affine
+/// transformation of those bits, so it is a linear combination rather than a committed column.
+pub fn groestl_p_permutation_sbox(
+	builder: &mut ConstraintSystemBuilder,
 	name: impl ToString,
 	log_size: usize,
 	input: OracleId,
-) -> Result<OracleId, anyhow::Error>
-where
-	U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<AESTowerField8b> + Pod,
-	F: TowerField + ExtensionField<AESTowerField8b>,
-{
-	builder.push_namespace(name);
+) -> Result<OracleId, anyhow::Error> {
+	let mut builder = builder.namespace(name);
 	let inv_bits: [OracleId; 8] =
 		builder.add_committed_multiple("inv_bits", log_size, BinaryField1b::TOWER_LEVEL);
 
@@ -181,37 +598,42 @@ where
 		"inv",
 		log_size,
 		(0..8).map(|b| {
-			let basis = <AESTowerField8b as ExtensionField<BinaryField1b>>::basis(b)
-				.expect("index is less than extension degree");
+			let basis = <BinaryField8b as ExtensionField<BinaryField1b>>::basis(b);
 			(inv_bits[b], basis.into())
 		}),
 	)?;
 
+	let (sbox_offset, sbox_matrix) = s_box_tower_affine();
 	let output = builder.add_linear_combination_with_offset(
 		"output",
 		log_size,
-		SBOX_VEC.into(),
-		(0..8).map(|b| (inv_bits[b], SBOX_MATRIX[b].into())),
+		sbox_offset.into(),
+		(0..8).map(|b| (inv_bits[b], sbox_matrix[b].into())),
 	)?;
 
 	if let Some(witness) = builder.witness() {
-		let input = witness
-			.get::<AESTowerField8b>(input)?
-			.as_slice::<AESTowerField8b>();
+		let input = witness.get::<BinaryField8b>(input)?;
 
 		let mut inv_bits_witness: [_; 8] =
 			inv_bits.map(|id| witness.new_column::<BinaryField1b>(id));
 		let inv_bits = inv_bits_witness.each_mut().map(|bit| bit.packed());
 
-		let mut inv = witness.new_column::<AESTowerField8b>(inv);
-		let inv = inv.as_mut_slice::<AESTowerField8b>();
+		let mut inv = witness.new_column::<BinaryField8b>(inv);
+
+		// Invert whole packed elements at once rather than lane by lane. Packed inversion is
+		// faster than Montgomery batch inversion here, see the `groestl_sbox` benchmark.
+		(inv.packed(), input.packed())
+			.into_par_iter()
+			.for_each(|(inv, input)| *inv = input.invert_or_zero());
+
+		let input = input.as_slice::<BinaryField8b>();
+		let inv = inv.as_mut_slice::<BinaryField8b>();
 
-		let mut output = witness.new_column::<AESTowerField8b>(output);
-		let output = output.as_mut_slice::<AESTowerField8b>();
+		let mut output = witness.new_column::<BinaryField8b>(output);
+		let output = output.as_mut_slice::<BinaryField8b>();
 
 		for z in 0..(1 << log_size) {
-			inv[z] = input[z].invert_or_zero();
-			output[z] = s_box(input[z]);
+			output[z] = s_box_tower(input[z]);
 			let inv_bits_bases = ExtensionField::<BinaryField1b>::iter_bases(&inv[z]);
 			for (b, bit) in inv_bits_bases.enumerate() {
 				set_packed_slice(inv_bits[b], z, bit);
@@ -220,60 +642,126 @@ where
 	}
 
 	builder.assert_zero("s_box", [input, inv], s_box_expr()?);
+	Ok(output)
+}
+
+/// An S-box gadget that is proven with a lookup instead of the algebraic inversion constraint.
+///
+/// The input and output pairs are looked up in a transparent 256-entry table of the S-box, so no
+/// inverse bits are committed. The output oracle has the same values as the one returned by
+/// [`groestl_p_permutation_sbox`], which makes the two interchangeable in a circuit.
+///
+/// ## Throws
+///
+/// * if `log_size` is not less than the log of the maximum multiplicity of a table entry
+pub fn groestl_p_permutation_sbox_lookup(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	log_size: usize,
+	input: OracleId,
+) -> Result<OracleId, anyhow::Error> {
+	ensure!(
+		log_size < SBOX_LOOKUP_LOG_MAX_MULTIPLICITY,
+		"S-box lookup supports less than 2^{SBOX_LOOKUP_LOG_MAX_MULTIPLICITY} rows, got 2^{log_size}"
+	);
+
+	builder.push_namespace(name);
+	let output = builder.add_committed("output", log_size, BinaryField8b::TOWER_LEVEL);
+
+	// Entry `k` of the table holds the specification byte `k`.
+	let table_in_values = (0..=u8::MAX).map(aes_byte).collect::<Vec<_>>();
+	let table_out_values = table_in_values
+		.iter()
+		.map(|&x| s_box_tower(x))
+		.collect::<Vec<_>>();
+	let table_in = transparent::make_transparent(builder, "table_in", &table_in_values)?;
+	let table_out = transparent::make_transparent(builder, "table_out", &table_out_values)?;
+
+	let multiplicities = if let Some(witness) = builder.witness() {
+		let input = witness
+			.get::<BinaryField8b>(input)?
+			.as_slice::<BinaryField8b>();
+
+		let mut output = witness.new_column::<BinaryField8b>(output);
+		let output = output.as_mut_slice::<BinaryField8b>();
+
+		let mut multiplicities = vec![0; table_in_values.len()];
+		for z in 0..(1 << log_size) {
+			output[z] = s_box_tower(input[z]);
+			multiplicities[AESTowerField8b::from(input[z]).val() as usize] += 1;
+		}
+		Some(multiplicities)
+	} else {
+		None
+	};
+
+	plain_lookup::plain_lookup::<BinaryField8b, SBOX_LOOKUP_LOG_MAX_MULTIPLICITY>(
+		builder,
+		"s_box_lookup",
+		&[1 << log_size],
+		&[[input, output]],
+		[table_in, table_out],
+		multiplicities,
+	)?;
+
 	builder.pop_namespace();
 	Ok(output)
 }
 
 // TODO: Get rid of round constants and bake them into the constraints
-fn permutation_round_consts<U, F>(
-	builder: &mut ConstraintSystemBuilder<U, F>,
+fn permutation_round_consts(
+	builder: &mut ConstraintSystemBuilder,
 	log_size: usize,
 	round_index: usize,
-	multiples_16: [OracleId; 8],
-	input: [OracleId; STATE_SIZE],
-) -> Result<[OracleId; 8], anyhow::Error>
-where
-	U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<AESTowerField8b> + Pod,
-	F: TowerField + ExtensionField<AESTowerField8b>,
-{
+	multiples_16: &[OracleId],
+	input: &[OracleId],
+) -> Result<Vec<OracleId>, anyhow::Error> {
 	let round = transparent::constant(
 		builder,
 		format!("round_index[{round_index}]"),
 		log_size,
-		AESTowerField8b::new(round_index as u8),
+		aes_byte(round_index as u8),
 	)?;
 
-	let round_consts: [OracleId; 8] = array_util::try_from_fn(|i| {
-		builder.add_linear_combination(
-			format!("round_consts[{i}]"),
-			log_size,
-			[
-				(input[8 * i], F::ONE),
-				(round, F::ONE),
-				(multiples_16[i], F::ONE),
-			],
-		)
-	})?;
+	let round_consts = multiples_16
+		.iter()
+		.enumerate()
+		.map(|(i, &multiple16)| {
+			builder.add_linear_combination(
+				format!("round_consts[{i}]"),
+				log_size,
+				[
+					(input[8 * i], F::ONE),
+					(round, F::ONE),
+					(multiple16, F::ONE),
+				],
+			)
+		})
+		.collect::<Result<Vec<_>, _>>()?;
 	if let Some(witness) = builder.witness() {
-		let mut round_consts_witness: [_; 8] =
-			round_consts.map(|id| witness.new_column::<AESTowerField8b>(id));
+		let mut round_consts_witness = round_consts
+			.iter()
+			.map(|&id| witness.new_column::<BinaryField8b>(id))
+			.collect::<Vec<_>>();
 		{
-			let input = input.try_map_ext(|id| witness.get::<AESTowerField8b>(id))?;
-			let round = witness.get::<AESTowerField8b>(round)?;
-			let multiples_16 = multiples_16.try_map_ext(|id| witness.get::<AESTowerField8b>(id))?;
+			let input = input
+				.iter()
+				.map(|&id| witness.get::<BinaryField8b>(id))
+				.collect::<Result<Vec<_>, _>>()?;
+			let round = witness.get::<BinaryField8b>(round)?;
+			let multiples_16 = multiples_16
+				.iter()
+				.map(|&id| witness.get::<BinaryField8b>(id))
+				.collect::<Result<Vec<_>, _>>()?;
 
 			round_consts_witness
-				.each_mut()
+				.iter_mut()
 				.map(|col| col.packed())
-				.par_iter_mut()
+				.collect::<Vec<_>>()
+				.into_par_iter()
 				.enumerate()
 				.for_each(|(i, round_consts)| {
-					(
-						&mut **round_consts,
-						input[8 * i].packed(),
-						round.packed(),
-						multiples_16[i].packed(),
-					)
+					(round_consts, input[8 * i].packed(), round.packed(), multiples_16[i].packed())
 						.into_par_iter()
 						.for_each(|(round_const, input, round, multiple16)| {
 							*round_const = (*input) + (*round) + (*multiple16);
@@ -284,15 +772,125 @@ where
 	Ok(round_consts)
 }
 
+/// Adds the Q permutation round constants to the full state.
+///
+/// Every byte of the state is XORed with `0xff`, and the bytes of the last row are additionally
+/// XORed with `(j << 4) ^ round_index`, where `j` is the column index.
+fn permutation_round_consts_q(
+	builder: &mut ConstraintSystemBuilder,
+	log_size: usize,
+	round_index: usize,
+	multiples_16: &[OracleId],
+	input: &[OracleId],
+) -> Result<Vec<OracleId>, anyhow::Error> {
+	debug_assert_eq!(input.len(), 8 * multiples_16.len());
+
+	let all_ones = aes_byte(0xff);
+	let last_row_offset = all_ones + aes_byte(round_index as u8);
+
+	let round_input = input
+		.iter()
+		.enumerate()
+		.map(|(ij, &input)| {
+			let name = format!("round_consts_q[{ij}]");
+			if ij % 8 == 7 {
+				builder.add_linear_combination_with_offset(
+					name,
+					log_size,
+					last_row_offset.into(),
+					[(input, F::ONE), (multiples_16[ij / 8], F::ONE)],
+				)
+			} else {
+				builder.add_linear_combination_with_offset(
+					name,
+					log_size,
+					all_ones.into(),
+					[(input, F::ONE)],
+				)
+			}
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+	if let Some(witness) = builder.witness() {
+		let mut round_input_witness = round_input
+			.iter()
+			.map(|&id| witness.new_column::<BinaryField8b>(id))
+			.collect::<Vec<_>>();
+		{
+			let input = input
+				.iter()
+				.map(|&id| witness.get::<BinaryField8b>(id))
+				.collect::<Result<Vec<_>, _>>()?;
+			let multiples_16 = multiples_16
+				.iter()
+				.map(|&id| witness.get::<BinaryField8b>(id))
+				.collect::<Result<Vec<_>, _>>()?;
+
+			round_input_witness
+				.iter_mut()
+				.map(|col| col.as_mut_slice::<BinaryField8b>())
+				.collect::<Vec<_>>()
+				.into_par_iter()
+				.enumerate()
+				.for_each(|(ij, round_input)| {
+					let input = input[ij].as_slice::<BinaryField8b>();
+					if ij % 8 == 7 {
+						let multiple16 = multiples_16[ij / 8].as_slice::<BinaryField8b>();
+						for z in 0..1 << log_size {
+							round_input[z] = input[z] + multiple16[z] + last_row_offset;
+						}
+					} else {
+						for z in 0..1 << log_size {
+							round_input[z] = input[z] + all_ones;
+						}
+					}
+				});
+		}
+	}
+	Ok(round_input)
+}
+
 /// Number of rounds in a Grøstl-256 compression
 const N_ROUNDS: usize = 10;
 
 const STATE_SIZE: usize = 64;
+/// Size in bytes of the Grøstl-256 digest.
+const DIGEST_SIZE: usize = 32;
+
+/// Log2 of the number of rows filled by one parallel task in the MixBytes witness computation.
+const MIX_BYTES_WITNESS_LOG_CHUNK_SIZE: usize = 10;
+
+/// Number of rounds in a Grøstl-512 compression
+const N_ROUNDS_1024: usize = 14;
+
+const STATE_SIZE_1024: usize = 128;
+
+/// Left rotation of each state row in the ShiftBytes step of P512.
+const P512_SHIFTS: [usize; 8] = [0, 1, 2, 3, 4, 5, 6, 7];
+/// Left rotation of each state row in the ShiftBytes step of Q512.
+const Q512_SHIFTS: [usize; 8] = [1, 3, 5, 7, 0, 2, 4, 6];
+/// Left rotation of each state row in the ShiftBytes step of P1024.
+const P1024_SHIFTS: [usize; 8] = [0, 1, 2, 3, 4, 5, 6, 11];
+/// Left rotation of each state row in the ShiftBytes step of Q1024.
+const Q1024_SHIFTS: [usize; 8] = [1, 3, 5, 11, 0, 2, 4, 6];
+
+/// Bound on the number of rounds, as the round index is added to the state as a byte.
+const MAX_ROUNDS: usize = 256;
+/// Bound on the number of state columns, as the column index is added to the state as the high
+/// nibble of a byte.
+const MAX_STATE_COLS: usize = 16;
+
+/// Bound on the number of S-box lookups in a single [`groestl_p_permutation_sbox_lookup`].
+const SBOX_LOOKUP_LOG_MAX_MULTIPLICITY: usize = 20;
 
 /// Constant vector of the Rijndael S-box affine transformation.
-const SBOX_VEC: AESTowerField8b = AESTowerField8b::new(0x63);
+///
+/// Shared by Grøstl and AES, which both use the Rijndael S-box.
+pub const SBOX_VEC: AESTowerField8b = AESTowerField8b::new(0x63);
 /// Matrix columns of the Rijndael S-box affine transformation.
-const SBOX_MATRIX: [AESTowerField8b; 8] = [
+///
+/// Column `i` is the image of the `i`-th bit of the field inverse, as used by the linear
+/// combination in the S-box gadget.
+pub const SBOX_MATRIX: [AESTowerField8b; 8] = [
 	AESTowerField8b::new(0b00011111),
 	AESTowerField8b::new(0b00111110),
 	AESTowerField8b::new(0b01111100),
@@ -303,28 +901,97 @@ const SBOX_MATRIX: [AESTowerField8b; 8] = [
 	AESTowerField8b::new(0b10001111),
 ];
 /// The first row of the circulant matrix defining the MixBytes step in Grøstl.
-const MIX_BYTES_VEC: [AESTowerField8b; 8] = [
-	AESTowerField8b::new(0x02),
-	AESTowerField8b::new(0x02),
-	AESTowerField8b::new(0x03),
-	AESTowerField8b::new(0x04),
-	AESTowerField8b::new(0x05),
-	AESTowerField8b::new(0x03),
-	AESTowerField8b::new(0x05),
-	AESTowerField8b::new(0x07),
-];
+const MIX_BYTES_VEC: [u8; 8] = [0x02, 0x02, 0x03, 0x04, 0x05, 0x03, 0x05, 0x07];
 
-fn mix_column_expr() -> ArithExpr<AESTowerField8b> {
-	let output = ArithExpr::<AESTowerField8b>::var(0);
-	let mixed = MIX_BYTES_VEC
-		.into_iter()
-		.enumerate()
-		.map(|(i, coeff)| ArithExpr::Var(i + 1) * ArithExpr::Const(coeff))
-		.sum::<ArithExpr<_>>();
-	mixed - output
+/// MixBytes applied to a single column `a` of the state after ShiftBytes.
+fn mix_bytes<P>(a: [P; 8]) -> [P; 8]
+where
+	P: PackedField<Scalar: ExtensionField<BinaryField8b>>,
+{
+	let two = P::Scalar::from(aes_byte(2));
+	array::from_fn(|i| {
+		let a_i: [P; 8] = array::from_fn(|k| a[(i + k) % 8]);
+		// Here we are using an optimized matrix multiplication, as documented in
+		// section 4.4.2 of https://www.groestl.info/groestl-implementation-guide.pdf
+		((a_i[3] + a_i[4] + a_i[6] + a_i[7]) * two + a_i[0] + a_i[1] + a_i[2] + a_i[5] + a_i[7])
+			* two + a_i[2]
+			+ a_i[4] + a_i[5]
+			+ a_i[6] + a_i[7]
+	})
+}
+
+/// The MixBytes step of a whole state column as a single composition.
+///
+/// The composition is over 16 variables: the 8 output bytes of the column followed by the 8
+/// input bytes after ShiftBytes. The 8 per-byte relations are combined using 8 basis elements of
+/// `F` over [`BinaryField8b`]. This is sound on the hypercube, where all the variables take
+/// values in the 8-bit subfield, so one zerocheck replaces 8 separate ones.
+#[derive(Debug, Clone)]
+pub struct MixColumnBlock<F> {
+	basis: [F; 8],
+}
+
+impl<F: ExtensionField<BinaryField8b>> MixColumnBlock<F> {
+	pub fn new() -> Result<Self> {
+		ensure!(
+			<F as ExtensionField<BinaryField8b>>::DEGREE >= 8,
+			"the field must have extension degree at least 8 over BinaryField8b"
+		);
+		Ok(Self {
+			basis: array::from_fn(<F as ExtensionField<BinaryField8b>>::basis),
+		})
+	}
+}
+
+impl<P> CompositionPoly<P> for MixColumnBlock<P::Scalar>
+where
+	P: PackedField<Scalar: TowerField + ExtensionField<BinaryField8b>>,
+{
+	fn n_vars(&self) -> usize {
+		16
+	}
+
+	fn degree(&self) -> usize {
+		1
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		CompositionPoly::<P>::expression(self).binary_tower_level()
+	}
+
+	fn expression(&self) -> ArithCircuit<P::Scalar> {
+		self.basis
+			.iter()
+			.enumerate()
+			.map(|(i, &basis)| {
+				let mixed = MIX_BYTES_VEC
+					.into_iter()
+					.enumerate()
+					.map(|(k, coeff)| {
+						ArithExpr::Var(8 + (i + k) % 8) * ArithExpr::Const(aes_byte(coeff).into())
+					})
+					.sum::<ArithExpr<_>>();
+				(mixed - ArithExpr::Var(i)) * ArithExpr::Const(basis)
+			})
+			.sum::<ArithExpr<_>>()
+			.into()
+	}
+
+	fn evaluate(&self, query: &[P]) -> Result<P, binius_math::Error> {
+		if query.len() != 16 {
+			bail!(binius_math::Error::IncorrectQuerySize { expected: 16 });
+		}
+		let mixed = mix_bytes::<P>(array::from_fn(|i| query[8 + i]));
+		Ok(mixed
+			.into_iter()
+			.zip(&self.basis)
+			.enumerate()
+			.map(|(i, (mixed, &basis))| (mixed - query[i]) * basis)
+			.sum())
+	}
 }
 
-fn s_box_expr<F: TowerField>() -> Result<ArithExpr<F>> {
+fn s_box_expr<F: TowerField>() -> Result<ArithCircuit<F>> {
 	let x = ArithExpr::Var(0);
 	let inv = ArithExpr::Var(1);
 
@@ -333,14 +1000,37 @@ fn s_box_expr<F: TowerField>() -> Result<ArithExpr<F>> {
 
 	// x == 0 AND inv == 0
 	// TODO: Implement `mul_primitive` expression for ArithExpr
-	let beta = <F as ExtensionField<BinaryField1b>>::basis(1 << 3)?;
+	let beta = <F as ExtensionField<BinaryField1b>>::basis_checked(1 << 3)?;
 	let zero_case = x + inv * ArithExpr::Const(beta);
 
 	// (x * inv == 1) OR (x == 0 AND inv == 0)
-	Ok(non_zero_case * zero_case)
+	let disjunction = DisjunctionComposition::new([non_zero_case.into(), zero_case.into()]);
+	Ok(disjunction.expression())
+}
+
+/// The Rijndael S-box affine transformation mapped to the [`BinaryField8b`] representation.
+///
+/// Returns the offset and the images of the [`BinaryField8b`] basis bits of the inverse, derived
+/// from [`SBOX_VEC`] and [`SBOX_MATRIX`].
+fn s_box_tower_affine() -> (BinaryField8b, [BinaryField8b; 8]) {
+	let matrix = array::from_fn(|b| {
+		let basis = <BinaryField8b as ExtensionField<BinaryField1b>>::basis(b);
+		let image = ExtensionField::<BinaryField1b>::iter_bases(&AESTowerField8b::from(basis))
+			.zip(SBOX_MATRIX)
+			.filter(|&(bit, _)| bit == BinaryField1b::ONE)
+			.map(|(_, col)| col)
+			.sum::<AESTowerField8b>();
+		BinaryField8b::from(image)
+	});
+	(BinaryField8b::from(SBOX_VEC), matrix)
 }
 
-fn s_box(x: AESTowerField8b) -> AESTowerField8b {
+/// The Rijndael S-box on the [`BinaryField8b`] representation of bytes.
+pub(crate) fn s_box_tower(x: BinaryField8b) -> BinaryField8b {
+	BinaryField8b::from(s_box(AESTowerField8b::from(x)))
+}
+
+pub(crate) fn s_box(x: AESTowerField8b) -> AESTowerField8b {
 	#[rustfmt::skip]
 	const S_BOX: [u8; 256] = [
 		0x63, 0x7c, 0x77, 0x7b, 0xf2, 0x6b, 0x6f, 0xc5,
@@ -382,25 +1072,414 @@ fn s_box(x: AESTowerField8b) -> AESTowerField8b {
 
 #[cfg(test)]
 mod tests {
-	use binius_core::constraint_system::validate::validate_witness;
-	use binius_field::{arch::OptimalUnderlier, AESTowerField16b};
+	use std::array;
+
+	use anyhow::Result;
+	use binius_core::{constraint_system::validate::validate_witness, oracle::OracleId};
+	use binius_field::{
+		arithmetic_traits::InvertOrZero, AESTowerField8b, BinaryField128b, BinaryField16b,
+		BinaryField1b, BinaryField8b, ExtensionField, Field, TowerField,
+	};
+	use binius_hash::groestl::{Groestl256, GroestlShortImpl, GroestlShortInternal};
+	use binius_math::CompositionPoly;
+	use digest::Digest;
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::{
+		aes_byte, groestl_hash, groestl_output_transform, groestl_p_permutation,
+		groestl_p_permutation_512, groestl_p_permutation_sbox, groestl_p_permutation_sbox_lookup,
+		groestl_p_permutation_with_rounds, groestl_permutation_round, groestl_q_permutation,
+		mix_bytes, s_box, s_box_tower, s_box_tower_affine, witness_states, GroestlParams,
+		MixColumnBlock, DIGEST_SIZE, MAX_ROUNDS, MAX_STATE_COLS, MIX_BYTES_VEC,
+		MIX_BYTES_WITNESS_LOG_CHUNK_SIZE, P1024_SHIFTS, P512_SHIFTS, Q1024_SHIFTS, Q512_SHIFTS,
+		SBOX_LOOKUP_LOG_MAX_MULTIPLICITY, SBOX_MATRIX, SBOX_VEC, STATE_SIZE,
+	};
+	use crate::{
+		builder::{test_utils::test_circuit, ConstraintSystemBuilder},
+		transparent,
+		unconstrained::unconstrained,
+	};
+
+	#[test]
+	fn test_mix_column_block() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let block = MixColumnBlock::<BinaryField128b>::new().unwrap();
+		let expr = CompositionPoly::<BinaryField128b>::expression(&block);
+		assert_eq!(CompositionPoly::<BinaryField128b>::degree(&block), 1);
+
+		let inputs: [BinaryField8b; 8] = array::from_fn(|_| BinaryField8b::random(&mut rng));
+		let outputs = mix_bytes(inputs);
+		let mut query = outputs
+			.into_iter()
+			.chain(inputs)
+			.map(BinaryField128b::from)
+			.collect::<Vec<_>>();
+		assert_eq!(block.evaluate(&query).unwrap(), BinaryField128b::ZERO);
+		assert_eq!(expr.evaluate(&query).unwrap(), BinaryField128b::ZERO);
+
+		query[3] += BinaryField128b::ONE;
+		assert_ne!(block.evaluate(&query).unwrap(), BinaryField128b::ZERO);
+
+		let query = (0..16)
+			.map(|_| BinaryField128b::random(&mut rng))
+			.collect::<Vec<_>>();
+		assert_eq!(block.evaluate(&query).unwrap(), expr.evaluate(&query).unwrap());
+
+		assert!(MixColumnBlock::<BinaryField16b>::new().is_err());
+	}
+
+	/// Computes the S-box, ShiftBytes and MixBytes steps of a round serially, with the MixBytes
+	/// circulant matrix multiplication written out.
+	fn serial_round(
+		round_input: &[Vec<BinaryField8b>],
+		shifts: [usize; 8],
+		n_rows: usize,
+	) -> Vec<Vec<BinaryField8b>> {
+		let n_cols = round_input.len() / 8;
+		let mut output = vec![vec![BinaryField8b::ZERO; n_rows]; round_input.len()];
+		for z in 0..n_rows {
+			for j in 0..n_cols {
+				for i in 0..8 {
+					output[j * 8 + i][z] = (0..8)
+						.map(|k| {
+							let row = (i + k) % 8;
+							let col = (j + shifts[row]) % n_cols;
+							aes_byte(MIX_BYTES_VEC[k]) * s_box_tower(round_input[col * 8 + row][z])
+						})
+						.sum();
+				}
+			}
+		}
+		output
+	}
+
+	fn check_round_witness_matches_serial(n_cols: usize, shifts: [usize; 8]) {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		// Spans several MixBytes witness chunks.
+		let log_size = MIX_BYTES_WITNESS_LOG_CHUNK_SIZE + 2;
+		let round_input = (0..8 * n_cols)
+			.map(|i| {
+				unconstrained::<BinaryField8b>(&mut builder, format!("round_input[{i}]"), log_size)
+			})
+			.collect::<Result<Vec<_>>>()
+			.unwrap();
+		let output =
+			groestl_permutation_round(&mut builder, "round", log_size, &round_input, shifts)
+				.unwrap();
+
+		let witness = builder.witness().unwrap();
+		let read_columns = |ids: &[OracleId]| {
+			ids.iter()
+				.map(|&id| {
+					witness
+						.get::<BinaryField8b>(id)
+						.unwrap()
+						.as_slice::<BinaryField8b>()
+						.to_vec()
+				})
+				.collect::<Vec<_>>()
+		};
+		let expected = serial_round(&read_columns(&round_input), shifts, 1 << log_size);
+		assert_eq!(read_columns(&output), expected);
+	}
+
+	#[test]
+	fn test_round_witness_matches_serial() {
+		check_round_witness_matches_serial(8, P512_SHIFTS);
+		check_round_witness_matches_serial(8, Q512_SHIFTS);
+		check_round_witness_matches_serial(16, P1024_SHIFTS);
+		check_round_witness_matches_serial(16, Q1024_SHIFTS);
+	}
+
+	#[test]
+	fn test_groestl_round_uses_mix_column_block() {
+		let mut builder = ConstraintSystemBuilder::new();
+		let log_size = 4;
+		let params = GroestlParams::new(1, 8).unwrap();
+		groestl_p_permutation(&mut builder, log_size, params).unwrap();
+		let constraint_system = builder.build().unwrap();
+
+		let mix_column_constraints = constraint_system
+			.table_constraints
+			.iter()
+			.flat_map(|constraint_set| &constraint_set.constraints)
+			.filter(|constraint| constraint.name.contains("mix_column_"))
+			.collect::<Vec<_>>();
+		// One constraint per state column, each over 8 output and 8 input bytes.
+		assert_eq!(mix_column_constraints.len(), params.state_cols());
+		for constraint in mix_column_constraints {
+			let n_used_vars = constraint
+				.composition
+				.vars_usage()
+				.into_iter()
+				.filter(|&used| used)
+				.count();
+			assert_eq!(n_used_vars, 16);
+			assert_eq!(constraint.composition.degree(), 1);
+		}
+	}
+
+	#[test]
+	fn test_sbox_affine_constants() {
+		for byte in 0..=u8::MAX {
+			let x = AESTowerField8b::new(byte);
+			let affine = ExtensionField::<BinaryField1b>::iter_bases(&x.invert_or_zero())
+				.zip(SBOX_MATRIX)
+				.filter(|&(bit, _)| bit == BinaryField1b::ONE)
+				.map(|(_, col)| col)
+				.sum::<AESTowerField8b>();
+			assert_eq!(affine + SBOX_VEC, s_box(x));
+
+			let x = aes_byte(byte);
+			let (sbox_offset, sbox_matrix) = s_box_tower_affine();
+			let affine = ExtensionField::<BinaryField1b>::iter_bases(&x.invert_or_zero())
+				.zip(sbox_matrix)
+				.filter(|&(bit, _)| bit == BinaryField1b::ONE)
+				.map(|(_, col)| col)
+				.sum::<BinaryField8b>();
+			assert_eq!(affine + sbox_offset, s_box_tower(x));
+		}
+	}
+
+	#[test]
+	fn test_groestl_sbox_lookup() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let log_size = 4;
+		let input = unconstrained::<BinaryField8b>(&mut builder, "input", log_size).unwrap();
+		let output = groestl_p_permutation_sbox(&mut builder, "s_box", log_size, input).unwrap();
+		let output_lookup =
+			groestl_p_permutation_sbox_lookup(&mut builder, "s_box_lookup", log_size, input)
+				.unwrap();
+
+		let witness = builder.witness().unwrap();
+		assert_eq!(
+			witness
+				.get::<BinaryField8b>(output)
+				.unwrap()
+				.as_slice::<BinaryField8b>(),
+			witness
+				.get::<BinaryField8b>(output_lookup)
+				.unwrap()
+				.as_slice::<BinaryField8b>(),
+		);
 
-	use super::groestl_p_permutation;
-	use crate::builder::ConstraintSystemBuilder;
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		let boundaries = vec![];
+		validate_witness(&constraint_system, &boundaries, &witness).unwrap();
+	}
+
+	#[test]
+	fn test_groestl_sbox_lookup_too_large() {
+		let mut builder = ConstraintSystemBuilder::new();
+		let log_size = SBOX_LOOKUP_LOG_MAX_MULTIPLICITY;
+		let input = builder.add_committed("input", log_size, BinaryField8b::TOWER_LEVEL);
+		assert!(groestl_p_permutation_sbox_lookup(&mut builder, "s_box_lookup", log_size, input)
+			.is_err());
+	}
+
+	#[test]
+	fn test_groestl_512() {
+		test_circuit(|builder| {
+			let log_size = 4;
+			let _state_out = groestl_p_permutation_512(builder, log_size)?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
 
 	#[test]
 	fn test_groestl() {
+		test_circuit(|builder| {
+			let log_size = 4;
+			let _state_out = groestl_p_permutation(builder, log_size, GroestlParams::default())?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_groestl_output_transform() {
+		test_circuit(|builder| {
+			let log_size = 4;
+			let state: [_; STATE_SIZE] = array_util::try_from_fn(|i| {
+				unconstrained::<BinaryField8b>(builder, format!("state[{i}]"), log_size)
+			})?;
+			let digest = groestl_output_transform(builder, "output_transform", log_size, state)?;
+			assert_eq!(digest.len(), DIGEST_SIZE);
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_groestl_hash_two_blocks() {
 		let allocator = bumpalo::Bump::new();
-		let mut builder =
-			ConstraintSystemBuilder::<OptimalUnderlier, AESTowerField16b>::new_with_witness(
-				&allocator,
-			);
-		let log_size = 9;
-		let _state_out = groestl_p_permutation(&mut builder, log_size).unwrap();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let log_size = 4;
+		let message_blocks: [[_; STATE_SIZE]; 2] = array::from_fn(|block_index| {
+			array::from_fn(|i| {
+				unconstrained::<BinaryField8b>(
+					&mut builder,
+					format!("message[{block_index}][{i}]"),
+					log_size,
+				)
+				.unwrap()
+			})
+		});
+		let digest = groestl_hash(&mut builder, "groestl", log_size, &message_blocks).unwrap();
+
+		{
+			let witness = builder.witness().unwrap();
+			let message_blocks = message_blocks
+				.iter()
+				.map(|block| witness_states(witness, block).unwrap())
+				.collect::<Vec<_>>();
+			let digest = witness_states(witness, &digest).unwrap();
+			for (z, actual) in digest.iter().enumerate() {
+				let mut iv = [0u8; STATE_SIZE];
+				iv[STATE_SIZE - 8..].copy_from_slice(&((8 * DIGEST_SIZE) as u64).to_be_bytes());
+				let mut state = GroestlShortImpl::state_from_bytes(&iv);
+				for block in &message_blocks {
+					GroestlShortImpl::compress(&mut state, &block[z]);
+				}
+				let mut p_out = state;
+				GroestlShortImpl::p_perm(&mut p_out);
+				GroestlShortImpl::xor_state(&mut state, &p_out);
+				let expected = GroestlShortImpl::state_to_bytes(&state);
+
+				assert_eq!(actual[..], expected[STATE_SIZE - DIGEST_SIZE..]);
+			}
+		}
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		let boundaries = vec![];
+		validate_witness(&constraint_system, &boundaries, &witness).unwrap();
+	}
+
+	/// Pads a message into Grøstl-256 blocks: a single one bit, zeros, and the number of blocks as
+	/// a 64-bit big-endian integer.
+	fn pad_message(message: &[u8]) -> Vec<[u8; STATE_SIZE]> {
+		let n_blocks = (message.len() + 1 + 8).div_ceil(STATE_SIZE);
+		let mut padded = vec![0u8; n_blocks * STATE_SIZE];
+		padded[..message.len()].copy_from_slice(message);
+		padded[message.len()] = 0x80;
+		padded[n_blocks * STATE_SIZE - 8..].copy_from_slice(&(n_blocks as u64).to_be_bytes());
+		padded
+			.chunks_exact(STATE_SIZE)
+			.map(|block| block.try_into().expect("chunk has the size of a block"))
+			.collect()
+	}
+
+	/// Hashes a fixed message in the circuit and compares the digest with [`Groestl256`].
+	fn check_groestl_hash_known_answer(message: &[u8]) {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let log_size = 4;
+		let message_blocks = pad_message(message)
+			.iter()
+			.enumerate()
+			.map(|(block_index, block)| {
+				array_util::try_from_fn(|i| {
+					transparent::constant(
+						&mut builder,
+						format!("message[{block_index}][{i}]"),
+						log_size,
+						aes_byte(block[i]),
+					)
+				})
+			})
+			.collect::<Result<Vec<_>>>()
+			.unwrap();
+		let digest = groestl_hash(&mut builder, "groestl", log_size, &message_blocks).unwrap();
+
+		let expected = Groestl256::digest(message);
+		let digests = witness_states(builder.witness().unwrap(), &digest).unwrap();
+		for actual in digests {
+			assert_eq!(actual[..], expected[..]);
+		}
 
 		let witness = builder.take_witness().unwrap();
 		let constraint_system = builder.build().unwrap();
 		let boundaries = vec![];
 		validate_witness(&constraint_system, &boundaries, &witness).unwrap();
 	}
+
+	#[test]
+	fn test_groestl_hash_known_answer() {
+		check_groestl_hash_known_answer(b"abc");
+	}
+
+	#[test]
+	fn test_groestl_hash_two_blocks_known_answer() {
+		let message = (0..100).collect::<Vec<u8>>();
+		assert_eq!(pad_message(&message).len(), 2);
+		check_groestl_hash_known_answer(&message);
+	}
+
+	#[test]
+	fn test_groestl_reduced_rounds() {
+		test_circuit(|builder| {
+			let log_size = 4;
+			let params = GroestlParams::new(4, 8)?;
+			let (state_out, rounds) = groestl_p_permutation_with_rounds(builder, log_size, params)?;
+			assert_eq!(state_out.len(), params.state_size());
+			assert_eq!(rounds.len(), params.n_rounds());
+			assert_eq!(rounds.last(), Some(&state_out));
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_groestl_with_rounds() {
+		test_circuit(|builder| {
+			let log_size = 4;
+			let params = GroestlParams::default();
+			// The permutation output is checked against the reference in debug builds.
+			let (state_out, rounds) = groestl_p_permutation_with_rounds(builder, log_size, params)?;
+			assert_eq!(rounds.len(), params.n_rounds());
+			assert!(rounds
+				.iter()
+				.all(|round| round.len() == params.state_size()));
+			assert_eq!(rounds.last(), Some(&state_out));
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_groestl_params() {
+		assert!(GroestlParams::new(0, 8).is_err());
+		assert!(GroestlParams::new(MAX_ROUNDS + 1, 8).is_err());
+		assert!(GroestlParams::new(10, 0).is_err());
+		assert!(GroestlParams::new(10, MAX_STATE_COLS + 1).is_err());
+		assert_eq!(GroestlParams::new(10, 8).unwrap(), GroestlParams::default());
+		assert_eq!(GroestlParams::new(14, 16).unwrap(), GroestlParams::groestl_512());
+	}
+
+	#[test]
+	fn test_groestl_narrow_q() {
+		test_circuit(|builder| {
+			let log_size = 4;
+			let params = GroestlParams::new(2, 4)?;
+			let state_out = groestl_q_permutation(builder, log_size, params)?;
+			assert_eq!(state_out.len(), params.state_size());
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_groestl_q() {
+		test_circuit(|builder| {
+			let log_size = 4;
+			let _state_out = groestl_q_permutation(builder, log_size, GroestlParams::default())?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
 }
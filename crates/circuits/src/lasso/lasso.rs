@@ -186,5 +186,6 @@ where
 			.map(OracleOrConst::Oracle),
 	)?;
 
+	builder.pop_namespace();
 	Ok(())
 }
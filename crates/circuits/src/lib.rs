@@ -8,11 +8,14 @@
 
 #![allow(clippy::module_inception)]
 
+pub mod aes;
 pub mod arithmetic;
 pub mod bitwise;
 pub mod blake3;
 pub mod builder;
 pub mod collatz;
+pub mod ec;
+pub mod groestl;
 pub mod keccakf;
 pub mod lasso;
 mod pack;
@@ -25,24 +28,30 @@ pub mod vision;
 
 #[cfg(test)]
 mod tests {
+	use assert_matches::assert_matches;
 	use binius_core::{
 		constraint_system::{
 			self,
 			channel::{validate_witness, Boundary, FlushDirection, OracleOrConst},
+			ConstraintSystem, OracleKind, Proof,
 		},
 		fiat_shamir::HasherChallenger,
-		oracle::{OracleId, ShiftVariant},
+		oracle::{Error as OracleError, OracleId, ShiftVariant},
 		polynomial::ArithCircuitPoly,
+		witness::MultilinearExtensionIndex,
 	};
 	use binius_field::{
-		arch::OptimalUnderlier, as_packed_field::PackedType, tower::CanonicalTowerFamily,
-		underlier::WithUnderlier, BinaryField128b, BinaryField1b, BinaryField64b, BinaryField8b,
-		Field, TowerField,
+		as_packed_field::PackedType, tower::CanonicalTowerFamily, underlier::WithUnderlier,
+		BinaryField128b, BinaryField1b, BinaryField64b, BinaryField8b, Field, TowerField,
+	};
+	use binius_hal::{make_portable_backend, CpuBackend};
+	use binius_hash::{
+		groestl::{Groestl256, Groestl256ByteCompression},
+		keccak::{Keccak256, Keccak256ByteCompression},
 	};
-	use binius_hal::make_portable_backend;
-	use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
 	use binius_macros::arith_expr;
 	use binius_math::CompositionPoly;
+	use binius_utils::{DeserializeBytes, SerializationError, SerializationMode, SerializeBytes};
 	use rand::{seq::SliceRandom, thread_rng};
 
 	type B128 = BinaryField128b;
@@ -149,25 +158,81 @@ mod tests {
 		let constraint_system = builder.build().unwrap();
 
 		let backend = make_portable_backend();
+		let proof =
+			prove_groestl(&constraint_system, 1, 10, &boundaries, witness, &backend).unwrap();
+		verify_groestl(&constraint_system, 1, 10, &boundaries, proof).unwrap();
+	}
 
-		let proof = constraint_system::prove::<
-			U,
-			CanonicalTowerFamily,
-			Groestl256,
-			Groestl256ByteCompression,
-			HasherChallenger<Groestl256>,
-			_,
-		>(&constraint_system, 1, 10, &boundaries, witness, &backend)
-		.unwrap();
+	#[test]
+	fn test_malformed_proof() {
+		let (constraint_system, _, proof) = prove_circuit(
+			|builder| x_times_y_circuit(builder, PackedType::<U, BinaryField8b>::LOG_WIDTH + 2),
+			1,
+			10,
+		);
 
-		constraint_system::verify::<
-			U,
-			CanonicalTowerFamily,
-			Groestl256,
-			Groestl256ByteCompression,
-			HasherChallenger<Groestl256>,
-		>(&constraint_system, 1, 10, &boundaries, proof)
-		.unwrap();
+		let verify = |proof| verify_groestl(&constraint_system, 1, 10, &[], proof);
+
+		// The round count comes from the constraint system, so trailing rounds are rejected.
+		let mut inflated = proof.clone();
+		inflated.transcript.extend(vec![0; 3 * size_of::<B128>()]);
+		assert_matches!(
+			verify(inflated),
+			Err(constraint_system::error::Error::Verification(
+				constraint_system::error::VerificationError::TranscriptMismatch { .. }
+			))
+		);
+
+		let mut truncated = proof.clone();
+		truncated.transcript.truncate(proof.transcript.len() / 2);
+		assert_matches!(
+			verify(truncated),
+			Err(constraint_system::error::Error::Verification(
+				constraint_system::error::VerificationError::TranscriptMismatch { .. }
+			))
+		);
+
+		verify(proof).unwrap();
+	}
+
+	#[test]
+	fn test_verification_errors() {
+		let (constraint_system, _, proof) =
+			prove_circuit(|builder| x_times_y_circuit(builder, 8), 1, 100);
+
+		let verify =
+			|constraint_system: &ConstraintSystem<F>, boundaries: &[Boundary<F>], proof| {
+				verify_groestl(constraint_system, 1, 100, boundaries, proof)
+			};
+
+		// The first message after the commitment is the zerocheck univariate round.
+		let mut tampered = proof.clone();
+		tampered.transcript[32] ^= 1;
+		assert_matches!(
+			verify(&constraint_system, &[], tampered),
+			Err(constraint_system::error::Error::Verification(
+				constraint_system::error::VerificationError::SumcheckFailed { round: Some(_) }
+			))
+		);
+
+		// The proof ends with the Merkle openings of the FRI queries.
+		let mut tampered = proof;
+		*tampered.transcript.last_mut().unwrap() ^= 1;
+		assert_matches!(
+			verify(&constraint_system, &[], tampered),
+			Err(constraint_system::error::Error::Verification(
+				constraint_system::error::VerificationError::MerkleOpeningInvalid
+			))
+		);
+
+		let (constraint_system, mut boundaries, proof) = prove_circuit(collatz_circuit, 1, 100);
+		boundaries[0].values[0] += F::ONE;
+		assert_matches!(
+			verify(&constraint_system, &boundaries, proof),
+			Err(constraint_system::error::Error::Verification(
+				constraint_system::error::VerificationError::ChannelUnbalanced { .. }
+			))
+		);
 	}
 
 	#[test]
@@ -366,24 +431,10 @@ mod tests {
 
 		validate_witness(&witness, &[], &[], 1).unwrap();
 
-		let proof = binius_core::constraint_system::prove::<
-			OptimalUnderlier,
-			CanonicalTowerFamily,
-			Groestl256,
-			Groestl256ByteCompression,
-			HasherChallenger<Groestl256>,
-			_,
-		>(&constraint_system, log_inv_rate, security_bits, &[], witness, &backend)
-		.unwrap();
-
-		binius_core::constraint_system::verify::<
-			OptimalUnderlier,
-			CanonicalTowerFamily,
-			Groestl256,
-			Groestl256ByteCompression,
-			HasherChallenger<Groestl256>,
-		>(&constraint_system, log_inv_rate, security_bits, &[], proof)
-		.unwrap();
+		let proof =
+			prove_groestl(&constraint_system, log_inv_rate, security_bits, &[], witness, &backend)
+				.unwrap();
+		verify_groestl(&constraint_system, log_inv_rate, security_bits, &[], proof).unwrap();
 	}
 
 	#[test]
@@ -465,4 +516,581 @@ mod tests {
 		})
 		.unwrap()
 	}
+
+	type BuildCircuit = fn(&mut ConstraintSystemBuilder) -> Result<Vec<Boundary<F>>, anyhow::Error>;
+
+	type Witness<'a> = MultilinearExtensionIndex<'a, PackedType<U, F>>;
+
+	/// Constrains `z = x * y` over random bytes `x` and `y`.
+	fn x_times_y_circuit(
+		builder: &mut ConstraintSystemBuilder,
+		log_size: usize,
+	) -> Result<Vec<Boundary<F>>, anyhow::Error> {
+		let x = unconstrained::<BinaryField8b>(builder, "x", log_size)?;
+		let y = unconstrained::<BinaryField8b>(builder, "y", log_size)?;
+		let z = builder.add_committed("z", log_size, BinaryField8b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			let x = witness.get::<BinaryField8b>(x)?.as_slice::<BinaryField8b>();
+			let y = witness.get::<BinaryField8b>(y)?.as_slice::<BinaryField8b>();
+			let mut z = witness.new_column::<BinaryField8b>(z);
+			for (z, (&x, &y)) in z
+				.as_mut_slice::<BinaryField8b>()
+				.iter_mut()
+				.zip(x.iter().zip(y))
+			{
+				*z = x * y;
+			}
+		}
+		builder.assert_zero(
+			"x_times_y",
+			[x, y, z],
+			arith_expr!([x, y, z] = x * y - z).convert_field(),
+		);
+		Ok(vec![])
+	}
+
+	fn collatz_circuit(
+		builder: &mut ConstraintSystemBuilder,
+	) -> Result<Vec<Boundary<F>>, anyhow::Error> {
+		let mut collatz = crate::collatz::Collatz::new(27);
+		let advice = collatz.init_prover();
+		collatz.build(builder, advice)
+	}
+
+	fn mul_circuit(
+		builder: &mut ConstraintSystemBuilder,
+	) -> Result<Vec<Boundary<F>>, anyhow::Error> {
+		let in_a = (0..2)
+			.map(|i| unconstrained::<BinaryField1b>(builder, format!("in_a_{i}"), 9))
+			.collect::<Result<Vec<_>, _>>()?;
+		let in_b = (0..2)
+			.map(|i| unconstrained::<BinaryField1b>(builder, format!("in_b_{i}"), 9))
+			.collect::<Result<Vec<_>, _>>()?;
+		crate::arithmetic::mul::mul::<BinaryField8b>(builder, "test", in_a, in_b)?;
+		Ok(vec![])
+	}
+
+	fn prove_groestl(
+		constraint_system: &ConstraintSystem<F>,
+		log_inv_rate: usize,
+		security_bits: usize,
+		boundaries: &[Boundary<F>],
+		witness: Witness,
+		backend: &CpuBackend,
+	) -> Result<Proof, constraint_system::error::Error> {
+		constraint_system::prove::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+			_,
+		>(constraint_system, log_inv_rate, security_bits, boundaries, witness, backend)
+	}
+
+	fn verify_groestl(
+		constraint_system: &ConstraintSystem<F>,
+		log_inv_rate: usize,
+		security_bits: usize,
+		boundaries: &[Boundary<F>],
+		proof: Proof,
+	) -> Result<(), constraint_system::error::Error> {
+		constraint_system::verify::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(constraint_system, log_inv_rate, security_bits, boundaries, proof)
+	}
+
+	/// Builds a circuit and runs `prove` on its witness.
+	///
+	/// The witness is drawn from `seed` when one is given, so that proving the same circuit twice
+	/// sees the same witness.
+	fn prove_circuit_with<R>(
+		build_circuit: BuildCircuit,
+		seed: Option<u64>,
+		prove: impl FnOnce(
+			&ConstraintSystem<F>,
+			&[Boundary<F>],
+			Witness,
+			&CpuBackend,
+		) -> Result<R, constraint_system::error::Error>,
+	) -> (ConstraintSystem<F>, Vec<Boundary<F>>, R) {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = match seed {
+			Some(seed) => ConstraintSystemBuilder::new_with_witness_seeded(&allocator, seed),
+			None => ConstraintSystemBuilder::new_with_witness(&allocator),
+		};
+		let boundaries = build_circuit(&mut builder).unwrap();
+		let witness = builder
+			.take_witness()
+			.expect("builder created with witness");
+		let constraint_system = builder.build().unwrap();
+
+		let backend = make_portable_backend();
+		let proof = prove(&constraint_system, &boundaries, witness, &backend).unwrap();
+		(constraint_system, boundaries, proof)
+	}
+
+	fn prove_circuit(
+		build_circuit: BuildCircuit,
+		log_inv_rate: usize,
+		security_bits: usize,
+	) -> (ConstraintSystem<F>, Vec<Boundary<F>>, Proof) {
+		prove_circuit_with(
+			build_circuit,
+			None,
+			|constraint_system, boundaries, witness, backend| {
+				prove_groestl(
+					constraint_system,
+					log_inv_rate,
+					security_bits,
+					boundaries,
+					witness,
+					backend,
+				)
+			},
+		)
+	}
+
+	fn proof_size_and_estimate(build_circuit: BuildCircuit) -> (usize, usize) {
+		let log_inv_rate = 1;
+		let security_bits = 100;
+
+		let (constraint_system, _, proof) =
+			prove_circuit(build_circuit, log_inv_rate, security_bits);
+		let estimate = constraint_system
+			.estimate_proof_size::<CanonicalTowerFamily, Groestl256, Groestl256ByteCompression>(
+				log_inv_rate,
+				security_bits,
+			)
+			.unwrap();
+		(proof.get_proof_size(), estimate)
+	}
+
+	#[test]
+	fn test_estimate_proof_size() {
+		let circuits: [BuildCircuit; 3] = [
+			|builder| x_times_y_circuit(builder, 8),
+			|builder| {
+				let x = unconstrained::<BinaryField1b>(builder, "x", 12)?;
+				let y = unconstrained::<BinaryField1b>(builder, "y", 12)?;
+				crate::arithmetic::u32::add(
+					builder,
+					"sum",
+					x,
+					y,
+					crate::arithmetic::Flags::Unchecked,
+				)?;
+				Ok(vec![])
+			},
+			collatz_circuit,
+		];
+
+		for build_circuit in circuits {
+			let (proof_size, estimate) = proof_size_and_estimate(build_circuit);
+			assert_eq!(estimate, proof_size);
+		}
+	}
+
+	#[test]
+	fn test_keccak_challenger() {
+		let (constraint_system, boundaries, proof) = prove_circuit_with(
+			collatz_circuit,
+			None,
+			|constraint_system, boundaries, witness, backend| {
+				constraint_system::prove::<
+					U,
+					CanonicalTowerFamily,
+					Keccak256,
+					Keccak256ByteCompression,
+					HasherChallenger<Keccak256>,
+					_,
+				>(constraint_system, 1, 100, boundaries, witness, backend)
+			},
+		);
+
+		constraint_system::verify::<
+			U,
+			CanonicalTowerFamily,
+			Keccak256,
+			Keccak256ByteCompression,
+			HasherChallenger<Keccak256>,
+		>(&constraint_system, 1, 100, &boundaries, proof)
+		.unwrap();
+	}
+
+	#[test]
+	fn test_verify_batch() {
+		let build_circuit: BuildCircuit = |builder| {
+			let x = unconstrained::<BinaryField1b>(builder, "x", 10)?;
+			let y = unconstrained::<BinaryField1b>(builder, "y", 10)?;
+			crate::arithmetic::u32::add(builder, "sum", x, y, crate::arithmetic::Flags::Unchecked)?;
+			Ok(vec![])
+		};
+
+		// The witnesses are random, so every proof is different.
+		let proofs = (0..4)
+			.map(|_| prove_circuit(build_circuit, 1, 100))
+			.collect::<Vec<_>>();
+		let (constraint_system, _, _) = &proofs[0];
+
+		let verify_batch = |proofs: Vec<(&[Boundary<F>], Proof)>| {
+			constraint_system::verify_batch::<
+				U,
+				CanonicalTowerFamily,
+				Groestl256,
+				Groestl256ByteCompression,
+				HasherChallenger<Groestl256>,
+			>(constraint_system, 1, 100, proofs)
+		};
+
+		verify_batch(
+			proofs
+				.iter()
+				.map(|(_, boundaries, proof)| (boundaries.as_slice(), proof.clone()))
+				.collect(),
+		)
+		.unwrap();
+
+		let mut truncated = proofs[2].2.clone();
+		truncated
+			.transcript
+			.truncate(truncated.transcript.len() / 2);
+		assert_matches!(
+			verify_batch(
+				proofs
+					.iter()
+					.enumerate()
+					.map(|(i, (_, boundaries, proof))| {
+						let proof = if i == 2 {
+							truncated.clone()
+						} else {
+							proof.clone()
+						};
+						(boundaries.as_slice(), proof)
+					})
+					.collect()
+			),
+			Err(constraint_system::error::Error::Verification(
+				constraint_system::error::VerificationError::TranscriptMismatch { .. }
+			))
+		);
+	}
+
+	#[test]
+	fn test_prove_until_commit() {
+		let (_, _, dry_run) = prove_circuit_with(
+			mul_circuit,
+			Some(0),
+			|constraint_system, boundaries, witness, backend| {
+				constraint_system::prove_until_commit::<
+					U,
+					CanonicalTowerFamily,
+					Groestl256,
+					Groestl256ByteCompression,
+					HasherChallenger<Groestl256>,
+					_,
+				>(constraint_system, 1, 10, boundaries, witness, backend)
+			},
+		);
+
+		// The commitment is the first message of a full proof of the same witness.
+		let (_, _, proof) = prove_circuit_with(
+			mul_circuit,
+			Some(0),
+			|constraint_system, boundaries, witness, backend| {
+				prove_groestl(constraint_system, 1, 10, boundaries, witness, backend)
+			},
+		);
+		assert!(proof.transcript.starts_with(&dry_run.commitment));
+	}
+
+	#[test]
+	fn test_prove_with_stats() {
+		let (constraint_system, _, (proof, stats)) = prove_circuit_with(
+			mul_circuit,
+			None,
+			|constraint_system, boundaries, witness, backend| {
+				constraint_system::prove_with_stats::<
+					U,
+					CanonicalTowerFamily,
+					Groestl256,
+					Groestl256ByteCompression,
+					HasherChallenger<Groestl256>,
+					_,
+				>(constraint_system, 1, 10, boundaries, witness, backend)
+			},
+		);
+		assert!(stats.commit_ms + stats.sumcheck_ms + stats.pcs_open_ms <= stats.total_ms);
+
+		verify_groestl(&constraint_system, 1, 10, &[], proof).unwrap();
+	}
+
+	/// Proves a circuit whose transcript is bound to `domain_separator`.
+	fn prove_circuit_with_domain_separator(
+		build_circuit: BuildCircuit,
+		security_bits: usize,
+		domain_separator: &[u8],
+	) -> (ConstraintSystem<F>, Vec<Boundary<F>>, Proof) {
+		prove_circuit_with(
+			build_circuit,
+			None,
+			|constraint_system, boundaries, witness, backend| {
+				constraint_system::prove_with_domain_separator::<
+					U,
+					CanonicalTowerFamily,
+					Groestl256,
+					Groestl256ByteCompression,
+					HasherChallenger<Groestl256>,
+					_,
+				>(
+					constraint_system,
+					1,
+					security_bits,
+					domain_separator,
+					boundaries,
+					witness,
+					backend,
+				)
+			},
+		)
+	}
+
+	fn verify_with_domain_separator(
+		constraint_system: &ConstraintSystem<F>,
+		security_bits: usize,
+		domain_separator: &[u8],
+		boundaries: &[Boundary<F>],
+		proof: Proof,
+	) -> Result<(), constraint_system::error::Error> {
+		constraint_system::verify_with_domain_separator::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(constraint_system, 1, security_bits, domain_separator, boundaries, proof)
+	}
+
+	#[test]
+	fn test_prove_with_domain_separator() {
+		let (constraint_system, _, proof) =
+			prove_circuit_with_domain_separator(mul_circuit, 10, b"application");
+
+		let verify = |domain_separator: &[u8]| {
+			verify_with_domain_separator(
+				&constraint_system,
+				10,
+				domain_separator,
+				&[],
+				proof.clone(),
+			)
+		};
+		verify(b"application").unwrap();
+		assert!(verify(b"other application").is_err());
+		assert!(verify(&[]).is_err());
+	}
+
+	#[test]
+	fn test_prove_streaming() {
+		let mut streamed = Vec::new();
+		prove_circuit_with(
+			mul_circuit,
+			Some(0),
+			|constraint_system, boundaries, witness, backend| {
+				constraint_system::prove_streaming::<
+					U,
+					CanonicalTowerFamily,
+					Groestl256,
+					Groestl256ByteCompression,
+					HasherChallenger<Groestl256>,
+					_,
+				>(constraint_system, 1, 10, boundaries, witness, backend, &mut streamed)
+			},
+		);
+
+		// The streamed bytes are the same proof as the one returned by `prove`.
+		let (constraint_system, _, proof) = prove_circuit_with(
+			mul_circuit,
+			Some(0),
+			|constraint_system, boundaries, witness, backend| {
+				prove_groestl(constraint_system, 1, 10, boundaries, witness, backend)
+			},
+		);
+		assert_eq!(streamed, proof.transcript);
+
+		let proof = Proof {
+			transcript: streamed,
+		};
+		verify_groestl(&constraint_system, 1, 10, &[], proof).unwrap();
+	}
+
+	#[test]
+	fn test_public_context_binding() {
+		// Public values that are not oracle boundaries, such as a circuit version and an output.
+		let public_context = [b"collatz v1".as_slice(), &27u32.to_le_bytes()].concat();
+
+		let (constraint_system, boundaries, proof) =
+			prove_circuit_with_domain_separator(collatz_circuit, 100, &public_context);
+
+		let verify = |public_context: &[u8]| {
+			verify_with_domain_separator(
+				&constraint_system,
+				100,
+				public_context,
+				&boundaries,
+				proof.clone(),
+			)
+		};
+		verify(&public_context).unwrap();
+
+		let other_context = [b"collatz v1".as_slice(), &28u32.to_le_bytes()].concat();
+		assert!(verify(&other_context).is_err());
+	}
+
+	#[test]
+	fn test_proof_serialization() {
+		let (constraint_system, boundaries, proof) = prove_circuit(collatz_circuit, 1, 100);
+
+		let mut bytes = Vec::new();
+		proof
+			.serialize(&mut bytes, SerializationMode::CanonicalTower)
+			.unwrap();
+
+		let deserialized =
+			Proof::deserialize(bytes.as_slice(), SerializationMode::CanonicalTower).unwrap();
+		assert_eq!(deserialized.transcript, proof.transcript);
+		verify_groestl(&constraint_system, 1, 100, &boundaries, deserialized).unwrap();
+
+		let deserialize =
+			|bytes: &[u8]| Proof::deserialize(bytes, SerializationMode::CanonicalTower);
+		for len in [0, 3, Proof::MAGIC.len() + 1, bytes.len() - 1] {
+			assert_matches!(deserialize(&bytes[..len]), Err(SerializationError::NotEnoughBytes));
+		}
+
+		let mut corrupted = bytes.clone();
+		corrupted[0] ^= 1;
+		assert_matches!(deserialize(&corrupted), Err(SerializationError::InvalidMagic { .. }));
+
+		let mut corrupted = bytes;
+		corrupted[Proof::MAGIC.len()] = Proof::VERSION + 1;
+		assert_matches!(
+			deserialize(&corrupted),
+			Err(SerializationError::UnsupportedVersion { got, .. }) if got == Proof::VERSION + 1
+		);
+	}
+
+	#[test]
+	fn test_constraint_system_stats() {
+		let mut builder = ConstraintSystemBuilder::new();
+		let x = builder.add_committed("x", 8, BinaryField8b::TOWER_LEVEL);
+		let y = builder.add_committed("y", 8, BinaryField8b::TOWER_LEVEL);
+		let z = builder.add_committed("z", 8, BinaryField8b::TOWER_LEVEL);
+		builder
+			.add_shifted("x_shifted", x, 1, 8, ShiftVariant::CircularLeft)
+			.unwrap();
+		builder.assert_zero(
+			"x_times_y",
+			[x, y, z],
+			arith_expr!([x, y, z] = x * y - z).convert_field(),
+		);
+		let stats = builder.build_shape_only().unwrap().stats();
+
+		assert_eq!(stats.n_oracles_of_kind(OracleKind::Committed), 3);
+		assert_eq!(stats.n_oracles[&(OracleKind::Committed, BinaryField8b::TOWER_LEVEL)], 3);
+		assert_eq!(stats.n_oracles[&(OracleKind::Shifted, BinaryField8b::TOWER_LEVEL)], 1);
+		assert_eq!(stats.committed_bits, 3 * (1 << 8) * 8);
+		assert_eq!(stats.n_zero_constraints, 1);
+		assert_eq!(stats.n_sum_constraints, 0);
+		assert_eq!(stats.n_flushes, 0);
+	}
+
+	#[test]
+	fn test_add_linear_combination_checked() {
+		let mut builder = ConstraintSystemBuilder::new();
+		let x = builder.add_committed("x", 4, BinaryField1b::TOWER_LEVEL);
+		let y = builder.add_committed("y", 4, BinaryField8b::TOWER_LEVEL);
+		let z = builder.add_committed("z", 5, BinaryField1b::TOWER_LEVEL);
+
+		builder
+			.add_linear_combination_checked(
+				"ok",
+				4,
+				BinaryField8b::TOWER_LEVEL,
+				[(x, BinaryField8b::new(3).into()), (y, F::ONE)],
+			)
+			.unwrap();
+		assert_matches!(
+			builder.add_linear_combination_checked(
+				"wrong_n_vars",
+				4,
+				BinaryField8b::TOWER_LEVEL,
+				[(x, F::ONE), (z, F::ONE)],
+			),
+			Err(OracleError::LinearCombinationTermNvarsMismatch {
+				index: 1,
+				n_vars: 5,
+				expected: 4
+			})
+		);
+		assert_matches!(
+			builder.add_linear_combination_checked(
+				"wrong_coeff",
+				4,
+				BinaryField1b::TOWER_LEVEL,
+				[(x, BinaryField8b::new(3).into())],
+			),
+			Err(OracleError::LinearCombinationTermTowerLevelTooHigh {
+				index: 0,
+				tower_level: 1,
+				max_tower_level: 0
+			})
+		);
+		assert_matches!(
+			builder.add_linear_combination_checked(
+				"wrong_oracle",
+				4,
+				BinaryField1b::TOWER_LEVEL,
+				[(x, F::ONE), (y, F::ONE)],
+			),
+			Err(OracleError::LinearCombinationTermTowerLevelTooHigh { index: 1, .. })
+		);
+	}
+
+	#[test]
+	fn test_constraint_checking_accepts_valid_witness() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		builder.enable_constraint_checking();
+		let x = unconstrained::<BinaryField8b>(&mut builder, "x", 4).unwrap();
+		builder.assert_zero("x_minus_x", [x, x], arith_expr!([x, y] = x - y).convert_field());
+	}
+
+	#[test]
+	#[should_panic(expected = "x_is_zero")]
+	fn test_constraint_checking_rejects_invalid_witness() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		builder.enable_constraint_checking();
+		let x = builder.add_committed("x", 4, BinaryField8b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			witness
+				.new_column::<BinaryField8b>(x)
+				.as_mut_slice::<BinaryField8b>()
+				.fill(BinaryField8b::ONE);
+		}
+		builder.assert_zero("x_is_zero", [x], arith_expr!([x] = x).convert_field());
+	}
+
+	#[test]
+	fn test_build_rejects_unpopped_namespace() {
+		let mut builder = ConstraintSystemBuilder::new();
+		builder.push_namespace("gadget");
+		builder.add_committed("x", 4, BinaryField8b::TOWER_LEVEL);
+		let err = builder.build().unwrap_err();
+		assert!(err.to_string().contains("gadget"));
+	}
 }
@@ -271,7 +271,7 @@ where
 	if check_inclusion {
 		let table_set: HashSet<_> = table.iter().collect();
 		if let Some(invalid_value) = values.iter().find(|value| !table_set.contains(value)) {
-			return Err(anyhow::anyhow!("value {:?} not in table", invalid_value));
+			return Err(anyhow::anyhow!("value {invalid_value:?} not in table"));
 		}
 	}
 
@@ -5,6 +5,7 @@ use binius_field::{
 	as_packed_field::{PackScalar, PackedType},
 	BinaryField1b, ExtensionField, PackedField, TowerField,
 };
+use binius_math::eq_ind_partial_eval;
 
 use crate::builder::{
 	types::{F, U},
@@ -61,6 +62,51 @@ where
 	Ok(id)
 }
 
+/// Adds one [`constant`] column per value, named `{name_prefix}[{i}]`.
+pub fn constants<FS, const N: usize>(
+	builder: &mut ConstraintSystemBuilder,
+	name_prefix: impl ToString,
+	log_size: usize,
+	values: [FS; N],
+) -> Result<[OracleId; N], anyhow::Error>
+where
+	U: PackScalar<FS>,
+	F: TowerField + ExtensionField<FS>,
+	FS: TowerField,
+{
+	let name_prefix = name_prefix.to_string();
+	array_util::try_from_fn(|i| {
+		constant(builder, format!("{name_prefix}[{i}]"), log_size, values[i])
+	})
+}
+
+/// Adds a transparent column for $\text{eq}(X, r)$, the equality indicator polynomial partially
+/// evaluated at a fixed point $r$.
+///
+/// The witness is computed by expanding the tensor product of the point coordinates, so it has a
+/// single non-zero entry of one when the point is a hypercube vertex.
+pub fn eq_indicator(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	point: &[F],
+	log_size: usize,
+) -> Result<OracleId, anyhow::Error> {
+	anyhow::ensure!(
+		point.len() == log_size,
+		"Point has {} coordinates, expected {log_size}",
+		point.len()
+	);
+	let eq_ind = transparent::eq_ind::EqIndPartialEval::new(point);
+	let id = builder.add_transparent(name, eq_ind)?;
+	if let Some(witness) = builder.witness() {
+		witness
+			.new_column::<F>(id)
+			.packed()
+			.copy_from_slice(&eq_ind_partial_eval::<PackedType<U, F>>(point));
+	}
+	Ok(id)
+}
+
 pub fn make_transparent<FS>(
 	builder: &mut ConstraintSystemBuilder,
 	name: impl ToString,
@@ -91,3 +137,80 @@ where
 
 	Ok(oracle)
 }
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{packed::get_packed_slice, BinaryField8b, Field};
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+	use crate::builder::test_utils::test_circuit;
+
+	#[test]
+	fn test_constants() {
+		test_circuit(|builder| {
+			let log_size = 4;
+			let values = [
+				BinaryField8b::new(0x00),
+				BinaryField8b::new(0x10),
+				BinaryField8b::new(0x20),
+			];
+			let ids = constants(builder, "multiples_16", log_size, values)?;
+
+			if let Some(witness) = builder.witness() {
+				for (&id, &value) in ids.iter().zip(&values) {
+					let column = witness.get::<BinaryField8b>(id)?.packed();
+					for i in 0..1 << log_size {
+						assert_eq!(get_packed_slice(column, i), value);
+					}
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_eq_indicator_at_vertex() {
+		test_circuit(|builder| {
+			let log_size = 4;
+			let vertex = 0b1101;
+			let point = (0..log_size)
+				.map(|i| {
+					if (vertex >> i) & 1 == 1 {
+						F::ONE
+					} else {
+						F::ZERO
+					}
+				})
+				.collect::<Vec<_>>();
+			let eq = eq_indicator(builder, "eq", &point, log_size)?;
+
+			if let Some(witness) = builder.witness() {
+				let eq = witness.get::<F>(eq)?.packed();
+				for i in 0..1 << log_size {
+					let expected = if i == vertex { F::ONE } else { F::ZERO };
+					assert_eq!(get_packed_slice(eq, i), expected);
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_eq_indicator_at_random_point() {
+		test_circuit(|builder| {
+			let log_size = 5;
+			// The verifier and prover circuits must agree on the point.
+			let mut rng = StdRng::seed_from_u64(0);
+			let point = (0..log_size)
+				.map(|_| <F as Field>::random(&mut rng))
+				.collect::<Vec<_>>();
+			let _eq = eq_indicator(builder, "eq", &point, log_size)?;
+			assert!(eq_indicator(builder, "eq_short", &point[1..], log_size).is_err());
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+}
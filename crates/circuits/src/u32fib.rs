@@ -4,7 +4,7 @@ use binius_core::oracle::{OracleId, ShiftVariant};
 use binius_field::{BinaryField1b, BinaryField32b, TowerField};
 use binius_macros::arith_expr;
 use binius_maybe_rayon::prelude::*;
-use rand::{thread_rng, Rng};
+use rand::{rngs::StdRng, Rng, SeedableRng};
 
 use crate::{
 	arithmetic,
@@ -24,11 +24,11 @@ pub fn u32fib(
 		builder.add_shifted("next_next", current, 64, log_size, ShiftVariant::LogicalRight)?;
 
 	if let Some(witness) = builder.witness() {
+		let mut rng = witness.seeded_rng().unwrap_or_else(StdRng::from_entropy);
 		let mut current = witness.new_column::<BinaryField1b>(current);
 		let mut next = witness.new_column::<BinaryField1b>(next);
 		let mut next_next = witness.new_column::<BinaryField1b>(next_next);
 
-		let mut rng = thread_rng();
 		let current = current.as_mut_slice::<u32>();
 		current[0] = rng.gen();
 		current[1] = rng.gen();
@@ -1,6 +1,8 @@
 // Copyright 2024-2025 Irreducible Inc.
 use binius_core::oracle::OracleId;
-use binius_field::{as_packed_field::PackScalar, ExtensionField, TowerField};
+use binius_field::{
+	as_packed_field::PackScalar, packed::set_packed_slice, ExtensionField, TowerField,
+};
 use binius_maybe_rayon::prelude::*;
 use bytemuck::Pod;
 use rand::{thread_rng, Rng};
@@ -23,13 +25,15 @@ where
 	let rng = builder.add_committed(name, log_size, FS::TOWER_LEVEL);
 
 	if let Some(witness) = builder.witness() {
-		witness
-			.new_column::<FS>(rng)
-			.as_mut_slice::<u8>()
-			.into_par_iter()
-			.for_each_init(thread_rng, |rng, data| {
+		let seeded_rng = witness.seeded_rng();
+		let mut column = witness.new_column::<FS>(rng);
+		let data = column.as_mut_slice::<u8>();
+		match seeded_rng {
+			Some(mut seeded_rng) => seeded_rng.fill(data),
+			None => data.into_par_iter().for_each_init(thread_rng, |rng, data| {
 				*data = rng.gen();
-			});
+			}),
+		}
 	}
 
 	Ok(rng)
@@ -62,3 +66,124 @@ where
 
 	Ok(fixed)
 }
+
+/// Same as [`unconstrained`] but fills the witness with the given values.
+///
+/// Errors if the witness is being built and there are not exactly `1 << log_size` values.
+pub fn unconstrained_from_values<FS>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	log_size: usize,
+	values: impl IntoIterator<Item = FS>,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<FS>,
+	F: TowerField + ExtensionField<FS>,
+	FS: TowerField,
+{
+	let id = builder.add_committed(name, log_size, FS::TOWER_LEVEL);
+
+	if let Some(witness) = builder.witness() {
+		let mut column = witness.new_column::<FS>(id);
+		let column = column.packed();
+
+		let mut n_values = 0;
+		for value in values {
+			anyhow::ensure!(
+				n_values < 1 << log_size,
+				"Too many values for a column with {} rows",
+				1 << log_size
+			);
+			set_packed_slice(column, n_values, value);
+			n_values += 1;
+		}
+		anyhow::ensure!(
+			n_values == 1 << log_size,
+			"Expected {} values, got {n_values}",
+			1 << log_size
+		);
+	}
+
+	Ok(id)
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField1b, BinaryField32b, Field};
+
+	use super::{unconstrained, unconstrained_from_values};
+	use crate::{
+		builder::{test_utils::test_circuit, ConstraintSystemBuilder},
+		u32fib::u32fib,
+	};
+
+	#[test]
+	fn test_unconstrained_from_values() {
+		test_circuit(|builder| {
+			let log_size = 5;
+			let words = unconstrained_from_values(
+				builder,
+				"words",
+				log_size,
+				(0..1 << log_size).map(|i| BinaryField32b::new(i * 0x01010101)),
+			)?;
+			let bits = unconstrained_from_values(
+				builder,
+				"bits",
+				log_size,
+				(0..1 << log_size).map(|i| BinaryField1b::from(i % 3 == 0)),
+			)?;
+
+			if let Some(witness) = builder.witness() {
+				let words = witness.get::<BinaryField32b>(words)?;
+				for (i, &word) in words.as_slice::<u32>().iter().enumerate() {
+					assert_eq!(word, i as u32 * 0x01010101);
+				}
+				let bits = witness.get::<BinaryField1b>(bits)?;
+				for i in 0..1 << log_size {
+					let bit = (bits.as_slice::<u8>()[i / 8] >> (i % 8)) & 1;
+					assert_eq!(bit == 1, i % 3 == 0);
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_unconstrained_from_values_length_mismatch() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let values = |n| (0..n).map(|_| BinaryField32b::ONE);
+		assert!(unconstrained_from_values(&mut builder, "short", 4, values(15)).is_err());
+		assert!(unconstrained_from_values(&mut builder, "long", 4, values(17)).is_err());
+		assert!(unconstrained_from_values(&mut builder, "exact", 4, values(16)).is_ok());
+
+		// Without a witness, the values are not used.
+		let mut builder = ConstraintSystemBuilder::new();
+		assert!(unconstrained_from_values(&mut builder, "short", 4, values(0)).is_ok());
+	}
+
+	#[test]
+	fn test_seeded_witness_is_reproducible() {
+		let allocator = bumpalo::Bump::new();
+		let witness_data = |seed| {
+			let mut builder = ConstraintSystemBuilder::new_with_witness_seeded(&allocator, seed);
+			let words = unconstrained::<BinaryField32b>(&mut builder, "words", 6).unwrap();
+			let bits = unconstrained::<BinaryField1b>(&mut builder, "bits", 10).unwrap();
+			let fib = u32fib(&mut builder, "fib", 10).unwrap();
+
+			let witness = builder.witness().unwrap();
+			let words = witness
+				.get::<BinaryField32b>(words)
+				.unwrap()
+				.as_slice::<u8>();
+			let bits = witness.get::<BinaryField1b>(bits).unwrap().as_slice::<u8>();
+			let fib = witness.get::<BinaryField1b>(fib).unwrap().as_slice::<u8>();
+			[words, bits, fib].concat()
+		};
+
+		assert_eq!(witness_data(1), witness_data(1));
+		assert_ne!(witness_data(1), witness_data(2));
+	}
+}
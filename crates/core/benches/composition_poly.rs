@@ -2,7 +2,7 @@
 
 use std::iter::repeat_with;
 
-use binius_core::polynomial::ArithCircuitPoly;
+use binius_core::{composition::IndexComposition, polynomial::ArithCircuitPoly};
 use binius_field::{
 	BinaryField1b, Field, PackedBinaryField128x1b, PackedBinaryField16x8b, PackedBinaryField1x128b,
 	PackedField,
@@ -194,5 +194,43 @@ fn benchmark_evaluate(c: &mut Criterion) {
 	group.finish();
 }
 
+fn benchmark_index_composition(c: &mut Criterion) {
+	const N_MULTILINEARS: usize = 64;
+
+	let mut rng = thread_rng();
+
+	let query = repeat_with(|| generate_random_vec::<PackedBinaryField1x128b>(&mut rng))
+		.take(N_MULTILINEARS)
+		.collect::<Vec<_>>();
+	let query = query.iter().map(|q| q.as_slice()).collect::<Vec<_>>();
+	let batch_query = RowsBatchRef::new(&query, BATCH_SIZE);
+	let mut results = vec![PackedBinaryField1x128b::zero(); BATCH_SIZE];
+
+	let composition = IndexComposition::new(
+		N_MULTILINEARS,
+		[3, 17, 42, 60],
+		arith_circuit_poly!([h4, h5, h6, ch] = (h4 * h5 + (1 - h4) * h6) - ch, BinaryField1b),
+	)
+	.unwrap();
+
+	let mut group = c.benchmark_group("index_composition");
+	group.throughput(Throughput::Elements(BATCH_SIZE as _));
+	group.bench_function("batch_evaluate_1x128b", |bench| {
+		bench.iter(|| {
+			composition
+				.batch_evaluate(&batch_query, &mut results)
+				.unwrap();
+		});
+	});
+	group.bench_function("batch_evaluate_gathered_1x128b", |bench| {
+		bench.iter(|| {
+			composition
+				.batch_evaluate_gathered(&batch_query, &mut results)
+				.unwrap();
+		});
+	});
+	group.finish();
+}
+
 criterion_main!(composition_poly);
-criterion_group!(composition_poly, benchmark_evaluate);
+criterion_group!(composition_poly, benchmark_evaluate, benchmark_index_composition);
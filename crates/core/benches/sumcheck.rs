@@ -32,6 +32,7 @@ fn bench_bivariate_with_evaluation_order<
 	field: &str,
 	eval_order: EvaluationOrder,
 	n_vars: usize,
+	n_claims: usize,
 ) {
 	let mut rng = thread_rng();
 	let multilins = repeat_with(|| {
@@ -59,16 +60,22 @@ fn bench_bivariate_with_evaluation_order<
 	let mut group = c.benchmark_group(format!("Sumcheck/{field}"));
 
 	let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
-	group.bench_function(format!("n_vars={n_vars}/{eval_order:?}"), |b| {
+	let name = if n_claims == 1 {
+		format!("n_vars={n_vars}/{eval_order:?}")
+	} else {
+		format!("n_vars={n_vars}/n_claims={n_claims}/{eval_order:?}")
+	};
+	group.bench_function(name, |b| {
 		b.iter_batched(
 			|| {
 				let prover = RegularSumcheckProver::<FDomain, _, _, _, _>::new(
 					eval_order,
 					multilins.iter().collect(),
-					[CompositeSumClaim {
+					repeat_with(|| CompositeSumClaim {
 						composition: &bivariate_composition,
 						sum,
-					}],
+					})
+					.take(n_claims),
 					&domain_factory,
 					move |_| 0,
 					&backend,
@@ -89,23 +96,34 @@ fn regular_sumcheck(c: &mut Criterion) {
 			_,
 			BinaryField8b,
 			PackedType<OptimalUnderlier, BinaryField128b>,
-		>(c, "BinaryField128b", eval_order, 20);
+		>(c, "BinaryField128b", eval_order, 20, 1);
 
 		bench_bivariate_with_evaluation_order::<
 			_,
 			BinaryField128bPolyval,
 			PackedType<OptimalUnderlier, BinaryField128bPolyval>,
-		>(c, "BinaryField128bPolyval", eval_order, 20);
+		>(c, "BinaryField128bPolyval", eval_order, 20, 1);
 
 		bench_bivariate_with_evaluation_order::<_, AESTowerField8b, ByteSlicedAES32x128b>(
 			c,
 			"ByteSlicedAES32x128b",
 			eval_order,
 			20,
+			1,
 		);
 	}
 }
 
-criterion_group!(sumcheck_benches, regular_sumcheck);
+fn regular_sumcheck_many_claims(c: &mut Criterion) {
+	for eval_order in [EvaluationOrder::LowToHigh, EvaluationOrder::HighToLow] {
+		bench_bivariate_with_evaluation_order::<
+			_,
+			BinaryField8b,
+			PackedType<OptimalUnderlier, BinaryField128b>,
+		>(c, "BinaryField128b", eval_order, 12, 256);
+	}
+}
+
+criterion_group!(sumcheck_benches, regular_sumcheck, regular_sumcheck_many_claims);
 
 criterion_main!(sumcheck_benches);
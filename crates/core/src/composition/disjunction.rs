@@ -0,0 +1,133 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::{ExtensionField, PackedField, TowerField};
+use binius_math::{ArithCircuit, CompositionPoly, Error, RowsBatchRef};
+
+use super::CompositionProduct;
+use crate::polynomial::ArithCircuitPoly;
+
+/// A composition that vanishes exactly where at least one of its branches vanishes.
+///
+/// A disjunction of zero constraints `c_0 = 0 OR ... OR c_{k-1} = 0` is encoded as the
+/// [`CompositionProduct`] `c_0 * ... * c_{k-1}`, so the degree of the disjunction is the sum of the
+/// branch degrees. All branches are evaluated over the same query, which has as many variables as
+/// the widest branch.
+#[derive(Debug, Clone)]
+pub struct DisjunctionComposition<F: TowerField> {
+	product: CompositionProduct<ArithCircuitPoly<F>>,
+}
+
+impl<F: TowerField> DisjunctionComposition<F> {
+	pub fn new(branches: impl IntoIterator<Item = ArithCircuit<F>>) -> Self {
+		let branches = branches.into_iter().collect::<Vec<_>>();
+		let n_vars = branches
+			.iter()
+			.map(|branch| branch.n_vars())
+			.max()
+			.unwrap_or(0);
+		let product = CompositionProduct::new(branches.into_iter().map(|branch| {
+			ArithCircuitPoly::with_n_vars(n_vars, branch)
+				.expect("n_vars is the maximum number of variables over all branches")
+		}));
+
+		Self { product }
+	}
+
+	pub fn n_vars(&self) -> usize {
+		CompositionPoly::<F>::n_vars(&self.product)
+	}
+
+	pub fn degree(&self) -> usize {
+		CompositionPoly::<F>::degree(&self.product)
+	}
+
+	/// Returns the product of the branches as a single arithmetic circuit.
+	pub fn expression(&self) -> ArithCircuit<F> {
+		CompositionPoly::<F>::expression(&self.product)
+	}
+}
+
+impl<F: TowerField, P: PackedField<Scalar: ExtensionField<F>>> CompositionPoly<P>
+	for DisjunctionComposition<F>
+{
+	fn n_vars(&self) -> usize {
+		CompositionPoly::<P>::n_vars(&self.product)
+	}
+
+	fn degree(&self) -> usize {
+		CompositionPoly::<P>::degree(&self.product)
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		CompositionPoly::<P>::binary_tower_level(&self.product)
+	}
+
+	fn expression(&self) -> ArithCircuit<P::Scalar> {
+		CompositionPoly::<P>::expression(&self.product)
+	}
+
+	fn evaluate(&self, query: &[P]) -> Result<P, Error> {
+		self.product.evaluate(query)
+	}
+
+	fn batch_evaluate(&self, batch_query: &RowsBatchRef<P>, evals: &mut [P]) -> Result<(), Error> {
+		self.product.batch_evaluate(batch_query, evals)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use assert_matches::assert_matches;
+	use binius_field::{AESTowerField8b, Field};
+
+	use super::*;
+
+	#[test]
+	fn test_disjunction_is_zero_iff_a_branch_is_zero() {
+		type F = AESTowerField8b;
+
+		let x = ArithCircuit::<F>::var(0);
+		let inv = ArithCircuit::<F>::var(1);
+		// x * inv == 1
+		let non_zero_case = x.clone() * inv.clone() - ArithCircuit::one();
+		// x == inv
+		let equal_case = x - inv;
+
+		let branches = [non_zero_case, equal_case];
+		let disjunction = DisjunctionComposition::new(branches.clone());
+		assert_eq!(disjunction.n_vars(), 2);
+		assert_eq!(disjunction.degree(), 3);
+		assert_eq!(disjunction.expression().degree(), 3);
+
+		for x in (0..=255).map(F::new) {
+			for inv in [F::ZERO, F::ONE, x, x.invert_or_zero(), x + F::new(0x53)] {
+				let query = [x, inv];
+				let any_branch_zero = branches
+					.iter()
+					.any(|branch| branch.evaluate(&query).unwrap().is_zero());
+				let eval = CompositionPoly::<F>::evaluate(&disjunction, &query).unwrap();
+				assert_eq!(eval.is_zero(), any_branch_zero);
+			}
+		}
+	}
+
+	#[test]
+	fn test_disjunction_pads_narrower_branches() {
+		type F = AESTowerField8b;
+
+		let disjunction = DisjunctionComposition::new([
+			ArithCircuit::<F>::var(0),
+			ArithCircuit::var(2) + ArithCircuit::constant(F::ONE),
+		]);
+		assert_eq!(disjunction.n_vars(), 3);
+		assert_eq!(disjunction.degree(), 2);
+
+		let eval =
+			CompositionPoly::<F>::evaluate(&disjunction, &[F::new(2), F::new(5), F::ONE]).unwrap();
+		assert!(eval.is_zero());
+		assert_matches!(
+			CompositionPoly::<F>::evaluate(&disjunction, &[F::ONE, F::ONE]),
+			Err(Error::IncorrectQuerySize { expected: 3 })
+		);
+	}
+}
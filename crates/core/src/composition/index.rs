@@ -2,11 +2,11 @@
 
 use std::fmt::Debug;
 
-use binius_field::PackedField;
-use binius_math::{ArithCircuit, CompositionPoly, RowsBatchRef};
+use binius_field::{PackedField, TowerField};
+use binius_math::{ArithCircuit, ArithExpr, CompositionPoly, RowsBatchRef};
 use binius_utils::bail;
 
-use crate::polynomial::Error;
+use crate::polynomial::{ArithCircuitPoly, Error};
 
 /// An adapter which allows evaluating a composition over a larger query by indexing into it.
 /// See [`index_composition`] for a factory method.
@@ -32,6 +32,51 @@ impl<C, const N: usize> IndexComposition<C, N> {
 			composition,
 		})
 	}
+
+	/// Batch evaluation that gathers the indexed rows into a contiguous scratch buffer first.
+	///
+	/// This is equivalent to [`CompositionPoly::batch_evaluate`], which only remaps the row
+	/// references. When the composition reads a sparse subset of a wide query, copying the few
+	/// rows it needs next to each other may improve the memory locality of the inner evaluation.
+	pub fn batch_evaluate_gathered<P>(
+		&self,
+		batch_query: &RowsBatchRef<P>,
+		evals: &mut [P],
+	) -> Result<(), binius_math::Error>
+	where
+		P: PackedField,
+		C: CompositionPoly<P>,
+	{
+		let row_len = batch_query.row_len();
+		if row_len == 0 {
+			return self
+				.composition
+				.batch_evaluate(&batch_query.map(self.indices).get_ref(), evals);
+		}
+
+		let mut scratch = Vec::with_capacity(N * row_len);
+		for &index in &self.indices {
+			scratch.extend_from_slice(batch_query.rows()[index]);
+		}
+		let rows = scratch.chunks_exact(row_len).collect::<Vec<_>>();
+		self.composition
+			.batch_evaluate(&RowsBatchRef::new(&rows, row_len), evals)
+	}
+}
+
+impl<F: TowerField, const N: usize> IndexComposition<ArithCircuitPoly<F>, N> {
+	/// Creates an index composition of an arithmetic expression.
+	///
+	/// Variable `i` of the expression is variable `indices[i]` of the outer query, so the
+	/// expression may only use the first `N` variables.
+	pub fn from_expr(
+		n_vars: usize,
+		indices: [usize; N],
+		expr: &ArithExpr<F>,
+	) -> Result<Self, Error> {
+		let composition = ArithCircuitPoly::with_n_vars(N, expr.into())?;
+		Self::new(n_vars, indices, composition)
+	}
 }
 
 impl<P: PackedField, C: CompositionPoly<P>, const N: usize> CompositionPoly<P>
@@ -52,6 +97,14 @@ impl<P: PackedField, C: CompositionPoly<P>, const N: usize> CompositionPoly<P>
 			.expect("remapping must be valid")
 	}
 
+	fn vars_usage(&self) -> Vec<bool> {
+		let mut usage = vec![false; self.n_vars];
+		for (&index, used) in self.indices.iter().zip(self.composition.vars_usage()) {
+			usage[index] |= used;
+		}
+		usage
+	}
+
 	fn evaluate(&self, query: &[P]) -> Result<P, binius_math::Error> {
 		if query.len() != self.n_vars {
 			bail!(binius_math::Error::IncorrectQuerySize {
@@ -76,6 +129,21 @@ impl<P: PackedField, C: CompositionPoly<P>, const N: usize> CompositionPoly<P>
 		self.composition
 			.batch_evaluate(&batch_subquery.get_ref(), evals)
 	}
+
+	fn batch_evaluate_columns(
+		&self,
+		columns: &[&[P]],
+		evals: &mut [P],
+	) -> Result<(), binius_math::Error> {
+		if columns.len() != self.n_vars {
+			bail!(binius_math::Error::IncorrectQuerySize {
+				expected: self.n_vars,
+			});
+		}
+
+		let subcolumns = self.indices.map(|index| columns[index]);
+		self.composition.batch_evaluate_columns(&subcolumns, evals)
+	}
 }
 
 /// A factory helper method to create an [`IndexComposition`] by looking at
@@ -164,6 +232,17 @@ impl<P: PackedField, C: CompositionPoly<P> + Debug + Send + Sync> CompositionPol
 		}
 	}
 
+	fn vars_usage(&self) -> Vec<bool> {
+		match self {
+			Self::Trivariate(index_composition) => {
+				CompositionPoly::<P>::vars_usage(index_composition)
+			}
+			Self::Bivariate(index_composition) => {
+				CompositionPoly::<P>::vars_usage(index_composition)
+			}
+		}
+	}
+
 	fn evaluate(&self, query: &[P]) -> Result<P, binius_math::Error> {
 		match self {
 			Self::Trivariate(index_composition) => index_composition.evaluate(query),
@@ -174,8 +253,11 @@ impl<P: PackedField, C: CompositionPoly<P> + Debug + Send + Sync> CompositionPol
 
 #[cfg(test)]
 mod tests {
-	use binius_field::{BinaryField1b, Field};
+	use std::iter::repeat_with;
+
+	use binius_field::{BinaryField1b, BinaryField32b, Field, PackedBinaryField4x32b};
 	use binius_math::ArithExpr;
+	use rand::{rngs::StdRng, SeedableRng};
 
 	use super::*;
 	use crate::polynomial::ArithCircuitPoly;
@@ -198,4 +280,106 @@ mod tests {
 			),
 		);
 	}
+
+	#[test]
+	fn test_from_expr() {
+		let expr = ArithExpr::Var(0) * (ArithExpr::Var(1) + ArithExpr::Const(BinaryField32b::ONE));
+		let composition = IndexComposition::from_expr(3, [2, 0], &expr).unwrap();
+		assert_eq!(
+			(&composition as &dyn CompositionPoly<BinaryField32b>).expression(),
+			ArithCircuit::from(
+				&(ArithExpr::Var(2) * (ArithExpr::Var(0) + ArithExpr::Const(BinaryField32b::ONE)))
+			),
+		);
+
+		assert!(IndexComposition::from_expr(3, [2, 3], &expr).is_err());
+		assert!(IndexComposition::from_expr(3, [2], &expr).is_err());
+	}
+
+	#[test]
+	fn test_from_expr_unused_trailing_var() {
+		let expr = ArithExpr::<BinaryField32b>::Var(0);
+		let composition = IndexComposition::from_expr(3, [2, 0], &expr).unwrap();
+		assert_eq!(CompositionPoly::<BinaryField32b>::n_vars(&composition), 3);
+
+		let query = [2, 3, 5].map(BinaryField32b::new);
+		assert_eq!(composition.evaluate(&query).unwrap(), query[2]);
+	}
+
+	#[test]
+	fn test_vars_usage() {
+		let expr = ArithExpr::<BinaryField32b>::Var(0) * ArithExpr::Var(2);
+		let composition = IndexComposition::from_expr(6, [4, 1, 3], &expr).unwrap();
+		let vars_usage = CompositionPoly::<BinaryField32b>::vars_usage(&composition);
+		assert_eq!(vars_usage, vec![false, false, false, true, true, false]);
+
+		let mut expected = CompositionPoly::<BinaryField32b>::expression(&composition).vars_usage();
+		expected.resize(6, false);
+		assert_eq!(vars_usage, expected);
+	}
+
+	#[test]
+	fn test_repeated_indices() {
+		let expr = ArithExpr::<BinaryField32b>::Var(0) * ArithExpr::Var(1) + ArithExpr::Var(0);
+		let composition = IndexComposition::from_expr(3, [1, 1], &expr).unwrap();
+		let expression = CompositionPoly::<BinaryField32b>::expression(&composition);
+		assert_eq!(
+			expression,
+			ArithCircuit::from(&(ArithExpr::Var(1) * ArithExpr::Var(1) + ArithExpr::Var(1)))
+		);
+
+		let expression_poly = ArithCircuitPoly::new(expression);
+		let mut rng = StdRng::seed_from_u64(0);
+		for _ in 0..8 {
+			let query = repeat_with(|| <BinaryField32b as Field>::random(&mut rng))
+				.take(3)
+				.collect::<Vec<_>>();
+			let expected = query[1] * query[1] + query[1];
+			assert_eq!(composition.evaluate(&query).unwrap(), expected);
+			// The remapped expression only has as many variables as its highest index needs.
+			assert_eq!(expression_poly.evaluate(&query[..2]).unwrap(), expected);
+		}
+	}
+
+	#[test]
+	fn test_batch_evaluate_gathered() {
+		type P = PackedBinaryField4x32b;
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let expr = ArithExpr::Var(0) * ArithExpr::Var(1) + ArithExpr::Var(2).pow(3);
+		let composition = IndexComposition::new(
+			16,
+			[11, 3, 7],
+			ArithCircuitPoly::<BinaryField32b>::new((&expr).into()),
+		)
+		.unwrap();
+
+		let row_len = 64;
+		let query = repeat_with(|| repeat_with(|| P::random(&mut rng)).take(row_len).collect())
+			.take(16)
+			.collect::<Vec<Vec<P>>>();
+		let query = query.iter().map(|row| row.as_slice()).collect::<Vec<_>>();
+		let batch_query = RowsBatchRef::new(&query, row_len);
+
+		let mut evals = vec![P::zero(); row_len];
+		composition
+			.batch_evaluate(&batch_query, &mut evals)
+			.unwrap();
+		let mut evals_gathered = vec![P::zero(); row_len];
+		composition
+			.batch_evaluate_gathered(&batch_query, &mut evals_gathered)
+			.unwrap();
+
+		assert_eq!(evals, evals_gathered);
+
+		let mut evals_columns = vec![P::zero(); row_len];
+		composition
+			.batch_evaluate_columns(&query, &mut evals_columns)
+			.unwrap();
+		assert_eq!(evals, evals_columns);
+
+		assert!(composition
+			.batch_evaluate_columns(&query[..15], &mut evals_columns)
+			.is_err());
+	}
 }
@@ -0,0 +1,156 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::{ExtensionField, PackedField, TowerField};
+use binius_math::{ArithCircuit, CompositionPoly, Error, RowsBatchRef};
+use binius_utils::bail;
+
+/// A weighted sum of compositions over a shared query.
+///
+/// The query has as many variables as the widest inner composition, and each inner composition is
+/// evaluated over the prefix of the query it reads. The degree of the sum is the maximum of the
+/// inner degrees.
+#[derive(Debug, Clone)]
+pub struct LinearCombinationComposition<F: TowerField, C> {
+	terms: Vec<(F, C)>,
+}
+
+impl<F: TowerField, C> LinearCombinationComposition<F, C> {
+	pub fn new(terms: impl IntoIterator<Item = (F, C)>) -> Self {
+		Self {
+			terms: terms.into_iter().collect(),
+		}
+	}
+
+	/// The coefficients and compositions of the sum.
+	pub fn terms(&self) -> &[(F, C)] {
+		&self.terms
+	}
+}
+
+impl<F, P, C> CompositionPoly<P> for LinearCombinationComposition<F, C>
+where
+	F: TowerField,
+	P: PackedField<Scalar: ExtensionField<F>>,
+	C: CompositionPoly<P>,
+{
+	fn n_vars(&self) -> usize {
+		self.terms
+			.iter()
+			.map(|(_, composition)| composition.n_vars())
+			.max()
+			.unwrap_or(0)
+	}
+
+	fn degree(&self) -> usize {
+		self.terms
+			.iter()
+			.map(|(_, composition)| composition.degree())
+			.max()
+			.unwrap_or(0)
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		self.terms
+			.iter()
+			.map(|(coeff, composition)| {
+				coeff
+					.min_tower_level()
+					.max(composition.binary_tower_level())
+			})
+			.max()
+			.unwrap_or(0)
+	}
+
+	fn expression(&self) -> ArithCircuit<P::Scalar> {
+		self.terms
+			.iter()
+			.map(|(coeff, composition)| {
+				ArithCircuit::constant((*coeff).into()) * composition.expression()
+			})
+			.sum()
+	}
+
+	fn evaluate(&self, query: &[P]) -> Result<P, Error> {
+		let n_vars = CompositionPoly::<P>::n_vars(self);
+		if query.len() != n_vars {
+			bail!(Error::IncorrectQuerySize { expected: n_vars });
+		}
+
+		self.terms
+			.iter()
+			.try_fold(P::zero(), |sum, (coeff, composition)| {
+				let eval = composition.evaluate(&query[..composition.n_vars()])?;
+				Ok(sum + eval * P::broadcast((*coeff).into()))
+			})
+	}
+
+	fn batch_evaluate(&self, batch_query: &RowsBatchRef<P>, evals: &mut [P]) -> Result<(), Error> {
+		let row_len = evals.len();
+		if batch_query.row_len() != row_len {
+			bail!(Error::BatchEvaluateSizeMismatch {
+				expected: row_len,
+				actual: batch_query.row_len(),
+			});
+		}
+		let n_vars = CompositionPoly::<P>::n_vars(self);
+		if batch_query.n_rows() != n_vars {
+			bail!(Error::IncorrectQuerySize { expected: n_vars });
+		}
+
+		evals.fill(P::zero());
+		let mut term_evals = vec![P::zero(); row_len];
+		for (coeff, composition) in &self.terms {
+			let subquery = RowsBatchRef::new(&batch_query.rows()[..composition.n_vars()], row_len);
+			composition.batch_evaluate(&subquery, &mut term_evals)?;
+
+			let coeff = P::broadcast((*coeff).into());
+			for (eval, &term_eval) in evals.iter_mut().zip(&term_evals) {
+				*eval += term_eval * coeff;
+			}
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField32b, BinaryField8b, PackedBinaryField4x32b};
+	use binius_math::ArithExpr;
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+	use crate::polynomial::{
+		test_utils::{assert_batch_evaluate_matches, assert_composition_consistent},
+		ArithCircuitPoly,
+	};
+
+	#[test]
+	fn test_linear_combination_composition() {
+		type F = BinaryField32b;
+		type P = PackedBinaryField4x32b;
+
+		let terms = [
+			(BinaryField8b::new(3), ArithExpr::Var(0) * ArithExpr::Var(1)),
+			(BinaryField8b::new(0x53), ArithExpr::Var(2).pow(3)),
+			(BinaryField8b::new(1), ArithExpr::Var(1) + ArithExpr::Const(F::new(7))),
+		]
+		.map(|(coeff, expr)| (coeff, ArithCircuitPoly::<F>::new((&expr).into())));
+		let composition = LinearCombinationComposition::new(terms.clone());
+		assert_eq!(CompositionPoly::<P>::n_vars(&composition), 3);
+		assert_eq!(CompositionPoly::<P>::degree(&composition), 3);
+		assert_eq!(CompositionPoly::<P>::binary_tower_level(&composition), 3);
+		assert_eq!(CompositionPoly::<P>::expression(&composition).degree(), 3);
+
+		let mut rng = StdRng::seed_from_u64(0);
+		assert_composition_consistent::<P, _>(&composition, &mut rng, 10);
+		assert_batch_evaluate_matches::<P, _>(&composition, &mut rng, 16, |point| {
+			terms
+				.iter()
+				.map(|(coeff, term)| {
+					let n_vars = CompositionPoly::<P>::n_vars(term);
+					term.evaluate(&point[..n_vars]).unwrap() * P::broadcast(F::from(*coeff))
+				})
+				.sum()
+		});
+	}
+}
@@ -2,8 +2,14 @@
 
 //! Commonly used composition polynomials.
 
+pub mod disjunction;
 pub mod index;
+pub mod linear_combination;
 pub mod product_composition;
+pub mod selected;
 
+pub use disjunction::*;
 pub use index::*;
+pub use linear_combination::*;
 pub use product_composition::*;
+pub use selected::*;
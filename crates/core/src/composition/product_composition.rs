@@ -1,7 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use binius_field::PackedField;
-use binius_math::{ArithCircuit, CompositionPoly};
+use binius_math::{ArithCircuit, CompositionPoly, RowsBatchRef};
 use binius_utils::bail;
 
 #[derive(Debug, Default, Copy, Clone)]
@@ -44,3 +44,150 @@ impl<P: PackedField, const N: usize> CompositionPoly<P> for ProductComposition<N
 
 pub type BivariateProduct = ProductComposition<2>;
 pub type TrivariateProduct = ProductComposition<3>;
+
+/// The product of several compositions over a shared query.
+///
+/// The query has as many variables as the widest inner composition, and each inner composition is
+/// evaluated over the prefix of the query it reads. The degree of the product is the sum of the
+/// inner degrees.
+#[derive(Debug, Clone)]
+pub struct CompositionProduct<C> {
+	compositions: Vec<C>,
+}
+
+impl<C> CompositionProduct<C> {
+	pub fn new(compositions: impl IntoIterator<Item = C>) -> Self {
+		Self {
+			compositions: compositions.into_iter().collect(),
+		}
+	}
+
+	pub fn compositions(&self) -> &[C] {
+		&self.compositions
+	}
+}
+
+impl<P: PackedField, C: CompositionPoly<P>> CompositionPoly<P> for CompositionProduct<C> {
+	fn n_vars(&self) -> usize {
+		self.compositions
+			.iter()
+			.map(|composition| composition.n_vars())
+			.max()
+			.unwrap_or(0)
+	}
+
+	fn degree(&self) -> usize {
+		self.compositions
+			.iter()
+			.map(|composition| composition.degree())
+			.sum()
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		self.compositions
+			.iter()
+			.map(|composition| composition.binary_tower_level())
+			.max()
+			.unwrap_or(0)
+	}
+
+	fn expression(&self) -> ArithCircuit<P::Scalar> {
+		self.compositions
+			.iter()
+			.map(|composition| composition.expression())
+			.product()
+	}
+
+	fn evaluate(&self, query: &[P]) -> Result<P, binius_math::Error> {
+		let n_vars = CompositionPoly::<P>::n_vars(self);
+		if query.len() != n_vars {
+			bail!(binius_math::Error::IncorrectQuerySize { expected: n_vars });
+		}
+
+		self.compositions
+			.iter()
+			.map(|composition| composition.evaluate(&query[..composition.n_vars()]))
+			.product()
+	}
+
+	fn batch_evaluate(
+		&self,
+		batch_query: &RowsBatchRef<P>,
+		evals: &mut [P],
+	) -> Result<(), binius_math::Error> {
+		let row_len = evals.len();
+		if batch_query.row_len() != row_len {
+			bail!(binius_math::Error::BatchEvaluateSizeMismatch {
+				expected: row_len,
+				actual: batch_query.row_len(),
+			});
+		}
+		let n_vars = CompositionPoly::<P>::n_vars(self);
+		if batch_query.n_rows() != n_vars {
+			bail!(binius_math::Error::IncorrectQuerySize { expected: n_vars });
+		}
+
+		let subquery = |composition: &C| {
+			RowsBatchRef::new(&batch_query.rows()[..composition.n_vars()], row_len)
+		};
+
+		let Some((first, rest)) = self.compositions.split_first() else {
+			evals.fill(P::one());
+			return Ok(());
+		};
+		first.batch_evaluate(&subquery(first), evals)?;
+
+		if !rest.is_empty() {
+			let mut factor_evals = vec![P::zero(); row_len];
+			for composition in rest {
+				composition.batch_evaluate(&subquery(composition), &mut factor_evals)?;
+				for (eval, &factor) in evals.iter_mut().zip(&factor_evals) {
+					*eval *= factor;
+				}
+			}
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField32b, PackedBinaryField4x32b};
+	use binius_math::ArithExpr;
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+	use crate::polynomial::{
+		test_utils::{assert_batch_evaluate_matches, assert_composition_consistent},
+		ArithCircuitPoly,
+	};
+
+	#[test]
+	fn test_composition_product() {
+		type F = BinaryField32b;
+		type P = PackedBinaryField4x32b;
+
+		let factors = [
+			ArithExpr::Var(0) * ArithExpr::Var(1) + ArithExpr::Const(F::new(7)),
+			ArithExpr::Var(2).pow(3),
+			ArithExpr::Var(1) + ArithExpr::Var(0),
+		]
+		.map(|expr| ArithCircuitPoly::<F>::new((&expr).into()));
+		let product = CompositionProduct::new(factors.clone());
+		assert_eq!(CompositionPoly::<P>::n_vars(&product), 3);
+		assert_eq!(CompositionPoly::<P>::degree(&product), 6);
+		assert_eq!(CompositionPoly::<P>::expression(&product).degree(), 6);
+
+		let mut rng = StdRng::seed_from_u64(0);
+		assert_composition_consistent::<P, _>(&product, &mut rng, 10);
+		assert_batch_evaluate_matches::<P, _>(&product, &mut rng, 16, |point| {
+			factors
+				.iter()
+				.map(|factor| {
+					let n_vars = CompositionPoly::<P>::n_vars(factor);
+					factor.evaluate(&point[..n_vars]).unwrap()
+				})
+				.product()
+		});
+	}
+}
@@ -0,0 +1,239 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::PackedField;
+use binius_math::{ArithCircuit, CompositionPoly, RowsBatchRef};
+use binius_utils::bail;
+
+use super::CompositionProduct;
+
+/// A composition that only applies where a selector is set.
+///
+/// The composition is the [`CompositionProduct`] `inner * selector`, where the selector is the
+/// variable following the variables of the inner composition. With a 1-bit selector, a zero
+/// constraint on this composition only constrains the rows where the selector is one. The degree
+/// is one more than the inner degree.
+#[derive(Debug, Clone)]
+pub struct SelectedComposition<C> {
+	n_vars: usize,
+	product: CompositionProduct<SelectedFactor<C>>,
+}
+
+impl<C> SelectedComposition<C> {
+	/// Creates the composition gating `inner`, which reads the first `inner_n_vars` variables of
+	/// the query, by the selector at variable `inner_n_vars`.
+	pub fn new(inner_n_vars: usize, inner: C) -> Self {
+		Self {
+			n_vars: inner_n_vars + 1,
+			product: CompositionProduct::new([
+				SelectedFactor::Inner(inner),
+				SelectedFactor::Selector {
+					index: inner_n_vars,
+				},
+			]),
+		}
+	}
+
+	pub fn inner(&self) -> &C {
+		match &self.product.compositions()[0] {
+			SelectedFactor::Inner(inner) => inner,
+			SelectedFactor::Selector { .. } => unreachable!("the first factor is the inner one"),
+		}
+	}
+
+	/// The index of the selector in the query.
+	pub const fn selector_index(&self) -> usize {
+		self.n_vars - 1
+	}
+
+	/// Checks that the inner composition does not read the selector variable.
+	fn check_inner_n_vars<P: PackedField>(&self) -> Result<(), binius_math::Error>
+	where
+		C: CompositionPoly<P>,
+	{
+		let inner_n_vars = self.inner().n_vars();
+		if inner_n_vars > self.selector_index() {
+			bail!(binius_math::Error::IncorrectNumberOfVariables {
+				expected: self.selector_index(),
+				actual: inner_n_vars,
+			});
+		}
+		Ok(())
+	}
+}
+
+impl<P: PackedField, C: CompositionPoly<P>> CompositionPoly<P> for SelectedComposition<C> {
+	fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	fn degree(&self) -> usize {
+		self.product.degree()
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		self.product.binary_tower_level()
+	}
+
+	fn expression(&self) -> ArithCircuit<P::Scalar> {
+		self.product.expression()
+	}
+
+	fn evaluate(&self, query: &[P]) -> Result<P, binius_math::Error> {
+		self.check_inner_n_vars()?;
+		self.product.evaluate(query)
+	}
+
+	fn batch_evaluate(
+		&self,
+		batch_query: &RowsBatchRef<P>,
+		evals: &mut [P],
+	) -> Result<(), binius_math::Error> {
+		self.check_inner_n_vars()?;
+		self.product.batch_evaluate(batch_query, evals)
+	}
+}
+
+/// A factor of a [`SelectedComposition`]: either the inner composition or the projection onto the
+/// selector variable.
+#[derive(Debug, Clone)]
+enum SelectedFactor<C> {
+	Inner(C),
+	Selector { index: usize },
+}
+
+impl<P: PackedField, C: CompositionPoly<P>> CompositionPoly<P> for SelectedFactor<C> {
+	fn n_vars(&self) -> usize {
+		match self {
+			Self::Inner(inner) => inner.n_vars(),
+			Self::Selector { index } => index + 1,
+		}
+	}
+
+	fn degree(&self) -> usize {
+		match self {
+			Self::Inner(inner) => inner.degree(),
+			Self::Selector { .. } => 1,
+		}
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		match self {
+			Self::Inner(inner) => inner.binary_tower_level(),
+			Self::Selector { .. } => 0,
+		}
+	}
+
+	fn expression(&self) -> ArithCircuit<P::Scalar> {
+		match self {
+			Self::Inner(inner) => inner.expression(),
+			Self::Selector { index } => ArithCircuit::var(*index),
+		}
+	}
+
+	fn evaluate(&self, query: &[P]) -> Result<P, binius_math::Error> {
+		match self {
+			Self::Inner(inner) => inner.evaluate(query),
+			Self::Selector { index } => {
+				if query.len() != index + 1 {
+					bail!(binius_math::Error::IncorrectQuerySize {
+						expected: index + 1
+					});
+				}
+				Ok(query[*index])
+			}
+		}
+	}
+
+	fn batch_evaluate(
+		&self,
+		batch_query: &RowsBatchRef<P>,
+		evals: &mut [P],
+	) -> Result<(), binius_math::Error> {
+		match self {
+			Self::Inner(inner) => inner.batch_evaluate(batch_query, evals),
+			Self::Selector { index } => {
+				if batch_query.row_len() != evals.len() {
+					bail!(binius_math::Error::BatchEvaluateSizeMismatch {
+						expected: evals.len(),
+						actual: batch_query.row_len(),
+					});
+				}
+				if batch_query.n_rows() != index + 1 {
+					bail!(binius_math::Error::IncorrectQuerySize {
+						expected: index + 1
+					});
+				}
+				evals.copy_from_slice(batch_query.rows()[*index]);
+				Ok(())
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use assert_matches::assert_matches;
+	use binius_field::{BinaryField32b, PackedBinaryField4x32b};
+	use binius_math::ArithExpr;
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+	use crate::polynomial::{
+		test_utils::{assert_batch_evaluate_matches, assert_composition_consistent},
+		ArithCircuitPoly,
+	};
+
+	#[test]
+	fn test_selected_composition() {
+		type F = BinaryField32b;
+		type P = PackedBinaryField4x32b;
+
+		let inner_expr = ArithExpr::Var(0) * ArithExpr::Var(1) + ArithExpr::Const(F::new(7));
+		let inner = ArithCircuitPoly::<F>::new((&inner_expr).into());
+		let selected = SelectedComposition::new(2, inner);
+		let expected = ArithCircuitPoly::<F>::new((&(inner_expr * ArithExpr::Var(2))).into());
+
+		assert_eq!(selected.selector_index(), 2);
+		assert_eq!(CompositionPoly::<P>::n_vars(&selected), 3);
+		assert_eq!(CompositionPoly::<P>::degree(&selected), 3);
+		assert_eq!(CompositionPoly::<P>::expression(&selected).degree(), 3);
+
+		let mut rng = StdRng::seed_from_u64(0);
+		assert_composition_consistent::<P, _>(&selected, &mut rng, 10);
+		assert_batch_evaluate_matches::<P, _>(&selected, &mut rng, 16, |point| {
+			expected.evaluate(point).unwrap()
+		});
+
+		// The inner composition does not apply where the selector is zero.
+		let point = [P::random(&mut rng), P::random(&mut rng), P::zero()];
+		assert_eq!(selected.evaluate(&point).unwrap(), P::zero());
+	}
+
+	#[test]
+	fn test_selected_composition_narrow_inner() {
+		type F = BinaryField32b;
+		type P = PackedBinaryField4x32b;
+
+		// The inner composition reads a prefix of the variables before the selector.
+		let inner = ArithCircuitPoly::<F>::new((&ArithExpr::Var(0).pow(2)).into());
+		let selected = SelectedComposition::new(3, inner);
+		assert_eq!(selected.selector_index(), 3);
+		assert_eq!(CompositionPoly::<P>::n_vars(&selected), 4);
+
+		let mut rng = StdRng::seed_from_u64(0);
+		assert_batch_evaluate_matches::<P, _>(&selected, &mut rng, 16, |point| {
+			point[0].square() * point[3]
+		});
+
+		// An inner composition that reads the selector variable is rejected.
+		let selected =
+			SelectedComposition::new(1, ArithCircuitPoly::<F>::new((&ArithExpr::Var(1)).into()));
+		assert_matches!(
+			CompositionPoly::<P>::evaluate(&selected, &[P::one(); 2]),
+			Err(binius_math::Error::IncorrectNumberOfVariables {
+				expected: 1,
+				actual: 2
+			})
+		);
+	}
+}
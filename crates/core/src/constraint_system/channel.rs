@@ -220,7 +220,7 @@ where
 
 			tracing::debug!("Channel {:?} unbalanced: {:?}", id, unbalanced_flushes);
 
-			return Err((VerificationError::ChannelUnbalanced { id }).into());
+			return Err((VerificationError::ChannelUnbalanced { channel: id }).into());
 		}
 	}
 
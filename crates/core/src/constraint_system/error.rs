@@ -45,6 +45,16 @@ pub enum Error {
 		witness_num_vars: usize,
 	},
 
+	#[error("zero constraint {constraint_name} over oracles {oracle_ids:?} is violated at row {row_index}: evaluated to {evaluated_value}")]
+	ZeroConstraintViolation {
+		constraint_name: String,
+		/// The oracles read by the constraint composition.
+		oracle_ids: Vec<OracleId>,
+		/// The hypercube index of the first violating row.
+		row_index: usize,
+		evaluated_value: String,
+	},
+
 	#[error("flush selector oracle {selector} incompatible with oracle {id}")]
 	IncompatibleFlushSelector { id: OracleId, selector: OracleId },
 
@@ -105,6 +115,9 @@ pub enum Error {
 	#[error("transcript error: {0}")]
 	TranscriptError(#[from] crate::transcript::Error),
 
+	#[error("failed to write the proof: {0}")]
+	ProofWrite(#[source] std::io::Error),
+
 	#[error("gkr exp error: {0}")]
 	GkrExp(#[from] crate::protocols::gkr_exp::Error),
 }
@@ -116,7 +129,20 @@ pub enum VerificationError {
 	#[error("the number of flush products must equal the number of flushes")]
 	IncorrectNumberOfFlushProducts,
 	#[error(
-		"Channel with id={id} is not balanced. Pushes and pulls do not contain the same elements"
+		"Channel with id={channel} is not balanced. Pushes and pulls do not contain the same elements"
 	)]
-	ChannelUnbalanced { id: ChannelId },
+	ChannelUnbalanced { channel: ChannelId },
+	#[error(
+		"sumcheck verification failed{}",
+		.round.map_or_else(String::new, |round| format!(" in round {round}"))
+	)]
+	SumcheckFailed {
+		/// The number of rounds verified when the proof is rejected, if the sub-protocol reports
+		/// it. A failed final evaluation check reports the total number of rounds.
+		round: Option<usize>,
+	},
+	#[error("a Merkle tree opening in the proof is invalid")]
+	MerkleOpeningInvalid,
+	#[error("the proof transcript does not match the constraint system: {reason}")]
+	TranscriptMismatch { reason: String },
 }
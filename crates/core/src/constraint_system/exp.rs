@@ -52,7 +52,7 @@ pub fn max_n_vars<F: TowerField>(exponents: &[Exp<F>], oracles: &MultilinearOrac
 		.unwrap_or(0)
 }
 
-type MultiplicationWitnesses<'a, U, Tower> =
+pub(super) type MultiplicationWitnesses<'a, U, Tower> =
 	Vec<BaseExpWitness<'a, PackedType<U, FFastExt<Tower>>>>;
 
 /// Constructs [`BaseExpWitness`] instances and adds the exponentiation-result witnesses
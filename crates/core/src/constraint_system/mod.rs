@@ -4,16 +4,26 @@ pub mod channel;
 mod common;
 pub mod error;
 pub mod exp;
+mod proof_size;
 mod prove;
+mod stats;
 pub mod validate;
 mod verify;
 
 use binius_field::{BinaryField128b, TowerField};
 use binius_macros::{DeserializeBytes, SerializeBytes};
+use binius_utils::{
+	bytes::{Buf, BufMut},
+	DeserializeBytes, SerializationError, SerializationMode, SerializeBytes,
+};
 use channel::{ChannelId, Flush};
 use exp::Exp;
-pub use prove::prove;
-pub use verify::verify;
+pub use prove::{
+	prove, prove_streaming, prove_until_commit, prove_with_domain_separator, prove_with_stats,
+	CommitDryRun, CommitTimings, ProveStats, ProverPackedTop,
+};
+pub use stats::{ConstraintSystemStats, OracleKind};
+pub use verify::{verify, verify_batch, verify_with_domain_separator};
 
 use crate::oracle::{ConstraintSet, MultilinearOracleSet, OracleId};
 
@@ -48,7 +58,68 @@ pub struct Proof {
 }
 
 impl Proof {
+	/// The header identifying serialized proofs.
+	pub const MAGIC: [u8; 6] = *b"BINIUS";
+	/// The version of the proof serialization format.
+	pub const VERSION: u8 = 1;
+
 	pub fn get_proof_size(&self) -> usize {
 		self.transcript.len()
 	}
 }
+
+/// Serializes the proof as the [`Proof::MAGIC`] header, the [`Proof::VERSION`] byte and the
+/// length-prefixed transcript bytes.
+impl SerializeBytes for Proof {
+	fn serialize(
+		&self,
+		mut write_buf: impl BufMut,
+		mode: SerializationMode,
+	) -> Result<(), SerializationError> {
+		let len = Self::MAGIC.len() + 1 + size_of::<u32>() + self.transcript.len();
+		if write_buf.remaining_mut() < len {
+			return Err(SerializationError::WriteBufferFull);
+		}
+		write_buf.put_slice(&Self::MAGIC);
+		SerializeBytes::serialize(&Self::VERSION, &mut write_buf, mode)?;
+		SerializeBytes::serialize(&self.transcript.len(), &mut write_buf, mode)?;
+		write_buf.put_slice(&self.transcript);
+		Ok(())
+	}
+}
+
+impl DeserializeBytes for Proof {
+	fn deserialize(
+		mut read_buf: impl Buf,
+		mode: SerializationMode,
+	) -> Result<Self, SerializationError>
+	where
+		Self: Sized,
+	{
+		if read_buf.remaining() < Self::MAGIC.len() {
+			return Err(SerializationError::NotEnoughBytes);
+		}
+		let mut magic = [0; Self::MAGIC.len()];
+		read_buf.copy_to_slice(&mut magic);
+		if magic != Self::MAGIC {
+			return Err(SerializationError::InvalidMagic { name: "Proof" });
+		}
+
+		let version: u8 = DeserializeBytes::deserialize(&mut read_buf, mode)?;
+		if version != Self::VERSION {
+			return Err(SerializationError::UnsupportedVersion {
+				name: "Proof",
+				expected: Self::VERSION,
+				got: version,
+			});
+		}
+
+		let len: usize = DeserializeBytes::deserialize(&mut read_buf, mode)?;
+		if read_buf.remaining() < len {
+			return Err(SerializationError::NotEnoughBytes);
+		}
+		Ok(Self {
+			transcript: read_buf.copy_to_bytes(len).to_vec(),
+		})
+	}
+}
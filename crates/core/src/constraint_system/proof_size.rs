@@ -0,0 +1,658 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{
+	collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+	iter, mem,
+};
+
+use binius_field::{tower::TowerFamily, BinaryField, ExtensionField, TowerField};
+use binius_hash::PseudoCompressionFunction;
+use binius_math::CompositionPoly;
+use digest::{core_api::BlockSizeUser, Digest, Output};
+use itertools::chain;
+
+use super::{
+	channel::OracleOrConst,
+	common::{FDomain, FEncode},
+	error::Error,
+	exp::{reorder_exponents, Exp},
+	verify::{make_flush_oracles, max_n_vars_and_skip_rounds},
+	ConstraintSystem,
+};
+use crate::{
+	merkle_tree::{BinaryMerkleTreeScheme, MerkleTreeScheme},
+	oracle::{MultilinearOracleSet, MultilinearPolyVariant, OracleId},
+	piop,
+	protocols::sumcheck::{constraint_set_zerocheck_claim, zerocheck::domain_size},
+	transparent::eq_ind::EqIndPartialEval,
+};
+
+impl<F: TowerField> ConstraintSystem<F> {
+	/// Estimates the size in bytes of a proof for this constraint system.
+	///
+	/// The estimate follows the verifier through the protocol and counts the field elements,
+	/// digests and hints it reads from the transcript, without generating a witness or a proof.
+	/// The evalcheck hints and the ring-switching tensor elements depend on which evaluation points
+	/// coincide, so the points are tracked symbolically, assuming distinct challenges never take
+	/// the same value.
+	///
+	/// Each step mirrors the step of the same name in `verify_transcript`, and any change to what
+	/// the verifier reads from the transcript must be made here as well. `test_estimate_proof_size`
+	/// in `binius_circuits` checks the estimate against the size of real proofs.
+	pub fn estimate_proof_size<Tower, Hash, Compress>(
+		&self,
+		log_inv_rate: usize,
+		security_bits: usize,
+	) -> Result<usize, Error>
+	where
+		Tower: TowerFamily<B128 = F>,
+		F: ExtensionField<FEncode<Tower>>,
+		Hash: Digest + BlockSizeUser,
+		Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	{
+		let Self {
+			mut oracles,
+			mut table_constraints,
+			mut flushes,
+			non_zero_oracle_ids,
+			max_channel_id,
+			mut exponents,
+		} = self.clone();
+
+		table_constraints.sort_by_key(|constraint_set| constraint_set.n_vars);
+
+		let merkle_scheme = BinaryMerkleTreeScheme::<F, Hash, _>::new(Compress::default());
+		let (commit_meta, _) = piop::make_oracle_commit_meta(&oracles)?;
+		let fri_params = piop::make_commit_params_with_optimal_arity::<_, FEncode<Tower>, _>(
+			&commit_meta,
+			&merkle_scheme,
+			security_bits,
+			log_inv_rate,
+		)?;
+
+		// Polynomial commitment, mirrors the commitment read in `verify_transcript`
+		let mut n_digests = 1;
+
+		// GKR exp multiplication, mirrors the exponent evaluations read in `verify_transcript` and
+		// `gkr_exp::batch_verify`
+		reorder_exponents(&mut exponents, &oracles);
+		let mut n_scalars = exponents.len() + exp_sumcheck_scalars(&exponents, &oracles);
+
+		// Grand products, mirrors the products read in `verify_transcript` and
+		// `gkr_gpa::batch_verify`. The challenges do not change the shape of the flush oracles.
+		flushes.sort_by_key(|flush| flush.channel_id);
+		let flush_oracle_ids =
+			make_flush_oracles(&mut oracles, &flushes, F::ONE, &vec![F::ONE; max_channel_id + 1])?;
+		n_scalars += non_zero_oracle_ids.len() + flush_oracle_ids.len();
+
+		let grand_product_ids = chain!(flush_oracle_ids, non_zero_oracle_ids).collect::<Vec<_>>();
+		n_scalars += grand_product_scalars(
+			&grand_product_ids
+				.iter()
+				.map(|&id| oracles.n_vars(id))
+				.collect::<Vec<_>>(),
+		);
+
+		// Zerocheck, mirrors `sumcheck::batch_verify_zerocheck`: the univariate round
+		// extrapolation, the front-loaded eq-ind sumcheck and the univariatizing reduction
+		let (zerocheck_claims, zerocheck_oracle_metas) = table_constraints
+			.into_iter()
+			.map(constraint_set_zerocheck_claim)
+			.collect::<Result<Vec<_>, _>>()?
+			.into_iter()
+			.unzip::<_, _, Vec<_>, Vec<_>>();
+
+		let (_max_n_vars, skip_rounds) =
+			max_n_vars_and_skip_rounds(&zerocheck_claims, <FDomain<Tower>>::N_BITS);
+
+		let max_domain_size = zerocheck_claims
+			.iter()
+			.map(|claim| domain_size(claim.max_individual_degree(), skip_rounds))
+			.max()
+			.unwrap_or(0);
+		n_scalars += max_domain_size - (1 << skip_rounds).min(max_domain_size);
+
+		let eq_ind_claims = zerocheck_claims
+			.iter()
+			.map(|claim| SumcheckShape {
+				n_vars: claim.n_vars().saturating_sub(skip_rounds),
+				n_multilinears: claim.n_multilinears() + 1,
+				degree: extra_product_degree(
+					claim
+						.composite_zeros()
+						.iter()
+						.map(CompositionPoly::<F>::degree),
+				),
+			})
+			.collect::<Vec<_>>();
+		n_scalars += front_loaded_scalars(&eq_ind_claims);
+
+		let n_univariatized = zerocheck_claims
+			.iter()
+			.map(|claim| claim.n_multilinears())
+			.sum::<usize>();
+		n_scalars += front_loaded_scalars(&[SumcheckShape {
+			n_vars: skip_rounds,
+			n_multilinears: n_univariatized + 1,
+			degree: if n_univariatized == 0 { 0 } else { 2 },
+		}]);
+
+		// Evalcheck, mirrors the claims passed to `greedy_evalcheck::verify` in `verify_transcript`
+		let mut evalcheck = EvalcheckEstimator::new(&mut oracles);
+
+		let grand_product_points = grand_product_ids
+			.iter()
+			.map(|&id| evalcheck.oracles.n_vars(id))
+			.collect::<BTreeSet<_>>()
+			.into_iter()
+			.map(|n_vars| (n_vars, evalcheck.new_challenges(n_vars)))
+			.collect::<HashMap<_, _>>();
+		let grand_product_claims = grand_product_ids
+			.iter()
+			.map(|&id| (id, grand_product_points[&evalcheck.oracles.n_vars(id)].clone()))
+			.collect::<Vec<_>>();
+
+		// The univariate round challenges come first, followed by the multilinear round challenges
+		// of which each claim takes a suffix.
+		let max_n_vars = zerocheck_claims
+			.iter()
+			.map(|claim| claim.n_vars())
+			.max()
+			.unwrap_or(0);
+		let skipped_challenges = evalcheck.new_challenges(skip_rounds);
+		let unskipped_challenges = evalcheck.new_challenges(max_n_vars - skip_rounds);
+		let zerocheck_eval_claims = zerocheck_oracle_metas
+			.iter()
+			.flat_map(|meta| {
+				let eval_point = [
+					&skipped_challenges[..meta.n_vars.min(skip_rounds)],
+					&unskipped_challenges
+						[(max_n_vars - meta.n_vars).min(unskipped_challenges.len())..],
+				]
+				.concat();
+				meta.oracle_ids
+					.iter()
+					.map(move |&id| (id, eval_point.clone()))
+			})
+			.collect::<Vec<_>>();
+
+		let exp_claims = exp_eval_claims(&mut evalcheck, &exponents);
+
+		evalcheck.run(chain!(grand_product_claims, zerocheck_eval_claims, exp_claims).collect())?;
+		let EvalcheckEstimator {
+			n_scalars: evalcheck_scalars,
+			n_bytes,
+			committed_claims,
+			..
+		} = evalcheck;
+		n_scalars += evalcheck_scalars;
+
+		// Ring switch, mirrors `ring_switch::verify`: one tensor algebra element per distinct
+		// evaluation point prefix, and one mixing scalar per committed claim
+		let kappa = |id: OracleId| F::TOWER_LEVEL - oracles.tower_level(id);
+		let prefixes = committed_claims
+			.iter()
+			.map(|(id, eval_point)| {
+				let mut prefix = eval_point[..kappa(*id).min(eval_point.len())].to_vec();
+				prefix.resize(kappa(*id), ZERO);
+				prefix
+			})
+			.collect::<HashSet<_>>();
+		n_scalars += prefixes
+			.iter()
+			.map(|prefix| 1 << prefix.len())
+			.sum::<usize>();
+		n_scalars += committed_claims.len();
+
+		// PIOP sumcheck, mirrors `piop::verify`: one claim per number of packed variables, and one
+		// FRI round commitment per oracle
+		let mut n_transparents_by_vars = vec![0; commit_meta.max_n_vars() + 1];
+		for &(id, _) in &committed_claims {
+			n_transparents_by_vars[oracles.n_vars(id) - kappa(id)] += 1;
+		}
+		let piop_claims = iter::zip(commit_meta.n_multilins_by_vars(), &n_transparents_by_vars)
+			.enumerate()
+			.filter(|(_, (&n_committed, _))| n_committed != 0)
+			.map(|(n_vars, (&n_committed, &n_transparent))| SumcheckShape {
+				n_vars,
+				n_multilinears: n_committed + n_transparent,
+				degree: if n_transparent == 0 { 0 } else { 2 },
+			})
+			.collect::<Vec<_>>();
+		n_scalars += front_loaded_scalars(&piop_claims);
+		n_digests += fri_params.n_oracles();
+
+		// FRI query phase, mirrors `FRIVerifier::verify`: the terminate codeword, then the Merkle
+		// layers and the coset openings of every query round
+		n_scalars += 1 << (fri_params.n_final_challenges() + log_inv_rate);
+		let n_test_queries = fri_params.n_test_queries();
+		let mut tree_depth = fri_params.log_len();
+		for &arity in fri_params.fold_arities() {
+			tree_depth -= arity;
+			let layer_depth = merkle_scheme.optimal_verify_layer(n_test_queries, tree_depth);
+			n_digests += 1 << layer_depth;
+			n_scalars += n_test_queries << arity;
+			n_digests += n_test_queries * (tree_depth - layer_depth);
+		}
+
+		Ok(n_scalars * size_of::<F>() + n_digests * <Hash as Digest>::output_size() + n_bytes)
+	}
+}
+
+/// The shape of a sumcheck claim, which is all that determines the size of its proof.
+#[derive(Debug, Clone, Copy)]
+struct SumcheckShape {
+	n_vars: usize,
+	n_multilinears: usize,
+	degree: usize,
+}
+
+/// Number of scalars in a front-loaded batch sumcheck proof, see
+/// [`front_loaded::BatchVerifier`](crate::protocols::sumcheck::front_loaded::BatchVerifier).
+fn front_loaded_scalars(claims: &[SumcheckShape]) -> usize {
+	let n_rounds = claims.iter().map(|claim| claim.n_vars).max().unwrap_or(0);
+	let round_scalars = (0..n_rounds)
+		.map(|round| {
+			claims
+				.iter()
+				.filter(|claim| claim.n_vars > round)
+				.map(|claim| claim.degree)
+				.max()
+				.unwrap_or(0)
+		})
+		.sum::<usize>();
+	round_scalars
+		+ claims
+			.iter()
+			.map(|claim| claim.n_multilinears)
+			.sum::<usize>()
+}
+
+/// Number of scalars in a regular batch sumcheck proof, see
+/// [`batch_verify`](crate::protocols::sumcheck::batch_verify).
+fn batch_sumcheck_scalars(claims: &[SumcheckShape]) -> usize {
+	let n_rounds = claims.iter().map(|claim| claim.n_vars).max().unwrap_or(0);
+	let round_scalars = (0..n_rounds)
+		.map(|round| {
+			claims
+				.iter()
+				.filter(|claim| claim.n_vars >= n_rounds - round)
+				.map(|claim| claim.degree)
+				.max()
+				.unwrap_or(0)
+		})
+		.sum::<usize>();
+	round_scalars
+		+ claims
+			.iter()
+			.map(|claim| claim.n_multilinears)
+			.sum::<usize>()
+}
+
+/// Degree of the compositions after multiplying each by the equality indicator.
+fn extra_product_degree(degrees: impl IntoIterator<Item = usize>) -> usize {
+	degrees
+		.into_iter()
+		.map(|degree| degree + 1)
+		.max()
+		.unwrap_or(0)
+}
+
+/// Number of scalars in the layer sumchecks of a batched grand product argument, see
+/// [`gkr_gpa::batch_verify`](crate::protocols::gkr_gpa::batch_verify).
+fn grand_product_scalars(n_vars: &[usize]) -> usize {
+	let max_n_vars = n_vars.iter().copied().max().unwrap_or(0);
+	(0..max_n_vars)
+		.map(|layer_no| {
+			let n_claims = n_vars.iter().filter(|&&n_vars| n_vars > layer_no).count();
+			front_loaded_scalars(&[SumcheckShape {
+				n_vars: layer_no,
+				n_multilinears: 2 * n_claims + 1,
+				degree: 3,
+			}])
+		})
+		.sum()
+}
+
+/// Number of scalars in the layer sumchecks of the GKR exponentiation, see
+/// [`gkr_exp::batch_verify`](crate::protocols::gkr_exp::batch_verify).
+///
+/// Exponents with the same number of variables share their evaluation points, so in every layer
+/// they are batched into one eq-ind sumcheck claim.
+fn exp_sumcheck_scalars<F: TowerField>(
+	exponents: &[Exp<F>],
+	oracles: &MultilinearOracleSet<F>,
+) -> usize {
+	let max_exponent_bit_number = exponents
+		.iter()
+		.map(|exp| exp.bits_ids.len())
+		.max()
+		.unwrap_or(0);
+
+	(0..max_exponent_bit_number)
+		.map(|layer_no| {
+			let mut claims = Vec::<SumcheckShape>::new();
+			for exp in exponents.iter().filter(|exp| layer_no < exp.bits_ids.len()) {
+				let is_last_layer = layer_no == exp.bits_ids.len() - 1;
+				let (n_multilinears, degree) = match (&exp.base, is_last_layer) {
+					(OracleOrConst::Const { .. }, true) => (0, 0),
+					(OracleOrConst::Const { .. }, false) | (OracleOrConst::Oracle(_), true) => {
+						(2, 2)
+					}
+					(OracleOrConst::Oracle(_), false) => (3, 4),
+				};
+				let n_vars = exp.n_vars(oracles);
+				match claims.last_mut() {
+					Some(claim) if claim.n_vars == n_vars => {
+						claim.n_multilinears += n_multilinears;
+						claim.degree = claim.degree.max(degree);
+					}
+					_ => claims.push(SumcheckShape {
+						n_vars,
+						n_multilinears,
+						degree,
+					}),
+				}
+			}
+
+			let claims = claims
+				.into_iter()
+				.filter(|claim| claim.n_multilinears != 0)
+				.map(|claim| SumcheckShape {
+					n_vars: claim.n_vars,
+					n_multilinears: claim.n_multilinears + 1,
+					degree: claim.degree + 1,
+				})
+				.collect::<Vec<_>>();
+			batch_sumcheck_scalars(&claims)
+		})
+		.sum()
+}
+
+/// The evaluation claims on exponent bits and dynamic bases left by the GKR exponentiation, as
+/// made by `exp::make_eval_claims`.
+fn exp_eval_claims<F: TowerField>(
+	evalcheck: &mut EvalcheckEstimator<F>,
+	exponents: &[Exp<F>],
+) -> Vec<(OracleId, SymbolicPoint)> {
+	let max_exponent_bit_number = exponents
+		.iter()
+		.map(|exp| exp.bits_ids.len())
+		.max()
+		.unwrap_or(0);
+	let n_vars = |evalcheck: &EvalcheckEstimator<F>, exp: &Exp<F>| exp.n_vars(evalcheck.oracles);
+
+	// The challenges of every layer sumcheck, preceded by the exponentiation challenge
+	let mut layer_challenges = vec![evalcheck.new_challenges(
+		exponents
+			.iter()
+			.map(|exp| n_vars(evalcheck, exp))
+			.max()
+			.unwrap_or(0),
+	)];
+	for layer_no in 0..max_exponent_bit_number {
+		let n_rounds = exponents
+			.iter()
+			.filter(|exp| match exp.base {
+				OracleOrConst::Const { .. } => layer_no + 1 < exp.bits_ids.len(),
+				OracleOrConst::Oracle(_) => layer_no < exp.bits_ids.len(),
+			})
+			.map(|exp| n_vars(evalcheck, exp))
+			.max()
+			.unwrap_or(0);
+		layer_challenges.push(evalcheck.new_challenges(n_rounds));
+	}
+
+	let mut claims = Vec::new();
+	for layer_no in 0..max_exponent_bit_number {
+		for exp in exponents.iter().rev() {
+			let width = exp.bits_ids.len();
+			if layer_no >= width {
+				continue;
+			}
+
+			let n_vars = n_vars(evalcheck, exp);
+			let eval_point = layer_challenges[layer_no + 1][..n_vars].to_vec();
+			match exp.base {
+				OracleOrConst::Oracle(base_id) => {
+					claims.push((exp.bits_ids[layer_no], eval_point.clone()));
+					claims.push((base_id, eval_point));
+				}
+				OracleOrConst::Const { .. } => {
+					// The last bit is derived from the claim of the previous layer
+					let eval_point = if layer_no == width - 1 {
+						layer_challenges[layer_no][..n_vars].to_vec()
+					} else {
+						eval_point
+					};
+					claims.push((exp.bits_ids[width - 1 - layer_no], eval_point));
+				}
+			}
+		}
+	}
+	claims
+}
+
+/// A symbolic evaluation point.
+///
+/// Every coordinate is a symbol that stands for one challenge or one constant, so two points are
+/// equal when they are made of the same challenges and constants.
+type SymbolicPoint = Vec<usize>;
+
+/// The symbol for zero coordinates.
+const ZERO: usize = 0;
+
+/// Replays [`greedy_evalcheck::verify`](crate::protocols::greedy_evalcheck::verify) on symbolic
+/// evaluation points, counting the hints and scalars read from the transcript.
+struct EvalcheckEstimator<'a, F: TowerField> {
+	oracles: &'a mut MultilinearOracleSet<F>,
+	n_scalars: usize,
+	n_bytes: usize,
+	n_symbols: usize,
+	constants: HashMap<F, usize>,
+	projected_values: HashMap<OracleId, SymbolicPoint>,
+	round_claims: HashSet<(OracleId, SymbolicPoint)>,
+	committed_claims: Vec<(OracleId, SymbolicPoint)>,
+	new_sumchecks: BTreeMap<usize, (BTreeSet<OracleId>, usize)>,
+}
+
+impl<'a, F: TowerField> EvalcheckEstimator<'a, F> {
+	fn new(oracles: &'a mut MultilinearOracleSet<F>) -> Self {
+		Self {
+			oracles,
+			n_scalars: 0,
+			n_bytes: 0,
+			n_symbols: ZERO + 1,
+			constants: HashMap::from([(F::ZERO, ZERO)]),
+			projected_values: HashMap::new(),
+			round_claims: HashSet::new(),
+			committed_claims: Vec::new(),
+			new_sumchecks: BTreeMap::new(),
+		}
+	}
+
+	fn new_challenges(&mut self, n: usize) -> SymbolicPoint {
+		self.n_symbols += n;
+		(self.n_symbols - n..self.n_symbols).collect()
+	}
+
+	fn constant(&mut self, value: F) -> usize {
+		*self.constants.entry(value).or_insert_with(|| {
+			self.n_symbols += 1;
+			self.n_symbols - 1
+		})
+	}
+
+	/// The values a projected oracle fixes its variables to.
+	///
+	/// The oracles projected by the estimator stand in for oracles projected onto challenges, the
+	/// others are projected onto the constants they hold.
+	fn projected_values(&mut self, id: OracleId, values: &[F]) -> SymbolicPoint {
+		match self.projected_values.get(&id) {
+			Some(values) => values.clone(),
+			None => values.iter().map(|&value| self.constant(value)).collect(),
+		}
+	}
+
+	fn run(&mut self, claims: Vec<(OracleId, SymbolicPoint)>) -> Result<(), Error> {
+		self.verify(claims)?;
+
+		loop {
+			let new_sumchecks = mem::take(&mut self.new_sumchecks);
+			if new_sumchecks.is_empty() {
+				break;
+			}
+
+			let claims = new_sumchecks
+				.iter()
+				.map(|(&n_vars, (oracle_ids, degree))| SumcheckShape {
+					n_vars,
+					n_multilinears: oracle_ids.len(),
+					degree: *degree,
+				})
+				.collect::<Vec<_>>();
+			self.n_scalars += front_loaded_scalars(&claims);
+
+			// The sumcheck folds high-to-low, so a claim on n variables is evaluated at the first
+			// n challenges in reverse order.
+			let max_n_vars = claims.last().map_or(0, |claim| claim.n_vars);
+			let challenges = self.new_challenges(max_n_vars);
+			let eval_claims = new_sumchecks
+				.into_iter()
+				.flat_map(|(n_vars, (oracle_ids, _))| {
+					let eval_point = challenges[..n_vars]
+						.iter()
+						.rev()
+						.copied()
+						.collect::<Vec<_>>();
+					oracle_ids
+						.into_iter()
+						.map(move |id| (id, eval_point.clone()))
+				})
+				.collect();
+			self.verify(eval_claims)?;
+		}
+		Ok(())
+	}
+
+	fn verify(&mut self, claims: Vec<(OracleId, SymbolicPoint)>) -> Result<(), Error> {
+		self.round_claims.clear();
+		for (id, eval_point) in claims {
+			self.verify_multilinear(id, eval_point)?;
+		}
+		Ok(())
+	}
+
+	fn verify_multilinear(&mut self, id: OracleId, eval_point: SymbolicPoint) -> Result<(), Error> {
+		self.n_bytes += size_of::<u32>();
+		if self.round_claims.contains(&(id, eval_point.clone())) {
+			self.n_bytes += size_of::<u32>();
+			return Ok(());
+		}
+		self.verify_multilinear_skip_duplicate_check(id, eval_point)
+	}
+
+	fn verify_multilinear_subclaim(
+		&mut self,
+		id: OracleId,
+		eval_point: SymbolicPoint,
+	) -> Result<(), Error> {
+		self.n_bytes += size_of::<u32>();
+		if self.round_claims.contains(&(id, eval_point.clone())) {
+			self.n_bytes += size_of::<u32>();
+			return Ok(());
+		}
+		self.n_scalars += 1;
+		self.verify_multilinear_skip_duplicate_check(id, eval_point)
+	}
+
+	fn verify_multilinear_skip_duplicate_check(
+		&mut self,
+		id: OracleId,
+		eval_point: SymbolicPoint,
+	) -> Result<(), Error> {
+		self.round_claims.insert((id, eval_point.clone()));
+
+		match self.oracles.oracle(id).variant {
+			MultilinearPolyVariant::Transparent(_) => {}
+			MultilinearPolyVariant::Committed => {
+				self.committed_claims.push((id, eval_point));
+			}
+			MultilinearPolyVariant::Repeating { id, log_count } => {
+				let n_vars = eval_point.len() - log_count;
+				self.verify_multilinear(id, eval_point[..n_vars].to_vec())?;
+			}
+			MultilinearPolyVariant::Projected(projected) => {
+				let values = self.projected_values(id, projected.values());
+				let (lo, hi) = eval_point.split_at(projected.start_index());
+				self.verify_multilinear(projected.id(), [lo, &values, hi].concat())?;
+			}
+			MultilinearPolyVariant::ZeroPadded(padded) => {
+				let eval_point = [
+					&eval_point[..padded.start_index()],
+					&eval_point[padded.start_index() + padded.n_pad_vars()..],
+				]
+				.concat();
+				self.verify_multilinear(padded.id(), eval_point)?;
+			}
+			MultilinearPolyVariant::Shifted(shifted) => {
+				self.add_bivariate_sumcheck(shifted.id(), shifted.block_size(), &eval_point)?;
+			}
+			MultilinearPolyVariant::Packed(packed) => {
+				self.add_bivariate_sumcheck(packed.id(), 0, &eval_point)?;
+			}
+			MultilinearPolyVariant::LinearCombination(linear_combination) => {
+				for sub_oracle_id in linear_combination.polys() {
+					self.verify_multilinear_subclaim(sub_oracle_id, eval_point.clone())?;
+				}
+			}
+			MultilinearPolyVariant::Composite(composite) => {
+				let n_vars = composite.n_vars();
+				let eq_ind_id = self
+					.oracles
+					.add_transparent(EqIndPartialEval::new(vec![F::ZERO; n_vars]))?;
+				let degree = CompositionPoly::<F>::degree(composite.c()) + 1;
+				self.add_sumcheck(n_vars, chain!(composite.polys(), [eq_ind_id]), degree);
+			}
+		}
+		Ok(())
+	}
+
+	/// Adds the sumcheck of the inner oracle against a transparent multiplier, as done for shifted
+	/// and packed oracles. The last variables of the inner oracle past `projected_n_vars` are
+	/// projected onto the evaluation point first.
+	fn add_bivariate_sumcheck(
+		&mut self,
+		inner_id: OracleId,
+		projected_n_vars: usize,
+		eval_point: &[usize],
+	) -> Result<(), Error> {
+		let inner_n_vars = self.oracles.n_vars(inner_id);
+		let (n_vars, inner_id) = if projected_n_vars < inner_n_vars {
+			let values = &eval_point[projected_n_vars..];
+			let projected_id = self
+				.oracles
+				.add_projected_last_vars(inner_id, vec![F::ZERO; values.len()])?;
+			self.projected_values.insert(projected_id, values.to_vec());
+			(inner_n_vars - values.len(), projected_id)
+		} else {
+			(eval_point.len(), inner_id)
+		};
+		let multiplier_id = self
+			.oracles
+			.add_transparent(EqIndPartialEval::new(vec![F::ZERO; n_vars]))?;
+		self.add_sumcheck(n_vars, [inner_id, multiplier_id], 2);
+		Ok(())
+	}
+
+	fn add_sumcheck(
+		&mut self,
+		n_vars: usize,
+		oracle_ids: impl IntoIterator<Item = OracleId>,
+		degree: usize,
+	) {
+		let (sumcheck_oracle_ids, sumcheck_degree) = self.new_sumchecks.entry(n_vars).or_default();
+		sumcheck_oracle_ids.extend(oracle_ids);
+		*sumcheck_degree = (*sumcheck_degree).max(degree);
+	}
+}
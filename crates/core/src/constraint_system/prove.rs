@@ -1,6 +1,11 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{env, marker::PhantomData};
+use std::{
+	env,
+	io::Write,
+	marker::PhantomData,
+	time::{Duration, Instant},
+};
 
 use binius_field::{
 	as_packed_field::PackedType,
@@ -17,11 +22,12 @@ use binius_math::{
 	IsomorphicEvaluationDomainFactory, MLEDirectAdapter, MultilinearExtension, MultilinearPoly,
 };
 use binius_maybe_rayon::prelude::*;
-use binius_ntt::SingleThreadedNTT;
-use binius_utils::bail;
+use binius_ntt::{twiddle::PrecomputedTwiddleAccess, MultithreadedNTT, SingleThreadedNTT};
+use binius_utils::{bail, sparse_index::SparseIndex};
 use digest::{core_api::BlockSizeUser, Digest, FixedOutputReset, Output};
 use itertools::chain;
 use tracing::instrument;
+use trait_set::trait_set;
 
 use super::{
 	channel::Boundary,
@@ -35,11 +41,11 @@ use crate::{
 		exp::{self, reorder_exponents},
 	},
 	fiat_shamir::{CanSample, Challenger},
-	merkle_tree::BinaryMerkleTreeProver,
+	merkle_tree::{BinaryMerkleTree, BinaryMerkleTreeProver},
 	oracle::{Constraint, MultilinearOracleSet, MultilinearPolyVariant, OracleId},
-	piop,
+	piop::{self, CommitMeta},
 	protocols::{
-		fri::CommitOutput,
+		fri::{CommitOutput, FRIParams},
 		gkr_exp,
 		gkr_gpa::{self, GrandProductBatchProveOutput, GrandProductWitness},
 		greedy_evalcheck::{self, GreedyEvalcheckProveOutput},
@@ -53,6 +59,20 @@ use crate::{
 	witness::{MultilinearExtensionIndex, MultilinearWitness},
 };
 
+trait_set! {
+	/// The bounds on the top packed field of the tower that the prover works over.
+	// REVIEW: Consider changing TowerFamily and associated traits to shorten/remove these bounds
+	pub trait ProverPackedTop<U: ProverTowerUnderlier<Tower>, Tower: ProverTowerFamily> =
+		PackedTop<Tower>
+		+ PackedFieldIndexable // REVIEW: remove this bound after piop::commit is adjusted
+		+ RepackedExtension<PackedType<U, Tower::B8>>
+		+ RepackedExtension<PackedType<U, Tower::B16>>
+		+ RepackedExtension<PackedType<U, Tower::B32>>
+		+ RepackedExtension<PackedType<U, Tower::B64>>
+		+ RepackedExtension<PackedType<U, Tower::B128>>
+		+ PackedTransformationFactory<PackedType<U, Tower::FastB128>>;
+}
+
 /// Generates a proof that a witness satisfies a constraint system with the standard FRI PCS.
 #[instrument("constraint_system::prove", skip_all, level = "debug")]
 pub fn prove<U, Tower, Hash, Compress, Challenger_, Backend>(
@@ -60,7 +80,7 @@ pub fn prove<U, Tower, Hash, Compress, Challenger_, Backend>(
 	log_inv_rate: usize,
 	security_bits: usize,
 	boundaries: &[Boundary<FExt<Tower>>],
-	mut witness: MultilinearExtensionIndex<PackedType<U, FExt<Tower>>>,
+	witness: MultilinearExtensionIndex<PackedType<U, FExt<Tower>>>,
 	backend: &Backend,
 ) -> Result<Proof, Error>
 where
@@ -71,57 +91,316 @@ where
 	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
 	Challenger_: Challenger + Default,
 	Backend: ComputationBackend,
-	// REVIEW: Consider changing TowerFamily and associated traits to shorten/remove these bounds
-	PackedType<U, Tower::B128>: PackedTop<Tower>
-		+ PackedFieldIndexable // REVIEW: remove this bound after piop::commit is adjusted
-		+ RepackedExtension<PackedType<U, Tower::B8>>
-		+ RepackedExtension<PackedType<U, Tower::B16>>
-		+ RepackedExtension<PackedType<U, Tower::B32>>
-		+ RepackedExtension<PackedType<U, Tower::B64>>
-		+ RepackedExtension<PackedType<U, Tower::B128>>
-		+ PackedTransformationFactory<PackedType<U, Tower::FastB128>>,
+	PackedType<U, Tower::B128>: ProverPackedTop<U, Tower>,
+	PackedType<U, Tower::FastB128>: PackedTransformationFactory<PackedType<U, Tower::B128>>,
+{
+	let (proof, _) = prove_phases::<U, Tower, Hash, Compress, Challenger_, Backend>(
+		constraint_system,
+		log_inv_rate,
+		security_bits,
+		boundaries,
+		witness,
+		backend,
+		ProveOptions::default(),
+	)?;
+	Ok(proof)
+}
+
+/// Wall-clock durations of the main prover phases, in milliseconds.
+///
+/// Each phase duration covers the `[phase]` tracing span of the same name, so these numbers agree
+/// with a profile of the same run.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ProveStats {
+	/// The `[phase] Commit` span: encoding and Merkle-committing the committed multilinears.
+	pub commit_ms: f64,
+	/// The `[phase] Zerocheck` span: the batched zerocheck sumcheck.
+	pub sumcheck_ms: f64,
+	/// The `[phase] PIOP Compiler` span: proving the committed evaluation claims with FRI.
+	pub pcs_open_ms: f64,
+	/// The whole proof, including the phases not broken out above.
+	pub total_ms: f64,
+}
+
+/// Like [`prove`], but also returns the durations of the prover phases.
+#[instrument("constraint_system::prove", skip_all, level = "debug")]
+pub fn prove_with_stats<U, Tower, Hash, Compress, Challenger_, Backend>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	log_inv_rate: usize,
+	security_bits: usize,
+	boundaries: &[Boundary<FExt<Tower>>],
+	witness: MultilinearExtensionIndex<PackedType<U, FExt<Tower>>>,
+	backend: &Backend,
+) -> Result<(Proof, ProveStats), Error>
+where
+	U: ProverTowerUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser + FixedOutputReset + Send + Sync + Clone,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+	Backend: ComputationBackend,
+	PackedType<U, Tower::B128>: ProverPackedTop<U, Tower>,
+	PackedType<U, Tower::FastB128>: PackedTransformationFactory<PackedType<U, Tower::B128>>,
+{
+	prove_phases::<U, Tower, Hash, Compress, Challenger_, Backend>(
+		constraint_system,
+		log_inv_rate,
+		security_bits,
+		boundaries,
+		witness,
+		backend,
+		ProveOptions::default(),
+	)
+}
+
+/// Like [`prove`], but binds the proof to an application-specific context.
+///
+/// The domain separator may carry any public values that are not oracle boundaries, such as a
+/// circuit version or public outputs. It is observed by the Fiat-Shamir transcript before any other
+/// message, so the proof only verifies with [`verify_with_domain_separator`] given the same
+/// separator. Proving with an empty separator is the same as [`prove`].
+///
+/// [`verify_with_domain_separator`]: super::verify_with_domain_separator
+#[instrument("constraint_system::prove", skip_all, level = "debug")]
+pub fn prove_with_domain_separator<U, Tower, Hash, Compress, Challenger_, Backend>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	log_inv_rate: usize,
+	security_bits: usize,
+	domain_separator: &[u8],
+	boundaries: &[Boundary<FExt<Tower>>],
+	witness: MultilinearExtensionIndex<PackedType<U, FExt<Tower>>>,
+	backend: &Backend,
+) -> Result<Proof, Error>
+where
+	U: ProverTowerUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser + FixedOutputReset + Send + Sync + Clone,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+	Backend: ComputationBackend,
+	PackedType<U, Tower::B128>: ProverPackedTop<U, Tower>,
+	PackedType<U, Tower::FastB128>: PackedTransformationFactory<PackedType<U, Tower::B128>>,
+{
+	let (proof, _) = prove_phases::<U, Tower, Hash, Compress, Challenger_, Backend>(
+		constraint_system,
+		log_inv_rate,
+		security_bits,
+		boundaries,
+		witness,
+		backend,
+		ProveOptions {
+			domain_separator,
+			..Default::default()
+		},
+	)?;
+	Ok(proof)
+}
+
+/// Like [`prove`], but writes the proof to `writer` as it is produced instead of returning it.
+///
+/// The proof bytes are written out at the end of each prover phase, so the whole proof is never
+/// held in memory at once. The bytes written are the [`Proof::transcript`].
+#[instrument("constraint_system::prove", skip_all, level = "debug")]
+pub fn prove_streaming<U, Tower, Hash, Compress, Challenger_, Backend>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	log_inv_rate: usize,
+	security_bits: usize,
+	boundaries: &[Boundary<FExt<Tower>>],
+	witness: MultilinearExtensionIndex<PackedType<U, FExt<Tower>>>,
+	backend: &Backend,
+	mut writer: impl Write,
+) -> Result<(), Error>
+where
+	U: ProverTowerUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser + FixedOutputReset + Send + Sync + Clone,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+	Backend: ComputationBackend,
+	PackedType<U, Tower::B128>: ProverPackedTop<U, Tower>,
+	PackedType<U, Tower::FastB128>: PackedTransformationFactory<PackedType<U, Tower::B128>>,
+{
+	prove_phases::<U, Tower, Hash, Compress, Challenger_, Backend>(
+		constraint_system,
+		log_inv_rate,
+		security_bits,
+		boundaries,
+		witness,
+		backend,
+		ProveOptions {
+			sink: Some(&mut writer),
+			..Default::default()
+		},
+	)?;
+	Ok(())
+}
+
+/// Durations of the prover phases run by [`prove_until_commit`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommitTimings {
+	/// Generating the witnesses of the exponentiation results.
+	pub exp_witness_generation: Duration,
+	/// Collecting the committed multilinears and choosing the FRI parameters.
+	pub commit_setup: Duration,
+	/// Encoding and Merkle-committing the committed multilinears.
+	pub commit: Duration,
+}
+
+/// The output of [`prove_until_commit`].
+#[derive(Debug, Clone)]
+pub struct CommitDryRun<Digest> {
+	/// The commitment to the committed multilinears, as it would be written to the proof.
+	pub commitment: Digest,
+	pub timings: CommitTimings,
+}
+
+/// Runs the prover up to and including the witness commitment, skipping the remaining phases.
+///
+/// This runs the same code as [`prove`] until the commitment is observed by the transcript, so it
+/// fails on the same witnesses that fail to commit. The returned timings make it possible to
+/// compare the cost of the commitment against that of a full proof.
+#[instrument("constraint_system::prove_until_commit", skip_all, level = "debug")]
+pub fn prove_until_commit<U, Tower, Hash, Compress, Challenger_, Backend>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	log_inv_rate: usize,
+	security_bits: usize,
+	boundaries: &[Boundary<FExt<Tower>>],
+	mut witness: MultilinearExtensionIndex<PackedType<U, FExt<Tower>>>,
+	backend: &Backend,
+) -> Result<CommitDryRun<Output<Hash>>, Error>
+where
+	U: ProverTowerUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser + FixedOutputReset + Send + Sync + Clone,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+	Backend: ComputationBackend,
+	PackedType<U, Tower::B128>: ProverPackedTop<U, Tower>,
 	PackedType<U, Tower::FastB128>: PackedTransformationFactory<PackedType<U, Tower::B128>>,
 {
+	log_backend(backend);
+
+	let mut transcript = start_transcript::<Challenger_, _>(&[], boundaries);
+	let committed = commit_witness::<U, Tower, Hash, Compress, Challenger_>(
+		constraint_system,
+		log_inv_rate,
+		security_bits,
+		&mut witness,
+		&mut transcript,
+	)?;
+	Ok(committed.dry_run)
+}
+
+/// Options of [`prove_phases`] that only some of the prover entry points set.
+#[derive(Default)]
+struct ProveOptions<'a> {
+	/// Observed by the transcript before any other message.
+	domain_separator: &'a [u8],
+	/// Receives the proof bytes at the end of each prover phase.
+	sink: Option<&'a mut dyn Write>,
+}
+
+/// Creates the prover transcript and observes the public inputs that precede the proof messages.
+fn start_transcript<Challenger_, F>(
+	domain_separator: &[u8],
+	boundaries: &[Boundary<F>],
+) -> ProverTranscript<Challenger_>
+where
+	Challenger_: Challenger + Default,
+	F: TowerField,
+{
+	let mut transcript = ProverTranscript::<Challenger_>::new();
+	transcript
+		.observe()
+		.write_domain_separator(domain_separator);
+	transcript.observe().write_slice(boundaries);
+	transcript
+}
+
+fn log_backend(backend: &impl ComputationBackend) {
 	tracing::debug!(
 		arch = env::consts::ARCH,
 		rayon_threads = binius_maybe_rayon::current_num_threads(),
 		"using computation backend: {backend:?}"
 	);
+}
 
-	let domain_factory = DefaultEvaluationDomainFactory::<FDomain<Tower>>::default();
-	let fast_domain_factory = IsomorphicEvaluationDomainFactory::<FFastExt<Tower>>::default();
-
-	let mut transcript = ProverTranscript::<Challenger_>::new();
-	transcript.observe().write_slice(boundaries);
+/// The prover state after the committed multilinears are committed.
+struct CommittedWitness<'a, U, Tower, Hash, Compress>
+where
+	U: ProverTowerUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+	Hash: Digest + Send + Sync + Clone,
+{
+	/// The constraint system, with the exponents and constraint sets in proving order.
+	constraint_system: ConstraintSystem<FExt<Tower>>,
+	exp_witnesses: exp::MultiplicationWitnesses<'a, U, Tower>,
+	merkle_prover: BinaryMerkleTreeProver<FExt<Tower>, Hash, Compress>,
+	commit_meta: CommitMeta,
+	oracle_to_commit_index: SparseIndex<usize>,
+	committed_multilins: Vec<MultilinearWitness<'a, PackedType<U, FExt<Tower>>>>,
+	fri_params: FRIParams<FExt<Tower>, FEncode<Tower>>,
+	ntt: MultithreadedNTT<FEncode<Tower>, PrecomputedTwiddleAccess<FEncode<Tower>>>,
+	committed: BinaryMerkleTree<Output<Hash>>,
+	codeword: Vec<PackedType<U, FExt<Tower>>>,
+	dry_run: CommitDryRun<Output<Hash>>,
+}
 
-	let ConstraintSystem {
-		mut oracles,
-		mut table_constraints,
-		mut flushes,
-		mut exponents,
-		non_zero_oracle_ids,
-		max_channel_id,
-	} = constraint_system.clone();
+/// Runs the prover phases up to and including the witness commitment, which is written to the
+/// transcript.
+///
+/// This generates the exponentiation witnesses, which adds the committed exponentiation results
+/// to `witness`, before committing.
+fn commit_witness<'a, U, Tower, Hash, Compress, Challenger_>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	log_inv_rate: usize,
+	security_bits: usize,
+	witness: &mut MultilinearExtensionIndex<'a, PackedType<U, FExt<Tower>>>,
+	transcript: &mut ProverTranscript<Challenger_>,
+) -> Result<CommittedWitness<'a, U, Tower, Hash, Compress>, Error>
+where
+	U: ProverTowerUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser + FixedOutputReset + Send + Sync + Clone,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger,
+	PackedType<U, Tower::B128>: ProverPackedTop<U, Tower>,
+	PackedType<U, Tower::FastB128>: PackedTransformationFactory<PackedType<U, Tower::B128>>,
+{
+	let mut constraint_system = constraint_system.clone();
+	let oracles = &constraint_system.oracles;
+	reorder_exponents(&mut constraint_system.exponents, oracles);
 
-	reorder_exponents(&mut exponents, &oracles);
+	let mut timings = CommitTimings::default();
 
 	// We must generate multiplication witnesses before committing, as this function
 	// adds the committed witnesses for exponentiation results to the witness index.
-	let exp_witnesses = exp::make_exp_witnesses::<U, Tower>(&mut witness, &oracles, &exponents)?;
+	let start = Instant::now();
+	let exp_witnesses =
+		exp::make_exp_witnesses::<U, Tower>(witness, oracles, &constraint_system.exponents)?;
+	timings.exp_witness_generation = start.elapsed();
 
 	// Stable sort constraint sets in ascending order by number of variables.
-	table_constraints.sort_by_key(|constraint_set| constraint_set.n_vars);
+	constraint_system
+		.table_constraints
+		.sort_by_key(|constraint_set| constraint_set.n_vars);
 
 	// Commit polynomials
+	let start = Instant::now();
 	let merkle_prover = BinaryMerkleTreeProver::<_, Hash, _>::new(Compress::default());
 	let merkle_scheme = merkle_prover.scheme();
 
-	let (commit_meta, oracle_to_commit_index) = piop::make_oracle_commit_meta(&oracles)?;
+	let (commit_meta, oracle_to_commit_index) = piop::make_oracle_commit_meta(oracles)?;
 	let committed_multilins = piop::collect_committed_witnesses::<U, _>(
 		&commit_meta,
 		&oracle_to_commit_index,
-		&oracles,
-		&witness,
+		oracles,
+		witness,
 	)?;
 
 	let fri_params = piop::make_commit_params_with_optimal_arity::<_, FEncode<Tower>, _>(
@@ -133,20 +412,102 @@ where
 	let ntt = SingleThreadedNTT::new(fri_params.rs_code().log_len())?
 		.precompute_twiddles()
 		.multithreaded();
+	timings.commit_setup = start.elapsed();
 
 	let commit_span =
 		tracing::info_span!("[phase] Commit", phase = "commit", perfetto_category = "phase.main")
 			.entered();
+	let start = Instant::now();
 	let CommitOutput {
 		commitment,
 		committed,
 		codeword,
 	} = piop::commit(&fri_params, &ntt, &merkle_prover, &committed_multilins)?;
+	timings.commit = start.elapsed();
 	drop(commit_span);
 
 	// Observe polynomial commitment
-	let mut writer = transcript.message();
-	writer.write(&commitment);
+	transcript.message().write(&commitment);
+
+	Ok(CommittedWitness {
+		constraint_system,
+		exp_witnesses,
+		merkle_prover,
+		commit_meta,
+		oracle_to_commit_index,
+		committed_multilins,
+		fri_params,
+		ntt,
+		committed,
+		codeword,
+		dry_run: CommitDryRun {
+			commitment,
+			timings,
+		},
+	})
+}
+
+fn prove_phases<U, Tower, Hash, Compress, Challenger_, Backend>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	log_inv_rate: usize,
+	security_bits: usize,
+	boundaries: &[Boundary<FExt<Tower>>],
+	mut witness: MultilinearExtensionIndex<PackedType<U, FExt<Tower>>>,
+	backend: &Backend,
+	options: ProveOptions,
+) -> Result<(Proof, ProveStats), Error>
+where
+	U: ProverTowerUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser + FixedOutputReset + Send + Sync + Clone,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+	Backend: ComputationBackend,
+	PackedType<U, Tower::B128>: ProverPackedTop<U, Tower>,
+	PackedType<U, Tower::FastB128>: PackedTransformationFactory<PackedType<U, Tower::B128>>,
+{
+	let prove_start = Instant::now();
+	log_backend(backend);
+
+	let ProveOptions {
+		domain_separator,
+		mut sink,
+	} = options;
+
+	let domain_factory = DefaultEvaluationDomainFactory::<FDomain<Tower>>::default();
+	let fast_domain_factory = IsomorphicEvaluationDomainFactory::<FFastExt<Tower>>::default();
+
+	let mut transcript = start_transcript::<Challenger_, _>(domain_separator, boundaries);
+	let CommittedWitness {
+		constraint_system,
+		exp_witnesses,
+		merkle_prover,
+		commit_meta,
+		oracle_to_commit_index,
+		committed_multilins,
+		fri_params,
+		ntt,
+		committed,
+		codeword,
+		dry_run: CommitDryRun { timings, .. },
+	} = commit_witness::<U, Tower, Hash, Compress, Challenger_>(
+		constraint_system,
+		log_inv_rate,
+		security_bits,
+		&mut witness,
+		&mut transcript,
+	)?;
+	let ConstraintSystem {
+		mut oracles,
+		table_constraints,
+		mut flushes,
+		exponents,
+		non_zero_oracle_ids,
+		max_channel_id,
+	} = constraint_system;
+
+	let mut streamed_len = stream_transcript(&mut transcript, &mut sink)?;
 
 	// GKR exp
 	let exp_challenge = transcript.sample_vec(exp::max_n_vars(&exponents, &oracles));
@@ -250,6 +611,7 @@ where
 			&mut transcript,
 			backend,
 		)?;
+	streamed_len += stream_transcript(&mut transcript, &mut sink)?;
 
 	// Apply isomorphism to the layer claims
 	let final_layer_claims = final_layer_claims
@@ -270,6 +632,7 @@ where
 		perfetto_category = "phase.main",
 	)
 	.entered();
+	let zerocheck_start = Instant::now();
 
 	let (zerocheck_claims, zerocheck_oracle_metas) = table_constraints
 		.iter()
@@ -339,7 +702,9 @@ where
 	let zerocheck_eval_claims =
 		sumcheck::make_zerocheck_eval_claims(zerocheck_oracle_metas, zerocheck_output)?;
 
+	let sumcheck_time = zerocheck_start.elapsed();
 	drop(zerocheck_span);
+	streamed_len += stream_transcript(&mut transcript, &mut sink)?;
 
 	let evalcheck_span = tracing::info_span!(
 		"[phase] Evalcheck",
@@ -371,6 +736,7 @@ where
 	)?;
 
 	drop(evalcheck_span);
+	streamed_len += stream_transcript(&mut transcript, &mut sink)?;
 
 	let ring_switch_span = tracing::info_span!(
 		"[phase] Ring Switch",
@@ -389,6 +755,7 @@ where
 		backend,
 	)?;
 	drop(ring_switch_span);
+	streamed_len += stream_transcript(&mut transcript, &mut sink)?;
 
 	// Prove evaluation claims using PIOP compiler
 	let piop_compiler_span = tracing::info_span!(
@@ -397,6 +764,7 @@ where
 		perfetto_category = "phase.main"
 	)
 	.entered();
+	let piop_compiler_start = Instant::now();
 	piop::prove::<_, FDomain<Tower>, _, _, _, _, _, _, _, _, _>(
 		&fri_params,
 		&ntt,
@@ -411,21 +779,46 @@ where
 		&mut transcript,
 		&backend,
 	)?;
+	let pcs_open_time = piop_compiler_start.elapsed();
 	drop(piop_compiler_span);
+	streamed_len += stream_transcript(&mut transcript, &mut sink)?;
 
 	let proof = Proof {
 		transcript: transcript.finalize(),
 	};
+	let proof_size = streamed_len + proof.get_proof_size();
 
 	tracing::event!(
 		name: "proof_size",
 		tracing::Level::INFO,
 		counter = true,
-		value = proof.get_proof_size() as u64,
+		value = proof_size as u64,
 		unit = "bytes",
 	);
 
-	Ok(proof)
+	let stats = ProveStats {
+		commit_ms: as_millis(timings.commit),
+		sumcheck_ms: as_millis(sumcheck_time),
+		pcs_open_ms: as_millis(pcs_open_time),
+		total_ms: as_millis(prove_start.elapsed()),
+	};
+
+	Ok((proof, stats))
+}
+
+/// Writes the proof bytes produced so far to the sink, if there is one, returning their count.
+fn stream_transcript<Challenger_: Challenger>(
+	transcript: &mut ProverTranscript<Challenger_>,
+	sink: &mut Option<&mut dyn Write>,
+) -> Result<usize, Error> {
+	match sink {
+		Some(sink) => transcript.flush_to(sink).map_err(Error::ProofWrite),
+		None => Ok(0),
+	}
+}
+
+fn as_millis(duration: Duration) -> f64 {
+	duration.as_secs_f64() * 1000.0
 }
 
 type TypeErasedZerocheck<'a, P> = Box<dyn ZerocheckProver<'a, P> + 'a>;
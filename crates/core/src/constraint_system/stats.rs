@@ -0,0 +1,103 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::collections::BTreeMap;
+
+use binius_field::TowerField;
+
+use super::ConstraintSystem;
+use crate::oracle::{ConstraintPredicate, MultilinearPolyVariant};
+
+/// The kind of a multilinear oracle, i.e. its [`MultilinearPolyVariant`] without the data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum OracleKind {
+	Committed,
+	Transparent,
+	Repeating,
+	Projected,
+	Shifted,
+	Packed,
+	LinearCombination,
+	ZeroPadded,
+	Composite,
+}
+
+impl<F: TowerField> From<&MultilinearPolyVariant<F>> for OracleKind {
+	fn from(variant: &MultilinearPolyVariant<F>) -> Self {
+		match variant {
+			MultilinearPolyVariant::Committed => Self::Committed,
+			MultilinearPolyVariant::Transparent(_) => Self::Transparent,
+			MultilinearPolyVariant::Repeating { .. } => Self::Repeating,
+			MultilinearPolyVariant::Projected(_) => Self::Projected,
+			MultilinearPolyVariant::Shifted(_) => Self::Shifted,
+			MultilinearPolyVariant::Packed(_) => Self::Packed,
+			MultilinearPolyVariant::LinearCombination(_) => Self::LinearCombination,
+			MultilinearPolyVariant::ZeroPadded(_) => Self::ZeroPadded,
+			MultilinearPolyVariant::Composite(_) => Self::Composite,
+		}
+	}
+}
+
+/// Counts of the oracles and constraints in a [`ConstraintSystem`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConstraintSystemStats {
+	/// The number of oracles of each kind and tower level.
+	pub n_oracles: BTreeMap<(OracleKind, usize), usize>,
+	/// The total size in bits of the committed oracles.
+	pub committed_bits: usize,
+	/// The number of zerocheck constraints.
+	pub n_zero_constraints: usize,
+	/// The number of sumcheck constraints.
+	pub n_sum_constraints: usize,
+	/// The number of oracles asserted to be non-zero everywhere.
+	pub n_non_zero_oracles: usize,
+	/// The number of channel flushes.
+	pub n_flushes: usize,
+	/// The number of exponentiation constraints.
+	pub n_exponents: usize,
+}
+
+impl ConstraintSystemStats {
+	/// The number of oracles of a kind, over all tower levels.
+	pub fn n_oracles_of_kind(&self, kind: OracleKind) -> usize {
+		self.n_oracles
+			.iter()
+			.filter(|((oracle_kind, _), _)| *oracle_kind == kind)
+			.map(|(_, count)| count)
+			.sum()
+	}
+}
+
+impl<F: TowerField> ConstraintSystem<F> {
+	/// Counts the oracles by kind and tower level, the committed bits and the constraints.
+	pub fn stats(&self) -> ConstraintSystemStats {
+		let mut stats = ConstraintSystemStats {
+			n_non_zero_oracles: self.non_zero_oracle_ids.len(),
+			n_flushes: self.flushes.len(),
+			n_exponents: self.exponents.len(),
+			..Default::default()
+		};
+
+		for oracle in self.oracles.polys() {
+			let kind = OracleKind::from(&oracle.variant);
+			*stats
+				.n_oracles
+				.entry((kind, oracle.tower_level))
+				.or_default() += 1;
+			if kind == OracleKind::Committed {
+				stats.committed_bits += 1 << (oracle.n_vars + oracle.tower_level);
+			}
+		}
+
+		for constraint in self
+			.table_constraints
+			.iter()
+			.flat_map(|constraint_set| &constraint_set.constraints)
+		{
+			match constraint.predicate {
+				ConstraintPredicate::Zero => stats.n_zero_constraints += 1,
+				ConstraintPredicate::Sum(_) => stats.n_sum_constraints += 1,
+			}
+		}
+		stats
+	}
+}
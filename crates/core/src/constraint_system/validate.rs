@@ -2,7 +2,8 @@
 
 use binius_field::{BinaryField1b, PackedExtension, PackedField, TowerField};
 use binius_hal::ComputationBackendExt;
-use binius_math::MultilinearPoly;
+use binius_math::{ArithCircuit, MultilinearPoly};
+use binius_maybe_rayon::prelude::*;
 use binius_utils::bail;
 
 use super::{
@@ -13,12 +14,12 @@ use super::{
 use crate::{
 	oracle::{
 		ConstraintPredicate, MultilinearOracleSet, MultilinearPolyOracle, MultilinearPolyVariant,
-		ShiftVariant,
+		OracleId, ShiftVariant,
 	},
 	polynomial::{
 		test_utils::decompose_index_to_hypercube_point, ArithCircuitPoly, MultilinearComposite,
 	},
-	protocols::sumcheck::prove::zerocheck,
+	protocols::sumcheck::Error as SumcheckError,
 	witness::MultilinearExtensionIndex,
 };
 
@@ -39,20 +40,29 @@ where
 			.map(|id| witness.get_multilin_poly(*id))
 			.collect::<Result<Vec<_>, _>>()?;
 
-		let mut zero_claims = vec![];
+		let n_vars = multilinears
+			.first()
+			.map(|multilinear| multilinear.n_vars())
+			.unwrap_or_default();
+		if multilinears
+			.iter()
+			.any(|multilinear| multilinear.n_vars() != n_vars)
+		{
+			bail!(SumcheckError::NumberOfVariablesMismatch);
+		}
+
 		for constraint in &constraint_set.constraints {
 			match constraint.predicate {
-				ConstraintPredicate::Zero => zero_claims.push((
-					constraint.name.clone(),
-					ArithCircuitPoly::with_n_vars(
-						multilinears.len(),
-						constraint.composition.clone(),
-					)?,
-				)),
+				ConstraintPredicate::Zero => validate_zero_constraint(
+					&constraint.name,
+					&constraint_set.oracle_ids,
+					&constraint.composition,
+					n_vars,
+					&multilinears,
+				)?,
 				ConstraintPredicate::Sum(_) => unimplemented!(),
 			}
 		}
-		zerocheck::validate_witness(&multilinears, &zero_claims)?;
 	}
 
 	// Check that nonzero oracles are non-zero over the entire hypercube
@@ -79,6 +89,48 @@ where
 	Ok(())
 }
 
+/// Checks that a zero constraint vanishes on every vertex of the hypercube.
+///
+/// On failure, reports the first violating vertex together with the oracles the composition
+/// actually reads, so that the offending row can be located in the witness.
+fn validate_zero_constraint<F, P, M>(
+	name: &str,
+	oracle_ids: &[OracleId],
+	composition: &ArithCircuit<F>,
+	n_vars: usize,
+	multilinears: &[M],
+) -> Result<(), Error>
+where
+	P: PackedField<Scalar = F>,
+	F: TowerField,
+	M: MultilinearPoly<P> + Send + Sync,
+{
+	let used_oracle_ids = composition
+		.vars_usage()
+		.into_iter()
+		.zip(oracle_ids)
+		.filter_map(|(used, &id)| used.then_some(id))
+		.collect::<Vec<_>>();
+	let composition = ArithCircuitPoly::with_n_vars(multilinears.len(), composition.clone())?;
+	let witness = MultilinearComposite::new(n_vars, composition, multilinears.iter().collect())?;
+
+	let violation = (0..1 << n_vars)
+		.into_par_iter()
+		.map(|index| Ok::<_, Error>((index, witness.evaluate_on_hypercube(index)?)))
+		.find_first(|result| !matches!(result, Ok((_, value)) if *value == F::ZERO))
+		.transpose()?;
+
+	if let Some((row_index, evaluated_value)) = violation {
+		bail!(Error::ZeroConstraintViolation {
+			constraint_name: name.to_string(),
+			oracle_ids: used_oracle_ids,
+			row_index,
+			evaluated_value: evaluated_value.to_string(),
+		});
+	}
+	Ok(())
+}
+
 pub fn validate_virtual_oracle_witness<F, P>(
 	oracle: &MultilinearPolyOracle<F>,
 	oracles: &MultilinearOracleSet<F>,
@@ -6,15 +6,15 @@ use binius_field::{
 };
 use binius_hash::PseudoCompressionFunction;
 use binius_math::{ArithExpr, CompositionPoly, EvaluationOrder};
-use binius_utils::{bail, checked_arithmetics::log2_ceil_usize};
+use binius_utils::{bail, checked_arithmetics::log2_ceil_usize, sparse_index::SparseIndex};
 use digest::{core_api::BlockSizeUser, Digest, Output};
 use itertools::{chain, Itertools};
 use tracing::instrument;
 
 use super::{
-	channel::{Boundary, OracleOrConst},
+	channel::{Boundary, ChannelId, OracleOrConst},
 	error::{Error, VerificationError},
-	exp::{self, reorder_exponents},
+	exp::{self, reorder_exponents, Exp},
 	ConstraintSystem, Proof,
 };
 use crate::{
@@ -23,20 +23,31 @@ use crate::{
 		common::{FDomain, FEncode, FExt},
 	},
 	fiat_shamir::{CanSample, Challenger},
-	merkle_tree::BinaryMerkleTreeScheme,
+	merkle_tree::{self, BinaryMerkleTreeScheme},
 	oracle::{MultilinearOracleSet, OracleId},
-	piop,
+	piop::{self, CommitMeta},
+	polynomial::ArithCircuitPoly,
 	protocols::{
+		fri::FRIParams,
 		gkr_exp,
 		gkr_gpa::{self},
 		greedy_evalcheck,
-		sumcheck::{self, constraint_set_zerocheck_claim, ZerocheckClaim},
+		sumcheck::{self, constraint_set_zerocheck_claim, OracleClaimMeta, ZerocheckClaim},
 	},
 	ring_switch,
-	transcript::VerifierTranscript,
+	transcript::{self, VerifierTranscript},
 };
 
 /// Verifies a proof against a constraint system.
+///
+/// The proof may come from an untrusted source. The verifier never allocates based on sizes read
+/// from the proof: the number of sumcheck rounds, commitments and evaluations are all derived from
+/// the constraint system. A proof whose bytes do not match that structure, e.g. one that is
+/// truncated or carries extra rounds, is rejected with [`VerificationError::TranscriptMismatch`].
+///
+/// Failed sumchecks, Merkle openings and channel balance checks are also reported as the
+/// corresponding [`VerificationError`], wrapped in [`Error::Verification`]. Other failures keep
+/// the error of the sub-protocol that detected them.
 #[instrument("constraint_system::verify", skip_all, level = "debug")]
 pub fn verify<U, Tower, Hash, Compress, Challenger_>(
 	constraint_system: &ConstraintSystem<FExt<Tower>>,
@@ -53,53 +64,268 @@ where
 	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
 	Challenger_: Challenger + Default,
 {
-	let ConstraintSystem {
-		mut oracles,
-		mut table_constraints,
-		mut flushes,
+	verify_with_domain_separator::<U, Tower, Hash, Compress, Challenger_>(
+		constraint_system,
+		log_inv_rate,
+		security_bits,
+		&[],
+		boundaries,
+		proof,
+	)
+}
+
+/// Verifies a proof bound to an application-specific context.
+///
+/// The domain separator must be the one the proof was generated with by
+/// [`prove_with_domain_separator`], otherwise the Fiat-Shamir challenges differ and verification
+/// fails. Verifying with an empty separator is the same as [`verify`].
+///
+/// [`prove_with_domain_separator`]: super::prove_with_domain_separator
+#[instrument("constraint_system::verify", skip_all, level = "debug")]
+pub fn verify_with_domain_separator<U, Tower, Hash, Compress, Challenger_>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	log_inv_rate: usize,
+	security_bits: usize,
+	domain_separator: &[u8],
+	boundaries: &[Boundary<FExt<Tower>>],
+	proof: Proof,
+) -> Result<(), Error>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+{
+	let setup = VerifierSetup::<Tower, Hash, Compress>::new(
+		constraint_system,
+		log_inv_rate,
+		security_bits,
+	)?;
+	verify_transcript::<Tower, Hash, Compress, Challenger_>(
+		&setup,
+		domain_separator,
+		boundaries,
+		proof,
+	)
+	.map_err(into_proof_error)
+}
+
+/// Verifies a batch of proofs against the same constraint system.
+///
+/// Each item pairs the boundaries of a statement with its proof. The setup that depends only on
+/// the constraint system, such as the commitment metadata, the FRI parameters and the zerocheck
+/// claims, is computed once and shared by all proofs. Each proof is otherwise verified as by
+/// [`verify`], and the first proof that fails determines the returned error.
+#[instrument("constraint_system::verify_batch", skip_all, level = "debug")]
+pub fn verify_batch<'a, U, Tower, Hash, Compress, Challenger_>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	log_inv_rate: usize,
+	security_bits: usize,
+	proofs: impl IntoIterator<Item = (&'a [Boundary<FExt<Tower>>], Proof)>,
+) -> Result<(), Error>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+{
+	let setup = VerifierSetup::<Tower, Hash, Compress>::new(
+		constraint_system,
+		log_inv_rate,
+		security_bits,
+	)?;
+	for (boundaries, proof) in proofs {
+		verify_transcript::<Tower, Hash, Compress, Challenger_>(&setup, &[], boundaries, proof)
+			.map_err(into_proof_error)?;
+	}
+	Ok(())
+}
+
+/// Replaces errors caused by the proof with the top-level error describing the failure.
+///
+/// Errors reading the proof transcript, failed sumchecks and failed Merkle openings in the
+/// sub-protocols become the corresponding [`VerificationError`].
+fn into_proof_error(err: Error) -> Error {
+	let mut source: Option<&(dyn std::error::Error + 'static)> = Some(&err);
+	while let Some(inner) = source {
+		if let Some(transcript_err) = inner.downcast_ref::<transcript::Error>() {
+			return VerificationError::TranscriptMismatch {
+				reason: transcript_err.to_string(),
+			}
+			.into();
+		}
+		if let Some(sumcheck_err) = inner.downcast_ref::<sumcheck::VerificationError>() {
+			let round = match sumcheck_err {
+				sumcheck::VerificationError::NumberOfCoefficients { round, .. }
+				| sumcheck::VerificationError::IncorrectBatchEvaluation { round } => Some(*round),
+				_ => None,
+			};
+			return VerificationError::SumcheckFailed { round }.into();
+		}
+		if let Some(piop::VerificationError::IncorrectSumcheckEvaluation { round }) =
+			inner.downcast_ref()
+		{
+			return VerificationError::SumcheckFailed {
+				round: Some(*round),
+			}
+			.into();
+		}
+		if inner.is::<merkle_tree::VerificationError>() {
+			return VerificationError::MerkleOpeningInvalid.into();
+		}
+		source = inner.source();
+	}
+	err
+}
+
+/// The verifier state that depends only on the constraint system and the protocol parameters.
+struct VerifierSetup<Tower, Hash, Compress>
+where
+	Tower: TowerFamily,
+	Hash: Digest + BlockSizeUser,
+{
+	oracles: MultilinearOracleSet<FExt<Tower>>,
+	/// Flushes sorted by channel ID.
+	flushes: Vec<Flush<FExt<Tower>>>,
+	non_zero_oracle_ids: Vec<OracleId>,
+	max_channel_id: ChannelId,
+	/// Exponents in the order of the GKR exponentiation.
+	exponents: Vec<Exp<FExt<Tower>>>,
+	zerocheck_claims: Vec<ZerocheckClaim<FExt<Tower>, ArithCircuitPoly<FExt<Tower>>>>,
+	zerocheck_oracle_metas: Vec<OracleClaimMeta>,
+	skip_rounds: usize,
+	merkle_scheme: BinaryMerkleTreeScheme<FExt<Tower>, Hash, Compress>,
+	commit_meta: CommitMeta,
+	oracle_to_commit_index: SparseIndex<usize>,
+	fri_params: FRIParams<FExt<Tower>, FEncode<Tower>>,
+}
+
+impl<Tower, Hash, Compress> VerifierSetup<Tower, Hash, Compress>
+where
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+{
+	fn new(
+		constraint_system: &ConstraintSystem<FExt<Tower>>,
+		log_inv_rate: usize,
+		security_bits: usize,
+	) -> Result<Self, Error> {
+		let ConstraintSystem {
+			oracles,
+			mut table_constraints,
+			mut flushes,
+			non_zero_oracle_ids,
+			max_channel_id,
+			mut exponents,
+			..
+		} = constraint_system.clone();
+
+		// Stable sort constraint sets in ascending order by number of variables.
+		table_constraints.sort_by_key(|constraint_set| constraint_set.n_vars);
+
+		flushes.sort_by_key(|flush| flush.channel_id);
+
+		reorder_exponents(&mut exponents, &oracles);
+
+		let merkle_scheme = BinaryMerkleTreeScheme::<_, Hash, _>::new(Compress::default());
+		let (commit_meta, oracle_to_commit_index) = piop::make_oracle_commit_meta(&oracles)?;
+		let fri_params = piop::make_commit_params_with_optimal_arity::<_, FEncode<Tower>, _>(
+			&commit_meta,
+			&merkle_scheme,
+			security_bits,
+			log_inv_rate,
+		)?;
+
+		let (zerocheck_claims, zerocheck_oracle_metas) = table_constraints
+			.into_iter()
+			.map(constraint_set_zerocheck_claim)
+			.collect::<Result<Vec<_>, _>>()?
+			.into_iter()
+			.unzip::<_, _, Vec<_>, Vec<_>>();
+
+		let (_max_n_vars, skip_rounds) =
+			max_n_vars_and_skip_rounds(&zerocheck_claims, <FDomain<Tower>>::N_BITS);
+
+		Ok(Self {
+			oracles,
+			flushes,
+			non_zero_oracle_ids,
+			max_channel_id,
+			exponents,
+			zerocheck_claims,
+			zerocheck_oracle_metas,
+			skip_rounds,
+			merkle_scheme,
+			commit_meta,
+			oracle_to_commit_index,
+			fri_params,
+		})
+	}
+}
+
+fn verify_transcript<Tower, Hash, Compress, Challenger_>(
+	setup: &VerifierSetup<Tower, Hash, Compress>,
+	domain_separator: &[u8],
+	boundaries: &[Boundary<FExt<Tower>>],
+	proof: Proof,
+) -> Result<(), Error>
+where
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+{
+	let VerifierSetup {
+		oracles,
+		flushes,
 		non_zero_oracle_ids,
 		max_channel_id,
-		mut exponents,
-		..
-	} = constraint_system.clone();
-
-	// Stable sort constraint sets in ascending order by number of variables.
-	table_constraints.sort_by_key(|constraint_set| constraint_set.n_vars);
+		exponents,
+		zerocheck_claims,
+		zerocheck_oracle_metas,
+		skip_rounds,
+		merkle_scheme,
+		commit_meta,
+		oracle_to_commit_index,
+		fri_params,
+	} = setup;
+
+	// The oracle set is extended with the flush oracles and the evalcheck oracles of this proof.
+	let mut oracles = oracles.clone();
 
 	let Proof { transcript } = proof;
 
 	let mut transcript = VerifierTranscript::<Challenger_>::new(transcript);
+	transcript
+		.observe()
+		.write_domain_separator(domain_separator);
 	transcript.observe().write_slice(boundaries);
 
-	let merkle_scheme = BinaryMerkleTreeScheme::<_, Hash, _>::new(Compress::default());
-	let (commit_meta, oracle_to_commit_index) = piop::make_oracle_commit_meta(&oracles)?;
-	let fri_params = piop::make_commit_params_with_optimal_arity::<_, FEncode<Tower>, _>(
-		&commit_meta,
-		&merkle_scheme,
-		security_bits,
-		log_inv_rate,
-	)?;
-
 	// Read polynomial commitment polynomials
 	let mut reader = transcript.message();
 	let commitment = reader.read::<Output<Hash>>()?;
 
 	// GKR exp multiplication
-	reorder_exponents(&mut exponents, &oracles);
-
-	let exp_challenge = transcript.sample_vec(exp::max_n_vars(&exponents, &oracles));
+	let exp_challenge = transcript.sample_vec(exp::max_n_vars(exponents, &oracles));
 
 	let mut reader = transcript.message();
 	let exp_evals = reader.read_scalar_slice(exponents.len())?;
 
-	let exp_claims = exp::make_claims(&exponents, &oracles, &exp_challenge, &exp_evals)?
+	let exp_claims = exp::make_claims(exponents, &oracles, &exp_challenge, &exp_evals)?
 		.into_iter()
 		.collect::<Vec<_>>();
 
 	let base_exp_output =
 		gkr_exp::batch_verify(EvaluationOrder::HighToLow, &exp_claims, &mut transcript)?;
 
-	let exp_eval_claims = exp::make_eval_claims(&exponents, base_exp_output)?;
+	let exp_eval_claims = exp::make_eval_claims(exponents, base_exp_output)?;
 
 	// Grand product arguments
 	// Grand products for non-zero checks
@@ -112,26 +338,22 @@ where
 		bail!(Error::Zeros);
 	}
 
-	let non_zero_prodcheck_claims = gkr_gpa::construct_grand_product_claims(
-		&non_zero_oracle_ids,
-		&oracles,
-		&non_zero_products,
-	)?;
+	let non_zero_prodcheck_claims =
+		gkr_gpa::construct_grand_product_claims(non_zero_oracle_ids, &oracles, &non_zero_products)?;
 
 	// Grand products for flushing
 	let mixing_challenge = transcript.sample();
 	// TODO(cryptographers): Find a way to sample less randomness
 	let permutation_challenges = transcript.sample_vec(max_channel_id + 1);
 
-	flushes.sort_by_key(|flush| flush.channel_id);
 	let flush_oracle_ids =
-		make_flush_oracles(&mut oracles, &flushes, mixing_challenge, &permutation_challenges)?;
+		make_flush_oracles(&mut oracles, flushes, mixing_challenge, &permutation_challenges)?;
 
 	let flush_products = transcript
 		.message()
 		.read_scalar_slice(flush_oracle_ids.len())?;
 	verify_channels_balance(
-		&flushes,
+		flushes,
 		&flush_products,
 		boundaries,
 		mixing_challenge,
@@ -150,27 +372,18 @@ where
 
 	// Reduce non_zero_final_layer_claims to evalcheck claims
 	let prodcheck_eval_claims = gkr_gpa::make_eval_claims(
-		chain!(flush_oracle_ids, non_zero_oracle_ids),
+		chain!(flush_oracle_ids, non_zero_oracle_ids.iter().copied()),
 		final_layer_claims,
 	)?;
 
 	// Zerocheck
-	let (zerocheck_claims, zerocheck_oracle_metas) = table_constraints
-		.iter()
-		.cloned()
-		.map(constraint_set_zerocheck_claim)
-		.collect::<Result<Vec<_>, _>>()?
-		.into_iter()
-		.unzip::<_, _, Vec<_>, Vec<_>>();
-
-	let (_max_n_vars, skip_rounds) =
-		max_n_vars_and_skip_rounds(&zerocheck_claims, <FDomain<Tower>>::N_BITS);
-
 	let zerocheck_output =
-		sumcheck::batch_verify_zerocheck(&zerocheck_claims, skip_rounds, &mut transcript)?;
+		sumcheck::batch_verify_zerocheck(zerocheck_claims, *skip_rounds, &mut transcript)?;
 
-	let zerocheck_eval_claims =
-		sumcheck::make_zerocheck_eval_claims(zerocheck_oracle_metas, zerocheck_output)?;
+	let zerocheck_eval_claims = sumcheck::make_zerocheck_eval_claims(
+		zerocheck_oracle_metas.iter().cloned(),
+		zerocheck_output,
+	)?;
 
 	// Evalcheck
 	let eval_claims = greedy_evalcheck::verify(
@@ -182,8 +395,8 @@ where
 	// Reduce committed evaluation claims to PIOP sumcheck claims
 	let system = ring_switch::EvalClaimSystem::new(
 		&oracles,
-		&commit_meta,
-		&oracle_to_commit_index,
+		commit_meta,
+		oracle_to_commit_index,
 		&eval_claims,
 	)?;
 
@@ -194,9 +407,9 @@ where
 
 	// Prove evaluation claims using PIOP compiler
 	piop::verify(
-		&commit_meta,
-		&merkle_scheme,
-		&fri_params,
+		commit_meta,
+		merkle_scheme,
+		fri_params,
 		&commitment,
 		&transparents,
 		&piop_sumcheck_claims,
@@ -314,7 +527,10 @@ fn verify_channels_balance<F: TowerField>(
 				}
 			});
 		if pull_product != push_product {
-			return Err(VerificationError::ChannelUnbalanced { id: channel_id }.into());
+			return Err(VerificationError::ChannelUnbalanced {
+				channel: channel_id,
+			}
+			.into());
 		}
 	}
 
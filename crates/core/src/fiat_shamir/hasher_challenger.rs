@@ -60,6 +60,30 @@ where
 	}
 }
 
+impl<H> HasherChallenger<H>
+where
+	H: Digest + BlockSizeUser + FixedOutputReset + Default,
+{
+	/// Creates a challenger that has observed an application-specific domain separator.
+	///
+	/// The separator is observed with a little-endian `u32` length prefix, the same way
+	/// [`TranscriptWriter::write_domain_separator`] writes it. An empty separator gives the
+	/// default challenger.
+	///
+	/// [`TranscriptWriter::write_domain_separator`]: crate::transcript::TranscriptWriter::write_domain_separator
+	pub fn with_domain_separator(domain_separator: &[u8]) -> Self {
+		let mut challenger = Self::default();
+		if !domain_separator.is_empty() {
+			let len = u32::try_from(domain_separator.len())
+				.expect("domain separator length must fit in a u32");
+			let observer = challenger.observer();
+			observer.put_u32_le(len);
+			observer.put_slice(domain_separator);
+		}
+		challenger
+	}
+}
+
 impl<H: Digest + BlockSizeUser + FixedOutputReset + Default> Challenger for HasherChallenger<H> {
 	/// This returns the inner challenger which implements `[BufMut]`
 	fn observer(&mut self) -> &mut impl BufMut {
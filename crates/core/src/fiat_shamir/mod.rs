@@ -14,4 +14,9 @@ pub trait Challenger {
 
 	/// Returns and infinite buffer for writing data that the challenger observes.
 	fn observer(&mut self) -> &mut impl BufMut;
+
+	/// Observes a slice of bytes.
+	fn observe_bytes(&mut self, data: &[u8]) {
+		self.observer().put_slice(data);
+	}
 }
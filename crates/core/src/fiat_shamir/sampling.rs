@@ -13,10 +13,19 @@ use std::array;
 pub trait CanSample<T> {
 	fn sample(&mut self) -> T;
 
+	/// Samples `N` independent values.
+	///
+	/// The values are sampled in order with successive calls to [`Self::sample`].
 	fn sample_array<const N: usize>(&mut self) -> [T; N] {
 		array::from_fn(|_| self.sample())
 	}
 
+	/// Samples `n` independent values.
+	///
+	/// The values are sampled in order with successive calls to [`Self::sample`], so
+	/// `sample_vec(n)` draws exactly the same values as `n` calls to `sample()` would. This is the
+	/// canonical schedule that every implementation must follow for the prover and verifier
+	/// transcripts to agree.
 	fn sample_vec(&mut self, n: usize) -> Vec<T> {
 		(0..n).map(|_| self.sample()).collect()
 	}
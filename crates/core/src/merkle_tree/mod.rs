@@ -10,6 +10,7 @@ mod scheme;
 mod tests;
 
 pub use binary_merkle_tree::*;
+pub use errors::{Error, VerificationError};
 pub use merkle_tree_vcs::*;
 pub use prover::BinaryMerkleTreeProver;
 pub use scheme::BinaryMerkleTreeScheme;
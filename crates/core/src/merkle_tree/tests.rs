@@ -4,7 +4,12 @@ use core::slice;
 use std::iter::repeat_with;
 
 use binius_field::{BinaryField16b, Field};
-use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+use binius_hash::{
+	blake3::Blake3ByteCompression,
+	groestl::{Groestl256, Groestl256ByteCompression},
+	PseudoCompressionFunction,
+};
+use digest::Output;
 use rand::{rngs::StdRng, SeedableRng};
 
 use super::{BinaryMerkleTreeProver, MerkleTreeProver, MerkleTreeScheme};
@@ -100,3 +105,45 @@ fn test_binary_merkle_vcs_verify_vector() {
 		.verify_vector(&commitment.root, &data, 1)
 		.unwrap();
 }
+
+fn commit_and_verify_openings<C>(compression: C, data: &[BinaryField16b]) -> Output<Groestl256>
+where
+	C: PseudoCompressionFunction<Output<Groestl256>, 2> + Sync,
+{
+	let mr_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(compression);
+	let (commitment, tree) = mr_prover.commit(data, 1).unwrap();
+
+	for (i, value) in data.iter().enumerate() {
+		let mut proof_writer = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		mr_prover
+			.prove_opening(&tree, 0, i, &mut proof_writer.message())
+			.unwrap();
+
+		let mut proof_reader = proof_writer.into_verifier();
+		mr_prover
+			.scheme()
+			.verify_opening(
+				i,
+				slice::from_ref(value),
+				0,
+				4,
+				&[commitment.root],
+				&mut proof_reader.message(),
+			)
+			.unwrap();
+	}
+	commitment.root
+}
+
+#[test]
+fn test_binary_merkle_vcs_with_blake3_compression() {
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let data = repeat_with(|| Field::random(&mut rng))
+		.take(16)
+		.collect::<Vec<BinaryField16b>>();
+
+	let groestl_root = commit_and_verify_openings(Groestl256ByteCompression, &data);
+	let blake3_root = commit_and_verify_openings(Blake3ByteCompression, &data);
+	assert_ne!(groestl_root, blake3_root);
+}
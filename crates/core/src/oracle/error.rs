@@ -26,6 +26,18 @@ pub enum Error {
 	InvalidOracleId(OracleId),
 	#[error("tower_level ({tower_level}) exceeds maximum")]
 	TowerLevelTooHigh { tower_level: usize },
+	#[error("linear combination term {index} has {n_vars} variables, expected {expected}")]
+	LinearCombinationTermNvarsMismatch {
+		index: usize,
+		n_vars: usize,
+		expected: usize,
+	},
+	#[error("linear combination term {index} has tower level {tower_level}, which exceeds {max_tower_level}")]
+	LinearCombinationTermTowerLevelTooHigh {
+		index: usize,
+		tower_level: usize,
+		max_tower_level: usize,
+	},
 	#[error("constraint set is empty")]
 	EmptyConstraintSet,
 	#[error("expected constraint set to contain only constraints with n_vars={expected}, but found n_vars={got}")]
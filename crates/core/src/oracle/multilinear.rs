@@ -49,16 +49,27 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 	}
 
 	pub fn committed_multiple<const N: usize>(
-		mut self,
+		self,
 		n_vars: usize,
 		tower_level: usize,
+	) -> [OracleId; N] {
+		self.committed_multiple_typed(n_vars, [tower_level; N])
+	}
+
+	/// Adds `N` committed oracles with the same number of variables, where the `i`-th oracle has
+	/// tower level `tower_levels[i]`.
+	pub fn committed_multiple_typed<const N: usize>(
+		mut self,
+		n_vars: usize,
+		tower_levels: [usize; N],
 	) -> [OracleId; N] {
 		match &self.name.take() {
-			None => [0; N].map(|_| self.add_committed_with_name(n_vars, tower_level, None)),
+			None => tower_levels
+				.map(|tower_level| self.add_committed_with_name(n_vars, tower_level, None)),
 			Some(s) => {
 				let x: [usize; N] = array::from_fn(|i| i);
 				x.map(|i| {
-					self.add_committed_with_name(n_vars, tower_level, Some(format!("{s}_{i}")))
+					self.add_committed_with_name(n_vars, tower_levels[i], Some(format!("{s}_{i}")))
 				})
 			}
 		}
@@ -438,6 +449,14 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 		self.add().committed_multiple(n_vars, tower_level)
 	}
 
+	pub fn add_committed_multiple_typed<const N: usize>(
+		&mut self,
+		n_vars: usize,
+		tower_levels: [usize; N],
+	) -> [OracleId; N] {
+		self.add().committed_multiple_typed(n_vars, tower_levels)
+	}
+
 	pub fn add_repeating(&mut self, id: OracleId, log_count: usize) -> Result<OracleId, Error> {
 		self.add().repeating(id, log_count)
 	}
@@ -946,7 +965,7 @@ impl<F: TowerField> MultilinearPolyOracle<F> {
 
 #[cfg(test)]
 mod tests {
-	use binius_field::{BinaryField128b, BinaryField1b, Field, TowerField};
+	use binius_field::{BinaryField128b, BinaryField1b, BinaryField8b, Field, TowerField};
 
 	use super::MultilinearOracleSet;
 
@@ -961,4 +980,20 @@ mod tests {
 			.unwrap();
 		let _ = oracles.oracle(projected);
 	}
+
+	#[test]
+	fn add_committed_multiple_typed() {
+		type F = BinaryField128b;
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let levels = [
+			BinaryField1b::TOWER_LEVEL,
+			BinaryField8b::TOWER_LEVEL,
+			BinaryField1b::TOWER_LEVEL,
+		];
+		let ids = oracles.add_committed_multiple_typed(4, levels);
+		for (id, level) in ids.into_iter().zip(levels) {
+			assert_eq!(oracles.n_vars(id), 4);
+			assert_eq!(oracles.tower_level(id), level);
+		}
+	}
 }
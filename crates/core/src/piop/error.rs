@@ -45,8 +45,8 @@ pub enum Error {
 pub enum VerificationError {
 	#[error("sumcheck claimed evaluation for transparent {index} is incorrect")]
 	IncorrectTransparentEvaluation { index: usize },
-	#[error("sumcheck final evaluation is incorrect")]
-	IncorrectSumcheckEvaluation,
+	#[error("sumcheck final evaluation after round {round} is incorrect")]
+	IncorrectSumcheckEvaluation { round: usize },
 	#[error("Transcript error: {0}")]
 	Transcript(#[from] transcript::Error),
 }
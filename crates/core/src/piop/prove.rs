@@ -7,14 +7,13 @@ use binius_field::{
 };
 use binius_hal::ComputationBackend;
 use binius_math::{
-	EvaluationDomainFactory, EvaluationOrder, MLEDirectAdapter, MultilinearExtension,
-	MultilinearPoly,
+	bit_reverse_permute, EvaluationDomainFactory, EvaluationOrder, MLEDirectAdapter,
+	MultilinearExtension, MultilinearPoly,
 };
 use binius_maybe_rayon::{iter::IntoParallelIterator, prelude::*};
 use binius_ntt::AdditiveNTT;
 use binius_utils::{
 	bail,
-	checked_arithmetics::checked_log_2,
 	random_access_sequence::{RandomAccessSequenceMut, SequenceSubrangeMut},
 	sorting::is_sorted_ascending,
 	SerializeBytes,
@@ -47,29 +46,6 @@ use crate::{
 	transcript::ProverTranscript,
 };
 
-#[inline(always)]
-fn reverse_bits(x: usize, log_len: usize) -> usize {
-	x.reverse_bits()
-		.wrapping_shr((usize::BITS as usize - log_len) as _)
-}
-
-/// Reorders the scalars in a slice of packed field elements by reversing the bits of their indices.
-/// TODO: investigate if we can optimize this.
-fn reverse_index_bits<T: Copy>(collection: &mut impl RandomAccessSequenceMut<T>) {
-	let log_len = checked_log_2(collection.len());
-	for i in 0..collection.len() {
-		let bit_reversed_index = reverse_bits(i, log_len);
-		if i < bit_reversed_index {
-			// Safety: `i` and `j` are guaranteed to be in bounds of the slice
-			unsafe {
-				let tmp = collection.get_unchecked(i);
-				collection.set_unchecked(i, collection.get_unchecked(bit_reversed_index));
-				collection.set_unchecked(bit_reversed_index, tmp);
-			}
-		}
-	}
-}
-
 // ## Preconditions
 //
 // * all multilinears in `multilins` have at least log_extension_degree packed variables
@@ -94,26 +70,29 @@ fn merge_multilins<F, P, Data>(
 	for mle in mle_iter.peeking_take_while(|mle| mle.n_vars() >= P::LOG_WIDTH) {
 		let evals = mle.evals();
 		let (chunk, rest) = remaining_buffer.split_at_mut(evals.len());
-		full_packed_mles.push((evals, chunk));
+		full_packed_mles.push((mle.n_vars(), evals, chunk));
 		remaining_buffer = rest;
 	}
-	full_packed_mles.into_par_iter().for_each(|(evals, chunk)| {
-		chunk.copy_from_slice(evals);
-		reverse_index_bits(&mut PackedSliceMut::new(chunk));
-	});
+	full_packed_mles
+		.into_par_iter()
+		.for_each(|(n_vars, evals, chunk)| {
+			chunk.copy_from_slice(evals);
+			bit_reverse_permute(chunk, n_vars).expect("chunk holds the 2^n_vars evaluations");
+		});
 
 	// Now copy scalars from the remaining multilinears, which have too few elements to copy full
 	// packed elements.
 	let mut scalar_offset = 0;
 	let mut remaining_buffer = PackedSliceMut::new(remaining_buffer);
 	for mle in mle_iter {
-		let packed_eval = mle.evals()[0];
+		let mut packed_eval = [mle.evals()[0]];
+		bit_reverse_permute(&mut packed_eval, mle.n_vars())
+			.expect("a single packed element holds the evaluations");
 		let len = 1 << mle.n_vars();
 		let mut packed_chunk = SequenceSubrangeMut::new(&mut remaining_buffer, scalar_offset, len);
 		for i in 0..len {
-			packed_chunk.set(i, packed_eval.get(i));
+			packed_chunk.set(i, packed_eval[0].get(i));
 		}
-		reverse_index_bits(&mut packed_chunk);
 
 		scalar_offset += len;
 	}
@@ -476,6 +455,7 @@ mod tests {
 	use std::iter::repeat_with;
 
 	use binius_field::PackedBinaryField2x128b;
+	use binius_math::reverse_bits;
 	use rand::{rngs::StdRng, SeedableRng};
 
 	use super::*;
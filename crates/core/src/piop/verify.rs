@@ -394,7 +394,10 @@ where
 	let piecewise_eval =
 		evaluate_piecewise_multilinear(&challenges, &n_pieces_by_vars, &mut piecewise_evals)?;
 	if piecewise_eval != fri_final {
-		return Err(VerificationError::IncorrectSumcheckEvaluation.into());
+		return Err(VerificationError::IncorrectSumcheckEvaluation {
+			round: challenges.len(),
+		}
+		.into());
 	}
 
 	Ok(())
@@ -190,6 +190,8 @@ pub struct ArithCircuitPoly<F: Field> {
 	degree: usize,
 	n_vars: usize,
 	tower_level: usize,
+	/// Cached [`ArithCircuit::vars_usage`], padded to `n_vars`.
+	vars_usage: Arc<[bool]>,
 }
 
 impl<F: Field> PartialEq for ArithCircuitPoly<F> {
@@ -232,6 +234,7 @@ impl<F: TowerField> ArithCircuitPoly<F> {
 		let degree = expr.degree();
 		let n_vars = expr.n_vars();
 		let tower_level = expr.binary_tower_level();
+		let vars_usage = expr.vars_usage().into();
 		let (exprs, retval) = convert_circuit_steps(&expr);
 
 		Self {
@@ -241,6 +244,7 @@ impl<F: TowerField> ArithCircuitPoly<F> {
 			degree,
 			n_vars,
 			tower_level,
+			vars_usage,
 		}
 	}
 	/// Constructs an [`ArithCircuitPoly`] with the given number of variables.
@@ -258,6 +262,8 @@ impl<F: TowerField> ArithCircuitPoly<F> {
 				actual: n_vars,
 			});
 		}
+		let mut vars_usage = expr.vars_usage();
+		vars_usage.resize(n_vars, false);
 		let (steps, retval) = convert_circuit_steps(&expr);
 
 		Ok(Self {
@@ -267,8 +273,23 @@ impl<F: TowerField> ArithCircuitPoly<F> {
 			n_vars,
 			degree,
 			tower_level,
+			vars_usage: vars_usage.into(),
 		})
 	}
+
+	/// Evaluates the circuit and blends the result into `out` on the lanes selected by `mask`.
+	///
+	/// The lanes of `mask` must be zero or one. Lanes of `out` where the mask is one are replaced
+	/// by the evaluation, and lanes where it is zero are left unchanged. This allows evaluating
+	/// different compositions on different lanes of the same packed query.
+	pub fn evaluate_masked<P>(&self, query: &[P], mask: P, out: &mut P) -> Result<(), Error>
+	where
+		P: PackedField<Scalar: ExtensionField<F>>,
+	{
+		let eval = CompositionPoly::<P>::evaluate(self, query)?;
+		*out += mask * (eval - *out);
+		Ok(())
+	}
 }
 
 impl<F: TowerField, P: PackedField<Scalar: ExtensionField<F>>> CompositionPoly<P>
@@ -290,6 +311,10 @@ impl<F: TowerField, P: PackedField<Scalar: ExtensionField<F>>> CompositionPoly<P
 		self.expr.convert_field()
 	}
 
+	fn vars_usage(&self) -> Vec<bool> {
+		self.vars_usage.to_vec()
+	}
+
 	fn evaluate(&self, query: &[P]) -> Result<P, Error> {
 		if query.len() != self.n_vars {
 			return Err(Error::IncorrectQuerySize {
@@ -1248,4 +1273,58 @@ mod tests {
 			"Final result should be stored in Slot(2)"
 		);
 	}
+
+	#[test]
+	fn test_vars_usage() {
+		type F = BinaryField8b;
+
+		// Variables 1 and 3 are never read.
+		let expr = ArithExpr::Var(0) * ArithExpr::Var(2) + ArithExpr::Const(F::ONE);
+		let circuit = ArithCircuitPoly::<F>::with_n_vars(4, expr.into()).unwrap();
+
+		assert_eq!(CompositionPoly::<F>::vars_usage(&circuit), vec![true, false, true, false]);
+		assert_eq!(
+			CompositionPoly::<F>::vars_usage(&circuit),
+			CompositionPoly::<F>::expression(&circuit)
+				.vars_usage()
+				.into_iter()
+				.chain([false])
+				.collect::<Vec<_>>()
+		);
+	}
+
+	#[test]
+	fn test_evaluate_masked() {
+		type F = BinaryField8b;
+		type P = PackedBinaryField8x16b;
+
+		let circuit = ArithCircuitPoly::<F>::new(
+			(ArithExpr::Var(0) * ArithExpr::Var(1) + ArithExpr::Const(F::new(3))).into(),
+		);
+		let first = ArithCircuitPoly::<F>::new((ArithExpr::Var(0) + ArithExpr::Var(1)).into());
+
+		let query = [
+			P::from_scalars(felts!(BinaryField16b[1, 2, 3, 4, 5, 6, 7, 8])),
+			P::from_scalars(felts!(BinaryField16b[8, 7, 6, 5, 4, 3, 2, 1])),
+		];
+		let mask = P::from_scalars(felts!(BinaryField16b[1, 0, 1, 0, 0, 0, 1, 1]));
+
+		// Evaluate `first` on the lanes outside the mask and `circuit` on the lanes inside it.
+		let mut out = P::zero();
+		first
+			.evaluate_masked(&query, P::one() - mask, &mut out)
+			.unwrap();
+		circuit.evaluate_masked(&query, mask, &mut out).unwrap();
+
+		let circuit_eval = CompositionPoly::<P>::evaluate(&circuit, &query).unwrap();
+		let first_eval = CompositionPoly::<P>::evaluate(&first, &query).unwrap();
+		for i in 0..P::WIDTH {
+			let expected = if mask.get(i) == BinaryField16b::ONE {
+				circuit_eval.get(i)
+			} else {
+				first_eval.get(i)
+			};
+			assert_eq!(out.get(i), expected);
+		}
+	}
 }
@@ -1,6 +1,10 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+use std::iter::repeat_with;
+
 use binius_field::{packed::set_packed_slice, BinaryField1b, Field, PackedField};
+use binius_math::{CompositionPoly, RowsBatchRef};
+use rand::RngCore;
 
 use crate::polynomial::MultivariatePoly;
 
@@ -57,3 +61,83 @@ where
 	}
 	result
 }
+
+/// Checks the methods of a composition against each other on random queries.
+///
+/// For each of `trials` random packed queries, asserts that `evaluate`, `batch_evaluate` over a
+/// single-row batch and the `expression` circuit evaluated lane by lane all agree. Also asserts
+/// that the degree of the expression does not exceed the reported degree. By the Schwartz-Zippel
+/// lemma, agreement on random queries makes a mismatch between the methods unlikely.
+pub fn assert_composition_consistent<P, C>(composition: &C, mut rng: impl RngCore, trials: usize)
+where
+	P: PackedField,
+	C: CompositionPoly<P> + ?Sized,
+{
+	let n_vars = composition.n_vars();
+	let expression = composition.expression();
+	assert!(
+		expression.degree() <= composition.degree(),
+		"expression degree {} exceeds the reported degree {}",
+		expression.degree(),
+		composition.degree()
+	);
+
+	for _ in 0..trials {
+		let query = repeat_with(|| P::random(&mut rng))
+			.take(n_vars)
+			.collect::<Vec<_>>();
+		let eval = composition.evaluate(&query).unwrap();
+
+		let rows = query.iter().map(std::slice::from_ref).collect::<Vec<_>>();
+		let mut batch_eval = [P::zero()];
+		composition
+			.batch_evaluate(&RowsBatchRef::new(&rows, 1), &mut batch_eval)
+			.unwrap();
+		assert_eq!(batch_eval[0], eval, "batch_evaluate disagrees with evaluate");
+
+		for i in 0..P::WIDTH {
+			let scalar_query = query.iter().map(|x| x.get(i)).collect::<Vec<_>>();
+			assert_eq!(
+				expression.evaluate(&scalar_query).unwrap(),
+				eval.get(i),
+				"expression disagrees with evaluate"
+			);
+		}
+	}
+}
+
+/// Checks the batch and single-point evaluation of a composition against `expected`.
+///
+/// Evaluates the composition over a batch of `row_len` random packed rows with `batch_evaluate`,
+/// and asserts that each row of the result, as well as `evaluate` at that row's query, equals
+/// `expected` of the query.
+pub fn assert_batch_evaluate_matches<P, C>(
+	composition: &C,
+	mut rng: impl RngCore,
+	row_len: usize,
+	expected: impl Fn(&[P]) -> P,
+) where
+	P: PackedField,
+	C: CompositionPoly<P> + ?Sized,
+{
+	let query = repeat_with(|| {
+		repeat_with(|| P::random(&mut rng))
+			.take(row_len)
+			.collect::<Vec<_>>()
+	})
+	.take(composition.n_vars())
+	.collect::<Vec<_>>();
+	let rows = query.iter().map(|row| row.as_slice()).collect::<Vec<_>>();
+
+	let mut evals = vec![P::zero(); row_len];
+	composition
+		.batch_evaluate(&RowsBatchRef::new(&rows, row_len), &mut evals)
+		.unwrap();
+
+	for (i, &eval) in evals.iter().enumerate() {
+		let point = query.iter().map(|row| row[i]).collect::<Vec<_>>();
+		let expected = expected(&point);
+		assert_eq!(composition.evaluate(&point).unwrap(), expected);
+		assert_eq!(eval, expected);
+	}
+}
@@ -415,7 +415,7 @@ where
 				self.claims_without_evals
 					.push((inner, inner_eval_point.into()));
 			}
-			_ => return,
+			_ => {}
 		};
 	}
 
@@ -267,14 +267,13 @@ impl<'a, F: TowerField> EvalcheckVerifier<'a, F> {
 		let subproof = deserialize_evalcheck_proof(&mut transcript.message())?;
 		match subproof {
 			EvalcheckHint::DuplicateClaim(index) => {
-				let index = index as usize;
-				if self.round_claims[index].id != oracle_id
-					|| self.round_claims[index].eval_point != eval_point
-				{
-					return Err(VerificationError::DuplicateClaimMismatch.into());
+				// The index is read from the proof, so it must be bounds-checked.
+				match self.round_claims.get(index as usize) {
+					Some(claim) if claim.id == oracle_id && claim.eval_point == eval_point => {
+						Ok(claim.eval)
+					}
+					_ => Err(VerificationError::DuplicateClaimMismatch.into()),
 				}
-
-				Ok(self.round_claims[index].eval)
 			}
 			EvalcheckHint::NewClaim => {
 				let eval = transcript.message().read_scalar()?;
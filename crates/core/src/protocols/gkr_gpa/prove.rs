@@ -260,7 +260,7 @@ where
 			}
 		}
 
-		let prover = EqIndSumcheckProverBuilder::without_switchover(n_vars, multilinears, backend)
+		let prover = EqIndSumcheckProverBuilder::without_switchover(n_vars, multilinears, backend)?
 			.with_const_suffixes(&const_suffixes)?
 			.build(
 				evaluation_order,
@@ -232,6 +232,28 @@ impl<F: Field> BatchSumcheckOutput<F> {
 	}
 }
 
+/// A hook that post-processes each sumcheck round challenge before it is used for folding.
+///
+/// The transformed challenge is the one the provers fold with, the verifier interpolates the round
+/// polynomial at, and that is returned in [`BatchSumcheckOutput::challenges`]. The prover and the
+/// verifier must use the same transform, or verification fails. A transform that is not a
+/// bijection on the field lowers the entropy of the challenges and with it the soundness of the
+/// protocol, so this is meant for experimental protocol variants.
+pub trait ChallengeTransform<F: Field> {
+	/// Transforms the challenge sampled in the given round, counted from zero.
+	fn transform(&self, round: usize, challenge: F) -> F;
+}
+
+/// The [`ChallengeTransform`] that uses the sampled challenges unchanged.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct IdentityChallengeTransform;
+
+impl<F: Field> ChallengeTransform<F> for IdentityChallengeTransform {
+	fn transform(&self, _round: usize, challenge: F) -> F {
+		challenge
+	}
+}
+
 /// Constructs a switchover function thaw returns the round number where folded multilinear is at
 /// least 2^k times smaller (in bytes) than the original, or 1 when not applicable.
 pub fn standard_switchover_heuristic(k: isize) -> impl Fn(usize) -> usize + Copy {
@@ -238,15 +238,16 @@ mod tests {
 	};
 	use binius_hash::groestl::Groestl256;
 	use binius_math::{
-		CompositionPoly, DefaultEvaluationDomainFactory, EvaluationDomainFactory, EvaluationOrder,
-		IsomorphicEvaluationDomainFactory, MLEDirectAdapter, MultilinearExtension, MultilinearPoly,
-		MultilinearQuery,
+		ArithExpr, CompositionPoly, DefaultEvaluationDomainFactory, EvaluationDomainFactory,
+		EvaluationOrder, IsomorphicEvaluationDomainFactory, MLEDirectAdapter, MultilinearExtension,
+		MultilinearPoly, MultilinearQuery,
 	};
 	use rand::{rngs::StdRng, Rng, SeedableRng};
 
 	use crate::{
 		composition::BivariateProduct,
 		fiat_shamir::{CanSample, HasherChallenger},
+		polynomial::ArithCircuitPoly,
 		protocols::{
 			sumcheck::{
 				self,
@@ -392,6 +393,119 @@ mod tests {
 		.unwrap();
 	}
 
+	// Proves `a * b + b` with and without a constant suffix hint for `a`. The hint truncates the
+	// folded evaluations of `a`, whose suffix subcubes are then filled with the suffix value
+	// instead of being fetched, while `b` keeps the composition from having a constant suffix.
+	fn test_const_suffix_hint_matches_unhinted_proof<U, F, FDomain>(n_vars: usize)
+	where
+		U: UnderlierType + PackScalar<F> + PackScalar<FDomain>,
+		F: TowerField + ExtensionField<FDomain>,
+		FDomain: TowerField,
+		PackedType<U, F>: PackedFieldIndexable,
+	{
+		let mut rng = StdRng::seed_from_u64(0);
+
+		let packed_len = 1 << n_vars.saturating_sub(PackedType::<U, F>::LOG_WIDTH);
+		for nonzero_prefix in [
+			0,
+			1,
+			3,
+			1 << (n_vars - 3),
+			1 << (n_vars - 1),
+			(1 << n_vars) - 5,
+		] {
+			let mut a_column = (0..packed_len)
+				.map(|_| PackedType::<U, F>::random(&mut rng))
+				.collect::<Vec<_>>();
+			let b_column = (0..packed_len)
+				.map(|_| PackedType::<U, F>::random(&mut rng))
+				.collect::<Vec<_>>();
+
+			for i in nonzero_prefix..1 << n_vars {
+				set_packed_slice(&mut a_column, i, F::ZERO);
+			}
+
+			let a_mle =
+				MLEDirectAdapter::from(MultilinearExtension::from_values_slice(&a_column).unwrap());
+			let b_mle =
+				MLEDirectAdapter::from(MultilinearExtension::from_values_slice(&b_column).unwrap());
+
+			let composition = ArithCircuitPoly::<F>::new(
+				(ArithExpr::Var(0) * ArithExpr::Var(1) + ArithExpr::Var(1)).into(),
+			);
+			let eq_ind_challenges = (0..n_vars).map(|_| F::random(&mut rng)).collect::<Vec<_>>();
+			let eq_ind = EqIndPartialEval::new(eq_ind_challenges.clone())
+				.multilinear_extension::<F, _>(&make_portable_backend())
+				.unwrap();
+			let sum = (0..1 << n_vars)
+				.map(|i| {
+					let query = [
+						a_mle.evaluate_on_hypercube(i).unwrap(),
+						b_mle.evaluate_on_hypercube(i).unwrap(),
+					];
+					eq_ind.evaluate_on_hypercube(i).unwrap()
+						* CompositionPoly::<F>::evaluate(&composition, &query).unwrap()
+				})
+				.sum::<F>();
+			let composite_claim = CompositeSumClaim { sum, composition };
+
+			for evaluation_order in [EvaluationOrder::LowToHigh, EvaluationOrder::HighToLow] {
+				let backend = make_portable_backend();
+				let proofs = [None, Some((1 << n_vars) - nonzero_prefix)].map(|suffix| {
+					let mut builder = EqIndSumcheckProverBuilder::with_switchover(
+						vec![a_mle.clone(), b_mle.clone()],
+						immediate_switchover_heuristic,
+						&backend,
+					)
+					.unwrap();
+					if let Some(suffix) = suffix {
+						builder = builder
+							.with_const_suffixes(&[(F::ZERO, suffix), (F::ZERO, 0)])
+							.unwrap();
+					}
+					let prover = builder
+						.build(
+							evaluation_order,
+							&eq_ind_challenges,
+							[composite_claim.clone()],
+							DefaultEvaluationDomainFactory::<FDomain>::default(),
+						)
+						.unwrap();
+
+					let (_, const_eval_suffix) = prover.compositions().first().unwrap();
+					assert_eq!(const_eval_suffix.suffix, 0);
+
+					let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+					let output =
+						sumcheck::prove::batch_prove(vec![prover], &mut transcript).unwrap();
+					(output, transcript.finalize())
+				});
+
+				let [(unhinted_output, unhinted_proof), (hinted_output, hinted_proof)] = proofs;
+				assert_eq!(hinted_output, unhinted_output);
+				assert_eq!(hinted_proof, unhinted_proof);
+			}
+		}
+	}
+
+	#[test]
+	fn test_const_suffix_hint_matches_unhinted_proof_128b() {
+		test_const_suffix_hint_matches_unhinted_proof::<
+			OptimalUnderlier128b,
+			BinaryField128b,
+			BinaryField8b,
+		>(12);
+	}
+
+	#[test]
+	fn test_const_suffix_hint_matches_unhinted_proof_512b() {
+		test_const_suffix_hint_matches_unhinted_proof::<
+			OptimalUnderlier512b,
+			BinaryField128b,
+			BinaryField8b,
+		>(12);
+	}
+
 	#[test]
 	fn test_eq_ind_sumcheck_prove_verify_128b() {
 		let n_vars = 8;
@@ -129,8 +129,8 @@ pub enum VerificationError {
 	NumberOfFinalEvaluations,
 	#[error("the number of reduced multilinear evaluations should conform to the claim shape")]
 	NumberOfMultilinearEvals,
-	#[error("the final batch composite evaluation is incorrect")]
-	IncorrectBatchEvaluation,
+	#[error("the batch composite evaluation after round {round} is incorrect")]
+	IncorrectBatchEvaluation { round: usize },
 	#[error("the proof contains an incorrect evaluation of the eq indicator")]
 	IncorrectEqIndEvaluation,
 	#[error(
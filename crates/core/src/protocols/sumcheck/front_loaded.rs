@@ -264,7 +264,9 @@ where
 			CoeffsOrSums::Coeffs(_) => Err(Error::ExpectedFinishRound),
 			CoeffsOrSums::Sum(sum) => {
 				if sum != F::ZERO {
-					return Err(VerificationError::IncorrectBatchEvaluation.into());
+					return Err(
+						VerificationError::IncorrectBatchEvaluation { round: self.round }.into()
+					);
 				}
 				Ok(())
 			}
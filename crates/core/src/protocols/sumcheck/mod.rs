@@ -20,12 +20,13 @@ pub mod zerocheck;
 
 pub use common::{
 	equal_n_vars_check, immediate_switchover_heuristic, standard_switchover_heuristic,
-	BatchSumcheckOutput, CompositeSumClaim, RoundCoeffs, RoundProof, SumcheckClaim,
+	BatchSumcheckOutput, ChallengeTransform, CompositeSumClaim, IdentityChallengeTransform,
+	RoundCoeffs, RoundProof, SumcheckClaim,
 };
 pub use eq_ind::EqIndSumcheckClaim;
 pub use error::*;
 pub use oracles::*;
-pub use prove::{batch_prove, batch_prove_zerocheck};
-pub use verify_sumcheck::batch_verify;
+pub use prove::{batch_prove, batch_prove_with_transform, batch_prove_zerocheck};
+pub use verify_sumcheck::{batch_verify, batch_verify_with_transform};
 pub use verify_zerocheck::batch_verify as batch_verify_zerocheck;
 pub use zerocheck::{BatchZerocheckOutput, ZerocheckClaim};
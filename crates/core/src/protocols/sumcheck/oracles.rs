@@ -22,6 +22,7 @@ pub enum ConcreteClaim<P: PackedField> {
 	Zerocheck(ZerocheckClaim<P::Scalar, TypeErasedComposition<P>>),
 }
 
+#[derive(Debug, Clone)]
 pub struct OracleClaimMeta {
 	pub n_vars: usize,
 	pub oracle_ids: Vec<OracleId>,
@@ -9,7 +9,9 @@ use tracing::instrument;
 use crate::{
 	fiat_shamir::{CanSample, Challenger},
 	protocols::sumcheck::{
-		common::{BatchSumcheckOutput, RoundCoeffs},
+		common::{
+			BatchSumcheckOutput, ChallengeTransform, IdentityChallengeTransform, RoundCoeffs,
+		},
 		error::Error,
 	},
 	transcript::ProverTranscript,
@@ -96,10 +98,28 @@ impl<F: Field, Prover: SumcheckProver<F> + ?Sized> SumcheckProver<F> for Box<Pro
 ///
 /// The provers in the `provers` parameter must in the same order as the corresponding claims
 /// provided to [`crate::protocols::sumcheck::batch_verify`] during proof verification.
-#[instrument(skip_all, name = "sumcheck::batch_prove")]
 pub fn batch_prove<F, Prover, Challenger_>(
+	provers: Vec<Prover>,
+	transcript: &mut ProverTranscript<Challenger_>,
+) -> Result<BatchSumcheckOutput<F>, Error>
+where
+	F: TowerField,
+	Prover: SumcheckProver<F>,
+	Challenger_: Challenger,
+{
+	batch_prove_with_transform(provers, transcript, &IdentityChallengeTransform)
+}
+
+/// Prove a batched sumcheck protocol execution, post-processing every round challenge.
+///
+/// This is [`batch_prove`] with each sampled round challenge passed through `transform` before
+/// the provers fold with it. The verifier must call
+/// [`crate::protocols::sumcheck::batch_verify_with_transform`] with the same transform.
+#[instrument(skip_all, name = "sumcheck::batch_prove")]
+pub fn batch_prove_with_transform<F, Prover, Challenger_>(
 	mut provers: Vec<Prover>,
 	transcript: &mut ProverTranscript<Challenger_>,
+	transform: &impl ChallengeTransform<F>,
 ) -> Result<BatchSumcheckOutput<F>, Error>
 where
 	F: TowerField,
@@ -160,7 +180,7 @@ where
 			.message()
 			.write_scalar_slice(round_proof.coeffs());
 
-		let challenge = transcript.sample();
+		let challenge = transform.transform(round_no, transcript.sample());
 		challenges.push(challenge);
 
 		for prover in &mut provers[..batch_coeffs.len()] {
@@ -165,19 +165,19 @@ where
 		n_vars: usize,
 		multilinears: Vec<Vec<P>>,
 		backend: &'a Backend,
-	) -> Self {
+	) -> Result<Self, Error> {
 		let multilinears = multilinears
 			.into_iter()
-			.map(SumcheckMultilinear::folded)
-			.collect();
+			.map(|evals| SumcheckMultilinear::folded(evals, F::ZERO, n_vars))
+			.collect::<Result<_, _>>()?;
 
-		Self {
+		Ok(Self {
 			n_vars,
 			eq_ind_partial_evals: None,
 			first_round_eval_1s: None,
 			multilinears,
 			backend,
-		}
+		})
 	}
 }
 
@@ -12,7 +12,7 @@ pub mod regular_sumcheck;
 pub mod univariate;
 pub mod zerocheck;
 
-pub use batch_sumcheck::{batch_prove, SumcheckProver};
+pub use batch_sumcheck::{batch_prove, batch_prove_with_transform, SumcheckProver};
 pub use batch_zerocheck::{batch_prove as batch_prove_zerocheck, ZerocheckProver};
 pub use logging::PIOPCompilerFoldData;
 pub use oracles::{
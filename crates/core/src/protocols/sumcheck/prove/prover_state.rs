@@ -222,6 +222,9 @@ where
 	}
 
 	/// Calculate the accumulated evaluations for an arbitrary sumcheck round.
+	///
+	/// A prover without composite claims has no evaluators, and its rounds have no evaluations.
+	/// The backend is not called in that case, as it rejects an empty set of evaluators.
 	#[instrument(skip_all, level = "debug")]
 	pub fn calculate_round_evals<Evaluator, Composition>(
 		&self,
@@ -231,6 +234,10 @@ where
 		Evaluator: SumcheckEvaluator<P, Composition> + Sync,
 		Composition: CompositionPoly<P>,
 	{
+		if evaluators.is_empty() {
+			return Ok(Vec::new());
+		}
+
 		Ok(self.backend.sumcheck_compute_round_evals(
 			self.evaluation_order,
 			self.n_vars,
@@ -264,7 +271,7 @@ where
 				}
 
 				let coeffs = izip!(interpolators, sums, evals)
-					.map(|(evaluator, &sum, RoundEvals(evals))| {
+					.map(|(evaluator, &sum, RoundEvals { evals, .. })| {
 						let coeffs = evaluator.round_evals_to_coeffs(sum, evals)?;
 						Ok::<_, Error>(RoundCoeffs(coeffs))
 					})
@@ -452,7 +452,7 @@ where
 			remaining_rounds,
 			folded_multilinears,
 			self.backend,
-		)
+		)?
 		.with_eq_ind_partial_evals(partial_eq_ind_evals)
 		.build(
 			EvaluationOrder::HighToLow,
@@ -1,10 +1,15 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use std::{
+	fmt::Debug,
 	iter::{self, repeat_with},
-	sync::Arc,
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+	},
 };
 
+use assert_matches::assert_matches;
 use binius_field::{
 	arch::{OptimalUnderlier128b, OptimalUnderlier512b},
 	as_packed_field::{PackScalar, PackedType},
@@ -14,12 +19,15 @@ use binius_field::{
 	PackedBinaryField1x128b, PackedBinaryField4x32b, PackedExtension, PackedField,
 	RepackedExtension, TowerField,
 };
-use binius_hal::{make_portable_backend, ComputationBackend, ComputationBackendExt};
+use binius_hal::{
+	make_portable_backend, ComputationBackend, ComputationBackendExt, RoundEvals,
+	SumcheckEvaluator, SumcheckMultilinear, SumcheckTaskGraph,
+};
 use binius_hash::groestl::Groestl256;
 use binius_math::{
 	ArithCircuit, CompositionPoly, EvaluationDomainFactory, EvaluationOrder,
-	IsomorphicEvaluationDomainFactory, MLEEmbeddingAdapter, MultilinearExtension, MultilinearPoly,
-	MultilinearQuery,
+	IsomorphicEvaluationDomainFactory, MLEDirectAdapter, MLEEmbeddingAdapter, MultilinearExtension,
+	MultilinearPoly, MultilinearQuery, MultilinearQueryRef, RowsBatchRef,
 };
 use binius_maybe_rayon::{current_num_threads, prelude::*};
 use binius_utils::checked_arithmetics::log2_ceil_usize;
@@ -29,11 +37,13 @@ use rand::{rngs::StdRng, Rng, SeedableRng};
 use super::{
 	common::CompositeSumClaim,
 	front_loaded::BatchVerifier as FrontLoadedBatchVerifier,
+	immediate_switchover_heuristic,
 	prove::{
-		batch_prove, front_loaded::BatchProver as FrontLoadedBatchProver, RegularSumcheckProver,
+		batch_prove, batch_prove_with_transform,
+		front_loaded::BatchProver as FrontLoadedBatchProver, RegularSumcheckProver,
 	},
-	verify_sumcheck::batch_verify,
-	BatchSumcheckOutput, SumcheckClaim,
+	verify_sumcheck::{batch_verify, batch_verify_with_transform},
+	BatchSumcheckOutput, ChallengeTransform, Error, IdentityChallengeTransform, SumcheckClaim,
 };
 use crate::{
 	composition::index_composition,
@@ -43,7 +53,7 @@ use crate::{
 		sumcheck::prove::SumcheckProver,
 		test_utils::{AddOneComposition, TestProductComposition},
 	},
-	transcript::ProverTranscript,
+	transcript::{ProverTranscript, VerifierTranscript},
 };
 
 #[derive(Debug, Clone)]
@@ -458,6 +468,572 @@ fn test_prove_verify_batch_constant_polys() {
 	]);
 }
 
+/// Squares every challenge, which is a bijection because squaring is the Frobenius automorphism of
+/// a binary field.
+#[derive(Debug)]
+struct SquareChallengeTransform;
+
+impl<F: Field> ChallengeTransform<F> for SquareChallengeTransform {
+	fn transform(&self, _round: usize, challenge: F) -> F {
+		challenge.square()
+	}
+}
+
+/// Proves a single sumcheck claim with the given challenge transforms on the prover and verifier
+/// sides, and returns the verification result along with the proof bytes.
+fn prove_verify_with_transforms(
+	prover_transform: Option<&dyn ChallengeTransform<BinaryField128b>>,
+	verifier_transform: &impl ChallengeTransform<BinaryField128b>,
+) -> (Result<BatchSumcheckOutput<BinaryField128b>, Error>, Vec<u8>) {
+	type P = PackedBinaryField4x32b;
+	type FDomain = BinaryField8b;
+	type FE = BinaryField128b;
+	type PE = PackedBinaryField1x128b;
+
+	struct DynTransform<'a>(&'a dyn ChallengeTransform<FE>);
+
+	impl ChallengeTransform<FE> for DynTransform<'_> {
+		fn transform(&self, round: usize, challenge: FE) -> FE {
+			self.0.transform(round, challenge)
+		}
+	}
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let backend = make_portable_backend();
+	let domain_factory = IsomorphicEvaluationDomainFactory::<FDomain>::default();
+	let (mles, claim, prover) = make_test_sumcheck::<FE, FDomain, P, PE, _>(
+		EvaluationOrder::LowToHigh,
+		&TestSumcheckClaimShape {
+			n_vars: 6,
+			degree: 3,
+		},
+		&mut rng,
+		&domain_factory,
+		&backend,
+	);
+
+	let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let prover_output = match prover_transform {
+		Some(transform) => batch_prove_with_transform(
+			vec![prover],
+			&mut prover_transcript,
+			&DynTransform(transform),
+		),
+		None => batch_prove(vec![prover], &mut prover_transcript),
+	}
+	.expect("failed to prove sumcheck");
+
+	// The reduced evaluations must be at the point of transformed challenges.
+	let multilin_query = backend
+		.multilinear_query::<PE>(&prover_output.challenges)
+		.unwrap();
+	for (mle, &expected) in iter::zip(mles, &prover_output.multilinear_evals[0]) {
+		let multilinear = MLEEmbeddingAdapter::<_, PE, _>::from(mle);
+		assert_eq!(multilinear.evaluate(multilin_query.to_ref()).unwrap(), expected);
+	}
+
+	let proof = prover_transcript.finalize();
+	let mut verifier_transcript =
+		VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof.clone());
+	let verifier_output = batch_verify_with_transform(
+		EvaluationOrder::LowToHigh,
+		&[claim],
+		&mut verifier_transcript,
+		verifier_transform,
+	);
+	if let Ok(verifier_output) = &verifier_output {
+		assert_eq!(verifier_output, &prover_output);
+	}
+	(verifier_output, proof)
+}
+
+#[test]
+fn test_identity_challenge_transform_matches_baseline() {
+	let (baseline_output, baseline_proof) =
+		prove_verify_with_transforms(None, &IdentityChallengeTransform);
+	let (output, proof) = prove_verify_with_transforms(
+		Some(&IdentityChallengeTransform),
+		&IdentityChallengeTransform,
+	);
+	assert_eq!(output.unwrap(), baseline_output.unwrap());
+	assert_eq!(proof, baseline_proof);
+}
+
+#[test]
+fn test_challenge_transform_applied_on_both_sides() {
+	let (baseline_output, _) = prove_verify_with_transforms(None, &IdentityChallengeTransform);
+	let (output, _) =
+		prove_verify_with_transforms(Some(&SquareChallengeTransform), &SquareChallengeTransform);
+	let output = output.unwrap();
+	assert_eq!(
+		output.challenges[0],
+		baseline_output.unwrap().challenges[0].square(),
+		"the first challenge is sampled from the same transcript state, then squared"
+	);
+
+	// A verifier that does not apply the prover's transform rejects the proof.
+	let (output, _) =
+		prove_verify_with_transforms(Some(&SquareChallengeTransform), &IdentityChallengeTransform);
+	assert!(output.is_err());
+}
+
+/// A backend that computes round evaluations by running the tasks of a [`SumcheckTaskGraph`]
+/// serially, checking each round against the Rayon path of the portable backend.
+#[derive(Debug, Default)]
+struct SerialTaskGraphBackend {
+	n_rounds: AtomicUsize,
+}
+
+impl ComputationBackend for SerialTaskGraphBackend {
+	type Vec<P: Send + Sync + Debug + 'static> = Vec<P>;
+
+	fn to_hal_slice<P: Debug + Send + Sync + 'static>(v: Vec<P>) -> Self::Vec<P> {
+		v
+	}
+
+	fn tensor_product_full_query<P: PackedField>(
+		&self,
+		query: &[P::Scalar],
+	) -> Result<Self::Vec<P>, binius_hal::Error> {
+		make_portable_backend().tensor_product_full_query(query)
+	}
+
+	fn sumcheck_compute_round_evals<FDomain, P, M, Evaluator, Composition>(
+		&self,
+		evaluation_order: EvaluationOrder,
+		n_vars: usize,
+		tensor_query: Option<MultilinearQueryRef<P>>,
+		multilinears: &[SumcheckMultilinear<P, M>],
+		evaluators: &[Evaluator],
+		nontrivial_evaluation_points: &[FDomain],
+	) -> Result<Vec<RoundEvals<P::Scalar>>, binius_hal::Error>
+	where
+		FDomain: Field,
+		P: PackedExtension<FDomain>,
+		M: MultilinearPoly<P> + Send + Sync,
+		Evaluator: SumcheckEvaluator<P, Composition> + Sync,
+		Composition: CompositionPoly<P>,
+	{
+		let task_graph = SumcheckTaskGraph::new(
+			evaluation_order,
+			n_vars,
+			tensor_query,
+			multilinears,
+			evaluators,
+			nontrivial_evaluation_points,
+		)?;
+
+		// Run uneven chunks of subcubes in reverse order, as an external scheduler might.
+		let n_subcubes = task_graph.n_subcubes();
+		let mut boundaries = vec![0, n_subcubes / 3, n_subcubes / 2, n_subcubes];
+		boundaries.dedup();
+		let partial_evals = boundaries
+			.windows(2)
+			.rev()
+			.map(|window| task_graph.task(window[0]..window[1])())
+			.collect::<Result<Vec<_>, _>>()?;
+		let round_evals = task_graph.reduce(partial_evals);
+
+		let expected_round_evals = make_portable_backend().sumcheck_compute_round_evals(
+			evaluation_order,
+			n_vars,
+			tensor_query,
+			multilinears,
+			evaluators,
+			nontrivial_evaluation_points,
+		)?;
+		assert_eq!(round_evals.len(), expected_round_evals.len());
+		for (round_eval, expected) in iter::zip(&round_evals, &expected_round_evals) {
+			assert_eq!(round_eval.evals, expected.evals);
+			assert_eq!(round_eval.eval_point_indices, expected.eval_point_indices);
+
+			// Sumcheck provers never compute the evaluation at zero, which the verifier derives
+			// from the claimed sum.
+			assert_eq!(round_eval.at_zero(), None);
+			assert_eq!(round_eval.labeled().count(), round_eval.evals.len());
+			for (label, eval) in round_eval.labeled() {
+				assert_eq!(round_eval.get(label), Some(eval));
+			}
+		}
+
+		self.n_rounds.fetch_add(1, Ordering::Relaxed);
+		Ok(round_evals)
+	}
+
+	fn sumcheck_fold_multilinears<P, M>(
+		&self,
+		evaluation_order: EvaluationOrder,
+		n_vars: usize,
+		multilinears: &mut [SumcheckMultilinear<P, M>],
+		challenge: P::Scalar,
+		tensor_query: Option<MultilinearQueryRef<P>>,
+	) -> Result<bool, binius_hal::Error>
+	where
+		P: PackedField,
+		M: MultilinearPoly<P> + Send + Sync,
+	{
+		make_portable_backend().sumcheck_fold_multilinears(
+			evaluation_order,
+			n_vars,
+			multilinears,
+			challenge,
+			tensor_query,
+		)
+	}
+
+	fn evaluate_partial_high<P: PackedField>(
+		&self,
+		multilinear: &impl MultilinearPoly<P>,
+		query_expansion: MultilinearQueryRef<P>,
+	) -> Result<MultilinearExtension<P>, binius_hal::Error> {
+		make_portable_backend().evaluate_partial_high(multilinear, query_expansion)
+	}
+}
+
+#[test]
+fn test_serial_task_graph_matches_rayon_round_evals() {
+	type P = PackedBinaryField4x32b;
+	type FDomain = BinaryField8b;
+	type FE = BinaryField128b;
+	type PE = PackedBinaryField1x128b;
+
+	let domain_factory = IsomorphicEvaluationDomainFactory::<FDomain>::default();
+	for evaluation_order in [EvaluationOrder::LowToHigh, EvaluationOrder::HighToLow] {
+		let claim_shape = TestSumcheckClaimShape {
+			n_vars: 12,
+			degree: 3,
+		};
+
+		let backend = SerialTaskGraphBackend::default();
+		let (_, claim, prover) = make_test_sumcheck::<FE, FDomain, P, PE, _>(
+			evaluation_order,
+			&claim_shape,
+			StdRng::seed_from_u64(0),
+			&domain_factory,
+			&backend,
+		);
+		let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		let output = batch_prove(vec![prover], &mut transcript).unwrap();
+		assert!(backend.n_rounds.load(Ordering::Relaxed) > 0);
+
+		// The proof is identical to the one produced with the portable backend.
+		let portable_backend = make_portable_backend();
+		let (_, _, portable_prover) = make_test_sumcheck::<FE, FDomain, P, PE, _>(
+			evaluation_order,
+			&claim_shape,
+			StdRng::seed_from_u64(0),
+			&domain_factory,
+			&portable_backend,
+		);
+		let mut portable_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		let portable_output = batch_prove(vec![portable_prover], &mut portable_transcript).unwrap();
+		assert_eq!(output, portable_output);
+
+		let proof = transcript.finalize();
+		assert_eq!(proof, portable_transcript.finalize());
+
+		let mut verifier_transcript =
+			VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof);
+		batch_verify(evaluation_order, &[claim], &mut verifier_transcript).unwrap();
+	}
+}
+
+/// A backend that checks the round evaluations of every batch of evaluators against those of each
+/// evaluator computed on its own, where the per-evaluator reduction is trivially serial.
+#[derive(Debug, Default)]
+struct PerEvaluatorBackend {
+	n_rounds: AtomicUsize,
+}
+
+impl ComputationBackend for PerEvaluatorBackend {
+	type Vec<P: Send + Sync + Debug + 'static> = Vec<P>;
+
+	fn to_hal_slice<P: Debug + Send + Sync + 'static>(v: Vec<P>) -> Self::Vec<P> {
+		v
+	}
+
+	fn tensor_product_full_query<P: PackedField>(
+		&self,
+		query: &[P::Scalar],
+	) -> Result<Self::Vec<P>, binius_hal::Error> {
+		make_portable_backend().tensor_product_full_query(query)
+	}
+
+	fn sumcheck_compute_round_evals<FDomain, P, M, Evaluator, Composition>(
+		&self,
+		evaluation_order: EvaluationOrder,
+		n_vars: usize,
+		tensor_query: Option<MultilinearQueryRef<P>>,
+		multilinears: &[SumcheckMultilinear<P, M>],
+		evaluators: &[Evaluator],
+		nontrivial_evaluation_points: &[FDomain],
+	) -> Result<Vec<RoundEvals<P::Scalar>>, binius_hal::Error>
+	where
+		FDomain: Field,
+		P: PackedExtension<FDomain>,
+		M: MultilinearPoly<P> + Send + Sync,
+		Evaluator: SumcheckEvaluator<P, Composition> + Sync,
+		Composition: CompositionPoly<P>,
+	{
+		let backend = make_portable_backend();
+		let round_evals = backend.sumcheck_compute_round_evals(
+			evaluation_order,
+			n_vars,
+			tensor_query,
+			multilinears,
+			evaluators,
+			nontrivial_evaluation_points,
+		)?;
+
+		assert_eq!(round_evals.len(), evaluators.len());
+		for (round_eval, evaluator) in iter::zip(&round_evals, evaluators) {
+			let n_nontrivial_points = evaluator.eval_point_indices().end.saturating_sub(3);
+			let expected = backend.sumcheck_compute_round_evals(
+				evaluation_order,
+				n_vars,
+				tensor_query,
+				multilinears,
+				std::slice::from_ref(evaluator),
+				&nontrivial_evaluation_points[..n_nontrivial_points],
+			)?;
+			assert_eq!(expected.len(), 1);
+			assert_eq!(round_eval.evals, expected[0].evals);
+			assert_eq!(round_eval.eval_point_indices, expected[0].eval_point_indices);
+		}
+
+		self.n_rounds.fetch_add(1, Ordering::Relaxed);
+		Ok(round_evals)
+	}
+
+	fn sumcheck_fold_multilinears<P, M>(
+		&self,
+		evaluation_order: EvaluationOrder,
+		n_vars: usize,
+		multilinears: &mut [SumcheckMultilinear<P, M>],
+		challenge: P::Scalar,
+		tensor_query: Option<MultilinearQueryRef<P>>,
+	) -> Result<bool, binius_hal::Error>
+	where
+		P: PackedField,
+		M: MultilinearPoly<P> + Send + Sync,
+	{
+		make_portable_backend().sumcheck_fold_multilinears(
+			evaluation_order,
+			n_vars,
+			multilinears,
+			challenge,
+			tensor_query,
+		)
+	}
+
+	fn evaluate_partial_high<P: PackedField>(
+		&self,
+		multilinear: &impl MultilinearPoly<P>,
+		query_expansion: MultilinearQueryRef<P>,
+	) -> Result<MultilinearExtension<P>, binius_hal::Error> {
+		make_portable_backend().evaluate_partial_high(multilinear, query_expansion)
+	}
+}
+
+#[test]
+fn test_many_claims_round_evals_match_per_evaluator() {
+	type P = PackedBinaryField4x32b;
+	type FDomain = BinaryField8b;
+	type FE = BinaryField128b;
+	type PE = PackedBinaryField1x128b;
+
+	let n_vars = 8;
+	let n_claims = 64;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let multilins = generate_random_multilinears::<P>(&mut rng, n_vars, 3)
+		.into_iter()
+		.map(MLEEmbeddingAdapter::<_, PE, _>::from)
+		.collect::<Vec<_>>();
+
+	let composite_sums = (0..n_claims)
+		.map(|i| {
+			let composition = index_composition(
+				&[0, 1, 2],
+				[i % 3],
+				PowerComposition {
+					exponent: 1 + i % 4,
+				},
+			)
+			.unwrap();
+			let sum = compute_composite_sum(&multilins, &composition);
+			CompositeSumClaim { composition, sum }
+		})
+		.collect::<Vec<_>>();
+
+	let domain_factory = IsomorphicEvaluationDomainFactory::<FDomain>::default();
+	for evaluation_order in [EvaluationOrder::LowToHigh, EvaluationOrder::HighToLow] {
+		let backend = PerEvaluatorBackend::default();
+		let prover = RegularSumcheckProver::<FDomain, _, _, _, _>::new(
+			evaluation_order,
+			multilins.iter().collect(),
+			composite_sums.clone(),
+			&domain_factory,
+			|_| 1,
+			&backend,
+		)
+		.unwrap();
+
+		let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		batch_prove::<FE, _, _>(vec![prover], &mut transcript).unwrap();
+		assert_eq!(backend.n_rounds.load(Ordering::Relaxed), n_vars);
+	}
+}
+
+/// An evaluator that is never run, used to name an evaluator type for empty evaluator slices.
+struct UnreachableEvaluator;
+
+impl<P: PackedField> SumcheckEvaluator<P, IdentityCompositionPoly> for UnreachableEvaluator {
+	fn eval_point_indices(&self) -> std::ops::Range<usize> {
+		unreachable!()
+	}
+
+	fn process_subcube_at_eval_point(
+		&self,
+		_subcube_vars: usize,
+		_subcube_index: usize,
+		_is_infinity_point: bool,
+		_batch_query: &RowsBatchRef<P>,
+	) -> P {
+		unreachable!()
+	}
+
+	fn composition(&self) -> &IdentityCompositionPoly {
+		unreachable!()
+	}
+
+	fn eq_ind_partial_eval(&self) -> Option<&[P]> {
+		unreachable!()
+	}
+}
+
+#[test]
+fn test_round_evals_without_evaluators() {
+	type P = PackedBinaryField1x128b;
+
+	let multilinears = generate_random_multilinears::<P>(StdRng::seed_from_u64(0), 4, 1)
+		.into_iter()
+		.map(|multilinear| {
+			SumcheckMultilinear::transparent(
+				MLEDirectAdapter::from(multilinear),
+				&immediate_switchover_heuristic,
+			)
+		})
+		.collect::<Vec<_>>();
+	let evaluators: [UnreachableEvaluator; 0] = [];
+
+	for evaluation_order in [EvaluationOrder::LowToHigh, EvaluationOrder::HighToLow] {
+		let result = make_portable_backend().sumcheck_compute_round_evals(
+			evaluation_order,
+			4,
+			None,
+			&multilinears,
+			&evaluators,
+			&[] as &[BinaryField8b],
+		);
+		assert_matches!(result, Err(binius_hal::Error::NoEvaluators));
+
+		let task_graph = SumcheckTaskGraph::<BinaryField8b, P, _, _, IdentityCompositionPoly>::new(
+			evaluation_order,
+			4,
+			None,
+			&multilinears,
+			&evaluators,
+			&[],
+		);
+		assert_matches!(task_graph.err(), Some(binius_hal::Error::NoEvaluators));
+	}
+}
+
+#[test]
+fn test_folded_rejects_oversized_evals() {
+	type P = PackedBinaryField4x32b;
+	type M = MLEDirectAdapter<P>;
+
+	for (n_vars, max_len) in [(4, 4), (1, 1), (0, 1)] {
+		let folded = SumcheckMultilinear::<P, M>::folded(
+			vec![P::zero(); max_len],
+			BinaryField32b::ZERO,
+			n_vars,
+		);
+		assert_matches!(folded, Ok(SumcheckMultilinear::Folded { .. }));
+
+		let folded = SumcheckMultilinear::<P, M>::folded(
+			vec![P::zero(); max_len + 1],
+			BinaryField32b::ZERO,
+			n_vars,
+		);
+		assert_matches!(folded, Err(binius_hal::Error::IncorrectDestSliceLengths));
+	}
+}
+
+fn folded_evals<P: PackedField, M: MultilinearPoly<P>>(
+	multilinear: &SumcheckMultilinear<P, M>,
+) -> (&[P], P::Scalar) {
+	match multilinear {
+		SumcheckMultilinear::Folded {
+			large_field_folded_evals,
+			suffix_eval,
+		} => (large_field_folded_evals, *suffix_eval),
+		SumcheckMultilinear::Transparent { .. } => panic!("expected a folded multilinear"),
+	}
+}
+
+#[test]
+fn test_fold_multilinear_matches_backend_fold() {
+	type F = BinaryField32b;
+	type P = PackedBinaryField4x32b;
+
+	let n_vars = 6;
+	let suffix_len = 20;
+	let mut rng = StdRng::seed_from_u64(0);
+	let suffix_eval = <F as Field>::random(&mut rng);
+	let mut evals = repeat_with(|| P::random(&mut rng))
+		.take(1 << (n_vars - P::LOG_WIDTH))
+		.collect::<Vec<_>>();
+	for i in (1 << n_vars) - suffix_len..1 << n_vars {
+		set_packed_slice(&mut evals, i, suffix_eval);
+	}
+	let multilinear = MLEDirectAdapter::from(MultilinearExtension::new(n_vars, evals).unwrap());
+	let challenges = repeat_with(|| <F as Field>::random(&mut rng))
+		.take(2)
+		.collect::<Vec<_>>();
+
+	let backend = make_portable_backend();
+	for evaluation_order in [EvaluationOrder::LowToHigh, EvaluationOrder::HighToLow] {
+		let transparent = || SumcheckMultilinear::Transparent {
+			multilinear: &multilinear,
+			switchover_round: 0,
+			const_suffix: (suffix_eval, suffix_len),
+		};
+
+		let mut expected = [transparent()];
+		let mut folded = transparent();
+		for (round, &challenge) in challenges.iter().enumerate() {
+			let tensor_query = MultilinearQuery::<P>::expand(&[challenge]);
+			backend
+				.sumcheck_fold_multilinears(
+					evaluation_order,
+					n_vars - round,
+					&mut expected,
+					challenge,
+					Some(tensor_query.to_ref()),
+				)
+				.unwrap();
+			folded =
+				binius_hal::fold_multilinear(evaluation_order, n_vars - round, folded, challenge)
+					.unwrap();
+
+			assert_eq!(folded_evals(&folded), folded_evals(&expected[0]));
+		}
+	}
+}
+
 fn prove_verify_batch_front_loaded(claim_shapes: &[TestSumcheckClaimShape]) {
 	for evaluation_order in [EvaluationOrder::LowToHigh, EvaluationOrder::HighToLow] {
 		test_prove_verify_batch_front_loaded_with_evaluation_order(evaluation_order, claim_shapes);
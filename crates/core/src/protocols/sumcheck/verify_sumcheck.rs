@@ -6,7 +6,10 @@ use binius_utils::{bail, sorting::is_sorted_ascending};
 use itertools::izip;
 
 use super::{
-	common::{batch_weighted_value, BatchSumcheckOutput, RoundProof, SumcheckClaim},
+	common::{
+		batch_weighted_value, BatchSumcheckOutput, ChallengeTransform, IdentityChallengeTransform,
+		RoundProof, SumcheckClaim,
+	},
 	error::{Error, VerificationError},
 	RoundCoeffs,
 };
@@ -32,6 +35,25 @@ pub fn batch_verify<F, Composition, Challenger_>(
 	claims: &[SumcheckClaim<F, Composition>],
 	transcript: &mut VerifierTranscript<Challenger_>,
 ) -> Result<BatchSumcheckOutput<F>, Error>
+where
+	F: TowerField,
+	Composition: CompositionPoly<F>,
+	Challenger_: Challenger,
+{
+	batch_verify_with_transform(evaluation_order, claims, transcript, &IdentityChallengeTransform)
+}
+
+/// Verify a batched sumcheck protocol execution, post-processing every round challenge.
+///
+/// This is [`batch_verify`] with each sampled round challenge passed through `transform` before
+/// the round polynomial is evaluated at it. The prover must have used
+/// [`crate::protocols::sumcheck::batch_prove_with_transform`] with the same transform.
+pub fn batch_verify_with_transform<F, Composition, Challenger_>(
+	evaluation_order: EvaluationOrder,
+	claims: &[SumcheckClaim<F, Composition>],
+	transcript: &mut VerifierTranscript<Challenger_>,
+	transform: &impl ChallengeTransform<F>,
+) -> Result<BatchSumcheckOutput<F>, Error>
 where
 	F: TowerField,
 	Composition: CompositionPoly<F>,
@@ -77,7 +99,7 @@ where
 		let coeffs = transcript.message().read_scalar_slice(max_degree)?;
 		let round_proof = RoundProof(RoundCoeffs(coeffs));
 
-		let challenge = transcript.sample();
+		let challenge = transform.transform(round_no, transcript.sample());
 		challenges.push(challenge);
 
 		sum = interpolate_round_proof(round_proof, sum, challenge);
@@ -115,7 +137,7 @@ where
 	)?;
 
 	if sum != expected_sum {
-		return Err(VerificationError::IncorrectBatchEvaluation.into());
+		return Err(VerificationError::IncorrectBatchEvaluation { round: n_rounds }.into());
 	}
 
 	if EvaluationOrder::HighToLow == evaluation_order {
@@ -254,9 +254,8 @@ fn compute_row_batched_sumcheck_evals<F, Tower>(
 	row_batch_coeffs: &[F],
 ) -> Vec<F>
 where
-	F: TowerField,
+	F: TowerField + PackedTop<Tower>,
 	Tower: TowerFamily<B128 = F>,
-	F: PackedTop<Tower>,
 {
 	tensor_elems
 		.into_par_iter()
@@ -14,7 +14,11 @@
 
 mod error;
 
-use std::{iter::repeat_with, slice};
+use std::{
+	io::{self, Write},
+	iter::repeat_with,
+	slice,
+};
 
 use binius_field::{PackedField, TowerField};
 use binius_utils::{DeserializeBytes, SerializationMode, SerializeBytes};
@@ -114,6 +118,19 @@ impl<Challenger_: Default + Challenger> ProverTranscript<Challenger_> {
 	}
 }
 
+impl<Challenger_: Challenger> ProverTranscript<Challenger_> {
+	/// Creates a new prover transcript starting from the given challenger state.
+	pub fn with_challenger(challenger: Challenger_) -> Self {
+		Self {
+			combined: FiatShamirBuf {
+				buffer: BytesMut::new(),
+				challenger,
+			},
+			debug_assertions: cfg!(debug_assertions),
+		}
+	}
+}
+
 impl<Challenger_: Default + Challenger> Default for ProverTranscript<Challenger_> {
 	fn default() -> Self {
 		Self::new()
@@ -125,6 +142,17 @@ impl<Challenger_: Challenger> ProverTranscript<Challenger_> {
 		self.combined.buffer.to_vec()
 	}
 
+	/// Writes the proof tape written so far to `writer` and removes it from the transcript.
+	///
+	/// The Fiat-Shamir state is unaffected, so the transcript can be used as before. The bytes
+	/// written by all calls, followed by those returned by [`Self::finalize`], form the proof.
+	/// Returns the number of bytes written.
+	pub fn flush_to(&mut self, mut writer: impl Write) -> io::Result<usize> {
+		let written = self.combined.buffer.split();
+		writer.write_all(&written)?;
+		Ok(written.len())
+	}
+
 	/// Sets the debug flag.
 	///
 	/// This flag is used to enable debug assertions in the [`TranscriptReader`] and
@@ -147,6 +175,14 @@ impl<Challenger_: Challenger> ProverTranscript<Challenger_> {
 		}
 	}
 
+	/// Observes public bytes that are known to the verifier, without writing them to the proof
+	/// tape.
+	///
+	/// This is a shorthand for writing the bytes to [`Self::observe`].
+	pub fn observe_bytes(&mut self, data: &[u8]) {
+		self.combined.challenger.observe_bytes(data);
+	}
+
 	/// Returns a writeable buffer that only writes the data to the proof tape, without observing
 	/// it.
 	///
@@ -189,6 +225,17 @@ impl<Challenger_: Default + Challenger> VerifierTranscript<Challenger_> {
 }
 
 impl<Challenger_: Challenger> VerifierTranscript<Challenger_> {
+	/// Creates a new verifier transcript starting from the given challenger state.
+	pub fn with_challenger(vec: Vec<u8>, challenger: Challenger_) -> Self {
+		Self {
+			combined: FiatShamirBuf {
+				challenger,
+				buffer: Bytes::from(vec),
+			},
+			debug_assertions: cfg!(debug_assertions),
+		}
+	}
+
 	pub fn finalize(self) -> Result<(), Error> {
 		if self.combined.buffer.has_remaining() {
 			return Err(Error::TranscriptNotEmpty {
@@ -216,6 +263,14 @@ impl<Challenger_: Challenger> VerifierTranscript<Challenger_> {
 		}
 	}
 
+	/// Observes public bytes that are known to the prover, without reading them from the proof
+	/// tape.
+	///
+	/// This is a shorthand for writing the bytes to [`Self::observe`].
+	pub fn observe_bytes(&mut self, data: &[u8]) {
+		self.combined.challenger.observe_bytes(data);
+	}
+
 	/// Returns a readable buffer that only reads the data from the proof tape, without observing
 	/// it.
 	///
@@ -366,6 +421,17 @@ impl<B: BufMut> TranscriptWriter<'_, B> {
 		});
 	}
 
+	/// Writes an application-specific domain separator.
+	///
+	/// The separator is prefixed with its length so that it cannot run into the following messages.
+	/// An empty separator writes nothing.
+	pub fn write_domain_separator(&mut self, domain_separator: &[u8]) {
+		if !domain_separator.is_empty() {
+			self.write(&domain_separator.len());
+			self.write_bytes(domain_separator);
+		}
+	}
+
 	pub fn write_scalar<F: TowerField>(&mut self, f: F) {
 		self.write_scalar_slice(slice::from_ref(&f));
 	}
@@ -558,6 +624,31 @@ mod tests {
 		verifier_transcript.finalize().unwrap();
 	}
 
+	#[test]
+	fn test_sample_vec_matches_successive_samples() {
+		let mut transcript_1 = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		let mut transcript_2 = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		for transcript in [&mut transcript_1, &mut transcript_2] {
+			transcript
+				.message()
+				.write_scalar(BinaryField128b::new(0x55669900112233550000CCDDFFEEAABB));
+		}
+
+		let sampled_vec: Vec<BinaryField128b> = transcript_1.sample_vec(3);
+		let sampled_successive: Vec<BinaryField128b> =
+			(0..3).map(|_| transcript_2.sample()).collect();
+		assert_eq!(sampled_vec, sampled_successive);
+
+		let mut verifier_transcript = transcript_1.into_verifier();
+		verifier_transcript
+			.message()
+			.read_scalar::<BinaryField128b>()
+			.unwrap();
+		let verifier_sampled: [BinaryField128b; 3] = verifier_transcript.sample_array();
+		assert_eq!(verifier_sampled.as_slice(), sampled_vec.as_slice());
+		verifier_transcript.finalize().unwrap();
+	}
+
 	#[test]
 	fn test_advicing() {
 		let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
@@ -669,4 +760,55 @@ mod tests {
 			.message()
 			.read_debug("test_transcript_debug_should_fail");
 	}
+
+	#[test]
+	fn test_domain_separator() {
+		let mut written = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		written.observe().write_domain_separator(b"context");
+		let mut seeded = ProverTranscript::with_challenger(
+			HasherChallenger::<Groestl256>::with_domain_separator(b"context"),
+		);
+		let mut other = ProverTranscript::with_challenger(
+			HasherChallenger::<Groestl256>::with_domain_separator(b"other context"),
+		);
+
+		let sampled: BinaryField128b = written.sample();
+		assert_eq!(sampled, seeded.sample());
+		assert_ne!(sampled, other.sample());
+
+		// An empty separator observes nothing ahead of the following messages.
+		let mut empty = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		let mut observer = empty.observe();
+		observer.write_domain_separator(&[]);
+		observer.write_bytes(b"statement");
+		let mut default = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		default.observe().write_bytes(b"statement");
+		assert_eq!(
+			CanSample::<BinaryField128b>::sample(&mut empty),
+			CanSample::<BinaryField128b>::sample(&mut default)
+		);
+	}
+
+	#[test]
+	fn test_observe_bytes() {
+		let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		prover_transcript.observe_bytes(b"circuit v1");
+		prover_transcript
+			.message()
+			.write_scalar(BinaryField128b::new(0x1234));
+		let prover_sample: BinaryField128b = prover_transcript.sample();
+		let proof = prover_transcript.finalize();
+
+		let sample_with_context = |context: &[u8]| {
+			let mut verifier_transcript =
+				VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof.clone());
+			verifier_transcript.observe_bytes(context);
+			let _: BinaryField128b = verifier_transcript.message().read_scalar().unwrap();
+			let sample: BinaryField128b = verifier_transcript.sample();
+			verifier_transcript.finalize().unwrap();
+			sample
+		};
+		assert_eq!(sample_with_context(b"circuit v1"), prover_sample);
+		assert_ne!(sample_with_context(b"circuit v2"), prover_sample);
+	}
 }
@@ -0,0 +1,53 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::array;
+
+use binius_field::{
+	PackedBinaryField16x32b, PackedBinaryField2x128b, PackedBinaryField2x64b,
+	PackedBinaryField4x128b, PackedBinaryField4x32b, PackedBinaryField4x64b,
+	PackedBinaryField8x32b, PackedBinaryField8x64b, PackedField,
+};
+use criterion::{
+	criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, Throughput,
+};
+use rand::thread_rng;
+
+const BATCH_SIZE: usize = 32;
+
+/// Deinterleaves adjacent scalars, as done for the evaluations at 0 and 1 in low-to-high sumcheck
+/// rounds. Packed fields of width one have nothing to deinterleave.
+fn benchmark_unzip_impl<P: PackedField>(group: &mut BenchmarkGroup<'_, WallTime>, id: &str) {
+	let mut rng = thread_rng();
+	let values = array::from_fn::<_, BATCH_SIZE, _>(|_| (P::random(&mut rng), P::random(&mut rng)));
+
+	group.throughput(Throughput::Elements((2 * P::WIDTH * BATCH_SIZE) as _));
+	group.bench_function(id, |b| {
+		b.iter(|| array::from_fn::<_, BATCH_SIZE, _>(|j| values[j].0.unzip(values[j].1, 0)))
+	});
+}
+
+fn packed_128(c: &mut criterion::Criterion) {
+	let mut group = c.benchmark_group("packed_128");
+
+	benchmark_unzip_impl::<PackedBinaryField4x32b>(&mut group, "4x32b");
+	benchmark_unzip_impl::<PackedBinaryField2x64b>(&mut group, "2x64b");
+}
+
+fn packed_256(c: &mut criterion::Criterion) {
+	let mut group = c.benchmark_group("packed_256");
+
+	benchmark_unzip_impl::<PackedBinaryField8x32b>(&mut group, "8x32b");
+	benchmark_unzip_impl::<PackedBinaryField4x64b>(&mut group, "4x64b");
+	benchmark_unzip_impl::<PackedBinaryField2x128b>(&mut group, "2x128b");
+}
+
+fn packed_512(c: &mut criterion::Criterion) {
+	let mut group = c.benchmark_group("packed_512");
+
+	benchmark_unzip_impl::<PackedBinaryField16x32b>(&mut group, "16x32b");
+	benchmark_unzip_impl::<PackedBinaryField8x64b>(&mut group, "8x64b");
+	benchmark_unzip_impl::<PackedBinaryField4x128b>(&mut group, "4x128b");
+}
+
+criterion_group!(unzip, packed_128, packed_256, packed_512);
+criterion_main!(unzip);
@@ -24,7 +24,7 @@ use super::{
 };
 use crate::{
 	arithmetic_traits::InvertOrZero, is_packed_field_indexable, underlier::WithUnderlier,
-	unpack_if_possible_mut, BinaryField, Field, PackedExtension,
+	unpack_if_possible_mut, BinaryField, BinaryField1b, Field, PackedExtension, TowerField,
 };
 
 /// A packed field represents a vector of underlying field elements.
@@ -439,6 +439,46 @@ pub fn mul_by_subfield_scalar<P: PackedExtension<FS>, FS: Field>(val: P, multipl
 	}
 }
 
+/// Multiply every scalar of a packed field element by the primitive element of the tower subfield
+/// `FS`.
+///
+/// This is the packed counterpart of [`TowerField::mul_primitive`] with
+/// `iota = FS::TOWER_LEVEL - 1`. The primitive element is the generator of `FS` over its direct
+/// subfield, so the product is [`MulAlpha::mul_alpha`] applied to the packed `FS` coordinates of
+/// `val`, without a full multiplication.
+pub fn packed_mul_primitive<P, FS>(val: P) -> P
+where
+	FS: TowerField,
+	P: PackedExtension<FS, PackedSubfield: MulAlpha>,
+{
+	P::cast_ext(P::cast_base(val).mul_alpha())
+}
+
+/// Apply the Frobenius endomorphism $x \mapsto x^{2^k}$ to every scalar of a packed field element.
+///
+/// The Frobenius map of a binary field with $2^n$ elements has order $n$, so `k` is reduced
+/// modulo the number of bits of the scalar before squaring.
+pub fn packed_frobenius<P>(val: P, k: usize) -> P
+where
+	P: PackedField<Scalar: BinaryField>,
+{
+	(0..k % P::Scalar::N_BITS).fold(val, |acc, _| acc.square())
+}
+
+/// Count the number of set bits in a slice of packed [`BinaryField1b`] elements.
+///
+/// Every bit of the underlier of a packed 1-bit field is one scalar, so this counts the bits of
+/// the underlying bytes directly, which is much faster than iterating over the scalars.
+pub fn popcount<P>(slice: &[P]) -> u64
+where
+	P: PackedField<Scalar = BinaryField1b> + WithUnderlier,
+{
+	bytemuck::cast_slice::<_, u8>(P::to_underliers_ref(slice))
+		.iter()
+		.map(|byte| byte.count_ones() as u64)
+		.sum()
+}
+
 /// Pack a slice of scalars into a vector of packed field elements.
 pub fn pack_slice<P: PackedField>(scalars: &[P::Scalar]) -> Vec<P> {
 	scalars
@@ -943,6 +983,81 @@ mod tests {
 		check_collection(&packed_slice, &PackedField::iter_slice(slice).take(3).collect_vec());
 	}
 
+	fn check_packed_mul_primitive<P, FS>(mut rng: impl RngCore)
+	where
+		P: PackedExtension<FS, Scalar: TowerField, PackedSubfield: MulAlpha>,
+		FS: TowerField,
+	{
+		let val = P::random(&mut rng);
+		let result = packed_mul_primitive::<P, FS>(val);
+		for (i, scalar) in val.iter().enumerate() {
+			assert_eq!(result.get(i), scalar.mul_primitive(FS::TOWER_LEVEL - 1).unwrap());
+		}
+	}
+
+	#[test]
+	fn test_packed_mul_primitive() {
+		let mut rng = StdRng::seed_from_u64(0);
+		check_packed_mul_primitive::<PackedBinaryField16x8b, BinaryField2b>(&mut rng);
+		check_packed_mul_primitive::<PackedBinaryField16x8b, BinaryField4b>(&mut rng);
+		check_packed_mul_primitive::<PackedBinaryField16x8b, BinaryField8b>(&mut rng);
+		check_packed_mul_primitive::<PackedBinaryField4x32b, BinaryField8b>(&mut rng);
+		check_packed_mul_primitive::<PackedBinaryField4x32b, BinaryField32b>(&mut rng);
+		check_packed_mul_primitive::<PackedBinaryField1x128b, BinaryField16b>(&mut rng);
+		check_packed_mul_primitive::<PackedBinaryField1x128b, BinaryField128b>(&mut rng);
+		check_packed_mul_primitive::<PackedAESBinaryField2x64b, AESTowerField32b>(&mut rng);
+		check_packed_mul_primitive::<PackedAESBinaryField2x64b, AESTowerField64b>(&mut rng);
+		check_packed_mul_primitive::<PackedBinaryField4x64b, BinaryField64b>(&mut rng);
+	}
+
+	fn check_packed_frobenius<P: PackedField<Scalar: BinaryField>>(mut rng: impl RngCore) {
+		let val = P::random(&mut rng);
+		assert_eq!(packed_frobenius(val, 0), val);
+		assert_eq!(packed_frobenius(val, 1), val.square());
+		assert_eq!(packed_frobenius(val, P::Scalar::N_BITS), val);
+
+		let mut iterated = val;
+		for _ in 0..P::Scalar::N_BITS {
+			iterated = packed_frobenius(iterated, 1);
+		}
+		assert_eq!(iterated, val);
+	}
+
+	#[test]
+	fn test_packed_frobenius() {
+		let mut rng = StdRng::seed_from_u64(0);
+		check_packed_frobenius::<PackedBinaryField16x8b>(&mut rng);
+		check_packed_frobenius::<PackedBinaryField4x32b>(&mut rng);
+		check_packed_frobenius::<PackedBinaryField1x128b>(&mut rng);
+		check_packed_frobenius::<PackedAESBinaryField16x8b>(&mut rng);
+		check_packed_frobenius::<PackedBinaryField4x64b>(&mut rng);
+	}
+
+	fn check_popcount<P>(mut rng: impl RngCore)
+	where
+		P: PackedField<Scalar = BinaryField1b> + WithUnderlier,
+	{
+		for len in [0, 1, 3, 8] {
+			let slice = (0..len).map(|_| P::random(&mut rng)).collect_vec();
+			let expected = PackedField::iter_slice(&slice)
+				.filter(|&bit| bit == BinaryField1b::ONE)
+				.count();
+			assert_eq!(popcount(&slice), expected as u64);
+		}
+	}
+
+	#[test]
+	fn test_popcount() {
+		let mut rng = StdRng::seed_from_u64(0);
+		check_popcount::<PackedBinaryField1x1b>(&mut rng);
+		check_popcount::<PackedBinaryField8x1b>(&mut rng);
+		check_popcount::<PackedBinaryField128x1b>(&mut rng);
+		check_popcount::<PackedBinaryField256x1b>(&mut rng);
+		check_popcount::<PackedBinaryField512x1b>(&mut rng);
+
+		assert_eq!(popcount(&[PackedBinaryField128x1b::one(); 3]), 3 * 128);
+	}
+
 	#[test]
 	fn check_packed_slice_mut() {
 		let mut rng = StdRng::seed_from_u64(0);
@@ -45,6 +45,8 @@ pub trait ComputationBackend: Send + Sync + Debug {
 	) -> Result<Self::Vec<P>, Error>;
 
 	/// Calculate the accumulated evaluations for an arbitrary round of zerocheck.
+	///
+	/// Fails with [`Error::NoEvaluators`] if no evaluators are given.
 	fn sumcheck_compute_round_evals<FDomain, P, M, Evaluator, Composition>(
 		&self,
 		evaluation_order: EvaluationOrder,
@@ -13,6 +13,8 @@ pub enum Error {
 	IncorrectQuerySize { expected: usize },
 	#[error("provided nontrivial evaluation points are of incorrect length")]
 	IncorrectNontrivialEvalPointsLength,
+	#[error("no sumcheck evaluators were provided")]
+	NoEvaluators,
 	#[error("scratch space not provided")]
 	NoScratchSpace,
 	#[error("incorrect multilinear access destination slice lengths")]
@@ -21,4 +21,6 @@ pub use backend::*;
 pub use cpu::*;
 pub use error::*;
 pub use sumcheck_evaluator::*;
+pub use sumcheck_folding::fold_multilinear;
 pub use sumcheck_multilinear::*;
+pub use sumcheck_round_calculation::{SumcheckPartialEvals, SumcheckTaskGraph};
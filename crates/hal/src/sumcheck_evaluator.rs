@@ -5,15 +5,103 @@ use std::ops::Range;
 use binius_field::{Field, PackedField};
 use binius_math::RowsBatchRef;
 
+/// A point at which a sumcheck round polynomial is evaluated.
+///
+/// The evaluation points of a round are indexed as 0, 1, "infinity", followed by the nontrivial
+/// finite evaluation points in order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvalPointLabel {
+	Zero,
+	One,
+	Infinity,
+	/// The nontrivial finite evaluation point with the given index.
+	Finite(usize),
+}
+
+impl EvalPointLabel {
+	/// Returns the label of the evaluation point with the given index.
+	pub const fn from_index(index: usize) -> Self {
+		match index {
+			0 => Self::Zero,
+			1 => Self::One,
+			2 => Self::Infinity,
+			_ => Self::Finite(index - 3),
+		}
+	}
+
+	/// Returns the index of the evaluation point.
+	pub const fn index(self) -> usize {
+		match self {
+			Self::Zero => 0,
+			Self::One => 1,
+			Self::Infinity => 2,
+			Self::Finite(i) => i + 3,
+		}
+	}
+}
+
 /// Evaluations of a polynomial at a set of evaluation points.
+///
+/// `evals[i]` is the evaluation at the point with index `eval_point_indices.start + i`, see
+/// [`EvalPointLabel`] for the indexing of the evaluation points. Points which can be derived by
+/// the verifier, such as the evaluation at zero, are usually skipped.
 #[derive(Debug, Clone)]
-pub struct RoundEvals<F: Field>(pub Vec<F>);
+pub struct RoundEvals<F: Field> {
+	pub evals: Vec<F>,
+	pub eval_point_indices: Range<usize>,
+}
+
+impl<F: Field> RoundEvals<F> {
+	/// Returns the evaluation at the point with the given label, if it was computed.
+	pub fn get(&self, label: EvalPointLabel) -> Option<&F> {
+		let index = label.index();
+		if !self.eval_point_indices.contains(&index) {
+			return None;
+		}
+		self.evals.get(index - self.eval_point_indices.start)
+	}
+
+	pub fn at_zero(&self) -> Option<&F> {
+		self.get(EvalPointLabel::Zero)
+	}
+
+	pub fn at_one(&self) -> Option<&F> {
+		self.get(EvalPointLabel::One)
+	}
+
+	pub fn at_infinity(&self) -> Option<&F> {
+		self.get(EvalPointLabel::Infinity)
+	}
+
+	/// Returns the evaluation at the `i`-th nontrivial finite evaluation point.
+	pub fn finite(&self, i: usize) -> Option<&F> {
+		self.get(EvalPointLabel::Finite(i))
+	}
+
+	/// Iterates over the computed evaluations along with the labels of their points.
+	pub fn labeled(&self) -> impl Iterator<Item = (EvalPointLabel, &F)> {
+		self.eval_point_indices
+			.clone()
+			.map(EvalPointLabel::from_index)
+			.zip(&self.evals)
+	}
+}
 
 pub trait SumcheckEvaluator<P: PackedField, Composition> {
 	/// The range of eval point indices over which composition evaluation and summation should
 	/// happen. Returned range must equal the result of `n_round_evals()` in length.
+	///
+	/// Evaluators that can derive the evaluation at 0 from the running round claim, as
+	/// $r(0) = s - r(1)$, skip it by starting the range at 1; the claim is then applied when
+	/// the round evaluations are interpolated.
 	fn eval_point_indices(&self) -> Range<usize>;
 
+	/// Whether this evaluator skips the evaluation at 0 and relies on the round claim to
+	/// recover it.
+	fn uses_claim_shortcut(&self) -> bool {
+		self.eval_point_indices().start > 0
+	}
+
 	/// Compute composition evals over a subcube.
 	///
 	/// `batch_query` should contain multilinears evals over a subcube represented
@@ -64,3 +152,24 @@ pub trait SumcheckEvaluator<P: PackedField, Composition> {
 		0
 	}
 }
+
+/// Computes the union of the evaluators' evaluation point index ranges and the number of
+/// nontrivial finite evaluation points it requires.
+///
+/// The returned count is the expected length of the `finite_evaluation_points` argument to
+/// [`ComputationBackend::sumcheck_compute_round_evals`](crate::ComputationBackend::sumcheck_compute_round_evals),
+/// which excludes the points 0, 1 and infinity. An empty set of evaluators requires no points.
+pub fn required_nontrivial_points<P, Composition, Evaluator>(
+	evaluators: &[Evaluator],
+) -> (usize, Range<usize>)
+where
+	P: PackedField,
+	Evaluator: SumcheckEvaluator<P, Composition>,
+{
+	let eval_point_indices = evaluators
+		.iter()
+		.map(|evaluator| evaluator.eval_point_indices())
+		.reduce(|range1, range2| range1.start.min(range2.start)..range1.end.max(range2.end))
+		.unwrap_or(0..0);
+	(eval_point_indices.end.saturating_sub(3), eval_point_indices)
+}
@@ -2,10 +2,11 @@
 
 use binius_field::PackedField;
 use binius_math::{
-	fold_left_lerp_inplace, fold_right_lerp, EvaluationOrder, MultilinearPoly, MultilinearQueryRef,
+	fold_left_lerp_inplace, fold_right_lerp, EvaluationOrder, MultilinearPoly, MultilinearQuery,
+	MultilinearQueryRef,
 };
 use binius_maybe_rayon::prelude::*;
-use binius_utils::checked_arithmetics::log2_ceil_usize;
+use binius_utils::{bail, checked_arithmetics::log2_ceil_usize};
 use bytemuck::zeroed_vec;
 
 use crate::{
@@ -34,6 +35,55 @@ where
 	}
 }
 
+/// Folds a single multilinear at a round challenge, producing a [`SumcheckMultilinear::Folded`].
+///
+/// The projected variable is the lowest or the highest one depending on `evaluation_order`, the
+/// same as in [`ComputationBackend::sumcheck_fold_multilinears`], which this shares its
+/// implementation with. Transparent multilinears are projected immediately regardless of their
+/// switchover round, and so must not have any pending challenges, i.e. they must have `n_vars`
+/// variables.
+///
+/// [`ComputationBackend::sumcheck_fold_multilinears`]: crate::ComputationBackend::sumcheck_fold_multilinears
+pub fn fold_multilinear<P, M>(
+	evaluation_order: EvaluationOrder,
+	n_vars: usize,
+	mut multilinear: SumcheckMultilinear<P, M>,
+	challenge: P::Scalar,
+) -> Result<SumcheckMultilinear<P, M>, Error>
+where
+	P: PackedField,
+	M: MultilinearPoly<P> + Send + Sync,
+{
+	if let SumcheckMultilinear::Transparent {
+		multilinear,
+		switchover_round,
+		..
+	} = &mut multilinear
+	{
+		if multilinear.n_vars() != n_vars {
+			bail!(binius_math::Error::IncorrectNumberOfVariables {
+				expected: n_vars,
+				actual: multilinear.n_vars(),
+			});
+		}
+
+		*switchover_round = 0;
+	}
+
+	let tensor_query = MultilinearQuery::<P>::expand(&[challenge]);
+	let mut multilinears = [multilinear];
+	fold_multilinears(
+		evaluation_order,
+		n_vars,
+		&mut multilinears,
+		challenge,
+		Some(tensor_query.to_ref()),
+	)?;
+
+	let [folded] = multilinears;
+	Ok(folded)
+}
+
 fn fold_multilinears_low_to_high<P, M>(
 	n_vars: usize,
 	multilinears: &mut [SumcheckMultilinear<P, M>],
@@ -2,6 +2,9 @@
 
 use binius_field::PackedField;
 use binius_math::MultilinearPoly;
+use binius_utils::bail;
+
+use crate::Error;
 
 /// An individual multilinear polynomial in a multivariate composite.
 #[derive(Debug, Clone)]
@@ -36,11 +39,24 @@ impl<P: PackedField, M: MultilinearPoly<P>> SumcheckMultilinear<P, M> {
 		}
 	}
 
-	pub fn folded(large_field_folded_evals: Vec<P>) -> Self {
-		Self::Folded {
-			large_field_folded_evals,
-			suffix_eval: Default::default(),
+	/// Creates a large field multilinear with `n_vars` variables from its evaluations.
+	///
+	/// This is the recommended way to construct [`Self::Folded`]. The evaluations may be shorter
+	/// than the hypercube, in which case they are padded with `suffix_eval` scalars, but not
+	/// longer than `1 << n_vars.saturating_sub(P::LOG_WIDTH)` packed elements.
+	pub fn folded(
+		large_field_folded_evals: Vec<P>,
+		suffix_eval: P::Scalar,
+		n_vars: usize,
+	) -> Result<Self, Error> {
+		if large_field_folded_evals.len() > 1 << n_vars.saturating_sub(P::LOG_WIDTH) {
+			bail!(Error::IncorrectDestSliceLengths);
 		}
+
+		Ok(Self::Folded {
+			large_field_folded_evals,
+			suffix_eval,
+		})
 	}
 
 	pub fn const_suffix(&self, n_vars: usize) -> (P::Scalar, usize) {
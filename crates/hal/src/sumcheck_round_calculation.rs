@@ -4,7 +4,7 @@
 //!
 //! This is one of the core computational tasks in the sumcheck proving algorithm.
 
-use std::iter;
+use std::{iter, marker::PhantomData, ops::Range};
 
 use binius_field::{
 	packed::get_packed_slice_checked, Field, PackedExtension, PackedField, PackedSubfield,
@@ -21,7 +21,7 @@ use stackalloc::stackalloc_with_iter;
 
 use crate::{
 	common::{subcube_vars_for_bits, MAX_SRC_SUBCUBE_LOG_BITS},
-	Error, RoundEvals, SumcheckEvaluator, SumcheckMultilinear,
+	required_nontrivial_points, Error, RoundEvals, SumcheckEvaluator, SumcheckMultilinear,
 };
 
 trait SumcheckMultilinearAccess<P: PackedField> {
@@ -82,6 +82,8 @@ trait SumcheckMultilinearAccess<P: PackedField> {
 ///
 /// See [`calculate_first_round_evals`] for an optimized version of this method
 /// that works over small fields in the first round.
+///
+/// Returns [`Error::NoEvaluators`] if `evaluators` is empty, rather than an empty result.
 pub(crate) fn calculate_round_evals<FDomain, F, P, M, Evaluator, Composition>(
 	evaluation_order: EvaluationOrder,
 	n_vars: usize,
@@ -98,254 +100,467 @@ where
 	Evaluator: SumcheckEvaluator<P, Composition> + Sync,
 	Composition: CompositionPoly<P>,
 {
-	assert!(n_vars > 0, "Computing round evaluations requires at least a single variable.");
+	let task_graph = SumcheckTaskGraph::new(
+		evaluation_order,
+		n_vars,
+		tensor_query,
+		multilinears,
+		evaluators,
+		finite_evaluation_points,
+	)?;
+
+	let partial_evals = (0..task_graph.n_subcubes())
+		.into_par_iter()
+		.try_fold(
+			|| task_graph.new_fold_states(),
+			|mut par_fold_states, subcube_index| {
+				task_graph.process_subcube(&mut par_fold_states, subcube_index)?;
+				Ok(par_fold_states)
+			},
+		)
+		.map(|states: Result<ParFoldStates<P>, Error>| -> Result<_, Error> {
+			Ok(SumcheckPartialEvals(states?.round_evals))
+		})
+		// Simply sum up the fold partitions.
+		.try_reduce(
+			|| task_graph.zero_partial_evals(),
+			|mut lhs, rhs| {
+				lhs.add_assign(rhs);
+				Ok(lhs)
+			},
+		)?;
 
-	let empty_query = MultilinearQuery::with_capacity(0);
-	let tensor_query = tensor_query.unwrap_or_else(|| empty_query.to_ref());
+	Ok(task_graph.reduce([partial_evals]))
+}
 
-	match evaluation_order {
-		EvaluationOrder::LowToHigh => calculate_round_evals_with_access(
-			LowToHighAccess,
-			n_vars,
-			tensor_query,
-			multilinears,
-			evaluators,
-			finite_evaluation_points,
-		),
-		EvaluationOrder::HighToLow => calculate_round_evals_with_access(
-			HighToLowAccess,
-			n_vars,
-			tensor_query,
-			multilinears,
-			evaluators,
-			finite_evaluation_points,
-		),
+/// The subcube evaluation work of a sumcheck round, exposed as independent tasks.
+///
+/// The $n-1$-variate hypercube of a round is split into [`Self::n_subcubes`] subcubes, and a task
+/// created with [`Self::task`] evaluates all compositions over a range of them. Tasks share no
+/// mutable state, so they can be run in any order on any executor. Once tasks covering every
+/// subcube exactly once have completed, [`Self::reduce`] sums their partial evaluations into the
+/// round evaluations. The portable CPU backend runs the same work with Rayon.
+pub struct SumcheckTaskGraph<'a, FDomain, P, M, Evaluator, Composition>
+where
+	P: PackedField,
+	M: MultilinearPoly<P>,
+{
+	evaluation_order: EvaluationOrder,
+	n_vars: usize,
+	tensor_query: Option<MultilinearQueryRef<'a, P>>,
+	empty_query: MultilinearQuery<P>,
+	multilinears: &'a [SumcheckMultilinear<P, M>],
+	evaluators: &'a [Evaluator],
+	nontrivial_evaluation_points: &'a [FDomain],
+	eval_point_indices: Range<usize>,
+	subcube_vars: usize,
+	subcube_count_by_evaluator: Vec<usize>,
+	subcube_count_by_multilinear: Vec<usize>,
+	/// The number of subcubes over which each multilinear is interpolated at an evaluation point,
+	/// indexed by the offset of the point into `eval_point_indices` and then by multilinear.
+	subcube_count_by_point_and_multilinear: Vec<Vec<usize>>,
+	/// For each folded multilinear, the first subcube lying entirely in its constant suffix,
+	/// together with the suffix value. Such subcubes are filled with the value, not fetched.
+	const_suffix_by_multilinear: Vec<Option<(usize, P::Scalar)>>,
+	_composition_marker: PhantomData<fn(&Composition)>,
+}
+
+/// Partial round evaluations accumulated by the tasks of a [`SumcheckTaskGraph`].
+#[derive(Debug, Clone)]
+pub struct SumcheckPartialEvals<P: PackedField>(Vec<Vec<P>>);
+
+impl<P: PackedField> SumcheckPartialEvals<P> {
+	fn add_assign(&mut self, rhs: Self) {
+		for (lhs_vals, rhs_vals) in izip!(&mut self.0, rhs.0) {
+			for (lhs_val, rhs_val) in lhs_vals.iter_mut().zip(rhs_vals) {
+				*lhs_val += rhs_val;
+			}
+		}
 	}
 }
 
-fn calculate_round_evals_with_access<FDomain, F, P, M, Evaluator, Access, Composition>(
-	access: Access,
-	n_vars: usize,
-	tensor_query: MultilinearQueryRef<P>,
-	multilinears: &[SumcheckMultilinear<P, M>],
-	evaluators: &[Evaluator],
-	nontrivial_evaluation_points: &[FDomain],
-) -> Result<Vec<RoundEvals<F>>, Error>
+impl<'a, FDomain, F, P, M, Evaluator, Composition>
+	SumcheckTaskGraph<'a, FDomain, P, M, Evaluator, Composition>
 where
 	FDomain: Field,
 	F: Field,
 	P: PackedField<Scalar = F> + PackedExtension<FDomain>,
 	M: MultilinearPoly<P> + Sync,
 	Evaluator: SumcheckEvaluator<P, Composition> + Sync,
-	Access: SumcheckMultilinearAccess<P> + Sync,
 	Composition: CompositionPoly<P>,
 {
-	let n_multilinears = multilinears.len();
-	let n_round_evals = evaluators
-		.iter()
-		.map(|evaluator| evaluator.eval_point_indices().len());
-
-	// Compute the union of all evaluation point index ranges.
-	let eval_point_indices = evaluators
-		.iter()
-		.map(|evaluator| evaluator.eval_point_indices())
-		.reduce(|range1, range2| range1.start.min(range2.start)..range1.end.max(range2.end))
-		.unwrap_or(0..0);
-
-	// Check that finite evaluation points  are of correct length (accounted for 0, 1 & infinity
-	// point).
-	if nontrivial_evaluation_points.len() != eval_point_indices.end.saturating_sub(3) {
-		bail!(Error::IncorrectNontrivialEvalPointsLength);
-	}
-
-	// Here we assume that at least one multilinear would be "full"
-	// REVIEW: come up with a better heuristic
-	let subcube_vars = subcube_vars_for_bits::<P>(
-		MAX_SRC_SUBCUBE_LOG_BITS,
-		n_vars - 1,
-		tensor_query.n_vars(),
-		n_vars - 1,
-	);
-
-	let subcube_count_by_evaluator = evaluators
-		.iter()
-		.map(|evaluator| {
-			((1 << (n_vars - 1)) - evaluator.const_eval_suffix()).div_ceil(1 << subcube_vars)
-		})
-		.collect::<Vec<_>>();
+	/// Prepares the task graph for a sumcheck round.
+	///
+	/// The arguments are the same as for
+	/// [`ComputationBackend::sumcheck_compute_round_evals`](crate::ComputationBackend::sumcheck_compute_round_evals).
+	///
+	/// Returns [`Error::NoEvaluators`] if `evaluators` is empty.
+	pub fn new(
+		evaluation_order: EvaluationOrder,
+		n_vars: usize,
+		tensor_query: Option<MultilinearQueryRef<'a, P>>,
+		multilinears: &'a [SumcheckMultilinear<P, M>],
+		evaluators: &'a [Evaluator],
+		nontrivial_evaluation_points: &'a [FDomain],
+	) -> Result<Self, Error> {
+		assert!(n_vars > 0, "Computing round evaluations requires at least a single variable.");
+
+		if evaluators.is_empty() {
+			bail!(Error::NoEvaluators);
+		}
 
-	let mut subcube_count_by_multilinear = vec![0; n_multilinears];
+		// Compute the union of all evaluation point index ranges.
+		let (n_nontrivial_points, eval_point_indices) =
+			required_nontrivial_points::<P, Composition, _>(evaluators);
 
-	for (&evaluator_subcube_count, evaluator) in izip!(&subcube_count_by_evaluator, evaluators) {
-		let used_vars = evaluator.composition().expression().vars_usage();
+		// Check that finite evaluation points  are of correct length (accounted for 0, 1 &
+		// infinity point).
+		if nontrivial_evaluation_points.len() != n_nontrivial_points {
+			bail!(Error::IncorrectNontrivialEvalPointsLength);
+		}
 
-		for (multilinear_subcube_count, usage_flag) in
-			izip!(&mut subcube_count_by_multilinear, used_vars)
+		// Here we assume that at least one multilinear would be "full"
+		// REVIEW: come up with a better heuristic
+		let subcube_vars = subcube_vars_for_bits::<P>(
+			MAX_SRC_SUBCUBE_LOG_BITS,
+			n_vars - 1,
+			tensor_query.map_or(0, |tensor_query| tensor_query.n_vars()),
+			n_vars - 1,
+		);
+
+		let subcube_count_by_evaluator = evaluators
+			.iter()
+			.map(|evaluator| {
+				((1 << (n_vars - 1)) - evaluator.const_eval_suffix()).div_ceil(1 << subcube_vars)
+			})
+			.collect::<Vec<_>>();
+
+		// A multilinear is only fetched over the subcubes processed by the evaluators that use it,
+		// and only interpolated at an evaluation point over the subcubes processed by those of them
+		// which evaluate at that point. Subcubes in the constant suffix of all these evaluators are
+		// skipped.
+		let mut subcube_count_by_point_and_multilinear =
+			vec![vec![0; multilinears.len()]; eval_point_indices.len()];
+
+		for (&evaluator_subcube_count, evaluator) in izip!(&subcube_count_by_evaluator, evaluators)
 		{
-			if usage_flag {
-				*multilinear_subcube_count =
-					(*multilinear_subcube_count).max(evaluator_subcube_count);
+			let used_vars = evaluator.composition().vars_usage();
+
+			for eval_point_index in evaluator.eval_point_indices() {
+				let subcube_count_by_multilinear = &mut subcube_count_by_point_and_multilinear
+					[eval_point_index - eval_point_indices.start];
+				for (multilinear_subcube_count, &usage_flag) in
+					izip!(subcube_count_by_multilinear, &used_vars)
+				{
+					if usage_flag {
+						*multilinear_subcube_count =
+							(*multilinear_subcube_count).max(evaluator_subcube_count);
+					}
+				}
 			}
 		}
+
+		let subcube_count_by_multilinear = (0..multilinears.len())
+			.map(|multilinear_index| {
+				subcube_count_by_point_and_multilinear
+					.iter()
+					.map(|subcube_count_by_multilinear| {
+						subcube_count_by_multilinear[multilinear_index]
+					})
+					.max()
+					.unwrap_or(0)
+			})
+			.collect();
+
+		let index_vars = n_vars - 1 - subcube_vars;
+		let const_suffix_by_multilinear = multilinears
+			.iter()
+			.map(|multilinear| match multilinear {
+				SumcheckMultilinear::Transparent { .. } => None,
+				SumcheckMultilinear::Folded {
+					large_field_folded_evals,
+					suffix_eval,
+				} => {
+					let n_evals = large_field_folded_evals.len() << P::LOG_WIDTH;
+					// The evaluations at 0 and 1 of a subcube are interleaved in low-to-high
+					// order, while in high-to-low order those at 1 lie in the upper half.
+					let log_evals_per_subcube = match evaluation_order {
+						EvaluationOrder::LowToHigh => subcube_vars + 1,
+						EvaluationOrder::HighToLow => subcube_vars,
+					};
+					let first_suffix_subcube = n_evals
+						.div_ceil(1 << log_evals_per_subcube)
+						.min(1 << index_vars);
+					Some((first_suffix_subcube, *suffix_eval))
+				}
+			})
+			.collect();
+
+		Ok(Self {
+			evaluation_order,
+			n_vars,
+			tensor_query,
+			empty_query: MultilinearQuery::with_capacity(0),
+			multilinears,
+			evaluators,
+			nontrivial_evaluation_points,
+			eval_point_indices,
+			subcube_vars,
+			subcube_count_by_evaluator,
+			subcube_count_by_multilinear,
+			subcube_count_by_point_and_multilinear,
+			const_suffix_by_multilinear,
+			_composition_marker: PhantomData,
+		})
 	}
 
-	let index_vars = n_vars - 1 - subcube_vars;
-	let packed_accumulators = (0..1 << index_vars)
-		.into_par_iter()
-		.try_fold(
-			|| ParFoldStates::new(&access, n_multilinears, n_round_evals.clone(), subcube_vars),
-			|mut par_fold_states, subcube_index| {
-				let ParFoldStates {
-					multilinear_evals,
-					scratch_space,
-					round_evals,
-				} = &mut par_fold_states;
-
-				for (multilinear, evals, &subcube_count) in
-					izip!(multilinears, multilinear_evals.iter_mut(), &subcube_count_by_multilinear)
+	/// The number of subcubes the round is split into.
+	pub fn n_subcubes(&self) -> usize {
+		1 << self.index_vars()
+	}
+
+	/// Returns a task that evaluates the compositions over a range of subcubes.
+	///
+	/// ## Preconditions
+	///
+	/// * `subcubes` must be a subrange of `0..self.n_subcubes()`
+	pub fn task(
+		&self,
+		subcubes: Range<usize>,
+	) -> impl FnOnce() -> Result<SumcheckPartialEvals<P>, Error> + Send + '_ {
+		move || {
+			let mut par_fold_states = self.new_fold_states();
+			for subcube_index in subcubes {
+				self.process_subcube(&mut par_fold_states, subcube_index)?;
+			}
+			Ok(SumcheckPartialEvals(par_fold_states.round_evals))
+		}
+	}
+
+	/// Sums the partial evaluations of tasks into the round evaluations.
+	///
+	/// The tasks must cover every subcube exactly once.
+	pub fn reduce(
+		&self,
+		partial_evals: impl IntoIterator<Item = SumcheckPartialEvals<P>>,
+	) -> Vec<RoundEvals<F>> {
+		let packed_accumulators =
+			partial_evals
+				.into_iter()
+				.fold(self.zero_partial_evals(), |mut lhs, rhs| {
+					lhs.add_assign(rhs);
+					lhs
+				});
+
+		// Truncation and constant suffix processing are independent per evaluator.
+		(packed_accumulators.0, self.evaluators, self.subcube_count_by_evaluator.as_slice())
+			.into_par_iter()
+			.map(|(packed_round_evals, evaluator, &subcube_count)| {
+				let mut round_evals = packed_round_evals
+					.into_iter()
+					// Truncate subcubes smaller than packing width.
+					.map(|packed_round_eval| {
+						packed_round_eval.iter().take(1 << self.subcube_vars).sum()
+					})
+					.collect::<Vec<F>>();
+
+				let const_eval_suffix = (1 << self.n_vars) - (subcube_count << self.subcube_vars);
+				for (eval_point_index, round_eval) in
+					izip!(self.eval_point_indices.clone(), &mut round_evals)
 				{
-					if subcube_index < subcube_count {
-						access.subcube_evaluations(
-							multilinear,
-							subcube_vars,
-							subcube_index,
-							index_vars,
-							tensor_query,
-							scratch_space.as_deref_mut(),
-							&mut evals.evals_0,
-							&mut evals.evals_1,
-						)?;
+					let is_infinity_point = eval_point_index == 2;
+					*round_eval += evaluator
+						.process_constant_eval_suffix(const_eval_suffix, is_infinity_point);
+				}
+
+				RoundEvals {
+					evals: round_evals,
+					eval_point_indices: evaluator.eval_point_indices(),
+				}
+			})
+			.collect()
+	}
+
+	fn index_vars(&self) -> usize {
+		self.n_vars - 1 - self.subcube_vars
+	}
+
+	fn zero_partial_evals(&self) -> SumcheckPartialEvals<P> {
+		SumcheckPartialEvals(
+			self.evaluators
+				.iter()
+				.map(|evaluator| vec![P::zero(); evaluator.eval_point_indices().len()])
+				.collect(),
+		)
+	}
+
+	fn new_fold_states(&self) -> ParFoldStates<P> {
+		let scratch_space_len = SumcheckMultilinearAccess::<P>::scratch_space_len(
+			&self.evaluation_order,
+			self.subcube_vars,
+		);
+		ParFoldStates::new(
+			scratch_space_len,
+			self.multilinears.len(),
+			self.evaluators
+				.iter()
+				.map(|evaluator| evaluator.eval_point_indices().len()),
+			self.subcube_vars,
+		)
+	}
+
+	fn process_subcube(
+		&self,
+		par_fold_states: &mut ParFoldStates<P>,
+		subcube_index: usize,
+	) -> Result<(), Error> {
+		let Self {
+			evaluation_order,
+			multilinears,
+			evaluators,
+			nontrivial_evaluation_points,
+			eval_point_indices,
+			subcube_vars,
+			subcube_count_by_evaluator,
+			subcube_count_by_multilinear,
+			subcube_count_by_point_and_multilinear,
+			const_suffix_by_multilinear,
+			..
+		} = self;
+		let subcube_vars = *subcube_vars;
+		let index_vars = self.index_vars();
+		let n_multilinears = multilinears.len();
+		let tensor_query = self
+			.tensor_query
+			.unwrap_or_else(|| self.empty_query.to_ref());
+
+		let ParFoldStates {
+			multilinear_evals,
+			scratch_space,
+			round_evals,
+		} = par_fold_states;
+
+		for (multilinear, evals, &subcube_count, &const_suffix) in izip!(
+			*multilinears,
+			multilinear_evals.iter_mut(),
+			subcube_count_by_multilinear,
+			const_suffix_by_multilinear
+		) {
+			if subcube_index >= subcube_count {
+				continue;
+			}
+
+			match const_suffix {
+				Some((first_suffix_subcube, suffix_eval))
+					if subcube_index >= first_suffix_subcube =>
+				{
+					// The constant stays in place until the next fetch, so it is only written
+					// once per run of suffix subcubes.
+					if !evals.holds_const_suffix {
+						evals.evals_0.fill(P::broadcast(suffix_eval));
+						evals.evals_1.fill(P::broadcast(suffix_eval));
+						evals.holds_const_suffix = true;
 					}
 				}
+				_ => {
+					evaluation_order.subcube_evaluations(
+						multilinear,
+						subcube_vars,
+						subcube_index,
+						index_vars,
+						tensor_query,
+						scratch_space.as_deref_mut(),
+						&mut evals.evals_0,
+						&mut evals.evals_1,
+					)?;
+					evals.holds_const_suffix = false;
+				}
+			}
+		}
 
-				// Proceed by evaluation point first to share interpolation work between evaluators.
-				for eval_point_index in eval_point_indices.clone() {
-					// Infinity point requires special evaluation rules
-					let is_infinity_point = eval_point_index == 2;
+		// Proceed by evaluation point first to share interpolation work between evaluators.
+		for eval_point_index in eval_point_indices.clone() {
+			// Infinity point requires special evaluation rules
+			let is_infinity_point = eval_point_index == 2;
+
+			// Multilinears are evaluated at a point t via linear interpolation:
+			//   f(z, xs) = f(0, xs) + z * (f(1, xs) - f(0, xs))
+			// The first three points are treated specially:
+			//   index 0 - z = 0   => f(z, xs) = f(0, xs)
+			//   index 1 - z = 1   => f(z, xs) = f(1, xs)
+			//   index 2 = z = inf => f(inf, xs) = high (f(0, xs) + z * (f(1, xs) - f(0,
+			// xs))) =                                   = f(1, xs) - f(0, xs)
+			//   index 3 and above - remaining finite evaluation points
+			let subcube_counts = &subcube_count_by_point_and_multilinear
+				[eval_point_index - eval_point_indices.start];
+			let evals_z_iter = izip!(multilinear_evals.iter_mut(), subcube_counts).map(
+				|(evals, &subcube_count)| match eval_point_index {
+					// This multilinear is not accessed, return arbitrary slice
+					_ if subcube_index >= subcube_count => evals.evals_0.as_slice(),
+					0 => evals.evals_0.as_slice(),
+					1 => evals.evals_1.as_slice(),
+					2 => {
+						// infinity point
+						izip!(&mut evals.evals_z, &evals.evals_0, &evals.evals_1).for_each(
+							|(eval_z, &eval_0, &eval_1)| {
+								*eval_z = eval_1 - eval_0;
+							},
+						);
 
-					// Multilinears are evaluated at a point t via linear interpolation:
-					//   f(z, xs) = f(0, xs) + z * (f(1, xs) - f(0, xs))
-					// The first three points are treated specially:
-					//   index 0 - z = 0   => f(z, xs) = f(0, xs)
-					//   index 1 - z = 1   => f(z, xs) = f(1, xs)
-					//   index 2 = z = inf => f(inf, xs) = high (f(0, xs) + z * (f(1, xs) - f(0,
-					// xs))) =                                   = f(1, xs) - f(0, xs)
-					//   index 3 and above - remaining finite evaluation points
-					let evals_z_iter =
-						izip!(multilinear_evals.iter_mut(), &subcube_count_by_multilinear).map(
-							|(evals, &subcube_count)| match eval_point_index {
-								// This multilinear is not accessed, return arbitrary slice
-								_ if subcube_index >= subcube_count => evals.evals_0.as_slice(),
-								0 => evals.evals_0.as_slice(),
-								1 => evals.evals_1.as_slice(),
-								2 => {
-									// infinity point
-									izip!(&mut evals.evals_z, &evals.evals_0, &evals.evals_1)
-										.for_each(|(eval_z, &eval_0, &eval_1)| {
-											*eval_z = eval_1 - eval_0;
-										});
-
-									evals.evals_z.as_slice()
-								}
-								3.. => {
-									// Account for the gap occupied by the 0, 1 & infinity point
-									let eval_point =
-										nontrivial_evaluation_points[eval_point_index - 3];
-									let eval_point_broadcast =
-										<PackedSubfield<P, FDomain>>::broadcast(eval_point);
-
-									izip!(&mut evals.evals_z, &evals.evals_0, &evals.evals_1)
-										.for_each(|(eval_z, &eval_0, &eval_1)| {
-											// This is logically the same as calling
-											// `binius_math::univariate::extrapolate_line`, except
-											// that we do not repeat the broadcast of the
-											// subfield element to a packed subfield.
-											*eval_z = P::cast_ext(extrapolate_lines(
-												P::cast_base(eval_0),
-												P::cast_base(eval_1),
-												eval_point_broadcast,
-											));
-										});
-
-									evals.evals_z.as_slice()
-								}
+						evals.evals_z.as_slice()
+					}
+					3.. => {
+						// Account for the gap occupied by the 0, 1 & infinity point
+						let eval_point = nontrivial_evaluation_points[eval_point_index - 3];
+						let eval_point_broadcast =
+							<PackedSubfield<P, FDomain>>::broadcast(eval_point);
+
+						izip!(&mut evals.evals_z, &evals.evals_0, &evals.evals_1).for_each(
+							|(eval_z, &eval_0, &eval_1)| {
+								// This is logically the same as calling
+								// `binius_math::univariate::extrapolate_line`, except
+								// that we do not repeat the broadcast of the
+								// subfield element to a packed subfield.
+								*eval_z = P::cast_ext(extrapolate_lines(
+									P::cast_base(eval_0),
+									P::cast_base(eval_1),
+									eval_point_broadcast,
+								));
 							},
 						);
 
-					let row_len = 1 << subcube_vars.saturating_sub(P::LOG_WIDTH);
-					stackalloc_with_iter(n_multilinears, evals_z_iter, |evals_z| {
-						let evals_z = RowsBatchRef::new(evals_z, row_len);
-
-						for (evaluator, round_evals, &subcube_count) in
-							izip!(evaluators, round_evals.iter_mut(), &subcube_count_by_evaluator)
-						{
-							let eval_point_indices = evaluator.eval_point_indices();
-							if !eval_point_indices.contains(&eval_point_index)
-								|| subcube_index >= subcube_count
-							{
-								continue;
-							}
-
-							round_evals[eval_point_index - eval_point_indices.start] += evaluator
-								.process_subcube_at_eval_point(
-									subcube_vars,
-									subcube_index,
-									is_infinity_point,
-									&evals_z,
-								);
-						}
-					});
-				}
+						evals.evals_z.as_slice()
+					}
+				},
+			);
 
-				Ok(par_fold_states)
-			},
-		)
-		.map(|states: Result<ParFoldStates<P>, Error>| -> Result<_, Error> {
-			Ok(states?.round_evals)
-		})
-		// Simply sum up the fold partitions.
-		.try_reduce(
-			|| {
-				evaluators
-					.iter()
-					.map(|evaluator| vec![P::zero(); evaluator.eval_point_indices().len()])
-					.collect()
-			},
-			|lhs, rhs| {
-				let sum = izip!(lhs, rhs)
-					.map(|(mut lhs_vals, rhs_vals)| {
-						for (lhs_val, rhs_val) in lhs_vals.iter_mut().zip(rhs_vals) {
-							*lhs_val += rhs_val;
-						}
-						lhs_vals
-					})
-					.collect();
-				Ok(sum)
-			},
-		)?;
+			let row_len = 1 << subcube_vars.saturating_sub(P::LOG_WIDTH);
+			stackalloc_with_iter(n_multilinears, evals_z_iter, |evals_z| {
+				let evals_z = RowsBatchRef::new(evals_z, row_len);
 
-	let round_evals = izip!(packed_accumulators, evaluators, subcube_count_by_evaluator)
-		.map(|(packed_round_evals, evaluator, subcube_count)| {
-			let mut round_evals = packed_round_evals
-				.into_iter()
-				// Truncate subcubes smaller than packing width.
-				.map(|packed_round_eval| packed_round_eval.iter().take(1 << subcube_vars).sum())
-				.collect::<Vec<F>>();
-
-			let const_eval_suffix = (1 << n_vars) - (subcube_count << subcube_vars);
-			for (eval_point_index, round_eval) in
-				izip!(eval_point_indices.clone(), &mut round_evals)
-			{
-				let is_infinity_point = eval_point_index == 2;
-				*round_eval +=
-					evaluator.process_constant_eval_suffix(const_eval_suffix, is_infinity_point);
-			}
+				for (evaluator, round_evals, &subcube_count) in
+					izip!(*evaluators, round_evals.iter_mut(), subcube_count_by_evaluator)
+				{
+					let eval_point_indices = evaluator.eval_point_indices();
+					if !eval_point_indices.contains(&eval_point_index)
+						|| subcube_index >= subcube_count
+					{
+						continue;
+					}
 
-			RoundEvals(round_evals)
-		})
-		.collect();
+					round_evals[eval_point_index - eval_point_indices.start] += evaluator
+						.process_subcube_at_eval_point(
+							subcube_vars,
+							subcube_index,
+							is_infinity_point,
+							&evals_z,
+						);
+				}
+			});
+		}
 
-	Ok(round_evals)
+		Ok(())
+	}
 }
 
 // Evals of a single multilinear over a subcube, at 0/1 and some interpolated point.
@@ -354,6 +569,8 @@ struct MultilinearEvals<P: PackedField> {
 	evals_0: Vec<P>,
 	evals_1: Vec<P>,
 	evals_z: Vec<P>,
+	// Whether `evals_0` and `evals_1` are filled with the constant suffix value.
+	holds_const_suffix: bool,
 }
 
 impl<P: PackedField> MultilinearEvals<P> {
@@ -363,6 +580,7 @@ impl<P: PackedField> MultilinearEvals<P> {
 			evals_0: zeroed_vec(len),
 			evals_1: zeroed_vec(len),
 			evals_z: zeroed_vec(len),
+			holds_const_suffix: false,
 		}
 	}
 }
@@ -385,7 +603,7 @@ struct ParFoldStates<P: PackedField> {
 
 impl<P: PackedField> ParFoldStates<P> {
 	fn new(
-		access: &impl SumcheckMultilinearAccess<P>,
+		scratch_space_len: Option<usize>,
 		n_multilinears: usize,
 		n_round_evals: impl Iterator<Item = usize>,
 		subcube_vars: usize,
@@ -394,9 +612,7 @@ impl<P: PackedField> ParFoldStates<P> {
 			multilinear_evals: (0..n_multilinears)
 				.map(|_| MultilinearEvals::new(subcube_vars))
 				.collect(),
-			scratch_space: access
-				.scratch_space_len(subcube_vars)
-				.map(|len| zeroed_vec(len)),
+			scratch_space: scratch_space_len.map(|len| zeroed_vec(len)),
 			round_evals: n_round_evals
 				.map(|n_round_evals| zeroed_vec(n_round_evals))
 				.collect(),
@@ -418,12 +634,14 @@ impl<P: PackedField> SumcheckMultilinearAccess<P> for LowToHighAccess {
 		multilinear: &SumcheckMultilinear<P, M>,
 		subcube_vars: usize,
 		subcube_index: usize,
-		_index_vars: usize,
+		index_vars: usize,
 		tensor_query: MultilinearQueryRef<P>,
 		scratch_space: Option<&mut [P]>,
 		evals_0: &mut [P],
 		evals_1: &mut [P],
 	) -> Result<(), Error> {
+		debug_assert!(subcube_index < 1 << index_vars, "subcube_index out of bounds");
+
 		let Some(scratch_space) = scratch_space else {
 			bail!(Error::NoScratchSpace);
 		};
@@ -522,6 +740,10 @@ impl<P: PackedField> SumcheckMultilinearAccess<P> for HighToLowAccess {
 		evals_0: &mut [P],
 		evals_1: &mut [P],
 	) -> Result<(), Error> {
+		// The subcube index of the "1" half sets the bit above `index_vars`, which would alias
+		// for an out of bounds index.
+		debug_assert!(subcube_index < 1 << index_vars, "subcube_index out of bounds");
+
 		if evals_0.len() != 1 << subcube_vars.saturating_sub(P::LOG_WIDTH)
 			|| evals_1.len() != 1 << subcube_vars.saturating_sub(P::LOG_WIDTH)
 		{
@@ -603,3 +825,51 @@ impl<P: PackedField> SumcheckMultilinearAccess<P> for HighToLowAccess {
 		Ok(())
 	}
 }
+
+impl<P: PackedField> SumcheckMultilinearAccess<P> for EvaluationOrder {
+	fn scratch_space_len(&self, subcube_vars: usize) -> Option<usize> {
+		match self {
+			Self::LowToHigh => {
+				SumcheckMultilinearAccess::<P>::scratch_space_len(&LowToHighAccess, subcube_vars)
+			}
+			Self::HighToLow => {
+				SumcheckMultilinearAccess::<P>::scratch_space_len(&HighToLowAccess, subcube_vars)
+			}
+		}
+	}
+
+	fn subcube_evaluations<M: MultilinearPoly<P>>(
+		&self,
+		multilinear: &SumcheckMultilinear<P, M>,
+		subcube_vars: usize,
+		subcube_index: usize,
+		index_vars: usize,
+		tensor_query: MultilinearQueryRef<P>,
+		scratch_space: Option<&mut [P]>,
+		evals_0: &mut [P],
+		evals_1: &mut [P],
+	) -> Result<(), Error> {
+		match self {
+			Self::LowToHigh => LowToHighAccess.subcube_evaluations(
+				multilinear,
+				subcube_vars,
+				subcube_index,
+				index_vars,
+				tensor_query,
+				scratch_space,
+				evals_0,
+				evals_1,
+			),
+			Self::HighToLow => HighToLowAccess.subcube_evaluations(
+				multilinear,
+				subcube_vars,
+				subcube_index,
+				index_vars,
+				tensor_query,
+				scratch_space,
+				evals_0,
+				evals_1,
+			),
+		}
+	}
+}
@@ -0,0 +1,24 @@
+// Copyright 2025 Irreducible Inc.
+
+use digest::{generic_array::GenericArray, typenum::U32};
+
+use crate::{CompressionFunction, PseudoCompressionFunction};
+
+/// A two-to-one compression function for 32-byte digests based on BLAKE3.
+///
+/// The compression is the BLAKE3 hash of the concatenated digests. It can compress the digests of
+/// any 32-byte hash function, such as [`Groestl256`](crate::groestl::Groestl256), so it can
+/// replace the Merkle tree node compression independently of the leaf hash.
+#[derive(Debug, Default, Clone)]
+pub struct Blake3ByteCompression;
+
+impl PseudoCompressionFunction<GenericArray<u8, U32>, 2> for Blake3ByteCompression {
+	fn compress(&self, input: [GenericArray<u8, U32>; 2]) -> GenericArray<u8, U32> {
+		let mut hasher = blake3::Hasher::new();
+		hasher.update(&input[0]);
+		hasher.update(&input[1]);
+		(*hasher.finalize().as_bytes()).into()
+	}
+}
+
+impl CompressionFunction<GenericArray<u8, U32>, 2> for Blake3ByteCompression {}
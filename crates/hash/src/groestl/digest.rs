@@ -29,6 +29,11 @@ pub struct GroestlShortVarCore<G: GroestlShortInternal> {
 pub type GroestlShortCore<OutSize> =
 	CtVariableCoreWrapper<GroestlShortVarCore<GroestlShortImpl>, OutSize>;
 /// Groestl-256 hasher state.
+///
+/// The hasher is streaming: [`Digest::update`](digest::Digest::update) buffers the input and
+/// compresses it block by block, so large inputs can be hashed in chunks without holding them in
+/// memory. [`Digest::finalize`](digest::Digest::finalize) pads the last block and returns the same
+/// digest as hashing the whole input at once.
 pub type Groestl256 = CoreWrapper<GroestlShortCore<U32>>;
 
 impl<G: GroestlShortInternal> HashMarker for GroestlShortVarCore<G> {}
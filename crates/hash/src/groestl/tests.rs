@@ -15,4 +15,24 @@ proptest! {
 			groestl_crypto::Groestl256::digest(&input)
 		);
 	}
+
+	#[test]
+	fn test_groestl_streaming_vs_one_shot(
+		input in prop::collection::vec(any::<u8>(), 0..=2048),
+		split_points in prop::collection::vec(any::<prop::sample::Index>(), 0..8),
+	) {
+		let mut split_points = split_points
+			.iter()
+			.map(|index| index.index(input.len() + 1))
+			.collect::<Vec<_>>();
+		split_points.sort_unstable();
+
+		let mut hasher = Groestl256::new();
+		let mut start = 0;
+		for end in split_points.into_iter().chain([input.len()]) {
+			hasher.update(&input[start..end]);
+			start = end;
+		}
+		assert_eq!(hasher.finalize(), Groestl256::digest(&input));
+	}
 }
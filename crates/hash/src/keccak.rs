@@ -0,0 +1,24 @@
+// Copyright 2025 Irreducible Inc.
+
+use digest::{Digest, Output};
+pub use sha3::Keccak256;
+
+use crate::{CompressionFunction, PseudoCompressionFunction};
+
+/// A two-to-one compression function for Keccak-256 digests.
+///
+/// The compression is the Keccak-256 hash of the concatenated digests, which matches the node
+/// hashing of Merkle trees commonly verified on-chain.
+#[derive(Debug, Default, Clone)]
+pub struct Keccak256ByteCompression;
+
+impl PseudoCompressionFunction<Output<Keccak256>, 2> for Keccak256ByteCompression {
+	fn compress(&self, input: [Output<Keccak256>; 2]) -> Output<Keccak256> {
+		Keccak256::new()
+			.chain_update(input[0])
+			.chain_update(input[1])
+			.finalize()
+	}
+}
+
+impl CompressionFunction<Output<Keccak256>, 2> for Keccak256ByteCompression {}
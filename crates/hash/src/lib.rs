@@ -7,6 +7,9 @@
 //! default hash function because it internally makes use of the 8-bit Rijndael binary field, and
 //! so can be arithmetized efficiently with a Binius constraint system.
 //!
+//! Keccak-256 is supported as an alternative hash function for verifiers that have cheap access to
+//! it, such as on-chain verifiers.
+//!
 //! This crate also provides an implementation of [Vision Mark-32], a cryptographic sponge function
 //! designed for efficient Binius arithmetization.
 //!
@@ -18,8 +21,10 @@
 	feature(stdarch_x86_avx512)
 )]
 
+pub mod blake3;
 pub mod compression;
 pub mod groestl;
+pub mod keccak;
 pub mod multi_digest;
 pub mod permutation;
 mod serialization;
@@ -0,0 +1,347 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Filling table witnesses from Arrow columnar data.
+
+use arrow_array::{
+	cast::AsArray,
+	types::{UInt16Type, UInt32Type, UInt64Type, UInt8Type},
+	Array, RecordBatch,
+};
+use arrow_schema::DataType;
+use binius_field::{PackedExtension, PackedField, TowerField};
+
+use super::{
+	column::ColumnId,
+	error::Error,
+	table::TableId,
+	types::{B1, B128, B16, B32, B64, B8},
+	witness::{WitnessColViewMut, WitnessIndex},
+};
+
+impl<P> WitnessIndex<'_, '_, P>
+where
+	P: PackedField<Scalar: TowerField>
+		+ PackedExtension<B1>
+		+ PackedExtension<B8>
+		+ PackedExtension<B16>
+		+ PackedExtension<B32>
+		+ PackedExtension<B64>
+		+ PackedExtension<B128>,
+{
+	/// Initializes a table witness with one event per row of an Arrow record batch and copies
+	/// the mapped Arrow columns into it.
+	///
+	/// Each entry of `column_mapping` names an Arrow column and the table column it fills. Columns
+	/// with one value per row are filled from a primitive Arrow column, and columns with
+	/// `VALUES_PER_ROW > 1` from a fixed-size list column of that length. Arrow columns may not
+	/// contain nulls. Boolean columns are converted to [`B1`] and unsigned integer columns to the
+	/// binary field of the same bit width, so their values must fit in the tower height of the
+	/// target column. As with
+	/// [`TableWitnessIndex::fill_sequential`](super::TableWitnessIndex::fill_sequential), rows past
+	/// the end of the batch are padded up to the table capacity with copies of the last row. Table
+	/// columns that are not mapped are left zeroed for the caller to fill. If any column fails to
+	/// convert, the table is left uninitialized.
+	pub fn fill_table_from_arrow(
+		&mut self,
+		table_id: TableId,
+		record_batch: &RecordBatch,
+		column_mapping: &[(&str, ColumnId)],
+	) -> Result<(), Error> {
+		let n_events = record_batch.num_rows();
+		self.init_and_try_fill_table(table_id, n_events, |table_witness| {
+			let table = table_witness.table();
+			let capacity = table_witness.capacity();
+			let segment = table_witness.full_segment();
+
+			for &(name, column_id) in column_mapping {
+				if column_id.table_id != table_id {
+					return Err(Error::TableMismatch {
+						column_table_id: column_id.table_id,
+						witness_table_id: table_id,
+					});
+				}
+				let array =
+					record_batch
+						.column_by_name(name)
+						.ok_or_else(|| Error::MissingArrowColumn {
+							name: name.to_string(),
+						})?;
+
+				// Columns with multiple values per row are read from fixed-size lists, flattened
+				// into the list's child values.
+				let (values, values_per_row) = match array.data_type() {
+					DataType::FixedSizeList(_, len) => {
+						let list = array.as_fixed_size_list();
+						let len = *len as usize;
+						let values = list
+							.values()
+							.slice(list.value_offset(0) as usize, n_events * len);
+						(values, len)
+					}
+					_ => (array.clone(), 1),
+				};
+
+				let shape = table.columns[column_id.table_index].shape;
+				if values_per_row != 1 << shape.log_values_per_row {
+					return Err(Error::IncorrectArrowValuesPerRow {
+						name: name.to_string(),
+						expected: 1 << shape.log_values_per_row,
+						actual: values_per_row,
+					});
+				}
+				if array.logical_null_count() != 0 || values.logical_null_count() != 0 {
+					return Err(Error::ArrowColumnHasNulls {
+						name: name.to_string(),
+					});
+				}
+
+				let n_padded = capacity * values_per_row;
+				let mut col = segment.get_dyn_mut(column_id.table_index)?;
+				let col = &mut *col;
+				match values.data_type() {
+					DataType::Boolean => {
+						let values = values.as_boolean();
+						set_padded(col, n_events, values_per_row, n_padded, |i| {
+							B1::from(values.value(i))
+						})?
+					}
+					DataType::UInt8 => {
+						let values = values.as_primitive::<UInt8Type>();
+						set_padded(col, n_events, values_per_row, n_padded, |i| {
+							B8::new(values.value(i))
+						})?
+					}
+					DataType::UInt16 => {
+						let values = values.as_primitive::<UInt16Type>();
+						set_padded(col, n_events, values_per_row, n_padded, |i| {
+							B16::new(values.value(i))
+						})?
+					}
+					DataType::UInt32 => {
+						let values = values.as_primitive::<UInt32Type>();
+						set_padded(col, n_events, values_per_row, n_padded, |i| {
+							B32::new(values.value(i))
+						})?
+					}
+					DataType::UInt64 => {
+						let values = values.as_primitive::<UInt64Type>();
+						set_padded(col, n_events, values_per_row, n_padded, |i| {
+							B64::new(values.value(i))
+						})?
+					}
+					data_type => {
+						return Err(Error::UnsupportedArrowType {
+							name: name.to_string(),
+							data_type: data_type.to_string(),
+						})
+					}
+				}
+			}
+			Ok(())
+		})
+	}
+}
+
+/// Writes `n_events * values_per_row` values to a column, then pads it to `n_padded` values by
+/// repeating the values of the last event.
+fn set_padded<F, FSub>(
+	col: &mut dyn WitnessColViewMut<F>,
+	n_events: usize,
+	values_per_row: usize,
+	n_padded: usize,
+	get: impl Fn(usize) -> FSub,
+) -> Result<(), Error>
+where
+	F: From<FSub>,
+{
+	let n_values = n_events * values_per_row;
+	for i in 0..n_padded {
+		let src = if i < n_values {
+			i
+		} else {
+			n_values - values_per_row + i % values_per_row
+		};
+		col.set(i, get(src).into())?;
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use std::sync::Arc;
+
+	use arrow_array::{
+		ArrayRef, BooleanArray, FixedSizeListArray, UInt16Array, UInt32Array, UInt8Array,
+	};
+	use assert_matches::assert_matches;
+	use binius_field::{
+		arch::OptimalUnderlier128b, as_packed_field::PackedType, packed::set_packed_slice,
+	};
+	use bumpalo::Bump;
+
+	use super::*;
+	use crate::builder::{test_utils::ClosureFiller, ConstraintSystem};
+
+	type P = PackedType<OptimalUnderlier128b, B128>;
+
+	#[test]
+	fn test_fill_table_from_arrow_matches_manual_fill() {
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("arrow");
+		let table_id = table.id();
+		let values = table.add_committed::<B32, 1>("values");
+		let bytes = table.add_committed::<B8, 2>("bytes");
+		let flags = table.add_committed::<B1, 1>("flags");
+
+		let events = (0..5u32).collect::<Vec<_>>();
+		let value_of = |event: u32| event * 0x01010101;
+		let bytes_of = |event: u32| [event as u8, 0xf0 | event as u8];
+		let flag_of = |event: u32| event % 2 == 1;
+
+		let record_batch = RecordBatch::try_from_iter([
+			(
+				"values",
+				Arc::new(UInt32Array::from_iter_values(events.iter().map(|&e| value_of(e))))
+					as ArrayRef,
+			),
+			// The byte column holds wider integers whose values fit in the B8 column.
+			(
+				"bytes",
+				Arc::new(FixedSizeListArray::from_iter_primitive::<UInt16Type, _, _>(
+					events
+						.iter()
+						.map(|&e| Some(bytes_of(e).map(|byte| Some(u16::from(byte))))),
+					2,
+				)),
+			),
+			("flags", Arc::new(BooleanArray::from_iter(events.iter().map(|&e| Some(flag_of(e)))))),
+		])
+		.unwrap();
+
+		let allocator = Bump::new();
+		let mut arrow_witness = WitnessIndex::<P>::new(&cs, &allocator);
+		arrow_witness
+			.fill_table_from_arrow(
+				table_id,
+				&record_batch,
+				&[
+					("values", values.id()),
+					("bytes", bytes.id()),
+					("flags", flags.id()),
+				],
+			)
+			.unwrap();
+
+		let mut manual_witness = WitnessIndex::<P>::new(&cs, &allocator);
+		manual_witness
+			.fill_table_sequential(
+				&ClosureFiller::new(table_id, |events, index| {
+					let mut values = index.get_mut_as::<u32, _, 1>(values)?;
+					let mut bytes = index.get_mut_as::<u8, _, 2>(bytes)?;
+					let mut flags = index.get_mut(flags)?;
+					for (i, &&event) in events.iter().enumerate() {
+						values[i] = value_of(event);
+						bytes[2 * i..2 * i + 2].copy_from_slice(&bytes_of(event));
+						set_packed_slice(&mut flags, i, B1::from(flag_of(event)));
+					}
+					Ok(())
+				}),
+				&events,
+			)
+			.unwrap();
+
+		let arrow_segment = arrow_witness.get_table(table_id).unwrap().full_segment();
+		let manual_segment = manual_witness.get_table(table_id).unwrap().full_segment();
+		for col in [values.id(), bytes.id(), flags.id()] {
+			let arrow_col = arrow_segment.get_dyn(col.table_index).unwrap();
+			let manual_col = manual_segment.get_dyn(col.table_index).unwrap();
+			assert_eq!(arrow_col.size(), manual_col.size());
+			for i in 0..arrow_col.size() {
+				assert_eq!(arrow_col.get(i), manual_col.get(i));
+			}
+		}
+	}
+
+	#[test]
+	fn test_fill_table_from_arrow_errors() {
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("arrow");
+		let table_id = table.id();
+		let bytes = table.add_committed::<B8, 1>("bytes");
+
+		let allocator = Bump::new();
+		let fill = |record_batch: &RecordBatch, name: &str| {
+			WitnessIndex::<P>::new(&cs, &allocator).fill_table_from_arrow(
+				table_id,
+				record_batch,
+				&[(name, bytes.id())],
+			)
+		};
+
+		let too_big = RecordBatch::try_from_iter([(
+			"bytes",
+			Arc::new(UInt16Array::from(vec![1, 0x100])) as ArrayRef,
+		)])
+		.unwrap();
+		assert_matches!(fill(&too_big, "bytes"), Err(Error::FieldElementTooBig));
+		assert_matches!(fill(&too_big, "missing"), Err(Error::MissingArrowColumn { .. }));
+
+		let pairs = RecordBatch::try_from_iter([(
+			"bytes",
+			Arc::new(FixedSizeListArray::from_iter_primitive::<UInt8Type, _, _>(
+				[Some([Some(1), Some(2)])],
+				2,
+			)) as ArrayRef,
+		)])
+		.unwrap();
+		assert_matches!(
+			fill(&pairs, "bytes"),
+			Err(Error::IncorrectArrowValuesPerRow {
+				expected: 1,
+				actual: 2,
+				..
+			})
+		);
+
+		let with_nulls = RecordBatch::try_from_iter([(
+			"bytes",
+			Arc::new(UInt8Array::from(vec![Some(1), None])) as ArrayRef,
+		)])
+		.unwrap();
+		assert_matches!(fill(&with_nulls, "bytes"), Err(Error::ArrowColumnHasNulls { .. }));
+	}
+
+	#[test]
+	fn test_fill_table_from_arrow_failure_leaves_table_uninitialized() {
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("arrow");
+		let table_id = table.id();
+		let words = table.add_committed::<B32, 1>("words");
+		let bytes = table.add_committed::<B8, 1>("bytes");
+
+		let record_batch = |byte: u16| {
+			RecordBatch::try_from_iter([
+				("words", Arc::new(UInt32Array::from(vec![1, 2, 3])) as ArrayRef),
+				("bytes", Arc::new(UInt16Array::from(vec![4, 5, byte])) as ArrayRef),
+			])
+			.unwrap()
+		};
+		let column_mapping = [("words", words.id()), ("bytes", bytes.id())];
+
+		let allocator = Bump::new();
+		let mut witness = WitnessIndex::<P>::new(&cs, &allocator);
+
+		// The words column is copied before the bytes column fails to convert.
+		assert_matches!(
+			witness.fill_table_from_arrow(table_id, &record_batch(0x100), &column_mapping),
+			Err(Error::FieldElementTooBig)
+		);
+		assert!(witness.get_table(table_id).is_none());
+		assert_eq!(witness.table_sizes(), vec![0]);
+
+		witness
+			.fill_table_from_arrow(table_id, &record_batch(6), &column_mapping)
+			.unwrap();
+		assert_eq!(witness.table_sizes(), vec![3]);
+	}
+}
@@ -1,24 +1,104 @@
 // Copyright 2025 Irreducible Inc.
 
 use binius_core::constraint_system::channel::{ChannelId, FlushDirection};
+use binius_field::TowerField;
 
-use super::column::ColumnIndex;
+use super::{
+	column::{ColumnIndex, ColumnInfo},
+	error::Error,
+};
 use crate::builder::{Col, B1};
 
 /// A flushing rule within a table.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Flush {
 	pub column_indices: Vec<ColumnIndex>,
 	pub channel_id: ChannelId,
 	pub direction: FlushDirection,
 	/// The number of times the values are flushed to the channel.
-	pub multiplicity: u32,
+	pub multiplicity: MultiplicitySource,
 	/// Selector columns that determine which row events are flushed
 	///
 	/// The referenced selector columns must hold 1-bit values.
 	pub selectors: Vec<ColumnIndex>,
 }
 
+/// The number of times each row of a flush is flushed to the channel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MultiplicitySource {
+	/// Every row is flushed the same number of times.
+	Constant(u32),
+	/// Every row is flushed the number of times read from a witness column.
+	///
+	/// The multiplicity is stored in little-endian binary across 1-bit columns.
+	Column(Vec<ColumnIndex>),
+}
+
+impl Flush {
+	/// Creates a flush from the flushed column indices and the user-facing [`FlushOpts`].
+	///
+	/// The selector and multiplicity columns are resolved to their column indices within the
+	/// table whose columns are given by `columns`.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::MissingColumn`] if a selector or multiplicity column is not in the table.
+	/// * [`Error::FlushSelectorShape`] if a selector or multiplicity column does not hold 1-bit
+	///   values with the same number of values per row as the flushed columns.
+	/// * [`Error::FlushMultiplicityConflict`] if `opts.multiplicity_column` is set and
+	///   `opts.multiplicity` is not 1.
+	pub fn from_opts<F: TowerField>(
+		columns: &[ColumnInfo<F>],
+		column_indices: Vec<ColumnIndex>,
+		channel_id: ChannelId,
+		direction: FlushDirection,
+		opts: FlushOpts,
+	) -> Result<Self, Error> {
+		let resolve = |col: &Col<B1>| -> Result<ColumnIndex, Error> {
+			let info = columns
+				.get(col.table_index)
+				.filter(|info| info.id == col.id())
+				.ok_or(Error::MissingColumn(col.id()))?;
+			let log_values_per_row = column_indices
+				.first()
+				.and_then(|&index| columns.get(index))
+				.map_or(info.shape.log_values_per_row, |flushed| flushed.shape.log_values_per_row);
+			if info.shape.tower_height != 0 || info.shape.log_values_per_row != log_values_per_row {
+				return Err(Error::FlushSelectorShape {
+					column_id: info.id,
+					shape: info.shape,
+					log_values_per_row,
+				});
+			}
+			Ok(col.table_index)
+		};
+
+		let selectors = opts
+			.selectors
+			.iter()
+			.map(resolve)
+			.collect::<Result<_, _>>()?;
+		let multiplicity = match opts.multiplicity_column {
+			Some(bits) => {
+				if opts.multiplicity != 1 {
+					return Err(Error::FlushMultiplicityConflict {
+						multiplicity: opts.multiplicity,
+					});
+				}
+				MultiplicitySource::Column(bits.iter().map(resolve).collect::<Result<_, _>>()?)
+			}
+			None => MultiplicitySource::Constant(opts.multiplicity),
+		};
+		Ok(Self {
+			column_indices,
+			channel_id,
+			direction,
+			multiplicity,
+			selectors,
+		})
+	}
+}
+
 /// Options for a channel flush.
 #[derive(Debug)]
 pub struct FlushOpts {
@@ -29,7 +109,17 @@ pub struct FlushOpts {
 	/// The referenced selector columns must hold 1-bit values and contain only zeros after the
 	/// index that is the height of the table. If the selectors is empty, all values up to the
 	/// table height are flushed.
+	///
+	/// Columns added with [`TableBuilder::add_committed_zero_padded`] satisfy this requirement by
+	/// construction.
+	///
+	/// [`TableBuilder::add_committed_zero_padded`]: super::TableBuilder::add_committed_zero_padded
 	pub selectors: Vec<Col<B1>>,
+	/// Columns holding a per-row multiplicity, overriding the constant `multiplicity`.
+	///
+	/// The multiplicity of each row is read in little-endian binary from the given 1-bit columns,
+	/// so `n` columns support multiplicities up to $2^n - 1$.
+	pub multiplicity_column: Option<Vec<Col<B1>>>,
 }
 
 impl Default for FlushOpts {
@@ -37,6 +127,7 @@ impl Default for FlushOpts {
 		Self {
 			multiplicity: 1,
 			selectors: vec![],
+			multiplicity_column: None,
 		}
 	}
 }
@@ -46,3 +137,108 @@ impl Default for FlushOpts {
 pub struct Channel {
 	pub name: String,
 }
+
+/// A difference between the multisets of values pushed to and pulled from a channel.
+///
+/// Returned by [`ConstraintSystem::debug_channel_balances`] to help locate the witness rows that
+/// unbalance a channel.
+///
+/// [`ConstraintSystem::debug_channel_balances`]: super::ConstraintSystem::debug_channel_balances
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultisetImbalance<F> {
+	/// The number of distinct tuples whose push and pull counts differ.
+	pub n_unbalanced: usize,
+	/// The first unbalanced tuple encountered while replaying the flushes.
+	pub sample: Vec<F>,
+	/// The net multiplicity of the sample tuple, counting pushes as positive and pulls as
+	/// negative.
+	pub net_multiplicity: i64,
+}
+
+#[cfg(test)]
+mod tests {
+	use assert_matches::assert_matches;
+
+	use super::*;
+	use crate::builder::{column::ColumnId, Table, TableBuilder, B128, B32, B8};
+
+	#[test]
+	fn test_from_opts_resolves_selectors() {
+		let mut table = Table::<B128>::new(0, "table");
+		let mut tb = TableBuilder::new(&mut table);
+		let col = tb.add_committed::<B32, 1>("col");
+		let selector = tb.add_committed::<B1, 1>("selector");
+		let bit = tb.add_committed::<B1, 1>("bit");
+
+		let opts = FlushOpts {
+			multiplicity: 1,
+			selectors: vec![selector],
+			multiplicity_column: Some(vec![bit]),
+		};
+		let flush =
+			Flush::from_opts(&table.columns, vec![col.table_index], 3, FlushDirection::Push, opts)
+				.unwrap();
+		assert_eq!(flush.selectors, vec![selector.table_index]);
+		assert_eq!(flush.multiplicity, MultiplicitySource::Column(vec![bit.table_index]));
+	}
+
+	#[test]
+	fn test_from_opts_rejects_invalid_selectors() {
+		let mut table = Table::<B128>::new(0, "table");
+		let mut tb = TableBuilder::new(&mut table);
+		let col = tb.add_committed::<B32, 1>("col");
+		let wide = tb.add_committed::<B8, 1>("wide");
+		let packed = tb.add_committed::<B1, 32>("packed");
+		let selector = tb.add_committed::<B1, 1>("selector");
+
+		let from_opts = |selectors: Vec<Col<B1>>, multiplicity_column: Option<Vec<Col<B1>>>| {
+			let opts = FlushOpts {
+				multiplicity: 1,
+				selectors,
+				multiplicity_column,
+			};
+			Flush::from_opts(&table.columns, vec![col.table_index], 3, FlushDirection::Pull, opts)
+		};
+
+		// Handles whose type does not match the column definition.
+		let wide_as_b1 = Col::<B1>::new(wide.id(), 0);
+		let packed_as_b1 = Col::<B1>::new(packed.id(), 0);
+		for invalid in [wide_as_b1, packed_as_b1] {
+			assert_matches!(
+				from_opts(vec![invalid], None),
+				Err(Error::FlushSelectorShape { column_id, .. }) if column_id == invalid.id()
+			);
+			assert_matches!(
+				from_opts(vec![selector], Some(vec![invalid])),
+				Err(Error::FlushSelectorShape { column_id, .. }) if column_id == invalid.id()
+			);
+		}
+
+		let missing_id = ColumnId {
+			table_id: 0,
+			table_index: 99,
+		};
+		assert_matches!(
+			from_opts(vec![Col::new(missing_id, 0)], None),
+			Err(Error::MissingColumn(id)) if id == missing_id
+		);
+		let foreign_id = ColumnId {
+			table_id: 1,
+			table_index: selector.table_index,
+		};
+		assert_matches!(
+			from_opts(vec![Col::new(foreign_id, 0)], None),
+			Err(Error::MissingColumn(id)) if id == foreign_id
+		);
+
+		let opts = FlushOpts {
+			multiplicity: 2,
+			selectors: vec![],
+			multiplicity_column: Some(vec![selector]),
+		};
+		assert_matches!(
+			Flush::from_opts(&table.columns, vec![col.table_index], 3, FlushDirection::Pull, opts),
+			Err(Error::FlushMultiplicityConflict { multiplicity: 2 })
+		);
+	}
+}
@@ -90,6 +90,8 @@ pub struct ColumnInfo<F: TowerField = B128> {
 	pub shape: ColumnShape,
 	/// Whether the column is constrained to be non-zero.
 	pub is_nonzero: bool,
+	/// Whether the column is constrained to be zero past the table height.
+	pub is_zero_padded: bool,
 }
 
 /// The shape of each cell in a column.
@@ -112,7 +114,7 @@ impl ColumnShape {
 ///
 /// IDs are assigned when columns are added to the constraint system and remain stable when more
 /// columns are added.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ColumnId {
 	pub table_id: TableId,
 	pub table_index: ColumnIndex,
@@ -155,6 +157,22 @@ pub enum ColumnDef<F: TowerField = B128> {
 		cols: Vec<ColumnIndex>,
 		expr: ArithCircuit<F>,
 	},
+	/// A computed column whose expression also depends on a column of another table.
+	///
+	/// The expression variables are the table columns in `cols` followed by `foreign_col`. The
+	/// two tables must have the same size in every statement.
+	ComputedCross {
+		cols: Vec<ColumnIndex>,
+		foreign_col: ColumnId,
+		expr: ArithCircuit<F>,
+	},
+	/// A computed column that is never materialized.
+	///
+	/// The expression is over the partition columns and is inlined into every zero constraint
+	/// that references the column.
+	ComputedVirtual {
+		expr: ArithCircuit<F>,
+	},
 	Constant {
 		poly: Arc<dyn MultivariatePoly<F>>,
 		data: Vec<F>,
@@ -1,5 +1,7 @@
 // Copyright 2025 Irreducible Inc.
 
+use std::collections::HashMap;
+
 pub use binius_core::constraint_system::channel::{
 	Boundary, Flush as CompiledFlush, FlushDirection,
 };
@@ -12,19 +14,19 @@ use binius_core::{
 	oracle::{Constraint, ConstraintPredicate, ConstraintSet, MultilinearOracleSet, OracleId},
 	transparent::step_down::StepDown,
 };
-use binius_field::{PackedField, TowerField};
+use binius_field::{PackedExtension, PackedField, TowerField};
 use binius_math::{ArithCircuit, LinearNormalForm};
 use binius_utils::checked_arithmetics::log2_strict_usize;
 use bumpalo::Bump;
 use itertools::chain;
 
 use super::{
-	channel::{Channel, Flush},
-	column::{ColumnDef, ColumnInfo},
+	channel::{Channel, Flush, MultiplicitySource, MultisetImbalance},
+	column::{ColumnDef, ColumnId, ColumnIndex, ColumnInfo},
 	error::Error,
 	statement::Statement,
 	table::{self, TablePartition},
-	types::B128,
+	types::{B1, B128, B16, B32, B64, B8},
 	witness::WitnessIndex,
 	Table, TableBuilder, TableSizeSpec, ZeroConstraint,
 };
@@ -89,6 +91,9 @@ impl<F: TowerField> std::fmt::Display for ConstraintSystem<F> {
 			}
 
 			for col in table.columns.iter() {
+				if matches!(col.col, ColumnDef::ComputedVirtual { .. }) {
+					continue;
+				}
 				let name = col.name.clone();
 				let log_values_per_row = col.shape.log_values_per_row;
 				let field = match col.shape.tower_height {
@@ -134,6 +139,10 @@ impl<F: TowerField> ConstraintSystem<F> {
 		Self::default()
 	}
 
+	/// Adds a table to the constraint system.
+	///
+	/// Every table is guarded by its size in the [`Statement`]: a table with a size of zero is
+	/// inactive, so optional tables need no separate activation flag.
 	pub fn add_table(&mut self, name: impl ToString) -> TableBuilder<'_, F> {
 		let id = self.tables.len();
 		self.tables.push(Table::new(id, name.to_string()));
@@ -148,6 +157,41 @@ impl<F: TowerField> ConstraintSystem<F> {
 		id
 	}
 
+	/// Sets the tower levels of committed columns, as returned by
+	/// [`WitnessIndex::optimize_tower_levels`].
+	///
+	/// A column's tower level can only be lowered, since the values of a smaller field are
+	/// embedded in the larger one and so still satisfy all constraints over the column.
+	///
+	/// The tower levels are not part of the [`Statement`], but they change the compiled
+	/// constraint system. The verifier must apply the same levels to its constraint system before
+	/// compiling, so the prover has to send them alongside the proof. Since a column is lowered
+	/// based on its values, the levels reveal an upper bound on the size of the values.
+	pub fn apply_tower_levels(&mut self, tower_levels: &[(ColumnId, usize)]) -> Result<(), Error> {
+		for &(column_id, tower_level) in tower_levels {
+			let column = self
+				.tables
+				.get_mut(column_id.table_id)
+				.and_then(|table| table.columns.get_mut(column_id.table_index))
+				.ok_or(Error::MissingColumn(column_id))?;
+			match &mut column.col {
+				ColumnDef::Committed {
+					tower_level: committed_tower_level,
+				} if tower_level <= *committed_tower_level => {
+					*committed_tower_level = tower_level;
+					column.shape.tower_height = tower_level;
+				}
+				_ => {
+					return Err(Error::InvalidTowerLevel {
+						column_id,
+						tower_level,
+					})
+				}
+			}
+		}
+		Ok(())
+	}
+
 	/// Creates and allocates the witness index.
 	///
 	/// **Deprecated**: This is a thin wrapper over [`WitnessIndex::new`] now, which is preferred.
@@ -164,6 +208,10 @@ impl<F: TowerField> ConstraintSystem<F> {
 	/// oracles for all columns. The main difference between column definitions and oracle
 	/// definitions is that multilinear oracle definitions have a number of variables, whereas the
 	/// column definitions contained in a [`ConstraintSystem`] do not have size information.
+	///
+	/// Tables that are inactive in the statement, having a size of zero, are skipped entirely, so
+	/// optional code paths cost nothing to prove when unused. A [`WitnessIndex`] table that is
+	/// never filled, or filled with no events, is inactive.
 	pub fn compile(&self, statement: &Statement<F>) -> Result<CompiledConstraintSystem<F>, Error> {
 		if statement.table_sizes.len() != self.tables.len() {
 			return Err(Error::StatementMissingTableSize {
@@ -177,9 +225,12 @@ impl<F: TowerField> ConstraintSystem<F> {
 		let mut compiled_flushes = Vec::new();
 		let mut non_zero_oracle_ids = Vec::new();
 		let mut exponents = Vec::new();
+		// Oracle IDs of the columns of every table compiled so far, indexed by table ID.
+		let mut table_oracle_lookups = Vec::<Vec<OracleId>>::with_capacity(self.tables.len());
 
 		for (table, &count) in std::iter::zip(&self.tables, &statement.table_sizes) {
 			if count == 0 {
+				table_oracle_lookups.push(Vec::new());
 				continue;
 			}
 			match table.size_spec() {
@@ -209,6 +260,26 @@ impl<F: TowerField> ConstraintSystem<F> {
 				TableSizeSpec::Arbitrary => (),
 			}
 
+			// Only computed columns are re-evaluated when the padding rows of zero-padded columns
+			// are zeroed, so other columns derived from them would keep the last event's values.
+			if !table.requires_any_po2_size() {
+				let padding_dependents = table.zero_padding_dependents();
+				for column_info in &table.columns {
+					if let ColumnDef::Selected { col, .. }
+					| ColumnDef::Projected { col, .. }
+					| ColumnDef::ZeroPadded { col, .. }
+					| ColumnDef::Shifted { col, .. } = &column_info.col
+					{
+						if padding_dependents[col.table_index] {
+							return Err(Error::ZeroPaddedDependency {
+								column_id: column_info.id,
+								inner_id: *col,
+							});
+						}
+					}
+				}
+			}
+
 			let mut oracle_lookup = Vec::new();
 
 			let mut transparent_single = vec![None; table.columns.len()];
@@ -224,10 +295,41 @@ impl<F: TowerField> ConstraintSystem<F> {
 			// Add multilinear oracles for all table columns.
 			let log_capacity = table::log_capacity(count);
 			for column_info in table.columns.iter() {
+				// Virtual computed columns are inlined into the zero constraints and have no
+				// oracle.
+				if matches!(column_info.col, ColumnDef::ComputedVirtual { .. }) {
+					if column_info.is_nonzero {
+						return Err(Error::VirtualColumnReference(column_info.id));
+					}
+					oracle_lookup.push(OracleId::invalid());
+					continue;
+				}
+
+				// Cross-table computed columns are evaluated over the rows of both tables, whose
+				// oracles must already have been added.
+				if let ColumnDef::ComputedCross { foreign_col, .. } = &column_info.col {
+					if foreign_col.table_id >= table.id {
+						return Err(Error::CrossTableReferenceOrder {
+							column_id: column_info.id,
+							foreign_table_id: foreign_col.table_id,
+						});
+					}
+					let foreign_size = statement.table_sizes[foreign_col.table_id];
+					if foreign_size != count {
+						return Err(Error::CrossTableSizeMismatch {
+							column_id: column_info.id,
+							foreign_table_id: foreign_col.table_id,
+							size: count,
+							foreign_size,
+						});
+					}
+				}
+
 				let n_vars = log_capacity + column_info.shape.log_values_per_row;
 				let oracle_id = add_oracle_for_column(
 					&mut oracles,
 					&oracle_lookup,
+					&table_oracle_lookups,
 					&transparent_single,
 					column_info,
 					n_vars,
@@ -307,6 +409,16 @@ impl<F: TowerField> ConstraintSystem<F> {
 					selectors,
 				} in flushes
 				{
+					let multiplicity_bits = match multiplicity {
+						MultiplicitySource::Constant(_) => &[][..],
+						MultiplicitySource::Column(bits) => &bits[..],
+					};
+					if let Some(&index) = chain!(column_indices, selectors, multiplicity_bits)
+						.find(|&&index| oracle_lookup[index] == OracleId::invalid())
+					{
+						return Err(Error::VirtualColumnReference(table.columns[index].id));
+					}
+
 					let flush_oracles = column_indices
 						.iter()
 						.map(|&column_index| OracleOrConst::Oracle(oracle_lookup[column_index]))
@@ -319,21 +431,70 @@ impl<F: TowerField> ConstraintSystem<F> {
 					)
 					.collect::<Vec<_>>();
 
-					compiled_flushes.push(CompiledFlush {
-						oracles: flush_oracles,
-						channel_id: *channel_id,
-						direction: *direction,
-						selectors,
-						multiplicity: *multiplicity as u64,
-					});
+					match multiplicity {
+						MultiplicitySource::Constant(multiplicity) => {
+							compiled_flushes.push(CompiledFlush {
+								oracles: flush_oracles,
+								channel_id: *channel_id,
+								direction: *direction,
+								selectors,
+								multiplicity: *multiplicity as u64,
+							});
+						}
+						// A per-row multiplicity is flushed once per bit, with the bit column as an
+						// additional selector and the bit's weight as the constant multiplicity.
+						MultiplicitySource::Column(bits) => {
+							for (i, &bit) in bits.iter().enumerate() {
+								compiled_flushes.push(CompiledFlush {
+									oracles: flush_oracles.clone(),
+									channel_id: *channel_id,
+									direction: *direction,
+									selectors: chain!(
+										[oracle_lookup[bit]],
+										selectors.iter().copied()
+									)
+									.collect(),
+									multiplicity: 1 << i,
+								});
+							}
+						}
+					}
 				}
 
+				// Zero-padded columns must vanish wherever the step-down selector is zero. The
+				// selector is appended as an extra variable after the partition columns.
+				let mut partition_oracle_ids = partition_oracle_ids;
+				let mut zero_constraints = zero_constraints.iter().collect::<Vec<_>>();
+				let padding_constraints = step_down
+					.map(|step_down| {
+						partition_oracle_ids.push(step_down);
+						let step_down_var = ArithCircuit::var(columns.len());
+						columns
+							.iter()
+							.enumerate()
+							.filter(|(_, &index)| table.columns[index].is_zero_padded)
+							.map(|(partition_index, &index)| {
+								let column_info = &table.columns[index];
+								ZeroConstraint {
+									name: format!("{}_zero_padded", column_info.name),
+									expr: ArithCircuit::var(partition_index)
+										* (step_down_var.clone() + ArithCircuit::one()),
+									tower_level: column_info.shape.tower_height,
+								}
+							})
+							.collect::<Vec<_>>()
+					})
+					.unwrap_or_default();
+				zero_constraints.extend(&padding_constraints);
+
 				if !zero_constraints.is_empty() {
 					let constraint_set =
-						translate_constraint_set(n_vars, zero_constraints, partition_oracle_ids);
+						translate_constraint_set(n_vars, &zero_constraints, partition_oracle_ids);
 					table_constraints.push(constraint_set);
 				}
 			}
+
+			table_oracle_lookups.push(oracle_lookup);
 		}
 
 		Ok(CompiledConstraintSystem {
@@ -345,6 +506,128 @@ impl<F: TowerField> ConstraintSystem<F> {
 			exponents,
 		})
 	}
+
+	/// Replays all table flushes and boundaries against a witness and reports unbalanced channels.
+	///
+	/// This is a debugging aid for witnesses that fail validation with a channel balance error.
+	/// Each returned entry identifies a channel whose pushed multiset differs from its pulled
+	/// multiset, along with a sample offending tuple. Tables that have not been initialized in
+	/// the witness are skipped. A balanced witness yields an empty vector.
+	pub fn debug_channel_balances<P>(
+		&self,
+		witness: &mut WitnessIndex<P>,
+		boundaries: &[Boundary<F>],
+	) -> Result<Vec<(ChannelId, MultisetImbalance<F>)>, Error>
+	where
+		P: PackedField<Scalar = F>
+			+ PackedExtension<B1>
+			+ PackedExtension<B8>
+			+ PackedExtension<B16>
+			+ PackedExtension<B32>
+			+ PackedExtension<B64>
+			+ PackedExtension<B128>,
+	{
+		// For each channel, the net multiplicity of every tuple and the order it was first seen.
+		let mut balances = vec![HashMap::<Vec<F>, (usize, i64)>::new(); self.channels.len()];
+		let mut record = |channel_id: ChannelId, values: Vec<F>, count: i64| {
+			let channel = &mut balances[channel_id];
+			let next_seen = channel.len();
+			channel.entry(values).or_insert((next_seen, 0)).1 += count;
+		};
+
+		for boundary in boundaries {
+			record(
+				boundary.channel_id,
+				boundary.values.clone(),
+				signed_multiplicity(boundary.direction, boundary.multiplicity as i64),
+			);
+		}
+
+		for table in &self.tables {
+			let Some(table_witness) = witness.get_table(table.id()) else {
+				continue;
+			};
+			let size = table_witness.size();
+			let segment = table_witness.full_segment();
+
+			for partition in table.partitions.values() {
+				let n_rows = size * partition.values_per_row;
+				for flush in &partition.flushes {
+					let values = flush
+						.column_indices
+						.iter()
+						.map(|&index| segment.get_dyn(index))
+						.collect::<Result<Vec<_>, _>>()?;
+					let selectors = flush
+						.selectors
+						.iter()
+						.map(|&index| segment.get_dyn(index))
+						.collect::<Result<Vec<_>, _>>()?;
+					let multiplicity_bits = match &flush.multiplicity {
+						MultiplicitySource::Constant(_) => Vec::new(),
+						MultiplicitySource::Column(bits) => bits
+							.iter()
+							.map(|&index| segment.get_dyn(index))
+							.collect::<Result<Vec<_>, _>>()?,
+					};
+
+					for i in 0..n_rows {
+						if selectors.iter().any(|selector| selector.get(i).is_zero()) {
+							continue;
+						}
+						let multiplicity = match flush.multiplicity {
+							MultiplicitySource::Constant(multiplicity) => multiplicity as i64,
+							MultiplicitySource::Column(_) => multiplicity_bits
+								.iter()
+								.enumerate()
+								.filter(|(_, bit)| !bit.get(i).is_zero())
+								.map(|(bit_index, _)| 1 << bit_index)
+								.sum(),
+						};
+						if multiplicity == 0 {
+							continue;
+						}
+						record(
+							flush.channel_id,
+							values.iter().map(|col| col.get(i)).collect(),
+							signed_multiplicity(flush.direction, multiplicity),
+						);
+					}
+				}
+			}
+		}
+
+		let imbalances = balances
+			.into_iter()
+			.enumerate()
+			.filter_map(|(channel_id, channel)| {
+				let unbalanced = channel
+					.into_iter()
+					.filter(|(_, (_, count))| *count != 0)
+					.collect::<Vec<_>>();
+				let n_unbalanced = unbalanced.len();
+				let (sample, (_, net_multiplicity)) = unbalanced
+					.into_iter()
+					.min_by_key(|(_, (first_seen, _))| *first_seen)?;
+				Some((
+					channel_id,
+					MultisetImbalance {
+						n_unbalanced,
+						sample,
+						net_multiplicity,
+					},
+				))
+			})
+			.collect();
+		Ok(imbalances)
+	}
+}
+
+fn signed_multiplicity(direction: FlushDirection, multiplicity: i64) -> i64 {
+	match direction {
+		FlushDirection::Push => multiplicity,
+		FlushDirection::Pull => -multiplicity,
+	}
 }
 
 /// Add a table column to the multilinear oracle set with a specified number of variables.
@@ -358,6 +641,7 @@ impl<F: TowerField> ConstraintSystem<F> {
 fn add_oracle_for_column<F: TowerField>(
 	oracles: &mut MultilinearOracleSet<F>,
 	oracle_lookup: &[OracleId],
+	table_oracle_lookups: &[Vec<OracleId>],
 	transparent_single: &[Option<OracleId>],
 	column_info: &ColumnInfo<F>,
 	n_vars: usize,
@@ -369,6 +653,18 @@ fn add_oracle_for_column<F: TowerField>(
 		shape,
 		..
 	} = column_info;
+	// Virtual computed columns have no oracle, so they can only be referenced by zero constraints
+	// and by the expressions of other computed columns, where they are inlined.
+	let source_oracle = |table_index: ColumnIndex| {
+		let oracle_id = oracle_lookup[table_index];
+		if oracle_id == OracleId::invalid() {
+			return Err(Error::VirtualColumnReference(ColumnId {
+				table_id: id.table_id,
+				table_index,
+			}));
+		}
+		Ok(oracle_id)
+	};
 	let addition = oracles.add_named(name);
 	let oracle_id = match col {
 		ColumnDef::Committed { tower_level } => addition.committed(n_vars, *tower_level),
@@ -386,7 +682,7 @@ fn add_oracle_for_column<F: TowerField>(
 					}
 				})
 				.collect();
-			addition.projected(oracle_lookup[col.table_index], index_values, 0)?
+			addition.projected(source_oracle(col.table_index)?, index_values, 0)?
 		}
 		ColumnDef::Projected {
 			col,
@@ -403,7 +699,7 @@ fn add_oracle_for_column<F: TowerField>(
 					}
 				})
 				.collect();
-			addition.projected(oracle_lookup[col.table_index], query_values, *start_index)?
+			addition.projected(source_oracle(col.table_index)?, query_values, *start_index)?
 		}
 		ColumnDef::ZeroPadded {
 			col,
@@ -411,7 +707,7 @@ fn add_oracle_for_column<F: TowerField>(
 			start_index,
 			nonzero_index,
 		} => addition.zero_padded(
-			oracle_lookup[col.table_index],
+			source_oracle(col.table_index)?,
 			*n_pad_vars,
 			*nonzero_index,
 			*start_index,
@@ -423,11 +719,11 @@ fn add_oracle_for_column<F: TowerField>(
 			variant,
 		} => {
 			// TODO: debug assert column at col.table_index has the same values_per_row as col.id
-			addition.shifted(oracle_lookup[col.table_index], *offset, *log_block_size, *variant)?
+			addition.shifted(source_oracle(col.table_index)?, *offset, *log_block_size, *variant)?
 		}
 		ColumnDef::Packed { col, log_degree } => {
 			// TODO: debug assert column at col.table_index has the same values_per_row as col.id
-			addition.packed(oracle_lookup[col.table_index], *log_degree)?
+			addition.packed(source_oracle(col.table_index)?, *log_degree)?
 		}
 		ColumnDef::Computed { cols, expr } => {
 			if let Ok(LinearNormalForm {
@@ -438,17 +734,40 @@ fn add_oracle_for_column<F: TowerField>(
 				let col_scalars = cols
 					.iter()
 					.zip(var_coeffs)
-					.map(|(&col_index, coeff)| (oracle_lookup[col_index], coeff))
-					.collect::<Vec<_>>();
+					.map(|(&col_index, coeff)| Ok((source_oracle(col_index)?, coeff)))
+					.collect::<Result<Vec<_>, Error>>()?;
 				addition.linear_combination_with_offset(n_vars, offset, col_scalars)?
 			} else {
 				let inner_oracles = cols
 					.iter()
-					.map(|&col_index| oracle_lookup[col_index])
-					.collect::<Vec<_>>();
+					.map(|&col_index| source_oracle(col_index))
+					.collect::<Result<Vec<_>, _>>()?;
 				addition.composite_mle(n_vars, inner_oracles, expr.clone())?
 			}
 		}
+		ColumnDef::ComputedCross {
+			cols,
+			foreign_col,
+			expr,
+		} => {
+			let foreign_oracle = table_oracle_lookups
+				.get(foreign_col.table_id)
+				.and_then(|lookup| lookup.get(foreign_col.table_index))
+				.copied()
+				.ok_or(Error::MissingColumn(*foreign_col))?;
+			if foreign_oracle == OracleId::invalid() {
+				return Err(Error::VirtualColumnReference(*foreign_col));
+			}
+			let inner_oracles = chain!(
+				cols.iter().map(|&col_index| source_oracle(col_index)),
+				[Ok(foreign_oracle)]
+			)
+			.collect::<Result<Vec<_>, _>>()?;
+			addition.composite_mle(n_vars, inner_oracles, expr.clone())?
+		}
+		ColumnDef::ComputedVirtual { .. } => {
+			unreachable!("virtual computed columns are inlined and do not have oracles")
+		}
 		ColumnDef::Constant { .. } => addition.repeating(
 			transparent_single[id.table_index].unwrap(),
 			n_vars - shape.log_values_per_row,
@@ -474,7 +793,7 @@ fn add_oracle_for_column<F: TowerField>(
 /// of the constraint expressions.
 fn translate_constraint_set<F: TowerField>(
 	n_vars: usize,
-	zero_constraints: &[ZeroConstraint<F>],
+	zero_constraints: &[&ZeroConstraint<F>],
 	partition_oracle_ids: Vec<OracleId>,
 ) -> ConstraintSet<F> {
 	// We need to figure out which oracle ids from the entire set of the partition oracles is
@@ -5,7 +5,11 @@ use std::cell::{BorrowError, BorrowMutError};
 use binius_core::{oracle::Error as OracleError, polynomial::Error as PolynomialError};
 use binius_math::Error as MathError;
 
-use super::{column::ColumnId, structured::Error as StructuredError, table::TableId};
+use super::{
+	column::{ColumnId, ColumnShape},
+	structured::Error as StructuredError,
+	table::TableId,
+};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -20,6 +24,25 @@ pub enum Error {
 		table_id: TableId,
 		log_vals_per_row: usize,
 	},
+	#[error(
+		"virtual column {0:?} can only be referenced by zero constraints and computed columns of \
+		 its own table"
+	)]
+	VirtualColumnReference(ColumnId),
+	#[error(
+		"flush selector column {column_id:?} has shape {shape:?}; expected 1-bit values with \
+		 log_values_per_row={log_values_per_row}, like the flushed columns"
+	)]
+	FlushSelectorShape {
+		column_id: ColumnId,
+		shape: ColumnShape,
+		log_values_per_row: usize,
+	},
+	#[error(
+		"a constant flush multiplicity of {multiplicity} cannot be combined with a multiplicity \
+		 column"
+	)]
+	FlushMultiplicityConflict { multiplicity: u32 },
 	#[error("cannot construct witness index for empty table {table_id}")]
 	EmptyTable { table_id: TableId },
 	#[error("failed to write element to a column with a lower tower height")]
@@ -37,6 +60,37 @@ pub enum Error {
 	TableSizePowerOfTwoRequired { table_id: TableId, size: usize },
 	#[error("table {table_id} is required to have a fixed power-of-two size, instead got {size}")]
 	TableSizeFixedRequired { table_id: TableId, size: usize },
+	#[error("cannot set the tower level of column {column_id:?} to {tower_level}; only committed columns can be lowered")]
+	InvalidTowerLevel {
+		column_id: ColumnId,
+		tower_level: usize,
+	},
+	#[error(
+		"computed column {column_id:?} references a column of table {foreign_table_id}, which \
+		must have the same size; expected {size}, got {foreign_size}"
+	)]
+	CrossTableSizeMismatch {
+		column_id: ColumnId,
+		foreign_table_id: TableId,
+		size: usize,
+		foreign_size: usize,
+	},
+	#[error(
+		"computed column {column_id:?} references a column of table {foreign_table_id}, which \
+		 must be added to the constraint system before the column's own table"
+	)]
+	CrossTableReferenceOrder {
+		column_id: ColumnId,
+		foreign_table_id: TableId,
+	},
+	#[error(
+		"column {column_id:?} is derived from column {inner_id:?}, which depends on a zero-padded \
+		 column; only computed columns are updated over the padding rows"
+	)]
+	ZeroPaddedDependency {
+		column_id: ColumnId,
+		inner_id: ColumnId,
+	},
 	// TODO: These should have column IDs
 	#[error("witness borrow error: {0}. Note that packed columns are aliases for the unpacked column when accessing witness data")]
 	WitnessBorrow(#[source] BorrowError),
@@ -46,6 +100,14 @@ pub enum Error {
 		"the table index was initialized for {expected} events; attempted to fill with {actual}"
 	)]
 	IncorrectNumberOfTableEvents { expected: usize, actual: usize },
+	#[error("index out of range for column {name:?}: row {row}, lane {lane}; the segment has {height} rows of {values_per_row} values")]
+	ColumnIndexOutOfRange {
+		name: String,
+		row: usize,
+		lane: usize,
+		height: usize,
+		values_per_row: usize,
+	},
 	#[error("table fill error: {0}")]
 	TableFill(anyhow::Error),
 	#[error("math error: {0}")]
@@ -54,4 +116,20 @@ pub enum Error {
 	Oracle(#[from] OracleError),
 	#[error("polynomial error: {0}")]
 	Polynomial(#[from] PolynomialError),
+	#[cfg(feature = "arrow")]
+	#[error("record batch has no Arrow column named {name:?}")]
+	MissingArrowColumn { name: String },
+	#[cfg(feature = "arrow")]
+	#[error("Arrow column {name:?} has {actual} values per row; expected {expected}")]
+	IncorrectArrowValuesPerRow {
+		name: String,
+		expected: usize,
+		actual: usize,
+	},
+	#[cfg(feature = "arrow")]
+	#[error("Arrow column {name:?} contains null values")]
+	ArrowColumnHasNulls { name: String },
+	#[cfg(feature = "arrow")]
+	#[error("Arrow column {name:?} has unsupported data type {data_type}")]
+	UnsupportedArrowType { name: String, data_type: String },
 }
@@ -4,7 +4,10 @@ use binius_field::{ExtensionField, Field, TowerField};
 use binius_math::{ArithCircuit, ArithCircuitStep, ArithExpr};
 use getset::{CopyGetters, Getters};
 
-use super::{column::Col, table::TableId};
+use super::{
+	column::{Col, ColumnPartitionIndex},
+	table::TableId,
+};
 
 /// A constraint that the evaluation of an expression over a table is zero at every row.
 #[derive(Debug)]
@@ -26,6 +29,14 @@ pub struct Expr<F: TowerField, const V: usize> {
 }
 
 impl<F: TowerField, const V: usize> Expr<F, V> {
+	/// Creates an expression consisting of a single variable of a table partition.
+	pub(super) fn var(table_id: TableId, partition_index: ColumnPartitionIndex) -> Self {
+		Self {
+			table_id,
+			expr: ArithExpr::Var(partition_index),
+		}
+	}
+
 	/// Polynomial degree of the arithmetic expression.
 	pub fn degree(&self) -> usize {
 		ArithCircuit::from(&self.expr).degree()
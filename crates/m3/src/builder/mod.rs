@@ -1,5 +1,7 @@
 // Copyright 2025 Irreducible Inc.
 
+#[cfg(feature = "arrow")]
+mod arrow;
 pub mod channel;
 pub mod column;
 pub mod constraint_system;
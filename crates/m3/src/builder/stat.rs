@@ -40,6 +40,9 @@ impl TableStat {
 		let mut bits_per_row_virtual = 0;
 		for column in &table.columns {
 			let bits_per_column = 1 << column.shape.log_cell_size();
+			if matches!(column.col, super::ColumnDef::ComputedVirtual { .. }) {
+				continue;
+			}
 			if matches!(column.col, super::ColumnDef::Committed { .. }) {
 				bits_per_row_virtual += bits_per_column;
 			} else {
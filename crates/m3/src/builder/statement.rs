@@ -3,7 +3,7 @@
 use binius_core::constraint_system::channel::Boundary;
 use binius_field::TowerField;
 
-use super::types::B128;
+use super::{table::TableId, types::B128};
 
 /// A statement of values claimed to satisfy a constraint system.
 pub struct Statement<F: TowerField = B128> {
@@ -11,5 +11,18 @@ pub struct Statement<F: TowerField = B128> {
 	// TODO: This doesn't belong in `Statement`. We should split this struct somehow. Perhaps table
 	// sizes go into a separate `Advice` struct.
 	/// Direct index mapping table IDs to the count of rows per table.
+	///
+	/// A table with a size of zero is inactive. Inactive tables commit no columns, and their
+	/// constraints and flushes are dropped from the compiled constraint system. Since the sizes
+	/// are public, the verifier learns which tables are active from the statement.
 	pub table_sizes: Vec<usize>,
 }
+
+impl<F: TowerField> Statement<F> {
+	/// Returns whether the table with the given ID is active, meaning it has a non-zero size.
+	pub fn is_table_active(&self, table_id: TableId) -> bool {
+		self.table_sizes
+			.get(table_id)
+			.is_some_and(|&size| size != 0)
+	}
+}
@@ -110,6 +110,38 @@ impl<'a, F: TowerField> TableBuilder<'a, F> {
 		)
 	}
 
+	/// Adds a committed column whose values past the table height are constrained to be zero.
+	///
+	/// Table fillers pad the rows between the table height and its capacity with copies of the
+	/// last event. For this column, those entries are instead zeroed when the witness is converted
+	/// with [`WitnessIndex::into_multilinear_extension_index`], so `fill` implementations do not
+	/// need to clear the tail, and the columns added with [`Self::add_computed`] that depend on it
+	/// are re-evaluated over the padding rows. Selected, projected, zero-padded and shifted columns
+	/// derived from it are not updated, so the constraint system fails to compile with them. On
+	/// compilation, a zero constraint against the table's step-down selector enforces
+	/// the padding. Tables that require a power-of-two size have no padding rows and get no
+	/// constraint.
+	///
+	/// A 1-bit zero-padded column meets the requirement that [`FlushOpts::selectors`] be zero past
+	/// the table height, and so can be used as a flush selector without further checks. Note that
+	/// zero constraints relating this column to other columns must also hold on the padding rows,
+	/// where this column is zero but the others repeat the last event.
+	///
+	/// [`WitnessIndex::into_multilinear_extension_index`]:
+	///     super::WitnessIndex::into_multilinear_extension_index
+	pub fn add_committed_zero_padded<FSub, const VALUES_PER_ROW: usize>(
+		&mut self,
+		name: impl ToString,
+	) -> Col<FSub, VALUES_PER_ROW>
+	where
+		FSub: TowerField,
+		F: ExtensionField<FSub>,
+	{
+		let col = self.add_committed(name);
+		self.table.columns[col.table_index].is_zero_padded = true;
+		col
+	}
+
 	pub fn add_committed_multiple<FSub, const VALUES_PER_ROW: usize, const N: usize>(
 		&mut self,
 		name: impl ToString,
@@ -121,6 +153,12 @@ impl<'a, F: TowerField> TableBuilder<'a, F> {
 		std::array::from_fn(|i| self.add_committed(format!("{}[{}]", name.to_string(), i)))
 	}
 
+	/// Adds a column whose values are those of `col`, shifted by `offset` within blocks of
+	/// `2^log_block_size` values.
+	///
+	/// Blocks are usually within a row. Tables with a fixed size, set by
+	/// [`Self::require_fixed_size`], may also shift across rows, with blocks of up to the whole
+	/// column.
 	pub fn add_shifted<FSub, const VALUES_PER_ROW: usize>(
 		&mut self,
 		name: impl ToString,
@@ -133,7 +171,11 @@ impl<'a, F: TowerField> TableBuilder<'a, F> {
 		FSub: TowerField,
 		F: ExtensionField<FSub>,
 	{
-		assert!(log_block_size <= log2_strict_usize(VALUES_PER_ROW));
+		let log_rows = match self.table.table_size_spec {
+			TableSizeSpec::Fixed { log_size } => log_size,
+			_ => 0,
+		};
+		assert!(log_block_size <= log2_strict_usize(VALUES_PER_ROW) + log_rows);
 		assert!(offset <= 1 << log_block_size);
 		self.table.new_column(
 			self.namespaced_name(name),
@@ -180,7 +222,10 @@ impl<'a, F: TowerField> TableBuilder<'a, F> {
 		FSub: TowerField,
 		F: ExtensionField<FSub>,
 	{
-		let expr_circuit = ArithCircuit::from(expr.expr());
+		let expr_circuit = self.table.inline_virtual_cols(
+			partition_id::<V>(),
+			ArithCircuit::from(expr.expr()).convert_field(),
+		);
 		let partition_indexes = expr_circuit
 			.vars_usage()
 			.iter()
@@ -201,7 +246,6 @@ impl<'a, F: TowerField> TableBuilder<'a, F> {
 			var_remapping[old_index] = new_index;
 		}
 		let remapped_expr = expr_circuit
-			.convert_field()
 			.remap_vars(&var_remapping)
 			.expect("var_remapping should be large enought");
 
@@ -214,6 +258,104 @@ impl<'a, F: TowerField> TableBuilder<'a, F> {
 		)
 	}
 
+	/// Adds a computed column that is evaluated lazily and never stored in the witness.
+	///
+	/// Unlike [`Self::add_computed`], no oracle is created for the column. Instead, the expression
+	/// is substituted in place of the column in every zero constraint that refers to it. This
+	/// saves the memory for the column data at the cost of higher degree constraints.
+	///
+	/// The returned column may only be used in zero constraints and in the expressions of other
+	/// computed columns. It cannot be flushed, shifted, or otherwise referenced by other columns;
+	/// [`ConstraintSystem::compile`] rejects such uses with [`Error::VirtualColumnReference`].
+	///
+	/// [`ConstraintSystem::compile`]: super::ConstraintSystem::compile
+	/// [`Error::VirtualColumnReference`]: super::Error::VirtualColumnReference
+	pub fn add_computed_virtual<FSub, const V: usize>(
+		&mut self,
+		name: impl ToString,
+		expr: Expr<FSub, V>,
+	) -> Col<FSub, V>
+	where
+		FSub: TowerField,
+		F: ExtensionField<FSub>,
+	{
+		let expr = self.table.inline_virtual_cols(
+			partition_id::<V>(),
+			ArithCircuit::from(expr.expr()).convert_field(),
+		);
+		self.table
+			.new_column(self.namespaced_name(name), ColumnDef::ComputedVirtual { expr })
+	}
+
+	/// Adds a computed column whose expression also depends on a column of another table.
+	///
+	/// `expr` receives an expression standing for the values of `other_table_col` and builds the
+	/// column expression from it and the columns of this table. The two tables are evaluated row by
+	/// row over the same hypercube, so [`ConstraintSystem::compile`] rejects statements in which
+	/// they have different sizes. This is typically used when both tables are filled from the same
+	/// events. As with [`Self::add_computed`], the witness filler is responsible for writing the
+	/// column values.
+	///
+	/// The table of `other_table_col` must have been added to the constraint system before this
+	/// one, so in particular it cannot be this table; use [`Self::add_computed`] for that.
+	/// [`ConstraintSystem::compile`] rejects other statements with
+	/// [`Error::CrossTableReferenceOrder`].
+	///
+	/// [`ConstraintSystem::compile`]: super::ConstraintSystem::compile
+	/// [`Error::CrossTableReferenceOrder`]: super::Error::CrossTableReferenceOrder
+	pub fn add_computed_cross<FSub, const V: usize>(
+		&mut self,
+		name: impl ToString,
+		other_table_col: Col<FSub, V>,
+		expr: impl FnOnce(Expr<FSub, V>) -> Expr<FSub, V>,
+	) -> Col<FSub, V>
+	where
+		FSub: TowerField,
+		F: ExtensionField<FSub>,
+	{
+		// The foreign column is represented by the first variable past the partition columns.
+		let foreign_index = self
+			.table
+			.partitions
+			.get(partition_id::<V>())
+			.map_or(0, |partition| partition.columns.len());
+		let expr = expr(Expr::var(self.table.id, foreign_index));
+		let expr_circuit = self.table.inline_virtual_cols(
+			partition_id::<V>(),
+			ArithCircuit::from(expr.expr()).convert_field(),
+		);
+
+		let vars_usage = expr_circuit.vars_usage();
+		let partition_indexes = (0..foreign_index)
+			.filter(|&i| vars_usage.get(i).copied().unwrap_or(false))
+			.collect::<Vec<_>>();
+		let cols = partition_indexes
+			.iter()
+			.map(|&partition_index| {
+				let partition = &self.table.partitions[partition_id::<V>()];
+				partition.columns[partition_index]
+			})
+			.collect::<Vec<_>>();
+
+		let mut var_remapping = vec![0; expr_circuit.n_vars().max(foreign_index + 1)];
+		for (new_index, &old_index) in partition_indexes.iter().enumerate() {
+			var_remapping[old_index] = new_index;
+		}
+		var_remapping[foreign_index] = cols.len();
+		let remapped_expr = expr_circuit
+			.remap_vars(&var_remapping)
+			.expect("var_remapping should be large enough");
+
+		self.table.new_column(
+			self.namespaced_name(name),
+			ColumnDef::ComputedCross {
+				cols,
+				foreign_col: other_table_col.id(),
+				expr: remapped_expr,
+			},
+		)
+	}
+
 	pub fn add_selected<FSub, const VALUES_PER_ROW: usize>(
 		&mut self,
 		name: impl ToString,
@@ -478,9 +620,15 @@ impl<'a, F: TowerField> TableBuilder<'a, F> {
 		F: ExtensionField<FSub>,
 	{
 		let namespaced_name = self.namespaced_name(name);
-		self.table
-			.partition_mut(VALUES_PER_ROW)
-			.assert_zero(namespaced_name, expr)
+		let expr = self.table.inline_virtual_cols(
+			partition_id::<VALUES_PER_ROW>(),
+			ArithCircuit::from(expr.expr()).convert_field(),
+		);
+		self.table.partition_mut(VALUES_PER_ROW).assert_zero(
+			namespaced_name,
+			FSub::TOWER_LEVEL,
+			expr,
+		)
 	}
 
 	/// Constrains that all values contained in this column are non-zero.
@@ -520,12 +668,13 @@ impl<'a, F: TowerField> TableBuilder<'a, F> {
 		FSub: TowerField,
 		F: ExtensionField<FSub>,
 	{
-		self.table.partition_mut(1).flush(
+		let flush = self.table.resolve_flush(
 			channel,
 			FlushDirection::Pull,
 			cols.into_iter().map(upcast_col),
 			opts,
 		);
+		self.table.partition_mut(1).flushes.push(flush);
 	}
 
 	pub fn push_with_opts<FSub>(
@@ -537,12 +686,40 @@ impl<'a, F: TowerField> TableBuilder<'a, F> {
 		FSub: TowerField,
 		F: ExtensionField<FSub>,
 	{
-		self.table.partition_mut(1).flush(
+		let flush = self.table.resolve_flush(
+			channel,
+			FlushDirection::Push,
+			cols.into_iter().map(upcast_col),
+			opts,
+		);
+		self.table.partition_mut(1).flushes.push(flush);
+	}
+
+	/// Pushes and pulls the same columns to a channel in a single call.
+	///
+	/// This is shorthand for [`Self::push_with_opts`] followed by [`Self::pull_with_opts`] with
+	/// the same columns and options, and adds exactly the same flushes. It is useful for lookup
+	/// arguments that assert a value is present on both sides of a channel.
+	pub fn flush_both<FSub>(
+		&mut self,
+		channel: ChannelId,
+		cols: impl IntoIterator<Item = Col<FSub>>,
+		opts: FlushOpts,
+	) where
+		FSub: TowerField,
+		F: ExtensionField<FSub>,
+	{
+		let push = self.table.resolve_flush(
 			channel,
 			FlushDirection::Push,
 			cols.into_iter().map(upcast_col),
 			opts,
 		);
+		let pull = Flush {
+			direction: FlushDirection::Pull,
+			..push.clone()
+		};
+		self.table.partition_mut(1).flushes.extend([push, pull]);
 	}
 
 	fn namespaced_name(&self, name: impl ToString) -> String {
@@ -578,7 +755,6 @@ pub struct Table<F: TowerField = B128> {
 /// Zerocheck constraints can only be defined within table partitions.
 #[derive(Debug)]
 pub(super) struct TablePartition<F: TowerField = B128> {
-	pub table_id: TableId,
 	pub values_per_row: usize,
 	pub flushes: Vec<Flush>,
 	pub columns: Vec<ColumnIndex>,
@@ -586,9 +762,8 @@ pub(super) struct TablePartition<F: TowerField = B128> {
 }
 
 impl<F: TowerField> TablePartition<F> {
-	fn new(table_id: TableId, values_per_row: usize) -> Self {
+	fn new(values_per_row: usize) -> Self {
 		Self {
-			table_id,
 			values_per_row,
 			flushes: Vec::new(),
 			columns: Vec::new(),
@@ -596,49 +771,11 @@ impl<F: TowerField> TablePartition<F> {
 		}
 	}
 
-	fn assert_zero<FSub, const VALUES_PER_ROW: usize>(
-		&mut self,
-		name: impl ToString,
-		expr: Expr<FSub, VALUES_PER_ROW>,
-	) where
-		FSub: TowerField,
-		F: ExtensionField<FSub>,
-	{
+	fn assert_zero(&mut self, name: impl ToString, tower_level: usize, expr: ArithCircuit<F>) {
 		self.zero_constraints.push(ZeroConstraint {
-			tower_level: FSub::TOWER_LEVEL,
+			tower_level,
 			name: name.to_string(),
-			expr: ArithCircuit::from(expr.expr()).convert_field(),
-		});
-	}
-
-	fn flush(
-		&mut self,
-		channel_id: ChannelId,
-		direction: FlushDirection,
-		cols: impl IntoIterator<Item = Col<F>>,
-		opts: FlushOpts,
-	) {
-		let column_indices = cols
-			.into_iter()
-			.map(|col| {
-				assert_eq!(col.table_id, self.table_id);
-				col.table_index
-			})
-			.collect();
-		let selectors = opts
-			.selectors
-			.iter()
-			.map(|selector| {
-				assert_eq!(selector.table_id, self.table_id);
-				selector.table_index
-			})
-			.collect::<Vec<_>>();
-		self.flushes.push(Flush {
-			column_indices,
-			channel_id,
-			direction,
-			multiplicity: opts.multiplicity,
-			selectors,
+			expr,
 		});
 	}
 }
@@ -683,6 +820,7 @@ impl<F: TowerField> Table<F> {
 				log_values_per_row: log2_strict_usize(V),
 			},
 			is_nonzero: false,
+			is_zero_padded: false,
 		};
 
 		let partition_index = partition.columns.len();
@@ -691,10 +829,80 @@ impl<F: TowerField> Table<F> {
 		Col::new(id, partition_index)
 	}
 
+	/// Resolves the columns and options of a flush of this table.
+	///
+	/// ## Panics
+	///
+	/// * If a flushed column is not in this table, or the options are invalid as described in
+	///   [`Flush::from_opts`].
+	fn resolve_flush(
+		&self,
+		channel_id: ChannelId,
+		direction: FlushDirection,
+		cols: impl IntoIterator<Item = Col<F>>,
+		opts: FlushOpts,
+	) -> Flush {
+		let column_indices = cols
+			.into_iter()
+			.map(|col| {
+				assert_eq!(col.table_id, self.id);
+				col.table_index
+			})
+			.collect();
+		Flush::from_opts(&self.columns, column_indices, channel_id, direction, opts)
+			.unwrap_or_else(|err| panic!("invalid flush in table {:?}: {err}", self.name))
+	}
+
 	fn partition_mut(&mut self, values_per_row: usize) -> &mut TablePartition<F> {
 		self.partitions
 			.entry(log2_strict_usize(values_per_row))
-			.or_insert_with(|| TablePartition::new(self.id, values_per_row))
+			.or_insert_with(|| TablePartition::new(values_per_row))
+	}
+
+	/// Substitutes the expressions of the virtual computed columns in the given partition into an
+	/// expression over the partition columns.
+	fn inline_virtual_cols(
+		&self,
+		log_values_per_row: usize,
+		expr: ArithCircuit<F>,
+	) -> ArithCircuit<F> {
+		let Some(partition) = self.partitions.get(log_values_per_row) else {
+			return expr;
+		};
+		let virtual_exprs = partition
+			.columns
+			.iter()
+			.map(|&index| match &self.columns[index].col {
+				ColumnDef::ComputedVirtual { expr } => Some(expr),
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+		if virtual_exprs.iter().all(Option::is_none) {
+			return expr;
+		}
+		expr.subst_vars(&virtual_exprs)
+	}
+
+	/// Returns, for each column, whether its values past the table height depend on a zero-padded
+	/// column.
+	///
+	/// A column depends on the columns it is defined over, so this covers the zero-padded columns
+	/// and all columns derived from them.
+	pub(super) fn zero_padding_dependents(&self) -> Vec<bool> {
+		let mut dependents = Vec::with_capacity(self.columns.len());
+		for column in &self.columns {
+			let depends_on_inputs = match &column.col {
+				ColumnDef::Selected { col, .. }
+				| ColumnDef::Projected { col, .. }
+				| ColumnDef::ZeroPadded { col, .. }
+				| ColumnDef::Shifted { col, .. }
+				| ColumnDef::Packed { col, .. } => dependents[col.table_index],
+				ColumnDef::Computed { cols, .. } => cols.iter().any(|&index| dependents[index]),
+				_ => false,
+			};
+			dependents.push(column.is_zero_padded || depends_on_inputs);
+		}
+		dependents
 	}
 
 	/// Returns true if this table requires to have any power-of-two size.
@@ -743,7 +951,7 @@ pub fn log_capacity(table_size: usize) -> usize {
 #[cfg(test)]
 mod tests {
 	use super::{Table, TableBuilder};
-	use crate::builder::B128;
+	use crate::builder::{FlushOpts, B1, B128, B32};
 
 	#[test]
 	fn namespace_nesting() {
@@ -753,4 +961,38 @@ mod tests {
 		let tb_ns_2 = tb_ns_1.with_namespace("ns2");
 		assert_eq!(tb_ns_2.namespaced_name("column"), "ns1::ns2::column");
 	}
+
+	#[test]
+	fn flush_both_matches_push_and_pull() {
+		let opts = |selector, multiplicity_bit| FlushOpts {
+			multiplicity: 1,
+			selectors: vec![selector],
+			multiplicity_column: Some(vec![multiplicity_bit]),
+		};
+
+		let mut manual = Table::<B128>::new(0, "manual");
+		let mut tb = TableBuilder::new(&mut manual);
+		let col = tb.add_committed::<B32, 1>("col");
+		let selector = tb.add_committed::<B1, 1>("selector");
+		let multiplicity_bit = tb.add_committed::<B1, 1>("multiplicity_bit");
+		tb.push_with_opts(3, [col], opts(selector, multiplicity_bit));
+		tb.pull_with_opts(3, [col], opts(selector, multiplicity_bit));
+
+		let mut both = Table::<B128>::new(0, "both");
+		let mut tb = TableBuilder::new(&mut both);
+		let col = tb.add_committed::<B32, 1>("col");
+		let selector = tb.add_committed::<B1, 1>("selector");
+		let multiplicity_bit = tb.add_committed::<B1, 1>("multiplicity_bit");
+		tb.flush_both(3, [col], opts(selector, multiplicity_bit));
+
+		let flushes = |table: &Table<B128>| {
+			table
+				.partitions
+				.values()
+				.flat_map(|partition| partition.flushes.clone())
+				.collect::<Vec<_>>()
+		};
+		assert_eq!(flushes(&both), flushes(&manual));
+		assert_eq!(flushes(&both).len(), 2);
+	}
 }
@@ -3,7 +3,11 @@
 //! Utilities for testing M3 constraint systems and gadgets.
 
 use anyhow::Result;
-use binius_core::{constraint_system::channel::Boundary, fiat_shamir::HasherChallenger};
+use binius_core::{
+	constraint_system::{channel::Boundary, ConstraintSystem as CompiledConstraintSystem},
+	fiat_shamir::HasherChallenger,
+	witness::MultilinearExtensionIndex,
+};
 use binius_field::{
 	as_packed_field::{PackScalar, PackedType},
 	linear_transformation::PackedTransformationFactory,
@@ -117,13 +121,33 @@ pub fn validate_system_witness_with_prove_verify<U>(
 	};
 	let ccs = cs.compile(&statement).unwrap();
 	let witness = witness.into_multilinear_extension_index();
+	validate_compiled_witness::<U>(&ccs, &statement.boundaries, witness, prove_verify);
+}
 
-	binius_core::constraint_system::validate::validate_witness(
-		&ccs,
-		&statement.boundaries,
-		&witness,
-	)
-	.unwrap();
+/// Validates a witness against an already compiled constraint system, and optionally proves and
+/// verifies it.
+///
+/// This is useful when the constraint system is modified between filling the witness and
+/// compiling, such as with [`ConstraintSystem::apply_tower_levels`].
+pub fn validate_compiled_witness<U>(
+	ccs: &CompiledConstraintSystem<B128>,
+	boundaries: &[Boundary<B128>],
+	witness: MultilinearExtensionIndex<PackedType<U, B128>>,
+	prove_verify: bool,
+) where
+	U: UnderlierType
+		+ PackScalar<B1>
+		+ PackScalar<B8>
+		+ PackScalar<B16>
+		+ PackScalar<B32>
+		+ PackScalar<B64>
+		+ PackScalar<B128>
+		+ PackScalar<BinaryField128bPolyval>,
+	PackedType<U, B128>:
+		PackedFieldIndexable + PackedTransformationFactory<PackedType<U, BinaryField128bPolyval>>,
+	PackedType<U, BinaryField128bPolyval>: PackedTransformationFactory<PackedType<U, B128>>,
+{
+	binius_core::constraint_system::validate::validate_witness(ccs, boundaries, &witness).unwrap();
 
 	if prove_verify {
 		const LOG_INV_RATE: usize = 1;
@@ -137,10 +161,10 @@ pub fn validate_system_witness_with_prove_verify<U>(
 			HasherChallenger<Groestl256>,
 			_,
 		>(
-			&ccs,
+			ccs,
 			LOG_INV_RATE,
 			SECURITY_BITS,
-			&statement.boundaries,
+			boundaries,
 			witness,
 			&binius_hal::make_portable_backend(),
 		)
@@ -152,7 +176,7 @@ pub fn validate_system_witness_with_prove_verify<U>(
 			Groestl256,
 			Groestl256ByteCompression,
 			HasherChallenger<Groestl256>,
-		>(&ccs, LOG_INV_RATE, SECURITY_BITS, &statement.boundaries, proof)
+		>(ccs, LOG_INV_RATE, SECURITY_BITS, boundaries, proof)
 		.unwrap();
 	}
 }
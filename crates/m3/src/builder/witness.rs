@@ -70,19 +70,32 @@ impl<'cs, 'alloc, F: TowerField, P: PackedField<Scalar = F>> WitnessIndex<'cs, '
 		table_id: TableId,
 		size: usize,
 	) -> Result<&mut TableWitnessIndex<'cs, 'alloc, P>, Error> {
+		self.init_and_try_fill_table(table_id, size, |_| Ok(()))?;
+		Ok(self
+			.get_table(table_id)
+			.expect("table witness was initialized on the previous line"))
+	}
+
+	/// Initializes a table witness with `size` rows and fills it with `fill`.
+	///
+	/// The table witness is only stored once `fill` succeeds, so a failed fill leaves the table
+	/// uninitialized instead of partially filled.
+	pub(super) fn init_and_try_fill_table(
+		&mut self,
+		table_id: TableId,
+		size: usize,
+		fill: impl FnOnce(&mut TableWitnessIndex<'cs, 'alloc, P>) -> Result<(), Error>,
+	) -> Result<(), Error> {
 		match self.tables.get_mut(table_id) {
 			Some(entry) => match entry {
 				Either::Left(table) => {
 					if size == 0 {
-						Err(Error::EmptyTable { table_id })
-					} else {
-						let table_witness = TableWitnessIndex::new(self.allocator, table, size)?;
-						*entry = Either::Right(table_witness);
-						let Either::Right(table_witness) = entry else {
-							unreachable!("entry is assigned to this pattern on the previous line")
-						};
-						Ok(table_witness)
+						return Err(Error::EmptyTable { table_id });
 					}
+					let mut table_witness = TableWitnessIndex::new(self.allocator, table, size)?;
+					fill(&mut table_witness)?;
+					*entry = Either::Right(table_witness);
+					Ok(())
 				}
 				Either::Right(_) => Err(Error::TableIndexAlreadyInitialized { table_id }),
 			},
@@ -100,6 +113,10 @@ impl<'cs, 'alloc, F: TowerField, P: PackedField<Scalar = F>> WitnessIndex<'cs, '
 		})
 	}
 
+	/// Initializes a table witness with one row per event and fills it in a single thread.
+	///
+	/// This is the fallback for fillers that cannot be shared across threads; see
+	/// [`Self::fill_table_parallel`].
 	pub fn fill_table_sequential<T: TableFiller<P>>(
 		&mut self,
 		filler: &T,
@@ -112,6 +129,11 @@ impl<'cs, 'alloc, F: TowerField, P: PackedField<Scalar = F>> WitnessIndex<'cs, '
 		)
 	}
 
+	/// Initializes a table witness with one row per event and fills it in multiple threads.
+	///
+	/// The events are split into chunks, and each chunk fills a disjoint [`TableWitnessSegment`]
+	/// concurrently, so the filler and the events must be [`Sync`]. The result is identical to
+	/// [`Self::fill_table_sequential`], which remains available for fillers that are not `Sync`.
 	pub fn fill_table_parallel<T>(&mut self, filler: &T, rows: &[T::Event]) -> Result<(), Error>
 	where
 		T: TableFiller<P> + Sync,
@@ -160,6 +182,17 @@ impl<'cs, 'alloc, F: TowerField, P: PackedField<Scalar = F>> WitnessIndex<'cs, '
 			.collect()
 	}
 
+	/// Returns the number of events the table with the given ID was filled with.
+	///
+	/// This is the table size before padding, as opposed to the padded
+	/// [`TableWitnessIndex::capacity`]. Returns 0 if the table has not been initialized.
+	pub fn filled_rows(&self, table_id: TableId) -> usize {
+		match self.tables.get(table_id) {
+			Some(Either::Right(index)) => index.size(),
+			_ => 0,
+		}
+	}
+
 	pub fn into_multilinear_extension_index(self) -> MultilinearExtensionIndex<'alloc, P>
 	where
 		P: PackedExtension<B1>
@@ -172,16 +205,18 @@ impl<'cs, 'alloc, F: TowerField, P: PackedField<Scalar = F>> WitnessIndex<'cs, '
 		let mut index = MultilinearExtensionIndex::new();
 		let mut first_oracle_id_in_table = 0;
 		for table_witness in self.tables {
-			let Either::Right(table_witness) = table_witness else {
+			let Either::Right(mut table_witness) = table_witness else {
 				continue;
 			};
+			table_witness.zero_padding_rows();
 			let table = table_witness.table();
+
 			let cols = immutable_witness_index_columns(table_witness.cols);
 
 			// Append oracles for constant columns that are repeated.
 			let mut count = 0;
-			for (oracle_id_offset, col) in cols.into_iter().enumerate() {
-				let oracle_id = OracleId::from_index(first_oracle_id_in_table + oracle_id_offset);
+			for col in cols.into_iter().flatten() {
+				let oracle_id = OracleId::from_index(first_oracle_id_in_table + count);
 				let log_capacity = if col.is_single_row {
 					0
 				} else {
@@ -257,6 +292,244 @@ where
 		}
 		Ok(())
 	}
+
+	/// Checks that the computed columns of every filled table hold the evaluation of their
+	/// expressions.
+	///
+	/// In addition to the columns checked by [`TableWitnessSegment::verify_computed_columns`],
+	/// this covers the columns added with
+	/// [`TableBuilder::add_computed_cross`](super::TableBuilder::add_computed_cross), whose
+	/// expressions read a column of another table. Returns the column and row of the first
+	/// mismatch, or `None` if all computed columns are consistent.
+	pub fn verify_computed_columns(&mut self) -> Result<Option<(ColumnId, usize)>, Error> {
+		for table_id in 0..self.tables.len() {
+			// Foreign tables of cross-table columns always have lower IDs.
+			let (foreign_tables, tables) = self.tables.split_at_mut(table_id);
+			let Either::Right(table_witness) = &mut tables[0] else {
+				continue;
+			};
+			let (size, capacity) = (table_witness.size(), table_witness.capacity());
+			let segment = table_witness.full_segment();
+			if let Some(mismatch) = segment.verify_computed_columns()? {
+				return Ok(Some(mismatch));
+			}
+
+			for (table_index, column) in segment.table.columns.iter().enumerate() {
+				let ColumnDef::ComputedCross {
+					cols,
+					foreign_col,
+					expr,
+				} = &column.col
+				else {
+					continue;
+				};
+
+				let foreign_witness = match foreign_tables.get_mut(foreign_col.table_id) {
+					Some(Either::Right(foreign_witness)) => foreign_witness,
+					Some(Either::Left(_)) | None => {
+						return Err(Error::CrossTableSizeMismatch {
+							column_id: column.id,
+							foreign_table_id: foreign_col.table_id,
+							size,
+							foreign_size: 0,
+						});
+					}
+				};
+				if foreign_witness.capacity() != capacity {
+					return Err(Error::CrossTableSizeMismatch {
+						column_id: column.id,
+						foreign_table_id: foreign_col.table_id,
+						size,
+						foreign_size: foreign_witness.size(),
+					});
+				}
+				let foreign_segment = foreign_witness.full_segment();
+
+				let col = segment.get_dyn(table_index)?;
+				let inputs = cols
+					.iter()
+					.map(|&col_index| segment.get_dyn(col_index))
+					.chain([foreign_segment.get_dyn(foreign_col.table_index)])
+					.collect::<Result<Vec<_>, _>>()?;
+				let n_values = capacity << column.shape.log_values_per_row;
+				if let Some(i) = first_expr_mismatch(&*col, &inputs, expr, n_values)? {
+					return Ok(Some((column.id, i >> column.shape.log_values_per_row)));
+				}
+			}
+		}
+		Ok(None)
+	}
+
+	/// Lowers committed columns to the smallest tower level that contains all of their values.
+	///
+	/// This is a post-fill pass: it scans the data of every committed column of the filled tables
+	/// and repacks the columns whose values fit in a smaller field. Columns referenced by the
+	/// definition of another column, such as the inner column of a packed or shifted column, keep
+	/// their tower level. Returns the new tower level of every lowered column, which must be
+	/// applied with [`ConstraintSystem::apply_tower_levels`] before compiling the constraint
+	/// system, by both the prover and the verifier.
+	///
+	/// Typed column handles refer to the original tower levels, so the witness should not be
+	/// accessed through them after this call, only converted with
+	/// [`Self::into_multilinear_extension_index`].
+	pub fn optimize_tower_levels(&mut self) -> Result<Vec<(ColumnId, usize)>, Error> {
+		let packed_elem_log_bits = P::LOG_WIDTH + <P::Scalar as TowerField>::TOWER_LEVEL;
+
+		let mut tower_levels = Vec::new();
+		for table_witness in self.tables.iter_mut() {
+			let Either::Right(table_witness) = table_witness else {
+				continue;
+			};
+			let table = table_witness.table;
+			let referenced = referenced_columns(table);
+
+			for col_info in &table.columns {
+				let table_index = col_info.id.table_index;
+				if !matches!(col_info.col, ColumnDef::Committed { .. }) || referenced[table_index] {
+					continue;
+				}
+
+				let col = &mut table_witness.cols[table_witness.oracle_offset + table_index];
+				let WitnessDataMut::Owned(data) = &mut col.data else {
+					continue;
+				};
+
+				let shape = col.shape;
+				let n_vars = table_witness.log_capacity + shape.log_values_per_row;
+				let underlier_count =
+					1 << (n_vars + shape.tower_height).saturating_sub(packed_elem_log_bits);
+				let values = multilin_poly_from_underlier_data(
+					&data[..underlier_count],
+					n_vars,
+					shape.tower_height,
+				);
+				let values = (0..1 << n_vars)
+					.map(|i| values.evaluate_on_hypercube(i))
+					.collect::<Result<Vec<_>, _>>()?;
+
+				let tower_level = match values
+					.iter()
+					.map(|value| value.min_tower_level())
+					.max()
+					.unwrap_or(0)
+				{
+					// Witness data is only supported for B1 and fields of at least 8 bits.
+					1 | 2 => 3,
+					tower_level => tower_level,
+				};
+				if tower_level >= shape.tower_height {
+					continue;
+				}
+
+				let new_data = self.allocator.alloc_slice_fill_default::<P>(
+					1 << (tower_level + n_vars).saturating_sub(packed_elem_log_bits),
+				);
+				{
+					let mut view = col_view_mut(new_data, tower_level);
+					for (i, value) in values.into_iter().enumerate() {
+						view.set(i, value)?;
+					}
+				}
+				*data = new_data;
+				col.shape.tower_height = tower_level;
+				tower_levels.push((col_info.id, tower_level));
+			}
+		}
+		Ok(tower_levels)
+	}
+}
+
+/// Sets all elements of a packed slice from index `start` onwards to zero.
+fn zero_tail<P: PackedField>(data: &mut [P], start: usize) {
+	let first_full = start.div_ceil(P::WIDTH).min(data.len());
+	for i in start..first_full * P::WIDTH {
+		set_packed_slice(data, i, P::Scalar::zero());
+	}
+	for packed in &mut data[first_full..] {
+		*packed = P::zero();
+	}
+}
+
+/// Returns, for each column of a table, whether another column's definition refers to it.
+fn referenced_columns<F: TowerField>(table: &Table<F>) -> Vec<bool> {
+	let mut referenced = vec![false; table.columns.len()];
+	for col_info in &table.columns {
+		match &col_info.col {
+			ColumnDef::Selected { col, .. }
+			| ColumnDef::Projected { col, .. }
+			| ColumnDef::ZeroPadded { col, .. }
+			| ColumnDef::Shifted { col, .. }
+			| ColumnDef::Packed { col, .. } => referenced[col.table_index] = true,
+			ColumnDef::StaticExp { bit_cols, .. } => {
+				for &bit_col in bit_cols {
+					referenced[bit_col] = true;
+				}
+			}
+			ColumnDef::DynamicExp { bit_cols, base, .. } => {
+				for &bit_col in bit_cols {
+					referenced[bit_col] = true;
+				}
+				referenced[*base] = true;
+			}
+			_ => (),
+		}
+	}
+	referenced
+}
+
+/// Returns a view of a witness column, following the columns that alias the data of another.
+fn witness_col_view<'a, P>(
+	cols: &'a [WitnessIndexColumn<'_, P>],
+	index: usize,
+) -> Box<dyn WitnessColView<P::Scalar> + 'a>
+where
+	P: PackedExtension<B1>
+		+ PackedExtension<B8>
+		+ PackedExtension<B16>
+		+ PackedExtension<B32>
+		+ PackedExtension<B64>
+		+ PackedExtension<B128>,
+	P::Scalar: TowerField,
+{
+	let tower_level = cols[index].shape.tower_height;
+	let mut data = &cols[index].data;
+	while let WitnessDataMut::SameAsOracleId(id) = data {
+		data = &cols[id.index()].data;
+	}
+	let WitnessDataMut::Owned(data) = data else {
+		panic!("virtual columns have no witness data");
+	};
+	match tower_level {
+		0 => Box::new(WitnessColViewImpl(PackedExtension::<B1>::cast_bases(data))),
+		3 => Box::new(WitnessColViewImpl(PackedExtension::<B8>::cast_bases(data))),
+		4 => Box::new(WitnessColViewImpl(PackedExtension::<B16>::cast_bases(data))),
+		5 => Box::new(WitnessColViewImpl(PackedExtension::<B32>::cast_bases(data))),
+		6 => Box::new(WitnessColViewImpl(PackedExtension::<B64>::cast_bases(data))),
+		7 => Box::new(WitnessColViewImpl(PackedExtension::<B128>::cast_bases(data))),
+		_ => panic!("tower_level must be in the range [0, 7]"),
+	}
+}
+
+/// Returns a mutable view of column data packed at the given tower level.
+fn col_view_mut<P>(data: &mut [P], tower_level: usize) -> Box<dyn WitnessColViewMut<P::Scalar> + '_>
+where
+	P: PackedExtension<B1>
+		+ PackedExtension<B8>
+		+ PackedExtension<B16>
+		+ PackedExtension<B32>
+		+ PackedExtension<B64>
+		+ PackedExtension<B128>,
+	P::Scalar: TowerField,
+{
+	match tower_level {
+		0 => Box::new(WitnessColViewImpl(PackedExtension::<B1>::cast_bases_mut(data))),
+		3 => Box::new(WitnessColViewImpl(PackedExtension::<B8>::cast_bases_mut(data))),
+		4 => Box::new(WitnessColViewImpl(PackedExtension::<B16>::cast_bases_mut(data))),
+		5 => Box::new(WitnessColViewImpl(PackedExtension::<B32>::cast_bases_mut(data))),
+		6 => Box::new(WitnessColViewImpl(PackedExtension::<B64>::cast_bases_mut(data))),
+		7 => Box::new(WitnessColViewImpl(PackedExtension::<B128>::cast_bases_mut(data))),
+		_ => panic!("tower_level must be in the range [0, 7]"),
+	}
 }
 
 fn multilin_poly_from_underlier_data<P>(
@@ -332,6 +605,8 @@ pub struct WitnessIndexColumn<'a, P: PackedField> {
 enum WitnessColumnInfo<T> {
 	Owned(T),
 	SameAsOracleId(OracleId),
+	/// A virtual computed column, which has no witness data.
+	Virtual,
 }
 
 type WitnessDataMut<'a, P> = WitnessColumnInfo<&'a mut [P]>;
@@ -353,19 +628,23 @@ pub struct ImmutableWitnessIndexColumn<'a, P: PackedField> {
 
 /// Converts the vector of witness columns into immutable references to column data that may be
 /// shared.
+///
+/// Virtual columns have no data and are mapped to `None`.
 fn immutable_witness_index_columns<P: PackedField>(
 	cols: Vec<WitnessIndexColumn<P>>,
-) -> Vec<ImmutableWitnessIndexColumn<P>> {
-	let mut result = Vec::<ImmutableWitnessIndexColumn<_>>::with_capacity(cols.len());
+) -> Vec<Option<ImmutableWitnessIndexColumn<P>>> {
+	let mut result = Vec::<Option<ImmutableWitnessIndexColumn<_>>>::with_capacity(cols.len());
 	for col in cols {
-		result.push(ImmutableWitnessIndexColumn {
+		let data = match col.data {
+			WitnessDataMut::Owned(data) => Some(data as &[P]),
+			WitnessDataMut::SameAsOracleId(id) => result[id.index()].as_ref().map(|col| col.data),
+			WitnessDataMut::Virtual => None,
+		};
+		result.push(data.map(|data| ImmutableWitnessIndexColumn {
 			shape: col.shape,
-			data: match col.data {
-				WitnessDataMut::Owned(data) => data,
-				WitnessDataMut::SameAsOracleId(id) => result[id.index()].data,
-			},
+			data,
 			is_single_row: col.is_single_row,
-		});
+		}));
 	}
 	result
 }
@@ -416,6 +695,7 @@ impl<'cs, 'alloc, F: TowerField, P: PackedField<Scalar = F>> TableWitnessIndex<'
 				ColumnDef::Constant { .. } => WitnessDataMut::SameAsOracleId(
 					transparent_single_backing[col.id.table_index].unwrap(),
 				),
+				ColumnDef::ComputedVirtual { .. } => WitnessDataMut::Virtual,
 				_ => WitnessDataMut::new_owned(
 					allocator,
 					(col.shape.log_cell_size() + log_capacity).saturating_sub(packed_elem_log_bits),
@@ -464,6 +744,7 @@ impl<'cs, 'alloc, F: TowerField, P: PackedField<Scalar = F>> TableWitnessIndex<'
 			.map(|col| match &mut col.data {
 				WitnessDataMut::SameAsOracleId(id) => RefCellData::SameAsOracleId(*id),
 				WitnessDataMut::Owned(data) => RefCellData::Owned(RefCell::new(data)),
+				WitnessDataMut::Virtual => RefCellData::Virtual,
 			})
 			.collect();
 		TableWitnessSegment {
@@ -593,6 +874,7 @@ impl<'cs, 'alloc, F: TowerField, P: PackedField<Scalar = F>> TableWitnessIndex<'
 			.map(|col| match col {
 				RefCellData::Owned(data) => WitnessColumnInfo::Owned(data.get_mut()),
 				RefCellData::SameAsOracleId(idx) => WitnessColumnInfo::SameAsOracleId(*idx),
+				RefCellData::Virtual => WitnessColumnInfo::Virtual,
 			})
 			.collect::<Vec<_>>();
 
@@ -693,6 +975,7 @@ impl<'cs, 'alloc, F: TowerField, P: PackedField<Scalar = F>> TableWitnessIndex<'
 			.map(|col| match col {
 				RefCellData::Owned(data) => WitnessColumnInfo::Owned(data.get_mut()),
 				RefCellData::SameAsOracleId(idx) => WitnessColumnInfo::SameAsOracleId(*idx),
+				RefCellData::Virtual => WitnessColumnInfo::Virtual,
 			})
 			.collect::<Vec<_>>();
 
@@ -725,6 +1008,83 @@ impl<'cs, 'alloc, F: TowerField, P: PackedField<Scalar = F>> TableWitnessIndex<'
 	}
 }
 
+impl<P> TableWitnessIndex<'_, '_, P>
+where
+	P: PackedField<Scalar: TowerField>
+		+ PackedExtension<B1>
+		+ PackedExtension<B8>
+		+ PackedExtension<B16>
+		+ PackedExtension<B32>
+		+ PackedExtension<B64>
+		+ PackedExtension<B128>,
+{
+	/// Zeroes the zero-padded columns past the table height, where the filler wrote copies of the
+	/// last event.
+	///
+	/// The computed columns that depend on a zero-padded column are then re-evaluated over the
+	/// padding rows, so that they match the zeroed values. This reads the witness column shapes,
+	/// which reflect any tower level lowered by [`WitnessIndex::optimize_tower_levels`].
+	fn zero_padding_rows(&mut self) {
+		let table = self.table;
+		let padding_dependents = table.zero_padding_dependents();
+		if !padding_dependents.contains(&true) {
+			return;
+		}
+
+		let offset = self.oracle_offset;
+		let cols = &mut self.cols;
+		for (table_index, column) in table.columns.iter().enumerate() {
+			if column.is_zero_padded {
+				let shape = cols[offset + table_index].shape;
+				if let WitnessDataMut::Owned(data) = &mut cols[offset + table_index].data {
+					zero_tail(
+						PackedExtension::<B1>::cast_bases_mut(data),
+						self.size << shape.log_cell_size(),
+					);
+				}
+			} else if let ColumnDef::Computed { cols: inputs, expr } = &column.col {
+				if !inputs
+					.iter()
+					.any(|&col_index| padding_dependents[col_index])
+				{
+					continue;
+				}
+
+				let log_values_per_row = cols[offset + table_index].shape.log_values_per_row;
+				let padding =
+					self.size << log_values_per_row..1 << (self.log_capacity + log_values_per_row);
+				let values = {
+					let inputs = inputs
+						.iter()
+						.map(|&col_index| witness_col_view(cols, offset + col_index))
+						.collect::<Vec<_>>();
+					let mut query = vec![P::Scalar::default(); inputs.len()];
+					padding
+						.clone()
+						.map(|i| {
+							for (value, input) in iter::zip(&mut query, &inputs) {
+								*value = input.get(i);
+							}
+							expr.evaluate(&query)
+								.expect("the query has one value per expression variable")
+						})
+						.collect::<Vec<_>>()
+				};
+
+				let tower_level = cols[offset + table_index].shape.tower_height;
+				let WitnessDataMut::Owned(data) = &mut cols[offset + table_index].data else {
+					unreachable!("computed columns own their data");
+				};
+				let mut col = col_view_mut(data, tower_level);
+				for (i, value) in iter::zip(padding, values) {
+					col.set(i, value)
+						.expect("the expression maps the column inputs into the column field");
+				}
+			}
+		}
+	}
+}
+
 /// A view over a table witness that splits the table into segments.
 ///
 /// The purpose of this struct is to implement the `split_at` method, which safely splits the view
@@ -761,6 +1121,7 @@ impl<'a, F: TowerField, P: PackedField<Scalar = F>> TableWitnessSegmentedView<'a
 					WitnessColumnInfo::Owned((&mut **data, 1 << chunk_size))
 				}
 				WitnessColumnInfo::SameAsOracleId(id) => WitnessColumnInfo::SameAsOracleId(*id),
+				WitnessColumnInfo::Virtual => WitnessColumnInfo::Virtual,
 			})
 			.collect::<Vec<_>>();
 		Self {
@@ -792,6 +1153,9 @@ impl<'a, F: TowerField, P: PackedField<Scalar = F>> TableWitnessSegmentedView<'a
 				WitnessColumnInfo::SameAsOracleId(id) => {
 					(WitnessColumnInfo::SameAsOracleId(*id), WitnessColumnInfo::SameAsOracleId(*id))
 				}
+				WitnessColumnInfo::Virtual => {
+					(WitnessColumnInfo::Virtual, WitnessColumnInfo::Virtual)
+				}
 			})
 			.unzip();
 		(
@@ -834,9 +1198,16 @@ impl<'a, F: TowerField, P: PackedField<Scalar = F>> TableWitnessSegmentedView<'a
 							data.chunks_mut(chunk_size)
 								.map(|chunk| RefCellData::Owned(RefCell::new(chunk))),
 						),
-						WitnessColumnInfo::SameAsOracleId(id) => itertools::Either::Right(
-							iter::repeat_n(id, n_segments).map(RefCellData::SameAsOracleId),
-						),
+						WitnessColumnInfo::SameAsOracleId(id) => {
+							itertools::Either::Right(itertools::Either::Left(
+								iter::repeat_n(id, n_segments).map(RefCellData::SameAsOracleId),
+							))
+						}
+						WitnessColumnInfo::Virtual => {
+							itertools::Either::Right(itertools::Either::Right(
+								iter::repeat_with(|| RefCellData::Virtual).take(n_segments),
+							))
+						}
 					})
 					.collect(),
 			)
@@ -882,6 +1253,7 @@ impl<'a, F: TowerField, P: PackedField<Scalar = F>> TableWitnessSegmentedView<'a
 						WitnessColumnInfo::Owned((data, chunk_size))
 					}
 					WitnessColumnInfo::SameAsOracleId(id) => WitnessColumnInfo::SameAsOracleId(id),
+					WitnessColumnInfo::Virtual => WitnessColumnInfo::Virtual,
 				}
 			})
 			.collect::<Vec<_>>();
@@ -891,6 +1263,7 @@ impl<'a, F: TowerField, P: PackedField<Scalar = F>> TableWitnessSegmentedView<'a
 				.iter()
 				.map(|col| match col {
 					WitnessColumnInfo::SameAsOracleId(id) => RefCellData::SameAsOracleId(*id),
+					WitnessColumnInfo::Virtual => RefCellData::Virtual,
 					WitnessColumnInfo::Owned((data, chunk_size)) => {
 						RefCellData::Owned(RefCell::new(unsafe {
 							// Safety: The function borrows self mutably, so we have mutable access
@@ -1018,10 +1391,14 @@ impl<'a, F: TowerField, P: PackedField<Scalar = F>> TableWitnessSegment<'a, P> {
 		Ok(Ref::map(col_ref, |col| must_cast_slice(P::unpack_scalars(col))))
 	}
 
+	/// Mutably borrows a column of the segment as a slice of `T` values.
+	///
+	/// The returned view knows the segment height and the number of `T` values per row, and
+	/// provides the bounds-checked [`ColumnViewMut::set`] accessor in addition to raw slice access.
 	pub fn get_mut_as<T: Pod, FSub: TowerField, const V: usize>(
 		&self,
 		col: Col<FSub, V>,
-	) -> Result<RefMut<[T]>, Error>
+	) -> Result<ColumnViewMut<T>, Error>
 	where
 		P: PackedExtension<FSub> + PackedFieldIndexable,
 		F: ExtensionField<FSub> + Pod,
@@ -1033,11 +1410,16 @@ impl<'a, F: TowerField, P: PackedField<Scalar = F>> TableWitnessSegment<'a, P> {
 			});
 		}
 
+		let name = &self.table.columns[col.table_index].name;
 		let col = self
 			.get_col_data(col.table_index)
 			.ok_or_else(|| Error::MissingColumn(col.id()))?;
 		let col_ref = col.try_borrow_mut().map_err(Error::WitnessBorrowMut)?;
-		Ok(RefMut::map(col_ref, |col| must_cast_slice_mut(P::unpack_scalars_mut(col))))
+		Ok(ColumnViewMut {
+			data: RefMut::map(col_ref, |col| must_cast_slice_mut(P::unpack_scalars_mut(col))),
+			name,
+			log_height: self.log_size,
+		})
 	}
 
 	/// Evaluate an expression over columns that are assumed to be already populated.
@@ -1118,7 +1500,7 @@ impl<'a, F: TowerField, P: PackedField<Scalar = F>> TableWitnessSegment<'a, P> {
 		match self.cols.get(oracle_id.index()) {
 			Some(RefCellData::Owned(data)) => Some(data),
 			Some(RefCellData::SameAsOracleId(id)) => self.get_col_data_by_oracle_offset(*id),
-			None => None,
+			Some(RefCellData::Virtual) | None => None,
 		}
 	}
 }
@@ -1206,6 +1588,53 @@ where
 		};
 		Ok(ret)
 	}
+
+	/// Checks that every column added with
+	/// [`TableBuilder::add_computed`](super::TableBuilder::add_computed) holds the evaluation of
+	/// its expression over the other columns.
+	///
+	/// This is meant for debugging table fillers, as it evaluates the expressions one value at a
+	/// time. Returns the column and row of the first mismatch, or `None` if all computed columns
+	/// are consistent. Columns that depend on another table are checked by
+	/// [`WitnessIndex::verify_computed_columns`].
+	pub fn verify_computed_columns(&self) -> Result<Option<(ColumnId, usize)>, Error> {
+		for (table_index, column) in self.table.columns.iter().enumerate() {
+			let ColumnDef::Computed { cols, expr } = &column.col else {
+				continue;
+			};
+
+			let col = self.get_dyn(table_index)?;
+			let inputs = cols
+				.iter()
+				.map(|&col_index| self.get_dyn(col_index))
+				.collect::<Result<Vec<_>, _>>()?;
+			let n_values = self.size() << column.shape.log_values_per_row;
+			if let Some(i) = first_expr_mismatch(&*col, &inputs, expr, n_values)? {
+				return Ok(Some((column.id, i >> column.shape.log_values_per_row)));
+			}
+		}
+		Ok(None)
+	}
+}
+
+/// Returns the index of the first of the first `n_values` values of `col` that differs from the
+/// evaluation of `expr` over `inputs`.
+fn first_expr_mismatch<F: TowerField>(
+	col: &dyn WitnessColView<F>,
+	inputs: &[Box<dyn WitnessColView<F> + '_>],
+	expr: &ArithCircuit<F>,
+	n_values: usize,
+) -> Result<Option<usize>, Error> {
+	let mut query = vec![F::default(); inputs.len()];
+	for i in 0..n_values {
+		for (value, input) in iter::zip(&mut query, inputs) {
+			*value = input.get(i);
+		}
+		if expr.evaluate(&query)? != col.get(i) {
+			return Ok(Some(i));
+		}
+	}
+	Ok(None)
 }
 
 /// Type erased interface for viewing witness columns. Note that `F` will be an extension field of
@@ -1254,6 +1683,68 @@ where
 	}
 }
 
+/// A mutable view of a column in a [`TableWitnessSegment`] as a slice of `T` values.
+///
+/// Values are laid out row by row, so the value in `lane` of `row` is at index
+/// `row * values_per_row + lane`, where the lanes are counted in units of `T`. The view
+/// dereferences to the raw slice, whose indexing only checks the flattened index against the slice
+/// length; [`Self::set`] checks the row and lane separately.
+#[derive(Debug)]
+pub struct ColumnViewMut<'a, T> {
+	data: RefMut<'a, [T]>,
+	name: &'a str,
+	log_height: usize,
+}
+
+impl<T> ColumnViewMut<'_, T> {
+	/// The number of rows in the segment.
+	pub fn height(&self) -> usize {
+		1 << self.log_height
+	}
+
+	/// The number of `T` values in each row.
+	pub fn values_per_row(&self) -> usize {
+		self.data.len() >> self.log_height
+	}
+
+	/// Writes the value in `lane` of `row`, returning an error naming the column if either is out
+	/// of range.
+	pub fn set(&mut self, row: usize, lane: usize, value: T) -> Result<(), Error> {
+		let height = self.height();
+		let values_per_row = self.values_per_row();
+		if row >= height || lane >= values_per_row {
+			return Err(Error::ColumnIndexOutOfRange {
+				name: self.name.to_string(),
+				row,
+				lane,
+				height,
+				values_per_row,
+			});
+		}
+		self.data[row * values_per_row + lane] = value;
+		Ok(())
+	}
+
+	/// Returns the raw slice of column values, for hot paths that index it directly.
+	pub fn as_raw_mut(&mut self) -> &mut [T] {
+		&mut self.data
+	}
+}
+
+impl<T> Deref for ColumnViewMut<'_, T> {
+	type Target = [T];
+
+	fn deref(&self) -> &[T] {
+		&self.data
+	}
+}
+
+impl<T> DerefMut for ColumnViewMut<'_, T> {
+	fn deref_mut(&mut self) -> &mut [T] {
+		&mut self.data
+	}
+}
+
 /// A struct that can populate segments of a table witness using row descriptors.
 pub trait TableFiller<P = PackedType<OptimalUnderlier, B128>>
 where
@@ -1445,10 +1936,10 @@ mod tests {
 
 		// Fill the columns with a deterministic pattern.
 		{
-			let mut col0: RefMut<'_, [u32]> = segment.get_mut_as(col0).unwrap();
-			let mut col1: RefMut<'_, [[u8; 8]]> = segment.get_mut_as(col1).unwrap();
-			let mut col2: RefMut<'_, [[u16; 4]]> = segment.get_mut_as(col2).unwrap();
-			let mut col3: RefMut<'_, [[u8; 8]]> = segment.get_mut_as(col3).unwrap();
+			let mut col0: ColumnViewMut<'_, u32> = segment.get_mut_as(col0).unwrap();
+			let mut col1: ColumnViewMut<'_, [u8; 8]> = segment.get_mut_as(col1).unwrap();
+			let mut col2: ColumnViewMut<'_, [u16; 4]> = segment.get_mut_as(col2).unwrap();
+			let mut col3: ColumnViewMut<'_, [u8; 8]> = segment.get_mut_as(col3).unwrap();
 
 			col0[0] = 0x40;
 			col1[0] = [0x45, 0, 0, 0, 0, 0, 0, 0];
@@ -1470,6 +1961,42 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn test_get_mut_as_bounds_checked() {
+		let table_id = 0;
+		let mut inner_table = Table::<B128>::new(table_id, "table".to_string());
+		let mut table = TableBuilder::new(&mut inner_table);
+		let col = table.add_committed::<B32, 4>("col");
+
+		let allocator = Bump::new();
+		let mut index = TableWitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(
+			&allocator,
+			&inner_table,
+			4,
+		)
+		.unwrap();
+		let segment = index.full_segment();
+		let height = segment.size();
+
+		let mut col: ColumnViewMut<'_, u32> = segment.get_mut_as(col).unwrap();
+		assert_eq!(col.height(), height);
+		assert_eq!(col.values_per_row(), 4);
+
+		col.set(1, 3, 0xdead).unwrap();
+		assert_eq!(col.as_raw_mut()[7], 0xdead);
+		assert_eq!(col[7], 0xdead);
+
+		assert_matches!(
+			col.set(0, 4, 1),
+			Err(Error::ColumnIndexOutOfRange { name, row: 0, lane: 4, values_per_row: 4, .. })
+				if name == "col"
+		);
+		assert_matches!(
+			col.set(height, 0, 1),
+			Err(Error::ColumnIndexOutOfRange { row, .. }) if row == height
+		);
+	}
+
 	#[test]
 	fn test_small_tables() {
 		let table_id = 0;
@@ -1654,6 +2181,23 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn test_filled_rows() {
+		let mut cs = ConstraintSystem::new();
+		let test_table = TestTable::new(&mut cs);
+
+		let allocator = Bump::new();
+		let mut index = WitnessIndex::new(&cs, &allocator);
+		assert_eq!(index.filled_rows(test_table.id()), 0);
+
+		index
+			.fill_table_sequential(&test_table, &[1, 2, 3, 4, 5])
+			.unwrap();
+
+		assert_eq!(index.filled_rows(test_table.id()), 5);
+		assert_eq!(index.get_table(test_table.id()).unwrap().capacity(), 8);
+	}
+
 	#[test]
 	fn test_dyn_witness() {
 		let mut cs = ConstraintSystem::new();
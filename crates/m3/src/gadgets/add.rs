@@ -10,7 +10,10 @@ use binius_field::{
 use itertools::izip;
 
 use crate::builder::{
-	column::Col, types::B1, witness::TableWitnessSegment, TableBuilder, B128, B32, B64,
+	column::Col,
+	types::B1,
+	witness::{ColumnViewMut, TableWitnessSegment},
+	TableBuilder, B128, B32, B64,
 };
 
 /// A gadget for performing 32-bit integer addition on vertically-packed bit columns.
@@ -94,7 +97,7 @@ impl U32Add {
 	where
 		P: PackedFieldIndexable<Scalar = B128> + PackedExtension<B1>,
 	{
-		let xin: std::cell::RefMut<'_, [u32]> = index.get_mut_as(self.xin)?;
+		let xin: ColumnViewMut<'_, u32> = index.get_mut_as(self.xin)?;
 		let yin = index.get_mut_as(self.yin)?;
 		let mut cout = index.get_mut_as(self.cout)?;
 		let mut zout = index.get_mut_as(self.zout)?;
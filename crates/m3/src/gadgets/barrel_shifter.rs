@@ -1,11 +1,11 @@
 // Copyright 2025 Irreducible Inc.
 
-use std::cell::RefMut;
-
 use binius_core::oracle::ShiftVariant;
 use binius_field::{packed::set_packed_slice, Field, PackedExtension, PackedFieldIndexable};
 
-use crate::builder::{upcast_col, Col, Expr, TableBuilder, TableWitnessSegment, B1, B128, B32};
+use crate::builder::{
+	upcast_col, Col, ColumnViewMut, Expr, TableBuilder, TableWitnessSegment, B1, B128, B32,
+};
 
 /// Maximum number of bits of the shift amount, i.e. 0 < shift_amount < 1 <<
 /// SHIFT_MAX_BITS - 1 = 31 where dst_val = src_val >> shift_amount or dst_val =
@@ -115,8 +115,8 @@ impl BarrelShifter {
 	where
 		P: PackedFieldIndexable<Scalar = B128> + PackedExtension<B1>,
 	{
-		let input: RefMut<'_, [u32]> = index.get_mut_as(self.input).unwrap();
-		let shift_amount: RefMut<'_, [u16]> = index.get_mut_as(self.shift_amount).unwrap();
+		let input: ColumnViewMut<'_, u32> = index.get_mut_as(self.input).unwrap();
+		let shift_amount: ColumnViewMut<'_, u16> = index.get_mut_as(self.shift_amount).unwrap();
 		let mut partial_shift: [_; MAX_SHIFT_BITS] =
 			array_util::try_from_fn(|i| index.get_mut_as(self.partial_shift[i]))?;
 		let mut shifted: [_; MAX_SHIFT_BITS] =
@@ -0,0 +1,460 @@
+// Copyright 2025 Irreducible Inc.
+
+//! A gadget for hash chains, which link the rows of a table through a compression function.
+
+use std::array;
+
+use anyhow::Result;
+use array_util::ArrayExt;
+use binius_core::{constraint_system::channel::ChannelId, oracle::ShiftVariant};
+use binius_field::{
+	linear_transformation::PackedTransformationFactory,
+	packed::{get_packed_slice, set_packed_slice},
+	Field, PackedExtension, PackedFieldIndexable, PackedSubfield,
+};
+use binius_math::{ArithCircuit, ArithExpr};
+
+use super::groestl::populate_state;
+use crate::builder::{
+	upcast_col, Col, Expr, FlushOpts, TableBuilder, TableWitnessSegment, B1, B128, B64, B8,
+};
+
+/// A compression function $f(h, m)$ on 512-bit states that can be verified in a table.
+///
+/// States and message blocks are eight columns of 8 [`B8`] values, in the representation of
+/// [`groestl::Permutation`](super::groestl::Permutation).
+pub trait ChainHasher {
+	/// The gadget verifying one compression in every row.
+	type Compression;
+
+	/// Adds the columns and constraints verifying the compression of `state_in` and `message`.
+	fn add_compression(
+		&self,
+		table: &mut TableBuilder,
+		state_in: [Col<B8, 8>; 8],
+		message: [Col<B8, 8>; 8],
+	) -> Self::Compression;
+
+	/// Returns the output state columns of a compression gadget.
+	fn state_out(compression: &Self::Compression) -> [Col<B8, 8>; 8];
+
+	/// Populates a compression gadget whose input state and message columns are populated.
+	fn populate<P>(
+		compression: &Self::Compression,
+		index: &mut TableWitnessSegment<P>,
+	) -> Result<()>
+	where
+		P: PackedFieldIndexable<Scalar = B128> + PackedExtension<B1> + PackedExtension<B8>,
+		PackedSubfield<P, B8>: PackedTransformationFactory<PackedSubfield<P, B8>>;
+
+	/// Computes the compression function out of circuit.
+	fn compress(&self, state: &[B8; 64], message: &[B8; 64]) -> [B8; 64];
+}
+
+/// A hash chain with one link per table row, verifying `chain[i] = f(chain[i - 1], data[i])`.
+///
+/// The previous state of every row but the first is constrained to equal the next state of the
+/// row before it, through a column shifted by one row. Since shifts across rows need a known
+/// column size, the table has a fixed size of `2^log_len` rows. The first row pulls its previous
+/// state from the chain channel and the last row pushes its next state, so the statement anchors
+/// the chain with boundaries built by [`flush_values`].
+#[derive(Debug, Clone)]
+pub struct HashChain<H: ChainHasher> {
+	hasher: H,
+	// Inputs
+	pub data: [Col<B8, 8>; 8],
+	// Private
+	compression: H::Compression,
+	/// The next states packed into single values, shifted down by one row.
+	prev_link: [Col<B64>; 8],
+	/// Selects the first row.
+	first: Col<B1>,
+	/// Selects the last row.
+	last: Col<B1>,
+	// Outputs
+	pub prev: [Col<B8, 8>; 8],
+	pub next: [Col<B8, 8>; 8],
+}
+
+impl TableBuilder<'_> {
+	/// Adds a hash chain of `2^log_len` links over the `data` message blocks, one per row.
+	///
+	/// This requires the table to have a fixed size of `2^log_len` rows. See [`HashChain`].
+	pub fn add_hash_chain<H: ChainHasher>(
+		&mut self,
+		name: impl ToString,
+		chain_channel: ChannelId,
+		log_len: usize,
+		data: [Col<B8, 8>; 8],
+		hasher: H,
+	) -> HashChain<H> {
+		self.require_fixed_size(log_len);
+		let mut table = self.with_namespace(name);
+
+		let prev = table.add_committed_multiple("prev");
+		let compression =
+			hasher.add_compression(&mut table.with_namespace("compression"), prev, data);
+		let next = H::state_out(&compression);
+
+		let prev_packed: [Col<B64>; 8] =
+			array::from_fn(|i| table.add_packed(format!("prev_packed[{i}]"), prev[i]));
+		let next_packed: [Col<B64>; 8] =
+			array::from_fn(|i| table.add_packed(format!("next_packed[{i}]"), next[i]));
+		let prev_link = array::from_fn(|i| {
+			table.add_shifted(
+				format!("prev_link[{i}]"),
+				next_packed[i],
+				log_len,
+				1,
+				ShiftVariant::LogicalLeft,
+			)
+		});
+
+		let first = table.add_fixed(
+			"first",
+			ArithCircuit::from(
+				&(0..log_len)
+					.map(|i| ArithExpr::Var(i) + ArithExpr::one())
+					.product::<ArithExpr<B128>>(),
+			),
+		);
+		let last = table.add_fixed(
+			"last",
+			ArithCircuit::from(
+				&(0..log_len)
+					.map(ArithExpr::Var)
+					.product::<ArithExpr<B128>>(),
+			),
+		);
+
+		for i in 0..8 {
+			table.assert_zero(
+				format!("link[{i}]"),
+				(prev_packed[i] - prev_link[i]) * (Expr::from(upcast_col(first)) + B64::ONE),
+			);
+		}
+		table.pull_with_opts(
+			chain_channel,
+			prev_packed,
+			FlushOpts {
+				selectors: vec![first],
+				..Default::default()
+			},
+		);
+		table.push_with_opts(
+			chain_channel,
+			next_packed,
+			FlushOpts {
+				selectors: vec![last],
+				..Default::default()
+			},
+		);
+
+		HashChain {
+			hasher,
+			data,
+			compression,
+			prev_link,
+			first,
+			last,
+			prev,
+			next,
+		}
+	}
+}
+
+impl<H: ChainHasher> HashChain<H> {
+	/// Populates the witness by computing the chain from `init_state` over the data columns, and
+	/// returns the final state.
+	///
+	/// The data columns must be populated beforehand, and `index` must be the full table segment,
+	/// since every row depends on the one before it.
+	pub fn populate<P>(
+		&self,
+		index: &mut TableWitnessSegment<P>,
+		init_state: &[B8; 64],
+	) -> Result<[B8; 64]>
+	where
+		P: PackedFieldIndexable<Scalar = B128>
+			+ PackedExtension<B1>
+			+ PackedExtension<B8>
+			+ PackedExtension<B64>,
+		PackedSubfield<P, B8>: PackedTransformationFactory<PackedSubfield<P, B8>>,
+	{
+		let data = {
+			let data = self.data.try_map_ext(|data_i| index.get(data_i))?;
+			(0..index.size())
+				.map(|k| array::from_fn(|ij| get_packed_slice(&data[ij % 8], k * 8 + ij / 8)))
+				.collect::<Vec<[B8; 64]>>()
+		};
+
+		let mut states = Vec::with_capacity(data.len() + 1);
+		states.push(*init_state);
+		for data_k in &data {
+			let state = self
+				.hasher
+				.compress(states.last().expect("states is non-empty"), data_k);
+			states.push(state);
+		}
+
+		self.populate_rows(index, &states[..data.len()], &states[1..])?;
+		Ok(states[data.len()])
+	}
+
+	/// Populates every row from its previous state and its next state, which must be the
+	/// compression of the previous state with the row's data.
+	fn populate_rows<P>(
+		&self,
+		index: &mut TableWitnessSegment<P>,
+		prev_states: &[[B8; 64]],
+		next_states: &[[B8; 64]],
+	) -> Result<()>
+	where
+		P: PackedFieldIndexable<Scalar = B128>
+			+ PackedExtension<B1>
+			+ PackedExtension<B8>
+			+ PackedExtension<B64>,
+		PackedSubfield<P, B8>: PackedTransformationFactory<PackedSubfield<P, B8>>,
+	{
+		populate_state(index, self.prev, prev_states)?;
+		H::populate(&self.compression, index)?;
+
+		for (i, prev_link_i) in self.prev_link.into_iter().enumerate() {
+			let mut prev_link_i = index.get_mut_as::<u64, _, 1>(prev_link_i)?;
+			prev_link_i[0] = 0;
+			for (k, next_state) in next_states[..next_states.len() - 1].iter().enumerate() {
+				prev_link_i[k + 1] = pack_state_column(next_state, i);
+			}
+		}
+
+		let n_rows = index.size();
+		let mut first = index.get_mut(self.first)?;
+		let mut last = index.get_mut(self.last)?;
+		for k in 0..n_rows {
+			set_packed_slice(&mut first, k, B1::from(k == 0));
+			set_packed_slice(&mut last, k, B1::from(k == n_rows - 1));
+		}
+		Ok(())
+	}
+}
+
+/// Returns the values flushed to the chain channel for a chaining state.
+///
+/// These are used to construct the boundaries that anchor a [`HashChain`].
+pub fn flush_values(state: &[B8; 64]) -> Vec<B128> {
+	(0..8)
+		.map(|i| B128::from(B64::new(pack_state_column(state, i))))
+		.collect()
+}
+
+/// Packs the 8 values of a state column into the bytes of a 64-bit value.
+fn pack_state_column(state: &[B8; 64], i: usize) -> u64 {
+	(0..8).fold(0u64, |acc, j| acc | (u64::from(u8::from(state[j * 8 + i])) << (8 * j)))
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use binius_field::{
+		arch::OptimalUnderlier128b, arithmetic_traits::InvertOrZero, as_packed_field::PackedType,
+		AESTowerField8b,
+	};
+	use bumpalo::Bump;
+	use rand::{prelude::StdRng, SeedableRng};
+
+	use super::{
+		super::groestl::{
+			add_round_constant, compress, mix_bytes, permute, sbox, shift_bytes,
+			GroestlCompression, PermutationVariant, MIX_BYTES_VEC,
+		},
+		*,
+	};
+	use crate::builder::{Boundary, ConstraintSystem, FlushDirection, Statement, WitnessIndex};
+
+	type P = PackedType<OptimalUnderlier128b, B128>;
+
+	fn random_state(rng: &mut StdRng) -> [B8; 64] {
+		array::from_fn(|_| B8::random(&mut *rng))
+	}
+
+	/// Validates a hash chain over `data`, filled by `fill`, against boundaries anchoring it at
+	/// `init_state` and `final_state`.
+	fn validate_hash_chain(
+		data: &[[B8; 64]],
+		init_state: &[B8; 64],
+		final_state: &[B8; 64],
+		fill: impl FnOnce(&HashChain<GroestlCompression>, &mut TableWitnessSegment<P>),
+	) -> Result<(), binius_core::constraint_system::error::Error> {
+		let mut cs = ConstraintSystem::new();
+		let chain_channel = cs.add_channel("hash_chain");
+		let mut table = cs.add_table("hash chain test");
+		let table_id = table.id();
+
+		let data_cols = table.add_committed_multiple::<B8, 8, 8>("data");
+		let chain = table.add_hash_chain(
+			"chain",
+			chain_channel,
+			data.len().ilog2() as usize,
+			data_cols,
+			GroestlCompression,
+		);
+
+		let allocator = Bump::new();
+		let mut witness = WitnessIndex::<P>::new(&cs, &allocator);
+		let table_witness = witness.init_table(table_id, data.len()).unwrap();
+		let mut segment = table_witness.full_segment();
+		populate_state(&mut segment, data_cols, data).unwrap();
+		fill(&chain, &mut segment);
+		drop(segment);
+
+		let boundaries = vec![
+			Boundary {
+				values: flush_values(init_state),
+				channel_id: chain_channel,
+				direction: FlushDirection::Push,
+				multiplicity: 1,
+			},
+			Boundary {
+				values: flush_values(final_state),
+				channel_id: chain_channel,
+				direction: FlushDirection::Pull,
+				multiplicity: 1,
+			},
+		];
+		let statement = Statement {
+			boundaries,
+			table_sizes: witness.table_sizes(),
+		};
+		let ccs = cs.compile(&statement).unwrap();
+		let witness = witness.into_multilinear_extension_index();
+
+		binius_core::constraint_system::validate::validate_witness(
+			&ccs,
+			&statement.boundaries,
+			&witness,
+		)
+	}
+
+	/// Returns the states of the chain over `data`, starting at `init_state`.
+	fn chain_states(init_state: &[B8; 64], data: &[[B8; 64]]) -> Vec<[B8; 64]> {
+		let mut states = vec![*init_state];
+		for data_k in data {
+			states.push(compress(states.last().expect("states is non-empty"), data_k));
+		}
+		states
+	}
+
+	#[test]
+	fn test_hash_chain() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let init_state = random_state(&mut rng);
+		let data = repeat_with(|| random_state(&mut rng))
+			.take(8)
+			.collect::<Vec<_>>();
+		let final_state = chain_states(&init_state, &data)[data.len()];
+
+		validate_hash_chain(&data, &init_state, &final_state, |chain, segment| {
+			assert_eq!(chain.populate(segment, &init_state).unwrap(), final_state);
+		})
+		.unwrap();
+
+		// Tampering with the data of one row changes the final state of the chain.
+		let mut tampered_data = data.clone();
+		tampered_data[3][0] += B8::ONE;
+		assert!(validate_hash_chain(
+			&tampered_data,
+			&init_state,
+			&final_state,
+			|chain, segment| {
+				chain.populate(segment, &init_state).unwrap();
+			}
+		)
+		.is_err());
+	}
+
+	/// Applies the inverse of a Grøstl permutation.
+	fn inverse_permute(pq: PermutationVariant, state: &mut [B8; 64]) {
+		let inverse_sbox = |output: B8| {
+			(0..=255)
+				.map(B8::new)
+				.find(|&input| sbox(input) == output)
+				.expect("the S-box is a permutation")
+		};
+		let inverse_mix_bytes =
+			inverse_circulant(&MIX_BYTES_VEC.map(|byte| B8::from(AESTowerField8b::new(byte))));
+		for round in (0..10).rev() {
+			mix_bytes(state, &inverse_mix_bytes);
+			shift_bytes(pq, state, true);
+			for byte in state.iter_mut() {
+				*byte = inverse_sbox(*byte);
+			}
+			add_round_constant(pq, round, state);
+		}
+	}
+
+	/// Returns the first row of the inverse of a circulant matrix, by Gauss-Jordan elimination.
+	fn inverse_circulant(circulant: &[B8; 8]) -> [B8; 8] {
+		let mut rows: [[B8; 16]; 8] = array::from_fn(|j| {
+			array::from_fn(|i| match i {
+				0..8 => circulant[(8 + i - j) % 8],
+				_ => B8::from(B1::from(i - 8 == j)),
+			})
+		});
+		for col in 0..8 {
+			let pivot = (col..8)
+				.find(|&row| rows[row][col] != B8::ZERO)
+				.expect("the matrix is invertible");
+			rows.swap(col, pivot);
+			let scale = rows[col][col].invert_or_zero();
+			for value in &mut rows[col] {
+				*value *= scale;
+			}
+			for row in 0..8 {
+				let factor = rows[row][col];
+				if row != col && factor != B8::ZERO {
+					for k in 0..16 {
+						let value = rows[col][k];
+						rows[row][k] -= factor * value;
+					}
+				}
+			}
+		}
+		array::from_fn(|i| rows[0][8 + i])
+	}
+
+	#[test]
+	fn test_hash_chain_rejects_fixed_point_row() {
+		let mut rng = StdRng::seed_from_u64(1);
+
+		// f(h, m) = h for h = P^-1(Q(m)) + m, so a row with these values links to itself.
+		let fixed_message = random_state(&mut rng);
+		let mut fixed_state = fixed_message;
+		permute(PermutationVariant::Q, &mut fixed_state);
+		inverse_permute(PermutationVariant::P, &mut fixed_state);
+		let fixed_state = array::from_fn(|i| fixed_state[i] + fixed_message[i]);
+		assert_eq!(compress(&fixed_state, &fixed_message), fixed_state);
+
+		// An honest chain of 7 links with the fixed point row injected after the third.
+		let init_state = random_state(&mut rng);
+		let data = repeat_with(|| random_state(&mut rng))
+			.take(7)
+			.collect::<Vec<_>>();
+		let states = chain_states(&init_state, &data);
+		let final_state = states[data.len()];
+
+		let mut rows = data.clone();
+		rows.insert(3, fixed_message);
+		let mut prev_states = states[..data.len()].to_vec();
+		prev_states.insert(3, fixed_state);
+		let mut next_states = states[1..].to_vec();
+		next_states.insert(3, fixed_state);
+
+		let result = validate_hash_chain(&rows, &init_state, &final_state, |chain, segment| {
+			chain
+				.populate_rows(segment, &prev_states, &next_states)
+				.unwrap();
+		});
+		assert!(result.is_err());
+	}
+}
@@ -10,6 +10,7 @@ use anyhow::Result;
 use array_util::ArrayExt;
 use binius_core::oracle::ShiftVariant;
 use binius_field::{
+	arithmetic_traits::InvertOrZero,
 	ext_basis,
 	linear_transformation::{
 		FieldLinearTransformation, PackedTransformationFactory, Transformation,
@@ -19,10 +20,11 @@ use binius_field::{
 	PackedSubfield, TowerField,
 };
 
+use super::chain::ChainHasher;
 use crate::builder::{upcast_col, Col, Expr, TableBuilder, TableWitnessSegment, B1, B128, B8};
 
 /// The first row of the circulant matrix defining the MixBytes step in Grøstl.
-const MIX_BYTES_VEC: [u8; 8] = [0x02, 0x02, 0x03, 0x04, 0x05, 0x03, 0x05, 0x07];
+pub(super) const MIX_BYTES_VEC: [u8; 8] = [0x02, 0x02, 0x03, 0x04, 0x05, 0x03, 0x05, 0x07];
 
 /// The affine transformation matrix for the Rijndael S-box, isomorphically converted to the
 /// canonical tower basis.
@@ -107,17 +109,7 @@ impl Permutation {
 		P: PackedExtension<B8>,
 		P::Scalar: TowerField,
 	{
-		let mut state_in = self
-			.state_in()
-			.try_map_ext(|state_in_i| index.get_mut(state_in_i))?;
-		for (k, state_k) in states.into_iter().enumerate() {
-			for (i, state_in_i) in state_in.iter_mut().enumerate() {
-				for j in 0..8 {
-					set_packed_slice(state_in_i, k * 8 + j, state_k[j * 8 + i]);
-				}
-			}
-		}
-		Ok(())
+		populate_state(index, self.state_in(), states)
 	}
 
 	/// Reads the state outputs from the witness index.
@@ -145,6 +137,204 @@ impl Permutation {
 	}
 }
 
+/// The Grøstl compression function.
+///
+/// Verifies $f(h, m) = P(h \oplus m) \oplus Q(m) \oplus h$, where $h$ is the chaining state and
+/// $m$ is a message block, both using the same state representation as [`Permutation`].
+#[derive(Debug, Clone)]
+pub struct Compression {
+	// Inputs
+	pub state_in: [Col<B8, 8>; 8],
+	pub message: [Col<B8, 8>; 8],
+	// Private
+	p_in: [Col<B8, 8>; 8],
+	p: Permutation,
+	q: Permutation,
+	// Outputs
+	pub state_out: [Col<B8, 8>; 8],
+}
+
+impl Compression {
+	pub fn new(
+		table: &mut TableBuilder,
+		state_in: [Col<B8, 8>; 8],
+		message: [Col<B8, 8>; 8],
+	) -> Self {
+		let p_in =
+			array::from_fn(|i| table.add_computed(format!("p_in[{i}]"), state_in[i] + message[i]));
+		let p = Permutation::new(&mut table.with_namespace("P"), PermutationVariant::P, p_in);
+		let q = Permutation::new(&mut table.with_namespace("Q"), PermutationVariant::Q, message);
+		let p_out = p.state_out();
+		let q_out = q.state_out();
+		let state_out = array::from_fn(|i| {
+			table.add_computed(format!("state_out[{i}]"), p_out[i] + q_out[i] + state_in[i])
+		});
+		Self {
+			state_in,
+			message,
+			p_in,
+			p,
+			q,
+			state_out,
+		}
+	}
+
+	pub fn populate<P>(&self, index: &mut TableWitnessSegment<P>) -> Result<()>
+	where
+		P: PackedFieldIndexable<Scalar = B128> + PackedExtension<B1> + PackedExtension<B8>,
+		PackedSubfield<P, B8>: PackedTransformationFactory<PackedSubfield<P, B8>>,
+	{
+		for i in 0..8 {
+			let state_in = index.get(self.state_in[i])?;
+			let message = index.get(self.message[i])?;
+			let mut p_in = index.get_mut(self.p_in[i])?;
+			for (k, p_in_k) in p_in.iter_mut().enumerate() {
+				*p_in_k = state_in[k] + message[k];
+			}
+		}
+
+		self.p.populate(index)?;
+		self.q.populate(index)?;
+
+		let p_out: [_; 8] = array_util::try_from_fn(|i| index.get(self.p.state_out()[i]))?;
+		let q_out: [_; 8] = array_util::try_from_fn(|i| index.get(self.q.state_out()[i]))?;
+		for i in 0..8 {
+			let state_in = index.get(self.state_in[i])?;
+			let mut state_out = index.get_mut(self.state_out[i])?;
+			for (k, state_out_k) in state_out.iter_mut().enumerate() {
+				*state_out_k = p_out[i][k] + q_out[i][k] + state_in[k];
+			}
+		}
+
+		Ok(())
+	}
+}
+
+/// The Grøstl compression function as a [`ChainHasher`], verified by [`Compression`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GroestlCompression;
+
+impl ChainHasher for GroestlCompression {
+	type Compression = Compression;
+
+	fn add_compression(
+		&self,
+		table: &mut TableBuilder,
+		state_in: [Col<B8, 8>; 8],
+		message: [Col<B8, 8>; 8],
+	) -> Compression {
+		Compression::new(table, state_in, message)
+	}
+
+	fn state_out(compression: &Compression) -> [Col<B8, 8>; 8] {
+		compression.state_out
+	}
+
+	fn populate<P>(compression: &Compression, index: &mut TableWitnessSegment<P>) -> Result<()>
+	where
+		P: PackedFieldIndexable<Scalar = B128> + PackedExtension<B1> + PackedExtension<B8>,
+		PackedSubfield<P, B8>: PackedTransformationFactory<PackedSubfield<P, B8>>,
+	{
+		compression.populate(index)
+	}
+
+	fn compress(&self, state: &[B8; 64], message: &[B8; 64]) -> [B8; 64] {
+		compress(state, message)
+	}
+}
+
+/// Computes the Grøstl compression function $f(h, m) = P(h \oplus m) \oplus Q(m) \oplus h$ on
+/// states in the representation of [`Permutation`].
+pub fn compress(state: &[B8; 64], message: &[B8; 64]) -> [B8; 64] {
+	let mut p_state = array::from_fn(|i| state[i] + message[i]);
+	permute(PermutationVariant::P, &mut p_state);
+	let mut q_state = *message;
+	permute(PermutationVariant::Q, &mut q_state);
+	array::from_fn(|i| p_state[i] + q_state[i] + state[i])
+}
+
+/// Applies a Grøstl permutation to a state in the representation of [`Permutation`].
+///
+/// The state holds the byte in row `i` and column `j` of the Grøstl state matrix at index
+/// `j * 8 + i`.
+pub fn permute(pq: PermutationVariant, state: &mut [B8; 64]) {
+	for round in 0..10 {
+		add_round_constant(pq, round, state);
+		for byte in state.iter_mut() {
+			*byte = sbox(*byte);
+		}
+		shift_bytes(pq, state, false);
+		mix_bytes(state, &MIX_BYTES_VEC.map(|byte| B8::from(AESTowerField8b::new(byte))));
+	}
+}
+
+/// The AddRoundConstant step of a Grøstl permutation round, which is its own inverse.
+pub(super) fn add_round_constant(pq: PermutationVariant, round: usize, state: &mut [B8; 64]) {
+	let round_consts = round_consts(round);
+	for (j, round_const) in round_consts.into_iter().enumerate() {
+		match pq {
+			PermutationVariant::P => state[j * 8] += round_const,
+			PermutationVariant::Q => {
+				for i in 0..8 {
+					state[j * 8 + i] += B8::from(AESTowerField8b::new(0xFF));
+				}
+				state[j * 8 + 7] += round_const;
+			}
+		}
+	}
+}
+
+/// The Rijndael S-box on a single [`B8`] element.
+pub(super) fn sbox(input: B8) -> B8 {
+	S_BOX_TOWER_OFFSET + S_BOX_TOWER_MATRIX.transform(&InvertOrZero::invert_or_zero(input))
+}
+
+/// The ShiftBytes step of a Grøstl permutation round, or its inverse.
+pub(super) fn shift_bytes(pq: PermutationVariant, state: &mut [B8; 64], inverse: bool) {
+	let state_in = *state;
+	for i in 0..8 {
+		let offset = pq.shift_bytes_offset(i);
+		let offset = if inverse { (8 - offset) % 8 } else { offset };
+		for j in 0..8 {
+			state[(j + offset) % 8 * 8 + i] = state_in[j * 8 + i];
+		}
+	}
+}
+
+/// Multiplies every column of the state by the circulant matrix with the given first row, which
+/// is the MixBytes step for [`MIX_BYTES_VEC`].
+pub(super) fn mix_bytes(state: &mut [B8; 64], circulant: &[B8; 8]) {
+	let state_in = *state;
+	for k in 0..8 {
+		for j in 0..8 {
+			state[k * 8 + j] = (0..8)
+				.map(|i| state_in[k * 8 + i] * circulant[(8 + i - j) % 8])
+				.sum();
+		}
+	}
+}
+
+/// Populates state columns with full permutation states, one per row.
+pub(super) fn populate_state<'a, P>(
+	index: &mut TableWitnessSegment<P>,
+	cols: [Col<B8, 8>; 8],
+	states: impl IntoIterator<Item = &'a [B8; 64]>,
+) -> Result<()>
+where
+	P: PackedExtension<B8>,
+	P::Scalar: TowerField,
+{
+	let mut cols = cols.try_map_ext(|col| index.get_mut(col))?;
+	for (k, state_k) in states.into_iter().enumerate() {
+		for (i, col_i) in cols.iter_mut().enumerate() {
+			for j in 0..8 {
+				set_packed_slice(col_i, k * 8 + j, state_k[j * 8 + i]);
+			}
+		}
+	}
+	Ok(())
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display)]
 pub enum PermutationVariant {
 	P,
@@ -410,6 +600,29 @@ mod tests {
 	use super::*;
 	use crate::builder::{ConstraintSystem, Statement, WitnessIndex};
 
+	fn b8_state_to_bytes(state: &[B8; 64]) -> [u8; 64] {
+		state.map(|b8| AESTowerField8b::from(b8).val())
+	}
+
+	fn bytes_to_b8_state(bytes: &[u8; 64]) -> [B8; 64] {
+		bytes.map(|byte| B8::from(AESTowerField8b::new(byte)))
+	}
+
+	#[test]
+	fn test_compress() {
+		let mut rng = StdRng::seed_from_u64(0);
+		for _ in 0..4 {
+			let state = array::from_fn::<_, 64, _>(|_| B8::random(&mut rng));
+			let message = array::from_fn::<_, 64, _>(|_| B8::random(&mut rng));
+
+			let mut expected = GroestlShortImpl::state_from_bytes(&b8_state_to_bytes(&state));
+			GroestlShortImpl::compress(&mut expected, &b8_state_to_bytes(&message));
+			let expected = bytes_to_b8_state(&GroestlShortImpl::state_to_bytes(&expected));
+
+			assert_eq!(compress(&state, &message), expected);
+		}
+	}
+
 	#[test]
 	fn test_sbox() {
 		let mut cs = ConstraintSystem::new();
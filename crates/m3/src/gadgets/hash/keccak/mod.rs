@@ -13,7 +13,7 @@ use binius_field::{
 };
 pub use state::{StateMatrix, StateRow};
 
-use crate::builder::{Col, Expr, TableBuilder, TableWitnessSegment, B1, B128, B8};
+use crate::builder::{Col, ColumnViewMut, Expr, TableBuilder, TableWitnessSegment, B1, B128, B8};
 
 mod state;
 mod test_vector;
@@ -159,7 +159,7 @@ impl Keccakf {
 		for k in 0..pts.len() {
 			for x in 0..5 {
 				for y in 0..5 {
-					let mut next_state_in: std::cell::RefMut<'_, [u64]> =
+					let mut next_state_in: ColumnViewMut<'_, u64> =
 						index.get_mut_as(self.next_state_in[(x, y)])?;
 					let batch_0_state_in: std::cell::Ref<'_, [u64]> =
 						index.get_as(self.batches[0].state_in[(x, y)])?;
@@ -178,7 +178,7 @@ impl Keccakf {
 		}
 
 		{
-			let mut link_sel: std::cell::RefMut<'_, [u64]> = index.get_mut_as(self.link_sel)?;
+			let mut link_sel: ColumnViewMut<'_, u64> = index.get_mut_as(self.link_sel)?;
 			assert!(link_sel.len() % TRACKS_PER_BATCH == 0);
 			for link_sel_chunk in link_sel.chunks_exact_mut(TRACKS_PER_BATCH) {
 				link_sel_chunk.copy_from_slice(&[
@@ -361,18 +361,16 @@ impl RoundBatch {
 			let brt = pt.per_batch(self.batch_no);
 
 			// Fill in round_const witness with the corresponding round constants
-			let mut round_const: std::cell::RefMut<'_, [u64]> =
-				index.get_mut_as(self.round_const)?;
+			let mut round_const: ColumnViewMut<'_, u64> = index.get_mut_as(self.round_const)?;
 			for track in 0..TRACKS_PER_BATCH {
 				round_const[TRACKS_PER_BATCH * k + track] = brt[track].rc;
 			}
 			drop(round_const);
 
 			for x in 0..5 {
-				let mut c: std::cell::RefMut<'_, [u64]> = index.get_mut_as(self.c[x])?;
-				let mut c_shift: std::cell::RefMut<'_, [u64]> =
-					index.get_mut_as(self.c_shift[x])?;
-				let mut d: std::cell::RefMut<'_, [u64]> = index.get_mut_as(self.d[x])?;
+				let mut c: ColumnViewMut<'_, u64> = index.get_mut_as(self.c[x])?;
+				let mut c_shift: ColumnViewMut<'_, u64> = index.get_mut_as(self.c_shift[x])?;
+				let mut d: ColumnViewMut<'_, u64> = index.get_mut_as(self.d[x])?;
 
 				for track in 0..TRACKS_PER_BATCH {
 					let cell_pos = TRACKS_PER_BATCH * k + track;
@@ -382,11 +380,11 @@ impl RoundBatch {
 				}
 
 				for y in 0..5 {
-					let mut state_in: std::cell::RefMut<'_, [u64]> =
+					let mut state_in: ColumnViewMut<'_, u64> =
 						index.get_mut_as(self.state_in[(x, y)])?;
-					let mut state_out: std::cell::RefMut<'_, [u64]> =
+					let mut state_out: ColumnViewMut<'_, u64> =
 						index.get_mut_as(self.state_out[(x, y)])?;
-					let mut a_theta: std::cell::RefMut<'_, [u64]> =
+					let mut a_theta: ColumnViewMut<'_, u64> =
 						index.get_mut_as(self.a_theta[(x, y)])?;
 
 					// b[0,0] is defined as a_theta[0,0].
@@ -395,7 +393,7 @@ impl RoundBatch {
 					// 1. The value is assigned by `a_theta`.
 					// 2. We have to skip mutably borrowing it here because that would overlap with
 					//    the mutable borrow of `a_theta` above.
-					let mut b: Option<std::cell::RefMut<'_, [u64]>> = if (x, y) != (0, 0) {
+					let mut b: Option<ColumnViewMut<'_, u64>> = if (x, y) != (0, 0) {
 						Some(index.get_mut_as(self.b[(x, y)])?)
 					} else {
 						None
@@ -429,7 +427,7 @@ impl RoundBatch {
 		for (k, state_in) in state_ins.into_iter().enumerate() {
 			for x in 0..5 {
 				for y in 0..5 {
-					let mut state_in_data: std::cell::RefMut<'_, [u64]> =
+					let mut state_in_data: ColumnViewMut<'_, u64> =
 						index.get_mut_as(self.state_in[(x, y)])?;
 					state_in_data[TRACKS_PER_BATCH * k + track] = state_in[(x, y)];
 				}
@@ -1,4 +1,5 @@
 // Copyright 2025 Irreducible Inc.
 
+pub mod chain;
 pub mod groestl;
 pub mod keccak;
@@ -32,16 +32,14 @@ impl LookupProducer {
 			.map(|i| table.add_committed::<B1, 1>(format!("multiplicity_bits[{i}]")))
 			.collect::<Vec<_>>();
 
-		for (i, &multiplicity_col) in multiplicity_bits.iter().enumerate() {
-			table.push_with_opts(
-				chan,
-				value_cols.iter().copied(),
-				FlushOpts {
-					multiplicity: 1 << i,
-					selectors: vec![multiplicity_col],
-				},
-			);
-		}
+		table.push_with_opts(
+			chan,
+			value_cols.iter().copied(),
+			FlushOpts {
+				multiplicity_column: Some(multiplicity_bits.clone()),
+				..FlushOpts::default()
+			},
+		);
 
 		Self { multiplicity_bits }
 	}
@@ -9,7 +9,12 @@ use binius_field::{
 use itertools::izip;
 
 use crate::{
-	builder::{column::Col, types::B1, witness::TableWitnessSegment, TableBuilder, B128},
+	builder::{
+		column::Col,
+		types::B1,
+		witness::{ColumnViewMut, TableWitnessSegment},
+		TableBuilder, B128,
+	},
 	gadgets::add::UnsignedAddPrimitives,
 };
 
@@ -94,11 +99,11 @@ impl U32Sub {
 	where
 		P: PackedFieldIndexable<Scalar = B128> + PackedExtension<B1>,
 	{
-		let xin: std::cell::RefMut<'_, [u32]> = index.get_mut_as(self.xin)?;
-		let yin: std::cell::RefMut<'_, [u32]> = index.get_mut_as(self.yin)?;
-		let mut bout: std::cell::RefMut<'_, [u32]> = index.get_mut_as(self.bout)?;
-		let mut zout: std::cell::RefMut<'_, [u32]> = index.get_mut_as(self.zout)?;
-		let mut bin: std::cell::RefMut<'_, [u32]> = index.get_mut_as(self.bin)?;
+		let xin: ColumnViewMut<'_, u32> = index.get_mut_as(self.xin)?;
+		let yin: ColumnViewMut<'_, u32> = index.get_mut_as(self.yin)?;
+		let mut bout: ColumnViewMut<'_, u32> = index.get_mut_as(self.bout)?;
+		let mut zout: ColumnViewMut<'_, u32> = index.get_mut_as(self.zout)?;
+		let mut bin: ColumnViewMut<'_, u32> = index.get_mut_as(self.bin)?;
 		let mut final_borrow = if let Some(final_borrow) = self.final_borrow {
 			let final_borrow = index.get_mut(final_borrow)?;
 			Some(final_borrow)
@@ -0,0 +1,82 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::{arch::OptimalUnderlier128b, as_packed_field::PackedType};
+use binius_m3::builder::{
+	test_utils::ClosureFiller, Boundary, ConstraintSystem, FlushDirection, MultisetImbalance,
+	WitnessIndex, B128, B32,
+};
+use bumpalo::Bump;
+
+#[test]
+pub fn test_debug_channel_balances() {
+	let allocator = Bump::new();
+	let mut cs = ConstraintSystem::<B128>::new();
+
+	let values_channel = cs.add_channel("values");
+	let idle_channel = cs.add_channel("idle");
+
+	let mut table = cs.add_table("values");
+	let table_id = table.id();
+	let values_col = table.add_committed::<B32, 1>("values");
+	table.push(values_channel, [values_col]);
+
+	let events = (0..5u32).collect::<Vec<_>>();
+
+	let mut witness = WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+	witness
+		.fill_table_sequential(
+			&ClosureFiller::new(table_id, |events, index| {
+				let mut values_col = index.get_mut_as::<u32, _, 1>(values_col)?;
+				for (i, &&event) in events.iter().enumerate() {
+					values_col[i] = event;
+				}
+				Ok(())
+			}),
+			&events,
+		)
+		.unwrap();
+
+	let pull = |value: u32| Boundary {
+		values: vec![B128::new(value as u128)],
+		channel_id: values_channel,
+		direction: FlushDirection::Pull,
+		multiplicity: 1,
+	};
+
+	let boundaries = events.iter().map(|&event| pull(event)).collect::<Vec<_>>();
+	assert_eq!(
+		cs.debug_channel_balances(&mut witness, &boundaries)
+			.unwrap(),
+		vec![]
+	);
+
+	// Pulling 7 instead of 0 leaves 0 pushed but never pulled, and 7 pulled but never pushed.
+	let boundaries = [7, 1, 2, 3, 4].map(pull);
+	assert_eq!(
+		cs.debug_channel_balances(&mut witness, &boundaries)
+			.unwrap(),
+		vec![(
+			values_channel,
+			MultisetImbalance {
+				n_unbalanced: 2,
+				sample: vec![B128::new(7)],
+				net_multiplicity: -1,
+			}
+		)]
+	);
+
+	// A boundary alone on an otherwise unused channel is reported against that channel.
+	let mut boundaries = events.iter().map(|&event| pull(event)).collect::<Vec<_>>();
+	boundaries.push(Boundary {
+		values: vec![B128::new(42)],
+		channel_id: idle_channel,
+		direction: FlushDirection::Push,
+		multiplicity: 2,
+	});
+	let imbalances = cs
+		.debug_channel_balances(&mut witness, &boundaries)
+		.unwrap();
+	assert_eq!(imbalances.len(), 1);
+	assert_eq!(imbalances[0].0, idle_channel);
+	assert_eq!(imbalances[0].1.net_multiplicity, 2);
+}
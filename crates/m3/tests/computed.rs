@@ -1,12 +1,18 @@
 // Copyright 2025 Irreducible Inc.
 
+use assert_matches::assert_matches;
+use binius_core::{constraint_system::OracleKind, oracle::ShiftVariant};
 use binius_field::{
 	arch::OptimalUnderlier128b, as_packed_field::PackedType, Field, PackedExtension,
 	PackedFieldIndexable,
 };
 use binius_m3::builder::{
-	test_utils::validate_system_witness, Col, ConstraintSystem, TableFiller, TableId,
-	TableWitnessSegment, WitnessIndex, B128,
+	test_utils::{
+		validate_compiled_witness, validate_system_witness,
+		validate_system_witness_with_prove_verify, ClosureFiller,
+	},
+	Col, ColumnId, ConstraintSystem, Error, Expr, Statement, TableBuilder, TableFiller, TableId,
+	TableWitnessSegment, WitnessIndex, B128, B32, B64,
 };
 use bumpalo::Bump;
 
@@ -23,14 +29,40 @@ pub struct MyTable {
 impl MyTable {
 	pub fn new(cs: &mut ConstraintSystem) -> Self {
 		let mut table = cs.add_table("table_1");
-		let committed_1 = table.add_committed::<B128, VALUES_PER_ROW>("committed_2");
-		let committed_2 = table.add_committed::<B128, VALUES_PER_ROW>("committed_2");
-		let expr = (committed_1 + committed_2) * committed_1 * B128::from(10) + B128::ONE;
-		let computed = table.add_computed("computed", expr.clone());
+		let this = Self::add_columns(&mut table);
 
 		// Test that the computed column equals the composite evaluation over the table.
-		table.assert_zero("computed = expr", expr - computed);
+		table.assert_zero("computed = expr", this.expr() - this.computed);
+
+		this
+	}
+
+	/// Creates the table with virtual computed columns alongside the materialized one.
+	pub fn new_with_virtual(cs: &mut ConstraintSystem) -> Self {
+		let mut table = cs.add_table("table_1");
+		let this = Self::add_columns(&mut table);
+		let computed_virtual = table.add_computed_virtual("computed_virtual", this.expr());
+		let squared_virtual =
+			table.add_computed_virtual("squared_virtual", computed_virtual * computed_virtual);
 
+		// Test that the virtual column evaluates the same as the materialized one, including when
+		// a virtual column is defined in terms of another.
+		table.assert_zero("computed = computed_virtual", this.computed - computed_virtual);
+		table.assert_zero(
+			"computed^2 = squared_virtual",
+			this.computed * this.computed - squared_virtual,
+		);
+
+		this
+	}
+
+	fn add_columns(table: &mut TableBuilder) -> Self {
+		let committed_1 = table.add_committed::<B128, VALUES_PER_ROW>("committed_1");
+		let committed_2 = table.add_committed::<B128, VALUES_PER_ROW>("committed_2");
+		let computed = table.add_computed(
+			"computed",
+			(committed_1 + committed_2) * committed_1 * B128::from(10) + B128::ONE,
+		);
 		Self {
 			id: table.id(),
 			committed_1,
@@ -38,6 +70,14 @@ impl MyTable {
 			computed,
 		}
 	}
+
+	fn expr(&self) -> Expr<B128, VALUES_PER_ROW> {
+		(self.committed_1 + self.committed_2) * self.committed_1 * B128::from(10) + B128::ONE
+	}
+
+	fn computed_value(com1: u128, com2: u128) -> B128 {
+		(B128::from(com1) + B128::from(com2)) * B128::from(com1) * B128::from(10) + B128::ONE
+	}
 }
 
 impl<P> TableFiller<P> for MyTable
@@ -63,30 +103,458 @@ where
 			for j in 0..VALUES_PER_ROW {
 				committed_1[i * VALUES_PER_ROW + j] = com1;
 				committed_2[i * VALUES_PER_ROW + j] = com2;
-				computed[i * VALUES_PER_ROW + j] =
-					(B128::from(com1) + B128::from(com2)) * B128::from(com1) * B128::from(10)
-						+ B128::ONE;
+				computed[i * VALUES_PER_ROW + j] = Self::computed_value(com1, com2);
 			}
 		}
 		Ok(())
 	}
 }
 
+type TestWitnessIndex<'cs, 'alloc> =
+	WitnessIndex<'cs, 'alloc, PackedType<OptimalUnderlier128b, B128>>;
+
+fn events(n_rows: usize) -> Vec<(u128, u128)> {
+	(0..n_rows as u128).map(|i| (i, i + 10)).collect()
+}
+
+fn fill_witness<'cs, 'alloc>(
+	cs: &'cs ConstraintSystem,
+	allocator: &'alloc Bump,
+	table: &MyTable,
+	events: &[(u128, u128)],
+) -> TestWitnessIndex<'cs, 'alloc> {
+	let mut witness = TestWitnessIndex::new(cs, allocator);
+	witness.fill_table_sequential(table, events).unwrap();
+	witness
+}
+
 #[test]
 fn test_m3_computed_col() {
 	let allocator = Bump::new();
 	let mut cs = ConstraintSystem::<B128>::new();
 	let table = MyTable::new(&mut cs);
+	let witness = fill_witness(&cs, &allocator, &table, &events(N_ROWS));
+
+	validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
+}
+
+#[test]
+fn test_m3_computed_col_parallel_fill() {
+	let allocator = Bump::new();
+	let mut cs = ConstraintSystem::<B128>::new();
+	let table = MyTable::new(&mut cs);
+
+	// Enough rows to split the table into several segments, with a partial last chunk.
+	let rows = events(1000);
+	let mut sequential_witness = fill_witness(&cs, &allocator, &table, &rows);
+
+	let mut witness = TestWitnessIndex::new(&cs, &allocator);
+	witness.fill_table_parallel(&table, &rows).unwrap();
+
+	let sequential_segment = sequential_witness
+		.get_table(table.id)
+		.unwrap()
+		.full_segment();
+	let segment = witness.get_table(table.id).unwrap().full_segment();
+	for col in [table.committed_1, table.committed_2, table.computed] {
+		assert_eq!(*segment.get(col).unwrap(), *sequential_segment.get(col).unwrap(),);
+	}
+	drop(segment);
+
+	validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
+}
+
+#[test]
+fn test_m3_computed_col_fill_with_set() {
+	let allocator = Bump::new();
+	let mut cs = ConstraintSystem::<B128>::new();
+	let table = MyTable::new(&mut cs);
+	let events = events(N_ROWS);
+	let mut indexed_witness = fill_witness(&cs, &allocator, &table, &events);
 
-	let mut witness = WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+	// The bounds-checked accessor writes the same values as indexing the raw slice.
+	let mut witness = TestWitnessIndex::new(&cs, &allocator);
 	witness
 		.fill_table_sequential(
-			&table,
-			&(0..N_ROWS as u128)
-				.map(|i| (i, i + 10_u128))
-				.collect::<Vec<_>>(),
+			&ClosureFiller::new(table.id, |events, index| {
+				let mut committed_1 = index.get_mut_as(table.committed_1)?;
+				let mut committed_2 = index.get_mut_as(table.committed_2)?;
+				let mut computed = index.get_mut_as(table.computed)?;
+				for (i, &&(com1, com2)) in events.iter().enumerate() {
+					for j in 0..VALUES_PER_ROW {
+						committed_1.set(i, j, com1)?;
+						committed_2.set(i, j, com2)?;
+						computed.set(i, j, MyTable::computed_value(com1, com2))?;
+					}
+				}
+				Ok(())
+			}),
+			&events,
 		)
 		.unwrap();
 
+	let indexed_segment = indexed_witness.get_table(table.id).unwrap().full_segment();
+	let segment = witness.get_table(table.id).unwrap().full_segment();
+	for col in [table.committed_1, table.committed_2, table.computed] {
+		assert_eq!(*segment.get(col).unwrap(), *indexed_segment.get(col).unwrap());
+	}
+	drop(segment);
+
+	validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
+
+	// Writing past the last lane of a row fails the fill instead of spilling into the next row.
+	let mut witness = TestWitnessIndex::new(&cs, &allocator);
+	let result = witness.fill_table_sequential(
+		&ClosureFiller::new(table.id, |_events, index| {
+			let mut committed_1 = index.get_mut_as::<u128, _, VALUES_PER_ROW>(table.committed_1)?;
+			committed_1.set(0, VALUES_PER_ROW, 1)?;
+			Ok(())
+		}),
+		&events,
+	);
+	assert_matches!(
+		result,
+		Err(Error::TableFill(err)) if matches!(
+			err.downcast_ref::<Error>(),
+			Some(Error::ColumnIndexOutOfRange { row: 0, lane: VALUES_PER_ROW, .. })
+		)
+	);
+}
+
+#[test]
+fn test_m3_verify_computed_columns() {
+	let allocator = Bump::new();
+	let mut cs = ConstraintSystem::<B128>::new();
+	let table = MyTable::new(&mut cs);
+	let mut witness = fill_witness(&cs, &allocator, &table, &events(N_ROWS));
+
+	let segment = witness.get_table(table.id).unwrap().full_segment();
+	assert_eq!(segment.verify_computed_columns().unwrap(), None);
+
+	// Simulate a filler that drifted from the declared expression in one row.
+	segment
+		.get_mut_as::<B128, _, VALUES_PER_ROW>(table.computed)
+		.unwrap()
+		.set(3, 1, B128::ONE)
+		.unwrap();
+	assert_eq!(segment.verify_computed_columns().unwrap(), Some((table.computed.id(), 3)));
+}
+
+#[test]
+fn test_m3_validate_witness_reports_failing_row() {
+	let allocator = Bump::new();
+	let mut cs = ConstraintSystem::<B128>::new();
+	let table = MyTable::new(&mut cs);
+	let mut witness = fill_witness(&cs, &allocator, &table, &events(N_ROWS));
+
+	// Corrupt a single committed value in row 3, leaving the computed column stale.
+	witness
+		.get_table(table.id)
+		.unwrap()
+		.full_segment()
+		.get_mut_as::<B128, _, VALUES_PER_ROW>(table.committed_1)
+		.unwrap()
+		.set(3, 1, B128::from(100))
+		.unwrap();
+
+	let statement = Statement {
+		boundaries: vec![],
+		table_sizes: witness.table_sizes(),
+	};
+	let ccs = cs.compile(&statement).unwrap();
+	let witness = witness.into_multilinear_extension_index();
+	let err = binius_core::constraint_system::validate::validate_witness(&ccs, &[], &witness)
+		.unwrap_err();
+	assert_matches!(
+		err,
+		binius_core::constraint_system::error::Error::ZeroConstraintViolation {
+			constraint_name,
+			oracle_ids,
+			row_index,
+			evaluated_value,
+		} => {
+			assert!(constraint_name.contains("computed = expr"));
+			assert_eq!(oracle_ids.len(), 3);
+			assert_eq!(row_index, 3 * VALUES_PER_ROW + 1);
+			assert_ne!(evaluated_value, B128::ZERO.to_string());
+		}
+	);
+}
+
+#[test]
+fn test_m3_computed_virtual_col() {
+	let allocator = Bump::new();
+	let mut cs = ConstraintSystem::<B128>::new();
+	let table = MyTable::new_with_virtual(&mut cs);
+	let witness = fill_witness(&cs, &allocator, &table, &events(N_ROWS));
+
 	validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
 }
+
+#[test]
+fn test_m3_computed_virtual_col_prove_verify() {
+	let events = events(N_ROWS);
+
+	// Compiles the table, checks that its witness proves and verifies, and returns the stats of
+	// the compiled constraint system.
+	let prove_verify = |new_table: fn(&mut ConstraintSystem) -> MyTable| {
+		let allocator = Bump::new();
+		let mut cs = ConstraintSystem::<B128>::new();
+		let table = new_table(&mut cs);
+		let witness = fill_witness(&cs, &allocator, &table, &events);
+		let statement = Statement {
+			boundaries: vec![],
+			table_sizes: witness.table_sizes(),
+		};
+		let ccs = cs.compile(&statement).unwrap();
+		let witness = witness.into_multilinear_extension_index();
+		validate_compiled_witness::<OptimalUnderlier128b>(&ccs, &[], witness, true);
+		ccs.stats()
+	};
+
+	let materialized = prove_verify(MyTable::new);
+	let with_virtual = prove_verify(MyTable::new_with_virtual);
+
+	// The virtual columns are constrained without committing anything beyond the materialized
+	// table.
+	assert_eq!(
+		with_virtual.n_oracles_of_kind(OracleKind::Committed),
+		materialized.n_oracles_of_kind(OracleKind::Committed)
+	);
+	assert_eq!(with_virtual.committed_bits, materialized.committed_bits);
+}
+
+#[test]
+fn test_m3_virtual_col_referenced_by_derived_col() {
+	fn compile_with_derived(
+		derive: impl FnOnce(&mut TableBuilder, Col<B32, VALUES_PER_ROW>),
+	) -> (Result<(), Error>, ColumnId) {
+		let mut cs = ConstraintSystem::<B128>::new();
+		let mut table = cs.add_table("virtual_refs");
+		let committed = table.add_committed::<B32, VALUES_PER_ROW>("committed");
+		let squared = table.add_computed_virtual("squared", committed * committed);
+		derive(&mut table, squared);
+		drop(table);
+
+		let statement = Statement {
+			boundaries: vec![],
+			table_sizes: vec![N_ROWS],
+		};
+		(cs.compile(&statement).map(|_| ()), squared.id())
+	}
+
+	let (result, squared_id) = compile_with_derived(|table, squared| {
+		table.add_shifted("shifted", squared, 5, 1, ShiftVariant::LogicalLeft);
+	});
+	assert_matches!(result, Err(Error::VirtualColumnReference(id)) if id == squared_id);
+
+	let (result, squared_id) = compile_with_derived(|table, squared| {
+		table.add_packed::<_, VALUES_PER_ROW, B64, { VALUES_PER_ROW / 2 }>("packed", squared);
+	});
+	assert_matches!(result, Err(Error::VirtualColumnReference(id)) if id == squared_id);
+
+	let (result, squared_id) = compile_with_derived(|table, squared| {
+		table.add_selected("selected", squared, 3);
+	});
+	assert_matches!(result, Err(Error::VirtualColumnReference(id)) if id == squared_id);
+
+	let (result, squared_id) = compile_with_derived(|table, squared| {
+		table.add_zero_pad::<_, VALUES_PER_ROW, { VALUES_PER_ROW * 2 }>("zero_pad", squared, 1);
+	});
+	assert_matches!(result, Err(Error::VirtualColumnReference(id)) if id == squared_id);
+
+	// Computed columns of the same partition inline the virtual expression instead.
+	let (result, _) = compile_with_derived(|table, squared| {
+		let computed = table.add_computed("computed", squared + B32::ONE);
+		table.assert_zero("computed = squared + 1", computed - squared - B32::ONE);
+	});
+	assert!(result.is_ok());
+}
+
+/// Two tables filled from the same events, where the second table has a computed column that
+/// depends on a column of the first.
+pub struct CrossTables {
+	key_table_id: TableId,
+	key: Col<B32>,
+	value_table_id: TableId,
+	value: Col<B32>,
+	derived: Col<B32>,
+}
+
+impl CrossTables {
+	pub fn new(cs: &mut ConstraintSystem) -> Self {
+		let mut key_table = cs.add_table("keys");
+		let key_table_id = key_table.id();
+		let key = key_table.add_committed::<B32, 1>("key");
+
+		let mut value_table = cs.add_table("values");
+		let value_table_id = value_table.id();
+		let value = value_table.add_committed::<B32, 1>("value");
+		let derived = value_table
+			.add_computed_cross("derived", key, |key| (key.clone() + value) * key + B32::ONE);
+
+		Self {
+			key_table_id,
+			key,
+			value_table_id,
+			value,
+			derived,
+		}
+	}
+
+	fn derived_value(key: u32, value: u32) -> B32 {
+		(B32::new(key) + B32::new(value)) * B32::new(key) + B32::ONE
+	}
+}
+
+fn fill_cross_tables(tables: &CrossTables, witness: &mut TestWitnessIndex, events: &[(u32, u32)]) {
+	witness
+		.fill_table_sequential(
+			&ClosureFiller::new(tables.key_table_id, |events, index| {
+				let mut key = index.get_mut_as::<B32, _, 1>(tables.key)?;
+				for (i, &&(k, _)) in events.iter().enumerate() {
+					key.set(i, 0, B32::new(k))?;
+				}
+				Ok(())
+			}),
+			events,
+		)
+		.unwrap();
+	witness
+		.fill_table_sequential(
+			&ClosureFiller::new(tables.value_table_id, |events, index| {
+				let mut value = index.get_mut_as::<B32, _, 1>(tables.value)?;
+				let mut derived = index.get_mut_as::<B32, _, 1>(tables.derived)?;
+				for (i, &&(k, v)) in events.iter().enumerate() {
+					value.set(i, 0, B32::new(v))?;
+					derived.set(i, 0, CrossTables::derived_value(k, v))?;
+				}
+				Ok(())
+			}),
+			events,
+		)
+		.unwrap();
+}
+
+#[test]
+fn test_m3_computed_cross_col() {
+	let allocator = Bump::new();
+	let mut cs = ConstraintSystem::<B128>::new();
+	let tables = CrossTables::new(&mut cs);
+
+	let events = (0..5u32)
+		.map(|i| (i * 7 + 1, i * 13 + 2))
+		.collect::<Vec<_>>();
+
+	let mut witness = TestWitnessIndex::new(&cs, &allocator);
+	fill_cross_tables(&tables, &mut witness, &events);
+
+	validate_system_witness_with_prove_verify::<OptimalUnderlier128b>(&cs, witness, vec![], true);
+}
+
+#[test]
+fn test_m3_verify_computed_cross_columns() {
+	let allocator = Bump::new();
+	let mut cs = ConstraintSystem::<B128>::new();
+	let tables = CrossTables::new(&mut cs);
+
+	let events = (0..5u32)
+		.map(|i| (i * 7 + 1, i * 13 + 2))
+		.collect::<Vec<_>>();
+
+	let mut witness = TestWitnessIndex::new(&cs, &allocator);
+	fill_cross_tables(&tables, &mut witness, &events);
+	assert_eq!(witness.verify_computed_columns().unwrap(), None);
+
+	// The segment of the value table alone cannot see the key column.
+	let segment = witness
+		.get_table(tables.value_table_id)
+		.unwrap()
+		.full_segment();
+	segment
+		.get_mut_as::<B32, _, 1>(tables.derived)
+		.unwrap()
+		.set(2, 0, B32::ONE)
+		.unwrap();
+	assert_eq!(segment.verify_computed_columns().unwrap(), None);
+	drop(segment);
+
+	assert_eq!(witness.verify_computed_columns().unwrap(), Some((tables.derived.id(), 2)));
+}
+
+#[test]
+fn test_m3_computed_cross_col_table_order() {
+	let statement = Statement {
+		boundaries: vec![],
+		table_sizes: vec![8, 8],
+	};
+
+	// The foreign table is added after the table of the computed column.
+	let mut cs = ConstraintSystem::<B128>::new();
+	let mut value_table = cs.add_table("values");
+	let value_table_id = value_table.id();
+	let value = value_table.add_committed::<B32, 1>("value");
+	let mut key_table = cs.add_table("keys");
+	let key_table_id = key_table.id();
+	let key = key_table.add_committed::<B32, 1>("key");
+	let derived = TableBuilder::new(&mut cs.tables[value_table_id]).add_computed_cross(
+		"derived",
+		key,
+		|key| key + value,
+	);
+	assert_matches!(
+		cs.compile(&statement),
+		Err(Error::CrossTableReferenceOrder { column_id, foreign_table_id })
+			if column_id == derived.id() && foreign_table_id == key_table_id
+	);
+
+	// A column of the same table must use add_computed instead.
+	let mut cs = ConstraintSystem::<B128>::new();
+	let mut table = cs.add_table("values");
+	let table_id = table.id();
+	let value = table.add_committed::<B32, 1>("value");
+	let derived = table.add_computed_cross("derived", value, |value| value.clone() * value);
+	drop(table);
+	let statement = Statement {
+		boundaries: vec![],
+		table_sizes: vec![8],
+	};
+	assert_matches!(
+		cs.compile(&statement),
+		Err(Error::CrossTableReferenceOrder { column_id, foreign_table_id })
+			if column_id == derived.id() && foreign_table_id == table_id
+	);
+}
+
+#[test]
+fn test_m3_computed_cross_col_size_mismatch() {
+	let mut cs = ConstraintSystem::<B128>::new();
+	let tables = CrossTables::new(&mut cs);
+
+	let statement = Statement {
+		boundaries: vec![],
+		table_sizes: vec![4, 8],
+	};
+	assert_matches!(
+		cs.compile(&statement),
+		Err(Error::CrossTableSizeMismatch {
+			column_id,
+			foreign_table_id,
+			size: 8,
+			foreign_size: 4,
+		}) if column_id == tables.derived.id() && foreign_table_id == tables.key_table_id
+	);
+
+	// An inactive key table cannot back the computed column of an active table either.
+	let statement = Statement {
+		boundaries: vec![],
+		table_sizes: vec![0, 8],
+	};
+	assert_matches!(cs.compile(&statement), Err(Error::CrossTableSizeMismatch { .. }));
+
+	let statement = Statement {
+		boundaries: vec![],
+		table_sizes: vec![8, 8],
+	};
+	assert!(cs.compile(&statement).is_ok());
+}
@@ -39,6 +39,7 @@ pub fn test_flush_multiple_selectors() {
 		FlushOpts {
 			multiplicity: 1,
 			selectors: vec![selector1_col, selector2_col],
+			multiplicity_column: None,
 		},
 	);
 
@@ -82,3 +83,69 @@ pub fn test_flush_multiple_selectors() {
 
 	validate_system_witness::<OptimalUnderlier128b>(&cs, witness, boundaries);
 }
+
+#[test]
+pub fn test_flush_multiplicity_column() {
+	let allocator = Bump::new();
+	let mut cs = ConstraintSystem::<B128>::new();
+
+	let channel = cs.add_channel("channel");
+
+	let mut table = cs.add_table("multiplicity_column");
+	let table_id = table.id();
+
+	let values_col = table.add_committed::<B32, 1>("values");
+	let multiplicity_bits = (0..2)
+		.map(|i| table.add_committed::<B1, 1>(format!("multiplicity_bits[{i}]")))
+		.collect::<Vec<_>>();
+
+	table.push_with_opts(
+		channel,
+		[values_col],
+		FlushOpts {
+			multiplicity_column: Some(multiplicity_bits.clone()),
+			..FlushOpts::default()
+		},
+	);
+
+	// Row i pushes the value i + 1 exactly i times.
+	let events = (0..4u32).collect::<Vec<_>>();
+
+	let mut witness = WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+	witness
+		.fill_table_sequential(
+			&ClosureFiller::new(table_id, |events, index| {
+				let mut values_col = index.get_mut_as::<u32, _, 1>(values_col)?;
+				for (i, &&event) in events.iter().enumerate() {
+					values_col[i] = event + 1;
+				}
+				for (bit, &bit_col) in multiplicity_bits.iter().enumerate() {
+					let mut bit_col = index.get_mut(bit_col)?;
+					for (i, &&event) in events.iter().enumerate() {
+						let bit_val = if (event >> bit) & 1 == 1 {
+							B1::ONE
+						} else {
+							B1::ZERO
+						};
+						set_packed_slice(&mut bit_col, i, bit_val);
+					}
+				}
+				Ok(())
+			}),
+			&events,
+		)
+		.unwrap();
+
+	let boundaries = events
+		.iter()
+		.filter(|&&event| event > 0)
+		.map(|&event| Boundary {
+			values: vec![B128::new(event as u128 + 1)],
+			channel_id: channel,
+			direction: FlushDirection::Pull,
+			multiplicity: event as u64,
+		})
+		.collect::<Vec<_>>();
+
+	validate_system_witness::<OptimalUnderlier128b>(&cs, witness, boundaries);
+}
@@ -0,0 +1,115 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::{arch::OptimalUnderlier128b, as_packed_field::PackedType};
+use binius_m3::builder::{
+	test_utils::{validate_system_witness_with_prove_verify, ClosureFiller},
+	Col, ConstraintSystem, Statement, TableId, WitnessIndex, B128, B32,
+};
+use bumpalo::Bump;
+
+struct SquareTable {
+	id: TableId,
+	x: Col<B32>,
+	x_squared: Col<B32>,
+}
+
+impl SquareTable {
+	fn new(cs: &mut ConstraintSystem, name: &str) -> Self {
+		let mut table = cs.add_table(name);
+		let x = table.add_committed("x");
+		let x_squared = table.add_committed("x_squared");
+		table.assert_zero("x_squared", x * x - x_squared);
+		Self {
+			id: table.id(),
+			x,
+			x_squared,
+		}
+	}
+
+	fn fill(
+		&self,
+		witness: &mut WitnessIndex<PackedType<OptimalUnderlier128b, B128>>,
+		xs: &[(u32, u32)],
+	) {
+		let (x, x_squared) = (self.x, self.x_squared);
+		witness
+			.fill_table_sequential(
+				&ClosureFiller::new(self.id, move |events, index| {
+					let mut x = index.get_mut_as::<B32, _, 1>(x)?;
+					let mut x_squared = index.get_mut_as::<B32, _, 1>(x_squared)?;
+					for (i, &&(value, square)) in events.iter().enumerate() {
+						x[i] = B32::new(value);
+						x_squared[i] = B32::new(square);
+					}
+					Ok(())
+				}),
+				xs,
+			)
+			.unwrap();
+	}
+}
+
+fn squares(n: u32) -> Vec<(u32, u32)> {
+	(0..n)
+		.map(|i| {
+			let x = B32::new(i);
+			(i, (x * x).val())
+		})
+		.collect()
+}
+
+#[test]
+fn test_inactive_table_is_skipped() {
+	let mut cs = ConstraintSystem::<B128>::new();
+	let main = SquareTable::new(&mut cs, "main");
+	let optional = SquareTable::new(&mut cs, "optional");
+
+	let allocator = Bump::new();
+	let mut witness = WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+	main.fill(&mut witness, &squares(16));
+	// Filling with no events leaves the optional table inactive.
+	optional.fill(&mut witness, &[]);
+
+	let inactive = Statement {
+		boundaries: vec![],
+		table_sizes: witness.table_sizes(),
+	};
+	assert!(inactive.is_table_active(main.id));
+	assert!(!inactive.is_table_active(optional.id));
+
+	let active = Statement {
+		boundaries: vec![],
+		table_sizes: vec![16, 16],
+	};
+	let inactive_ccs = cs.compile(&inactive).unwrap();
+	let active_ccs = cs.compile(&active).unwrap();
+	assert!(inactive_ccs.oracles.size() < active_ccs.oracles.size());
+	assert!(inactive_ccs.table_constraints.len() < active_ccs.table_constraints.len());
+
+	validate_system_witness_with_prove_verify::<OptimalUnderlier128b>(&cs, witness, vec![], true);
+}
+
+#[test]
+fn test_active_table_is_constrained() {
+	let mut cs = ConstraintSystem::<B128>::new();
+	let main = SquareTable::new(&mut cs, "main");
+	let optional = SquareTable::new(&mut cs, "optional");
+
+	let allocator = Bump::new();
+	let mut witness = WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+	main.fill(&mut witness, &squares(16));
+	let mut bad_squares = squares(8);
+	bad_squares[5].1 += 1;
+	optional.fill(&mut witness, &bad_squares);
+
+	let statement = Statement {
+		boundaries: vec![],
+		table_sizes: witness.table_sizes(),
+	};
+	assert!(statement.is_table_active(optional.id));
+	let ccs = cs.compile(&statement).unwrap();
+	let witness = witness.into_multilinear_extension_index();
+	assert!(
+		binius_core::constraint_system::validate::validate_witness(&ccs, &[], &witness).is_err()
+	);
+}
@@ -0,0 +1,172 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_core::{constraint_system, fiat_shamir::HasherChallenger};
+use binius_field::{
+	arch::OptimalUnderlier128b, as_packed_field::PackedType, tower::CanonicalTowerFamily,
+};
+use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+use binius_m3::builder::{
+	test_utils::{validate_compiled_witness, ClosureFiller},
+	Col, ColumnId, ConstraintSystem, Statement, TableId, WitnessIndex, B128, B32,
+};
+use bumpalo::Bump;
+
+struct OversizedTable {
+	id: TableId,
+	bits: Col<B32>,
+	bytes: Col<B32>,
+	words: Col<B32>,
+}
+
+impl OversizedTable {
+	fn new(cs: &mut ConstraintSystem) -> Self {
+		let mut table = cs.add_table("oversized");
+		let bits = table.add_committed("bits");
+		let bytes = table.add_committed("bytes");
+		let words = table.add_committed("words");
+		table.assert_zero("bits_boolean", bits * bits - bits);
+		table.assert_zero("masked_bytes", bits * bytes - bits * words);
+		Self {
+			id: table.id(),
+			bits,
+			bytes,
+			words,
+		}
+	}
+}
+
+/// Fills the table with 13 events and returns the tower levels chosen by
+/// [`WitnessIndex::optimize_tower_levels`].
+fn fill_oversized_table<'cs, 'alloc>(
+	table: &OversizedTable,
+	witness: &mut WitnessIndex<'cs, 'alloc, PackedType<OptimalUnderlier128b, B128>>,
+) -> Vec<(ColumnId, usize)> {
+	// The bits are 0 or 1, the bytes fit in 8 bits, and the words need the full 32 bits. Where the
+	// bit is set, the word equals the byte.
+	let events = (0..13u32)
+		.map(|i| {
+			let bit = i % 3 == 0;
+			let byte = i * 17 + 3;
+			let word = if bit { byte } else { 0x9e3779b9 ^ i };
+			(bit, byte, word)
+		})
+		.collect::<Vec<_>>();
+
+	witness
+		.fill_table_sequential(
+			&ClosureFiller::new(table.id, |events, index| {
+				let mut bits = index.get_mut_as::<u32, _, 1>(table.bits)?;
+				let mut bytes = index.get_mut_as::<u32, _, 1>(table.bytes)?;
+				let mut words = index.get_mut_as::<u32, _, 1>(table.words)?;
+				for (i, &&(bit, byte, word)) in events.iter().enumerate() {
+					bits[i] = bit as u32;
+					bytes[i] = byte;
+					words[i] = word;
+				}
+				Ok(())
+			}),
+			&events,
+		)
+		.unwrap();
+
+	witness.optimize_tower_levels().unwrap()
+}
+
+#[test]
+fn test_optimize_tower_levels() {
+	let mut cs = ConstraintSystem::<B128>::new();
+	let table = OversizedTable::new(&mut cs);
+
+	let allocator = Bump::new();
+	let mut witness = WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+	let tower_levels = fill_oversized_table(&table, &mut witness);
+	assert_eq!(tower_levels, vec![(table.bits.id(), 0), (table.bytes.id(), 3)]);
+
+	let statement = Statement {
+		boundaries: vec![],
+		table_sizes: witness.table_sizes(),
+	};
+	let witness = witness.into_multilinear_extension_index();
+
+	cs.apply_tower_levels(&tower_levels).unwrap();
+	let ccs = cs.compile(&statement).unwrap();
+	let committed_tower_level = |name: &str| {
+		ccs.oracles
+			.iter()
+			.find(|(_, oracle)| oracle.name() == Some(name))
+			.map(|(_, oracle)| oracle.binary_tower_level())
+			.unwrap()
+	};
+	assert_eq!(committed_tower_level("bits"), 0);
+	assert_eq!(committed_tower_level("bytes"), 3);
+	assert_eq!(committed_tower_level("words"), 5);
+
+	validate_compiled_witness::<OptimalUnderlier128b>(&ccs, &statement.boundaries, witness, true);
+}
+
+#[test]
+fn test_tower_levels_prove_verify_round_trip() {
+	const LOG_INV_RATE: usize = 1;
+	const SECURITY_BITS: usize = 100;
+
+	// The prover lowers the tower levels after filling its witness.
+	let mut prover_cs = ConstraintSystem::<B128>::new();
+	let table = OversizedTable::new(&mut prover_cs);
+
+	let allocator = Bump::new();
+	let mut witness =
+		WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&prover_cs, &allocator);
+	let tower_levels = fill_oversized_table(&table, &mut witness);
+
+	let statement = Statement {
+		boundaries: vec![],
+		table_sizes: witness.table_sizes(),
+	};
+	let witness = witness.into_multilinear_extension_index();
+
+	prover_cs.apply_tower_levels(&tower_levels).unwrap();
+	let prover_ccs = prover_cs.compile(&statement).unwrap();
+	let proof = constraint_system::prove::<
+		OptimalUnderlier128b,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+		_,
+	>(
+		&prover_ccs,
+		LOG_INV_RATE,
+		SECURITY_BITS,
+		&statement.boundaries,
+		witness,
+		&binius_hal::make_portable_backend(),
+	)
+	.unwrap();
+
+	// The tower levels are not part of the statement. A verifier that builds its own constraint
+	// system must apply the same levels to accept the proof.
+	let verify = |tower_levels: &[(ColumnId, usize)]| {
+		let mut verifier_cs = ConstraintSystem::<B128>::new();
+		OversizedTable::new(&mut verifier_cs);
+		verifier_cs.apply_tower_levels(tower_levels).unwrap();
+		let verifier_ccs = verifier_cs.compile(&statement).unwrap();
+		constraint_system::verify::<
+			OptimalUnderlier128b,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(&verifier_ccs, LOG_INV_RATE, SECURITY_BITS, &statement.boundaries, proof.clone())
+	};
+	verify(&tower_levels).unwrap();
+	assert!(verify(&[]).is_err());
+}
+
+#[test]
+fn test_apply_tower_levels_rejects_raising() {
+	let mut cs = ConstraintSystem::<B128>::new();
+	let table = OversizedTable::new(&mut cs);
+
+	assert!(cs.apply_tower_levels(&[(table.words.id(), 7)]).is_err());
+	assert!(cs.apply_tower_levels(&[(table.words.id(), 3)]).is_ok());
+}
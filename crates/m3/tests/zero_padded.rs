@@ -0,0 +1,182 @@
+// Copyright 2025 Irreducible Inc.
+
+use assert_matches::assert_matches;
+use binius_core::{constraint_system::validate::validate_witness, oracle::ShiftVariant};
+use binius_field::{
+	arch::OptimalUnderlier128b, as_packed_field::PackedType, packed::set_packed_slice, Field,
+};
+use binius_m3::builder::{
+	test_utils::{validate_system_witness, ClosureFiller},
+	Boundary, ConstraintSystem, Error, FlushDirection, FlushOpts, Statement, WitnessIndex, B1,
+	B128, B32, B8,
+};
+use bumpalo::Bump;
+
+#[test]
+fn test_zero_padded_selector() {
+	let allocator = Bump::new();
+	let mut cs = ConstraintSystem::<B128>::new();
+
+	let channel = cs.add_channel("channel");
+
+	let mut table = cs.add_table("zero_padded");
+	let table_id = table.id();
+	let values = table.add_committed::<B32, 1>("values");
+	let selector = table.add_committed_zero_padded::<B1, 1>("selector");
+	let padded_values = table.add_committed_zero_padded::<B32, 2>("padded_values");
+
+	table.push_with_opts(
+		channel,
+		[values],
+		FlushOpts {
+			selectors: vec![selector],
+			..FlushOpts::default()
+		},
+	);
+
+	// The table is not a power of two in height, so the filler pads its last rows with copies of
+	// the last event, which would leave the selector and padded values non-zero.
+	let events = (1..=5u32).collect::<Vec<_>>();
+
+	let mut witness = WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+	witness
+		.fill_table_sequential(
+			&ClosureFiller::new(table_id, |events, index| {
+				let mut values = index.get_mut_as::<u32, _, 1>(values)?;
+				let mut selector = index.get_mut(selector)?;
+				let mut padded_values = index.get_mut_as::<u32, _, 2>(padded_values)?;
+				for (i, &&event) in events.iter().enumerate() {
+					values[i] = event;
+					set_packed_slice(&mut selector, i, B1::ONE);
+					padded_values[2 * i] = event;
+					padded_values[2 * i + 1] = !event;
+				}
+				Ok(())
+			}),
+			&events,
+		)
+		.unwrap();
+
+	let boundaries = events
+		.iter()
+		.map(|&event| Boundary {
+			values: vec![B128::new(event as u128)],
+			channel_id: channel,
+			direction: FlushDirection::Pull,
+			multiplicity: 1,
+		})
+		.collect::<Vec<_>>();
+
+	let statement = Statement {
+		boundaries: boundaries.clone(),
+		table_sizes: witness.table_sizes(),
+	};
+	let ccs = cs.compile(&statement).unwrap();
+	let padding_constraints = ccs
+		.table_constraints
+		.iter()
+		.flat_map(|constraint_set| &constraint_set.constraints)
+		.filter(|constraint| constraint.name.ends_with("_zero_padded"))
+		.count();
+	assert_eq!(padding_constraints, 2);
+
+	validate_system_witness::<OptimalUnderlier128b>(&cs, witness, boundaries);
+}
+
+#[test]
+fn test_zero_padded_power_of_two_table() {
+	let mut cs = ConstraintSystem::<B128>::new();
+
+	let mut table = cs.add_table("power_of_two");
+	table.require_power_of_two_size();
+	let _padded = table.add_committed_zero_padded::<B32, 1>("padded");
+
+	// A table with a power-of-two height has no padding rows to constrain.
+	let statement = Statement {
+		boundaries: vec![],
+		table_sizes: vec![8],
+	};
+	let ccs = cs.compile(&statement).unwrap();
+	assert!(ccs.table_constraints.is_empty());
+}
+
+#[test]
+fn test_zero_padded_dependent_computed_col() {
+	let allocator = Bump::new();
+	let mut cs = ConstraintSystem::<B128>::new();
+
+	let mut table = cs.add_table("zero_padded");
+	let table_id = table.id();
+	let values = table.add_committed::<B32, 1>("values");
+	let padded = table.add_committed_zero_padded::<B32, 1>("padded");
+	let expr = (padded + values) * padded + B32::ONE;
+	let computed = table.add_computed("computed", expr.clone());
+	table.assert_zero("computed = expr", expr - computed);
+
+	let events = (1..=5u32).collect::<Vec<_>>();
+
+	let mut witness = WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+	witness
+		.fill_table_sequential(
+			&ClosureFiller::new(table_id, |events, index| {
+				let mut values = index.get_mut_as::<u32, _, 1>(values)?;
+				let mut padded = index.get_mut_as::<u32, _, 1>(padded)?;
+				let mut computed = index.get_mut_as::<B32, _, 1>(computed)?;
+				for (i, &&event) in events.iter().enumerate() {
+					values[i] = event;
+					padded[i] = 3 * event;
+					computed[i] =
+						(B32::new(3 * event) + B32::new(event)) * B32::new(3 * event) + B32::ONE;
+				}
+				Ok(())
+			}),
+			&events,
+		)
+		.unwrap();
+
+	assert_eq!(witness.get_table(table_id).unwrap().capacity(), 8);
+	let statement = Statement {
+		boundaries: vec![],
+		table_sizes: witness.table_sizes(),
+	};
+	let ccs = cs.compile(&statement).unwrap();
+	let witness = witness.into_multilinear_extension_index();
+
+	// The padding rows of the computed column follow the zeroed column, not the last event.
+	let padding_values = |name: &str| {
+		let (oracle_id, _) = ccs
+			.oracles
+			.iter()
+			.find(|(_, oracle)| oracle.name() == Some(name))
+			.unwrap();
+		let poly = witness.get_multilin_poly(oracle_id).unwrap();
+		(5..8)
+			.map(|i| poly.evaluate_on_hypercube(i).unwrap())
+			.collect::<Vec<_>>()
+	};
+	assert_eq!(padding_values("padded"), [B128::ZERO; 3]);
+	assert_eq!(padding_values("computed"), [B128::ONE; 3]);
+
+	validate_witness(&ccs, &statement.boundaries, &witness).unwrap();
+}
+
+#[test]
+fn test_zero_padded_dependent_shifted_col() {
+	let mut cs = ConstraintSystem::<B128>::new();
+
+	let mut table = cs.add_table("zero_padded");
+	let padded = table.add_committed_zero_padded::<B8, 4>("padded");
+	let computed = table.add_computed("computed", padded + B8::ONE);
+	let shifted = table.add_shifted("shifted", computed, 2, 1, ShiftVariant::LogicalLeft);
+
+	// The shifted column would keep the last event's values on the padding rows.
+	let statement = Statement {
+		boundaries: vec![],
+		table_sizes: vec![5],
+	};
+	assert_matches!(
+		cs.compile(&statement),
+		Err(Error::ZeroPaddedDependency { column_id, inner_id })
+			if column_id == shifted.id() && inner_id == computed.id()
+	);
+}
@@ -196,7 +196,7 @@ impl<F: Field> Product for ArithExpr<F> {
 	}
 }
 
-#[derive(Clone, Copy, Debug, SerializeBytes, DeserializeBytes, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, SerializeBytes, DeserializeBytes, PartialEq, Eq, Hash)]
 pub enum ArithCircuitStep<F: Field> {
 	Add(usize, usize),
 	Mul(usize, usize),
@@ -259,22 +259,23 @@ impl<F: Field> ArithCircuit<F> {
 	}
 
 	/// The total degree of the polynomial the expression represents.
+	///
+	/// The degree is derived from the structure of the circuit, with constants having degree 0,
+	/// variables degree 1, additions the maximum and multiplications the sum of their operand
+	/// degrees. Every step is visited once, so shared subexpressions are not walked repeatedly.
 	pub fn degree(&self) -> usize {
-		fn step_degree<F: Field>(step: usize, steps: &[ArithCircuitStep<F>]) -> usize {
-			match steps[step] {
+		let mut step_degrees = Vec::<usize>::with_capacity(self.steps.len());
+		for step in &self.steps {
+			let degree = match *step {
 				ArithCircuitStep::Const(_) => 0,
 				ArithCircuitStep::Var(_) => 1,
-				ArithCircuitStep::Add(left, right) => {
-					step_degree(left, steps).max(step_degree(right, steps))
-				}
-				ArithCircuitStep::Mul(left, right) => {
-					step_degree(left, steps) + step_degree(right, steps)
-				}
-				ArithCircuitStep::Pow(base, exp) => step_degree(base, steps) * (exp as usize),
-			}
+				ArithCircuitStep::Add(left, right) => step_degrees[left].max(step_degrees[right]),
+				ArithCircuitStep::Mul(left, right) => step_degrees[left] + step_degrees[right],
+				ArithCircuitStep::Pow(base, exp) => step_degrees[base] * (exp as usize),
+			};
+			step_degrees.push(degree);
 		}
-
-		step_degree(self.steps.len() - 1, &self.steps)
+		step_degrees.last().copied().unwrap_or(0)
 	}
 
 	/// The number of variables the expression contains.
@@ -424,7 +425,8 @@ impl<F: Field> ArithCircuit<F> {
 	/// Creates a new expression with the variable indices remapped.
 	///
 	/// This recursively replaces the variable sub-expressions with an index `i` with the variable
-	/// `indices[i]`.
+	/// `indices[i]`. The mapping need not be injective: variables mapped to the same index become
+	/// the same variable.
 	///
 	/// ## Throws
 	///
@@ -451,6 +453,30 @@ impl<F: Field> ArithCircuit<F> {
 		Ok(Self { steps })
 	}
 
+	/// Returns the circuit with the unused variables removed, and the original index of each of the
+	/// remaining variables.
+	///
+	/// Variable `i` of the returned circuit is the variable `indices[i]` of this circuit, so
+	/// `pruned.remap_vars(&indices)` is equivalent to this circuit. The same indices can be used to
+	/// select the multilinears that the pruned circuit is evaluated over.
+	pub fn prune_unused_vars(&self) -> (Self, Vec<usize>) {
+		let indices = self
+			.vars_usage()
+			.into_iter()
+			.enumerate()
+			.filter_map(|(index, used)| used.then_some(index))
+			.collect::<Vec<_>>();
+
+		let mut new_indices = vec![0; self.n_vars()];
+		for (new_index, &index) in indices.iter().enumerate() {
+			new_indices[index] = new_index;
+		}
+		let pruned = self
+			.remap_vars(&new_indices)
+			.expect("new_indices has an entry for every variable");
+		(pruned, indices)
+	}
+
 	/// Substitute variable with index `var` with a constant `value`
 	pub fn const_subst(self, var: usize, value: F) -> Self {
 		let steps = self
@@ -464,6 +490,40 @@ impl<F: Field> ArithCircuit<F> {
 		Self { steps }
 	}
 
+	/// Substitute variables with sub-circuits.
+	///
+	/// Every variable with an index `i` such that `substitutions[i]` is `Some(circuit)` is replaced
+	/// with `circuit`. The remaining variables are left unchanged.
+	pub fn subst_vars(&self, substitutions: &[Option<&Self>]) -> Self {
+		let mut steps = Vec::with_capacity(self.steps.len());
+		let mut step_map = Vec::with_capacity(self.steps.len());
+		for step in &self.steps {
+			match *step {
+				ArithCircuitStep::Var(index) => {
+					if let Some(Some(circuit)) = substitutions.get(index) {
+						let mut sub_steps = circuit.steps.clone();
+						add_offset(&mut sub_steps, steps.len());
+						steps.extend(sub_steps);
+					} else {
+						steps.push(ArithCircuitStep::Var(index));
+					}
+				}
+				ArithCircuitStep::Const(value) => steps.push(ArithCircuitStep::Const(value)),
+				ArithCircuitStep::Add(left, right) => {
+					steps.push(ArithCircuitStep::Add(step_map[left], step_map[right]))
+				}
+				ArithCircuitStep::Mul(left, right) => {
+					steps.push(ArithCircuitStep::Mul(step_map[left], step_map[right]))
+				}
+				ArithCircuitStep::Pow(base, exp) => {
+					steps.push(ArithCircuitStep::Pow(step_map[base], exp))
+				}
+			}
+			step_map.push(steps.len() - 1);
+		}
+		Self { steps }
+	}
+
 	/// Returns `Some(F)` if the expression is a constant.
 	pub fn get_constant(&self) -> Option<F> {
 		if let ArithCircuitStep::Const(value) =
@@ -681,6 +741,46 @@ impl<F: Field> ArithCircuit<F> {
 		self.compress_unused_steps();
 	}
 
+	/// Returns an equivalent circuit in which structurally equal subexpressions share one step.
+	///
+	/// Each step is hashed after its operands have been replaced by their shared steps, so the
+	/// circuit is traversed once. The operands of additions and multiplications are put in a
+	/// canonical order, which also shares subexpressions that only differ by commuted operands.
+	/// Steps that do not contribute to the result are removed. Unlike [`Self::optimize`], constants
+	/// are not folded.
+	pub fn cse(&self) -> Self {
+		let mut steps = Vec::with_capacity(self.steps.len());
+		let mut step_map = HashMap::new();
+		let mut step_indices = Vec::with_capacity(self.steps.len());
+		for step in &self.steps {
+			let step = match *step {
+				ArithCircuitStep::Add(left, right) => {
+					let (left, right) = ordered_pair(step_indices[left], step_indices[right]);
+					ArithCircuitStep::Add(left, right)
+				}
+				ArithCircuitStep::Mul(left, right) => {
+					let (left, right) = ordered_pair(step_indices[left], step_indices[right]);
+					ArithCircuitStep::Mul(left, right)
+				}
+				ArithCircuitStep::Pow(base, exp) => ArithCircuitStep::Pow(step_indices[base], exp),
+				step @ (ArithCircuitStep::Const(_) | ArithCircuitStep::Var(_)) => step,
+			};
+			let index = *step_map.entry(step).or_insert_with(|| {
+				steps.push(step);
+				steps.len() - 1
+			});
+			step_indices.push(index);
+		}
+
+		let mut circuit = Self { steps };
+		if let Some(&result) = step_indices.last() {
+			// Steps after the result can only be used by steps that were deduplicated away.
+			circuit.steps.truncate(result + 1);
+			circuit.compress_unused_steps();
+		}
+		circuit
+	}
+
 	/// Same as `optimize_in_place`, but returns a new instance of the circuit.
 	pub fn optimize(mut self) -> Self {
 		self.optimize_constants();
@@ -917,6 +1017,14 @@ impl<F: Field> Product for ArithCircuit<F> {
 	}
 }
 
+const fn ordered_pair(a: usize, b: usize) -> (usize, usize) {
+	if a <= b {
+		(a, b)
+	} else {
+		(b, a)
+	}
+}
+
 fn add_offset<F: Field>(steps: &mut [ArithCircuitStep<F>], offset: usize) {
 	for step in steps.iter_mut() {
 		match step {
@@ -1153,11 +1261,12 @@ impl Add for EvalCost {
 
 #[cfg(test)]
 mod tests {
-	use std::collections::HashSet;
+	use std::{collections::HashSet, iter};
 
 	use assert_matches::assert_matches;
 	use binius_field::{BinaryField, BinaryField128b, BinaryField1b, BinaryField8b};
 	use binius_utils::{DeserializeBytes, SerializationMode, SerializeBytes};
+	use proptest::{collection::vec, prelude::*};
 
 	use super::*;
 
@@ -1174,6 +1283,36 @@ mod tests {
 		assert_eq!(expr.degree(), 14);
 	}
 
+	#[test]
+	fn test_degree_of_nested_add_mul() {
+		type F = BinaryField8b;
+		let x = || ArithCircuit::<F>::var(0);
+		let y = || ArithCircuit::<F>::var(1);
+		let z = || ArithCircuit::<F>::var(2);
+		let c = || ArithCircuit::<F>::constant(F::new(3));
+
+		assert_eq!(c().degree(), 0);
+		assert_eq!(x().degree(), 1);
+		assert_eq!((x() + c()).degree(), 1);
+		assert_eq!((x() * c()).degree(), 1);
+		assert_eq!((x() * y() + z()).degree(), 2);
+		assert_eq!(((x() + y()) * (y() + z()) * z() + c()).degree(), 3);
+		assert_eq!((x() * (y() * z() + x()) + y() * c()).degree(), 3);
+		assert_eq!(((x() * y() + c()) * (z() + c()) + x().pow(2)).degree(), 3);
+	}
+
+	#[test]
+	fn test_degree_with_shared_subexpressions() {
+		type F = BinaryField8b;
+		// Each step squares the previous one by multiplying it with itself, so a walk of the
+		// expression tree would visit 2^60 nodes.
+		let steps = iter::once(ArithCircuitStep::Var(0))
+			.chain((0..60).map(|i| ArithCircuitStep::Mul(i, i)))
+			.collect();
+		let circuit = ArithCircuit::<F> { steps };
+		assert_eq!(circuit.degree(), 1 << 60);
+	}
+
 	#[test]
 	fn test_n_vars() {
 		type F = BinaryField8b;
@@ -1220,6 +1359,61 @@ mod tests {
 		assert_eq!(new_expr.unwrap(), expected);
 	}
 
+	#[test]
+	fn test_remap_vars_non_injective() {
+		type F = BinaryField8b;
+		let expr = ArithCircuit::<F>::var(0) * ArithCircuit::var(1) + ArithCircuit::var(0);
+		let new_expr = expr.remap_vars(&[1, 1]).unwrap();
+
+		assert_eq!(new_expr, ArithCircuit::var(1) * ArithCircuit::var(1) + ArithCircuit::var(1));
+		assert_eq!(new_expr.n_vars(), 2);
+		assert_eq!(new_expr.degree(), 2);
+	}
+
+	#[test]
+	fn test_prune_unused_vars() {
+		type F = BinaryField8b;
+		let circuit = ArithCircuit::<F>::var(1) * ArithCircuit::var(4)
+			+ ArithCircuit::var(1).pow(2)
+			+ ArithCircuit::constant(F::new(3)) * ArithCircuit::var(6);
+
+		let (pruned, indices) = circuit.prune_unused_vars();
+		assert_eq!(indices, vec![1, 4, 6]);
+		assert_eq!(pruned.n_vars(), 3);
+		assert_eq!(
+			pruned,
+			ArithCircuit::var(0) * ArithCircuit::var(1)
+				+ ArithCircuit::var(0).pow(2)
+				+ ArithCircuit::constant(F::new(3)) * ArithCircuit::var(2)
+		);
+		assert_eq!(pruned.remap_vars(&indices).unwrap(), circuit);
+
+		let query = (0..7).map(|i| F::new(i + 2)).collect::<Vec<_>>();
+		let pruned_query = indices.iter().map(|&i| query[i]).collect::<Vec<_>>();
+		assert_eq!(pruned.evaluate(&pruned_query).unwrap(), circuit.evaluate(&query).unwrap());
+
+		let constant = ArithCircuit::constant(F::new(5));
+		assert_eq!(constant.prune_unused_vars(), (constant, vec![]));
+	}
+
+	#[test]
+	fn test_subst_vars() {
+		type F = BinaryField8b;
+		let expr =
+			((ArithCircuit::var(0) + ArithCircuit::constant(F::ONE)) * ArithCircuit::var(1)).pow(3);
+		let sub = ArithCircuit::var(2) * ArithCircuit::var(3) + ArithCircuit::var(0);
+		let new_expr = expr.subst_vars(&[None, Some(&sub)]);
+
+		let expected = ((ArithCircuit::var(0) + ArithCircuit::constant(F::ONE)) * sub).pow(3);
+		assert_eq!(new_expr, expected);
+
+		let query = [F::new(3), F::new(5), F::new(7), F::new(11)];
+		assert_eq!(
+			new_expr.evaluate(&query).unwrap(),
+			((query[0] + F::ONE) * (query[2] * query[3] + query[0])).pow(3)
+		);
+	}
+
 	#[test]
 	fn test_optimize_identity_handling() {
 		type F = BinaryField8b;
@@ -1526,4 +1720,55 @@ mod tests {
 			.unwrap();
 		assert_eq!(result, F::new(2) + F::new(3) * (F::new(4) + F::new(5)).pow(5));
 	}
+
+	#[test]
+	fn test_cse_shares_commuted_subexpressions() {
+		type F = BinaryField8b;
+		let circuit = (ArithCircuit::<F>::var(0) * ArithCircuit::var(1)).pow(2)
+			+ (ArithCircuit::var(1) * ArithCircuit::var(0)).pow(2);
+
+		let expected_circuit = ArithCircuit::<F> {
+			steps: vec![
+				ArithCircuitStep::Var(0),
+				ArithCircuitStep::Var(1),
+				ArithCircuitStep::Mul(0, 1),
+				ArithCircuitStep::Pow(2, 2),
+				ArithCircuitStep::Add(3, 3),
+			],
+		};
+		assert_eq!(circuit.cse().steps, expected_circuit.steps);
+	}
+
+	/// Builds a circuit from arbitrary step descriptions, drawing operands from the previous steps
+	/// and variables and constants from small sets so that duplicated subexpressions are common.
+	fn circuit_from_steps(descriptions: &[(u8, usize, usize)]) -> ArithCircuit<BinaryField128b> {
+		let steps = descriptions
+			.iter()
+			.enumerate()
+			.map(|(i, &(kind, a, b))| match kind % 5 {
+				0 if i > 0 => ArithCircuitStep::Add(a % i, b % i),
+				1 if i > 0 => ArithCircuitStep::Mul(a % i, b % i),
+				2 if i > 0 => ArithCircuitStep::Pow(a % i, (b % 4) as u64),
+				3 => ArithCircuitStep::Const(BinaryField128b::new((a % 3) as u128)),
+				_ => ArithCircuitStep::Var(a % 3),
+			})
+			.collect();
+		ArithCircuit { steps }
+	}
+
+	proptest! {
+		#[test]
+		fn test_cse_preserves_evaluation(
+			descriptions in vec((any::<u8>(), any::<usize>(), any::<usize>()), 1..64),
+			query in vec(any::<u128>(), 3),
+		) {
+			let circuit = circuit_from_steps(&descriptions);
+			let cse = circuit.cse();
+			prop_assert!(cse.steps.len() <= circuit.steps.len());
+			prop_assert_eq!(&cse.cse().steps, &cse.steps);
+
+			let query = query.into_iter().map(BinaryField128b::new).collect::<Vec<_>>();
+			prop_assert_eq!(cse.evaluate(&query).unwrap(), circuit.evaluate(&query).unwrap());
+		}
+	}
 }
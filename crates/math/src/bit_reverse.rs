@@ -0,0 +1,98 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::{
+	packed::{get_packed_slice_unchecked, set_packed_slice_unchecked},
+	PackedField,
+};
+use binius_utils::bail;
+
+use crate::Error;
+
+/// Reverses the lowest `log_len` bits of `index`.
+///
+/// Bits of `index` above the lowest `log_len` are discarded.
+#[inline]
+pub fn reverse_bits(index: usize, log_len: usize) -> usize {
+	index
+		.reverse_bits()
+		.checked_shr((usize::BITS as usize - log_len) as u32)
+		.unwrap_or(0)
+}
+
+/// Permutes the `2^log_len` scalars of a packed slice in place by reversing the bits of their
+/// indices.
+///
+/// The permutation is over the logical scalar indices, so scalars move across packed elements.
+/// When `log_len` is less than `P::LOG_WIDTH`, the slice is a single packed element and only its
+/// first `2^log_len` scalars are permuted.
+pub fn bit_reverse_permute<P: PackedField>(slice: &mut [P], log_len: usize) -> Result<(), Error> {
+	let expected_len = 1 << log_len.saturating_sub(P::LOG_WIDTH);
+	if slice.len() != expected_len {
+		bail!(Error::IncorrectArgumentLength {
+			arg: "slice".into(),
+			expected: expected_len,
+		});
+	}
+
+	for i in 0..1 << log_len {
+		let bit_reversed_index = reverse_bits(i, log_len);
+		if i < bit_reversed_index {
+			// Safety: both indices are less than `2^log_len`, which is at most the number of
+			// scalars in the slice by the length check above.
+			unsafe {
+				let tmp = get_packed_slice_unchecked(slice, i);
+				let other = get_packed_slice_unchecked(slice, bit_reversed_index);
+				set_packed_slice_unchecked(slice, i, other);
+				set_packed_slice_unchecked(slice, bit_reversed_index, tmp);
+			}
+		}
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use binius_field::{packed::iter_packed_slice_with_offset, PackedBinaryField4x32b};
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+
+	type P = PackedBinaryField4x32b;
+
+	fn check_against_reference(log_len: usize) {
+		let mut rng = StdRng::seed_from_u64(0);
+		let mut packed = repeat_with(|| P::random(&mut rng))
+			.take(1 << log_len.saturating_sub(P::LOG_WIDTH))
+			.collect::<Vec<_>>();
+		let scalars = iter_packed_slice_with_offset(&packed, 0).collect::<Vec<_>>();
+
+		bit_reverse_permute(&mut packed, log_len).unwrap();
+
+		let permuted = iter_packed_slice_with_offset(&packed, 0).collect::<Vec<_>>();
+		for (i, &scalar) in permuted.iter().take(1 << log_len).enumerate() {
+			let mut reversed = 0;
+			for bit in 0..log_len {
+				reversed |= ((i >> bit) & 1) << (log_len - 1 - bit);
+			}
+			assert_eq!(scalar, scalars[reversed]);
+		}
+		// Scalars past `2^log_len` in a partially used packed element are left untouched.
+		assert_eq!(permuted[1 << log_len..], scalars[1 << log_len..]);
+	}
+
+	#[test]
+	fn test_bit_reverse_permute() {
+		for log_len in 0..8 {
+			check_against_reference(log_len);
+		}
+	}
+
+	#[test]
+	fn test_bit_reverse_permute_incorrect_length() {
+		let mut packed = vec![P::default(); 3];
+		assert!(bit_reverse_permute(&mut packed, 3).is_err());
+		assert!(bit_reverse_permute(&mut packed, 1).is_err());
+	}
+}
@@ -0,0 +1,126 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::collections::HashMap;
+
+use binius_field::PackedField;
+use binius_utils::bail;
+
+use crate::{Error, MultilinearExtension, MultilinearQuery};
+
+/// A multilinear polynomial that memoizes its partial evaluations at query prefixes.
+///
+/// Evaluating at many points that share the values of the low-indexed variables is a common
+/// pattern, for example when opening a polynomial at several points of a batched claim. The
+/// polynomial is contracted with the tensor expansion of each distinct prefix once, and every
+/// evaluation with that prefix only contracts the smaller partial evaluation with its suffix.
+#[derive(Debug, Clone)]
+pub struct CachedMultilinear<P: PackedField> {
+	multilinear: MultilinearExtension<P>,
+	partial_evals: HashMap<Vec<P::Scalar>, MultilinearExtension<P>>,
+}
+
+impl<P: PackedField> CachedMultilinear<P> {
+	pub fn new(multilinear: MultilinearExtension<P>) -> Self {
+		Self {
+			multilinear,
+			partial_evals: HashMap::new(),
+		}
+	}
+
+	pub const fn multilinear(&self) -> &MultilinearExtension<P> {
+		&self.multilinear
+	}
+
+	pub fn n_vars(&self) -> usize {
+		self.multilinear.n_vars()
+	}
+
+	/// Evaluates the polynomial at the point that is the concatenation of `prefix` and `suffix`.
+	///
+	/// `prefix` assigns the low-indexed variables and `suffix` the remaining ones. The partial
+	/// evaluation at `prefix` is cached and reused by later calls with the same prefix.
+	pub fn eval_with_prefix(
+		&mut self,
+		prefix: &[P::Scalar],
+		suffix: &[P::Scalar],
+	) -> Result<P::Scalar, Error> {
+		if prefix.len() + suffix.len() != self.n_vars() {
+			bail!(Error::IncorrectQuerySize {
+				expected: self.n_vars(),
+			});
+		}
+
+		let partial_eval = match self.partial_evals.get(prefix) {
+			Some(partial_eval) => partial_eval,
+			None => {
+				let partial_eval = self
+					.multilinear
+					.evaluate_partial_low(&MultilinearQuery::<P>::expand(prefix))?;
+				self.partial_evals
+					.entry(prefix.to_vec())
+					.or_insert(partial_eval)
+			}
+		};
+		partial_eval.evaluate(&MultilinearQuery::<P>::expand(suffix))
+	}
+
+	/// The number of distinct prefixes whose partial evaluations are cached.
+	pub fn n_cached_prefixes(&self) -> usize {
+		self.partial_evals.len()
+	}
+
+	/// Drops all cached partial evaluations.
+	pub fn clear_cache(&mut self) {
+		self.partial_evals.clear();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use assert_matches::assert_matches;
+	use binius_field::{BinaryField32b, Field, PackedBinaryField4x32b};
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+
+	#[test]
+	fn test_eval_with_prefix_matches_evaluate() {
+		type P = PackedBinaryField4x32b;
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let n_vars = 6;
+		let multilinear = MultilinearExtension::from_values(
+			repeat_with(|| P::random(&mut rng))
+				.take(1 << (n_vars - P::LOG_WIDTH))
+				.collect(),
+		)
+		.unwrap();
+		let mut cached = CachedMultilinear::new(multilinear.clone());
+
+		for prefix_len in 0..=n_vars {
+			let prefix = repeat_with(|| <BinaryField32b as Field>::random(&mut rng))
+				.take(prefix_len)
+				.collect::<Vec<_>>();
+			for _ in 0..3 {
+				let suffix = repeat_with(|| <BinaryField32b as Field>::random(&mut rng))
+					.take(n_vars - prefix_len)
+					.collect::<Vec<_>>();
+				let point = [prefix.as_slice(), suffix.as_slice()].concat();
+				let expected = multilinear
+					.evaluate::<BinaryField32b, P>(&MultilinearQuery::expand(&point))
+					.unwrap();
+				assert_eq!(cached.eval_with_prefix(&prefix, &suffix).unwrap(), expected);
+			}
+			assert_eq!(cached.n_cached_prefixes(), prefix_len + 1);
+		}
+
+		assert_matches!(
+			cached.eval_with_prefix(&[BinaryField32b::ONE], &[]),
+			Err(Error::IncorrectQuerySize { expected: 6 })
+		);
+		cached.clear_cache();
+		assert_eq!(cached.n_cached_prefixes(), 0);
+	}
+}
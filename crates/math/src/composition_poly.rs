@@ -27,6 +27,17 @@ where
 	/// Returns the arithmetic expression representing the polynomial.
 	fn expression(&self) -> ArithCircuit<P::Scalar>;
 
+	/// Returns, for each of the [`Self::n_vars`] variables, whether the polynomial depends on it.
+	///
+	/// The default implementation builds the [`Self::expression`]. Sumcheck provers query this
+	/// every round, so compositions that can answer without rebuilding their expression should
+	/// override it.
+	fn vars_usage(&self) -> Vec<bool> {
+		let mut usage = self.expression().vars_usage();
+		usage.resize(self.n_vars(), false);
+		usage
+	}
+
 	/// Evaluates the polynomial using packed values, where each packed value may contain multiple
 	/// scalar values. The evaluation follows SIMD semantics, meaning that operations are performed
 	/// element-wise across corresponding scalar values in the packed values.
@@ -66,4 +77,20 @@ where
 			Ok(())
 		})
 	}
+
+	/// Batch evaluation over a query given as one slice per variable.
+	///
+	/// `columns[i][j]` is the value of variable `i` at point `j`, and every column should have
+	/// the same length as `evals`. This is the layout that [`RowsBatchRef`] holds, so the default
+	/// implementation wraps the columns without copying them.
+	fn batch_evaluate_columns(&self, columns: &[&[P]], evals: &mut [P]) -> Result<(), Error> {
+		if let Some(column) = columns.iter().find(|column| column.len() != evals.len()) {
+			bail!(Error::BatchEvaluateSizeMismatch {
+				expected: evals.len(),
+				actual: column.len(),
+			});
+		}
+
+		self.batch_evaluate(&RowsBatchRef::new(columns, evals.len()), evals)
+	}
 }
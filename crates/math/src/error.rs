@@ -8,8 +8,8 @@ pub enum Error {
 	IncorrectArgumentLength { arg: String, expected: usize },
 	#[error("the matrix is not square")]
 	MatrixNotSquare,
-	#[error("the matrix is singular")]
-	MatrixIsSingular,
+	#[error("the matrix is singular: no pivot found for row {pivot_row} during elimination")]
+	MatrixIsSingular { pivot_row: usize },
 	#[error("domain size is larger than the field")]
 	DomainSizeTooLarge,
 	#[error("the inputted packed values slice had an unexpected length")]
@@ -20,6 +20,8 @@ pub enum Error {
 	DuplicateDomainPoint,
 	#[error("expected the number of evaluations to match the domain size")]
 	ExtrapolateNumberOfEvaluations,
+	#[error("the evaluations are not those of a polynomial of degree at most {max_degree}")]
+	DegreeBoundExceeded { max_degree: usize },
 	#[error("{0}")]
 	FieldError(#[from] binius_field::Error),
 	#[error(
@@ -60,6 +62,8 @@ pub enum Error {
 	},
 	#[error("there are a total of {actual} polynomials, according to n_pieces_by_vars, while you have provided evaluations for {expected}")]
 	PiecewiseMultilinearIncompatibleEvals { actual: usize, expected: usize },
+	#[error("expected a permutation of {n_vars} variables")]
+	InvalidVariablePermutation { n_vars: usize },
 	#[error("cannot fold a constant multilinear")]
 	ConstantFold,
 	#[error("the function expects the expression to have degree at most 1")]
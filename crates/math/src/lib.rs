@@ -15,6 +15,8 @@
 
 mod arith_expr;
 mod binary_subspace;
+mod bit_reverse;
+mod cached_multilinear;
 mod composition_poly;
 mod error;
 mod evaluation_order;
@@ -25,13 +27,17 @@ mod multilinear;
 mod multilinear_extension;
 mod multilinear_query;
 mod packing_deref;
+mod permute_vars_multilinear;
 mod piecewise_multilinear;
 mod rows_batch;
 mod tensor_prod_eq_ind;
 mod univariate;
+mod zero_pad_multilinear;
 
 pub use arith_expr::*;
 pub use binary_subspace::*;
+pub use bit_reverse::*;
+pub use cached_multilinear::*;
 pub use composition_poly::*;
 pub use error::*;
 pub use evaluation_order::*;
@@ -42,7 +48,9 @@ pub use multilinear::*;
 pub use multilinear_extension::*;
 pub use multilinear_query::*;
 pub use packing_deref::*;
+pub use permute_vars_multilinear::*;
 pub use piecewise_multilinear::*;
 pub use rows_batch::*;
 pub use tensor_prod_eq_ind::*;
 pub use univariate::*;
+pub use zero_pad_multilinear::*;
@@ -5,7 +5,7 @@ use std::{
 	ops::{Add, AddAssign, Index, IndexMut, Sub, SubAssign},
 };
 
-use binius_field::{ExtensionField, Field};
+use binius_field::{packed::mul_by_subfield_scalar, ExtensionField, Field, PackedExtension};
 use binius_utils::bail;
 use bytemuck::zeroed_slice_box;
 use getset::CopyGetters;
@@ -106,12 +106,54 @@ impl<F: Field> Matrix<F> {
 		}
 	}
 
+	/// Returns the transpose of the matrix.
+	pub fn transpose(&self) -> Self {
+		let mut out = Self::zeros(self.n, self.m);
+		for i in 0..self.m {
+			for j in 0..self.n {
+				out[(j, i)] = self[(i, j)];
+			}
+		}
+		out
+	}
+
+	/// Transposes a square matrix in place.
+	///
+	/// ## Preconditions
+	///
+	/// * `self` - must be square
+	pub fn transpose_square(&mut self) {
+		assert_eq!(self.m, self.n);
+
+		for i in 0..self.n {
+			for j in i + 1..self.n {
+				self.elements.swap(i * self.n + j, j * self.n + i);
+			}
+		}
+	}
+
+	/// Multiplies the matrix by a vector of packed elements, lane by lane.
+	///
+	/// Each of the `P::WIDTH` lanes of `x` is an independent vector, and the corresponding lane of
+	/// `y` receives its product with the matrix.
+	pub fn mul_vec_into_packed<P: PackedExtension<F>>(&self, x: &[P], y: &mut [P]) {
+		assert_eq!(self.n(), x.len());
+		assert_eq!(self.m(), y.len());
+
+		for i in 0..y.len() {
+			y[i] = (0..self.n())
+				.map(|j| mul_by_subfield_scalar(x[j], self[(i, j)]))
+				.sum();
+		}
+	}
+
 	/// Invert a square matrix
 	///
 	/// ## Throws
 	///
 	/// * [`Error::MatrixNotSquare`]
-	/// * [`Error::MatrixIsSingular`]
+	/// * [`Error::MatrixIsSingular`] - with the row at which Gaussian elimination found no non-zero
+	///   pivot
 	///
 	/// ## Preconditions
 	///
@@ -134,7 +176,7 @@ impl<F: Field> Matrix<F> {
 			// Find the pivot row
 			let pivot = (i..n)
 				.find(|&pivot| tmp[(pivot, i)] != F::ZERO)
-				.ok_or(Error::MatrixIsSingular)?;
+				.ok_or(Error::MatrixIsSingular { pivot_row: i })?;
 			if pivot != i {
 				tmp.swap_rows(i, pivot, &mut row_buffer);
 				out.swap_rows(i, pivot, &mut row_buffer);
@@ -260,7 +302,8 @@ impl<F: Field> SubAssign<&Self> for Matrix<F> {
 
 #[cfg(test)]
 mod tests {
-	use binius_field::BinaryField32b;
+	use assert_matches::assert_matches;
+	use binius_field::{BinaryField128b, BinaryField32b, PackedBinaryField2x128b, PackedField};
 	use proptest::prelude::*;
 	use rand::{rngs::StdRng, SeedableRng};
 
@@ -340,5 +383,91 @@ mod tests {
 			Matrix::mul_into(&a_inv, &a, &mut prod);
 			assert_eq!(prod, Matrix::<F>::identity(n));
 		}
+
+		#[test]
+		fn test_inverse_singular(n in 2..8usize, dependent_row in 1..8usize) {
+			type F = BinaryField32b;
+
+			let dependent_row = dependent_row % n;
+			let mut rng = StdRng::seed_from_u64(0);
+			let mut a = Matrix::<F>::random(n, n, &mut rng);
+			// Make one row a sum of the rows above it, so that elimination runs out of pivots.
+			for j in 0..n {
+				a[(dependent_row, j)] = (0..dependent_row).map(|i| a[(i, j)]).sum();
+			}
+			let mut a_inv = Matrix::<F>::zeros(n, n);
+
+			let result = a.inverse_into(&mut a_inv);
+			assert_matches!(result, Err(Error::MatrixIsSingular { pivot_row }) if pivot_row < n);
+		}
+
+		#[test]
+		fn test_transpose(m in 0..8usize, n in 0..8usize) {
+			type F = BinaryField32b;
+
+			let mut rng = StdRng::seed_from_u64(0);
+			let a = Matrix::<F>::random(m, n, &mut rng);
+			let a_t = a.transpose();
+			assert_eq!(a_t.dim(), (n, m));
+			assert_eq!(a_t.transpose(), a);
+
+			// A^T x is the vector whose i-th entry is the inner product of x with column i of A.
+			let x = (0..m).map(|_| <F as Field>::random(&mut rng)).collect::<Vec<_>>();
+			let mut y = vec![F::ZERO; n];
+			a_t.mul_vec_into(&x, &mut y);
+			for (j, &y_j) in y.iter().enumerate() {
+				assert_eq!(y_j, (0..m).map(|i| x[i] * a[(i, j)]).sum::<F>());
+			}
+		}
+
+		#[test]
+		fn test_transpose_square(n in 0..8usize) {
+			type F = BinaryField32b;
+
+			let mut rng = StdRng::seed_from_u64(0);
+			let a = Matrix::<F>::random(n, n, &mut rng);
+			let mut a_t = a.clone();
+			a_t.transpose_square();
+			assert_eq!(a_t, a.transpose());
+
+			a_t.transpose_square();
+			assert_eq!(a_t, a);
+		}
+
+		#[test]
+		fn test_mul_vec_into_packed(m in 0..8usize, n in 0..8usize) {
+			type F = BinaryField32b;
+			type FE = BinaryField128b;
+			type P = PackedBinaryField2x128b;
+
+			let mut rng = StdRng::seed_from_u64(0);
+			let a = Matrix::<F>::random(m, n, &mut rng);
+			let x = (0..n).map(|_| P::random(&mut rng)).collect::<Vec<_>>();
+			let mut y = vec![P::zero(); m];
+			a.mul_vec_into_packed(&x, &mut y);
+
+			for lane in 0..P::WIDTH {
+				let x_lane = x.iter().map(|x_j| x_j.get(lane)).collect::<Vec<FE>>();
+				let mut y_lane = vec![FE::ZERO; m];
+				a.mul_vec_into(&x_lane, &mut y_lane);
+				for (y_i, y_lane_i) in y.iter().zip(y_lane) {
+					assert_eq!(y_i.get(lane), y_lane_i);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_inverse_singular_reports_pivot_row() {
+		type F = BinaryField32b;
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let mut a = Matrix::<F>::random(4, 4, &mut rng);
+		for i in 0..4 {
+			a[(i, 2)] = F::ZERO;
+		}
+		let mut a_inv = Matrix::<F>::zeros(4, 4);
+
+		assert_matches!(a.inverse_into(&mut a_inv), Err(Error::MatrixIsSingular { pivot_row: 2 }));
 	}
 }
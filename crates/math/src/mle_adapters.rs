@@ -573,7 +573,19 @@ where
 	}
 }
 
-fn validate_subcube_partial_evals_params<P: PackedField>(
+/// Builds a multilinear extension from a function computing its hypercube evaluations.
+pub(crate) fn multilinear_from_fn<P: PackedField>(
+	n_vars: usize,
+	f: impl Fn(usize) -> Result<P::Scalar, Error>,
+) -> Result<MultilinearExtension<P>, Error> {
+	let mut evals = vec![P::zero(); 1 << n_vars.saturating_sub(P::LOG_WIDTH)];
+	for i in 0..1 << n_vars {
+		set_packed_slice(&mut evals, i, f(i)?);
+	}
+	MultilinearExtension::new(n_vars, evals)
+}
+
+pub(crate) fn validate_subcube_partial_evals_params<P: PackedField>(
 	n_vars: usize,
 	query: MultilinearQueryRef<P>,
 	subcube_vars: usize,
@@ -607,20 +619,263 @@ fn validate_subcube_partial_evals_params<P: PackedField>(
 	Ok(())
 }
 
+// The helpers below derive the query-dependent methods of [`MultilinearPoly`] from hypercube
+// evaluations alone. They back lazy adapters, such as [`crate::ZeroPadMultilinear`] and
+// [`crate::PermuteVarsMultilinear`], whose evaluations are never materialized.
+
+/// Computes $\sum_i q_i f(\mathrm{index}(i))$ over the scalars of a query expansion.
+fn fold_expansion<P: PackedField, M: MultilinearPoly<P> + ?Sized>(
+	poly: &M,
+	expansion: &[P],
+	n_query_vars: usize,
+	index: impl Fn(usize) -> usize,
+) -> Result<P::Scalar, Error> {
+	let mut acc = P::Scalar::ZERO;
+	for i in 0..1 << n_query_vars {
+		let weight = get_packed_slice(expansion, i);
+		acc += poly.evaluate_on_hypercube_and_scale(index(i), weight)?;
+	}
+	Ok(acc)
+}
+
+/// Implements [`MultilinearPoly::evaluate`] on top of the hypercube evaluations of `poly`.
+pub(crate) fn hypercube_evaluate<P: PackedField, M: MultilinearPoly<P> + ?Sized>(
+	poly: &M,
+	query: MultilinearQueryRef<P>,
+) -> Result<P::Scalar, Error> {
+	if query.n_vars() != poly.n_vars() {
+		bail!(Error::IncorrectQuerySize {
+			expected: poly.n_vars()
+		});
+	}
+	fold_expansion(poly, query.expansion(), query.n_vars(), |i| i)
+}
+
+/// Implements [`MultilinearPoly::evaluate_partial`] on top of the hypercube evaluations of `poly`.
+pub(crate) fn hypercube_evaluate_partial<P: PackedField, M: MultilinearPoly<P> + ?Sized>(
+	poly: &M,
+	query: MultilinearQueryRef<P>,
+	start_index: usize,
+) -> Result<MultilinearExtension<P>, Error> {
+	let n_vars = poly.n_vars();
+	let query_n_vars = query.n_vars();
+	if start_index + query_n_vars > n_vars {
+		bail!(Error::IncorrectStartIndex { expected: n_vars });
+	}
+
+	let low_mask = (1 << start_index) - 1;
+	multilinear_from_fn(n_vars - query_n_vars, |j| {
+		let low = j & low_mask;
+		let high = (j >> start_index) << (start_index + query_n_vars);
+		fold_expansion(poly, query.expansion(), query_n_vars, |i| low | i << start_index | high)
+	})
+}
+
+/// Implements [`MultilinearPoly::zero_pad`] on top of the hypercube evaluations of `poly`.
+pub(crate) fn hypercube_zero_pad<P: PackedField, M: MultilinearPoly<P> + ?Sized>(
+	poly: &M,
+	n_pad_vars: usize,
+	start_index: usize,
+	nonzero_index: usize,
+) -> Result<MultilinearExtension<P>, Error> {
+	let n_vars = poly.n_vars();
+	if start_index > n_vars {
+		bail!(Error::IncorrectStartIndexZeroPad { expected: n_vars });
+	}
+	if nonzero_index >= 1 << n_pad_vars {
+		bail!(Error::IncorrectNonZeroIndex {
+			expected: 1 << n_pad_vars,
+		});
+	}
+
+	let low_mask = (1 << start_index) - 1;
+	let pad_mask = (1 << n_pad_vars) - 1;
+	multilinear_from_fn(n_vars + n_pad_vars, |j| {
+		if (j >> start_index) & pad_mask != nonzero_index {
+			return Ok(P::Scalar::ZERO);
+		}
+		let high = (j >> (start_index + n_pad_vars)) << start_index;
+		poly.evaluate_on_hypercube(j & low_mask | high)
+	})
+}
+
+/// Implements [`MultilinearPoly::subcube_partial_low_evals`] on top of the hypercube evaluations
+/// of `poly`.
+pub(crate) fn hypercube_subcube_partial_low_evals<
+	P: PackedField,
+	M: MultilinearPoly<P> + ?Sized,
+>(
+	poly: &M,
+	query: MultilinearQueryRef<P>,
+	subcube_vars: usize,
+	subcube_index: usize,
+	partial_low_evals: &mut [P],
+) -> Result<(), Error> {
+	validate_subcube_partial_evals_params(
+		poly.n_vars(),
+		query,
+		subcube_vars,
+		subcube_index,
+		partial_low_evals,
+	)?;
+
+	let query_n_vars = query.n_vars();
+	let subcube_start = subcube_index << (query_n_vars + subcube_vars);
+	for scalar_index in 0..1 << subcube_vars {
+		let evals_start = subcube_start + (scalar_index << query_n_vars);
+		let eval = fold_expansion(poly, query.expansion(), query_n_vars, |i| evals_start + i)?;
+		set_packed_slice(partial_low_evals, scalar_index, eval);
+	}
+
+	Ok(())
+}
+
+/// Implements [`MultilinearPoly::subcube_partial_high_evals`] on top of the hypercube evaluations
+/// of `poly`.
+pub(crate) fn hypercube_subcube_partial_high_evals<
+	P: PackedField,
+	M: MultilinearPoly<P> + ?Sized,
+>(
+	poly: &M,
+	query: MultilinearQueryRef<P>,
+	subcube_vars: usize,
+	subcube_index: usize,
+	partial_high_evals: &mut [P],
+) -> Result<(), Error> {
+	validate_subcube_partial_evals_params(
+		poly.n_vars(),
+		query,
+		subcube_vars,
+		subcube_index,
+		partial_high_evals,
+	)?;
+
+	let query_n_vars = query.n_vars();
+	let query_shift = poly.n_vars() - query_n_vars;
+	partial_high_evals.fill(P::zero());
+	for scalar_index in 0..1 << subcube_vars {
+		let evals_start = subcube_index << subcube_vars | scalar_index;
+		let eval = fold_expansion(poly, query.expansion(), query_n_vars, |i| {
+			evals_start | i << query_shift
+		})?;
+		set_packed_slice(partial_high_evals, scalar_index, eval);
+	}
+
+	Ok(())
+}
+
+/// Assertions shared by the tests of the lazy multilinear adapters.
+#[cfg(test)]
+pub(crate) mod test_utils {
+	use binius_field::{packed::get_packed_slice, PackedField};
+
+	use crate::{MultilinearExtension, MultilinearPoly, MultilinearQueryRef};
+
+	pub fn packed_scalars<P: PackedField>(evals: &[P], len: usize) -> Vec<P::Scalar> {
+		(0..len).map(|i| get_packed_slice(evals, i)).collect()
+	}
+
+	pub fn assert_same_multilinear<P: PackedField>(
+		lhs: &MultilinearExtension<P>,
+		rhs: &MultilinearExtension<P>,
+	) {
+		assert_eq!(lhs.n_vars(), rhs.n_vars());
+		for i in 0..lhs.size() {
+			assert_eq!(
+				lhs.evaluate_on_hypercube(i).unwrap(),
+				rhs.evaluate_on_hypercube(i).unwrap()
+			);
+		}
+	}
+
+	/// Checks that the partial evaluations of every subcube of `poly` at `query` match those of
+	/// `expected`.
+	pub fn assert_subcube_partial_evals_match<P: PackedField>(
+		poly: &impl MultilinearPoly<P>,
+		expected: &impl MultilinearPoly<P>,
+		query: MultilinearQueryRef<P>,
+	) {
+		let n_vars = expected.n_vars() - query.n_vars();
+		for subcube_vars in 0..=n_vars {
+			let len = 1 << subcube_vars.saturating_sub(P::LOG_WIDTH);
+			for subcube_index in 0..1 << (n_vars - subcube_vars) {
+				let mut poly_evals = vec![P::zero(); len];
+				let mut expected_evals = vec![P::zero(); len];
+				poly.subcube_partial_low_evals(query, subcube_vars, subcube_index, &mut poly_evals)
+					.unwrap();
+				expected
+					.subcube_partial_low_evals(
+						query,
+						subcube_vars,
+						subcube_index,
+						&mut expected_evals,
+					)
+					.unwrap();
+				assert_eq!(poly_evals, expected_evals);
+
+				poly.subcube_partial_high_evals(
+					query,
+					subcube_vars,
+					subcube_index,
+					&mut poly_evals,
+				)
+				.unwrap();
+				expected
+					.subcube_partial_high_evals(
+						query,
+						subcube_vars,
+						subcube_index,
+						&mut expected_evals,
+					)
+					.unwrap();
+				assert_eq!(
+					packed_scalars(&poly_evals, 1 << subcube_vars),
+					packed_scalars(&expected_evals, 1 << subcube_vars)
+				);
+			}
+		}
+	}
+
+	/// Checks that the evaluations of every subcube of `poly` match those of `expected`.
+	pub fn assert_subcube_evals_match<P: PackedField>(
+		poly: &impl MultilinearPoly<P>,
+		expected: &impl MultilinearPoly<P>,
+	) {
+		let n_vars = expected.n_vars();
+		for subcube_vars in 0..=n_vars {
+			let len = 1 << subcube_vars.saturating_sub(P::LOG_WIDTH);
+			for subcube_index in 0..1 << (n_vars - subcube_vars) {
+				let mut poly_evals = vec![P::zero(); len];
+				let mut expected_evals = vec![P::zero(); len];
+				poly.subcube_evals(subcube_vars, subcube_index, 0, &mut poly_evals)
+					.unwrap();
+				expected
+					.subcube_evals(subcube_vars, subcube_index, 0, &mut expected_evals)
+					.unwrap();
+				assert_eq!(
+					packed_scalars(&poly_evals, 1 << subcube_vars),
+					packed_scalars(&expected_evals, 1 << subcube_vars)
+				);
+			}
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use std::iter::repeat_with;
 
 	use binius_field::{
-		arch::OptimalUnderlier256b, as_packed_field::PackedType, BinaryField128b, BinaryField16b,
-		BinaryField32b, BinaryField8b, PackedBinaryField16x8b, PackedBinaryField1x128b,
-		PackedBinaryField4x128b, PackedBinaryField4x32b, PackedBinaryField64x8b,
-		PackedBinaryField8x16b, PackedExtension, PackedField, PackedFieldIndexable,
+		arch::OptimalUnderlier256b, as_packed_field::PackedType,
+		packed::iter_packed_slice_with_offset, BinaryField128b, BinaryField16b, BinaryField32b,
+		BinaryField8b, PackedBinaryField16x8b, PackedBinaryField1x128b, PackedBinaryField4x128b,
+		PackedBinaryField4x32b, PackedBinaryField64x8b, PackedBinaryField8x16b, PackedExtension,
+		PackedField, PackedFieldIndexable,
 	};
 	use rand::prelude::*;
 
 	use super::*;
-	use crate::{tensor_prod_eq_ind, MultilinearQuery};
+	use crate::{iter_subcubes, tensor_prod_eq_ind, MultilinearQuery};
 
 	type F = BinaryField16b;
 	type P = PackedBinaryField8x16b;
@@ -832,4 +1087,35 @@ mod tests {
 			.unwrap();
 		assert_eq!(evals_out, poly.packed_evals().unwrap());
 	}
+
+	#[test]
+	fn test_subcubes_concatenate_to_evals() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let evals = repeat_with(|| P::random(&mut rng))
+			.take(1 << 3)
+			.collect::<Vec<_>>();
+		let poly =
+			MLEDirectAdapter::from(MultilinearExtension::from_values(evals.clone()).unwrap())
+				.upcast_arc_dyn();
+		let scalars = iter_packed_slice_with_offset(&evals, 0).collect::<Vec<_>>();
+
+		for subcube_vars in 0..=poly.n_vars() {
+			let subcubes = iter_subcubes(&poly, subcube_vars)
+				.collect::<Result<Vec<_>, _>>()
+				.unwrap();
+			assert_eq!(subcubes.len(), 1 << (poly.n_vars() - subcube_vars));
+
+			let concatenated = subcubes
+				.iter()
+				.flat_map(|subcube| {
+					iter_packed_slice_with_offset(subcube, 0).take(1 << subcube_vars)
+				})
+				.collect::<Vec<_>>();
+			assert_eq!(concatenated, scalars);
+		}
+
+		assert!(iter_subcubes(&poly, poly.n_vars() + 1)
+			.collect::<Result<Vec<_>, _>>()
+			.is_err());
+	}
 }
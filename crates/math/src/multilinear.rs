@@ -134,6 +134,28 @@ pub trait MultilinearPoly<P: PackedField>: Debug {
 	fn packed_evals(&self) -> Option<&[P]>;
 }
 
+/// Iterates over all subcubes of a multilinear of a given size in order of `subcube_index`.
+///
+/// Each item is the result of [`MultilinearPoly::subcube_evals`] with a zero
+/// `log_embedding_degree` for the next subcube index. When `subcube_vars` is at least
+/// `P::LOG_WIDTH`, concatenating the items yields the full packed evaluation vector. Otherwise
+/// every item is a single packed element with the `2^subcube_vars` evaluations of the subcube in
+/// its lowest lanes, so the concatenation is padded and is not the evaluation vector.
+///
+/// This is a free function because the trait has no generic methods, which keeps it usable as a
+/// trait object.
+pub fn iter_subcubes<P: PackedField>(
+	multilinear: &impl MultilinearPoly<P>,
+	subcube_vars: usize,
+) -> impl Iterator<Item = Result<Vec<P>, Error>> + '_ {
+	let n_subcubes = 1 << multilinear.n_vars().saturating_sub(subcube_vars);
+	(0..n_subcubes).map(move |subcube_index| {
+		let mut evals = vec![P::zero(); 1 << subcube_vars.saturating_sub(P::LOG_WIDTH)];
+		multilinear.subcube_evals(subcube_vars, subcube_index, 0, &mut evals)?;
+		Ok(evals)
+	})
+}
+
 impl<P, L, R> MultilinearPoly<P> for Either<L, R>
 where
 	P: PackedField,
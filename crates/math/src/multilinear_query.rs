@@ -80,6 +80,24 @@ impl<P: PackedField> MultilinearQuery<P, Vec<P>> {
 			n_vars: query.len(),
 		}
 	}
+
+	/// Wraps a precomputed tensor product expansion of an `n_vars`-variate query.
+	///
+	/// Unlike [`Self::with_expansion`], which admits spare capacity for later updates, the
+	/// expansion must have exactly the length of an `n_vars`-variate expansion.
+	pub fn from_expansion(n_vars: usize, expansion: Vec<P>) -> Result<Self, Error> {
+		let expected_len = 1 << n_vars.saturating_sub(P::LOG_WIDTH);
+		if expansion.len() != expected_len {
+			bail!(Error::IncorrectArgumentLength {
+				arg: "expansion".to_string(),
+				expected: expected_len,
+			});
+		}
+		Ok(Self {
+			n_vars,
+			expanded_query: expansion,
+		})
+	}
 }
 
 impl<P: PackedField, Data: DerefMut<Target = [P]>> MultilinearQuery<P, Data> {
@@ -150,6 +168,7 @@ impl<P: PackedField, Data: DerefMut<Target = [P]>> MultilinearQuery<P, Data> {
 
 #[cfg(test)]
 mod tests {
+	use assert_matches::assert_matches;
 	use binius_field::{Field, PackedBinaryField4x32b, PackedField};
 	use binius_utils::felts;
 	use itertools::Itertools;
@@ -352,4 +371,25 @@ mod tests {
 
 		assert_eq!(expansion, vec![F::ONE, F::ZERO, F::ZERO, F::ZERO]);
 	}
+
+	#[test]
+	fn test_from_expansion() {
+		let query = felts!(F[2, 3, 5, 7, 11]);
+		let expanded = MultilinearQuery::<P>::expand(&query);
+		let wrapped =
+			MultilinearQuery::<P>::from_expansion(query.len(), expanded.expansion().to_vec())
+				.unwrap();
+		assert_eq!(wrapped.n_vars(), query.len());
+		assert_eq!(wrapped.expansion(), expanded.expansion());
+
+		let single = MultilinearQuery::<P>::from_expansion(1, tensor_prod(&query[..1])).unwrap();
+		assert_eq!(single.expansion(), MultilinearQuery::<P>::expand(&query[..1]).expansion());
+
+		for len in [4, 16] {
+			assert_matches!(
+				MultilinearQuery::<P>::from_expansion(query.len(), vec![P::zero(); len]),
+				Err(Error::IncorrectArgumentLength { expected: 8, .. })
+			);
+		}
+	}
 }
@@ -0,0 +1,423 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::marker::PhantomData;
+
+use binius_field::{
+	packed::{get_packed_slice, set_packed_slice},
+	PackedField,
+};
+use binius_utils::bail;
+
+use crate::{
+	mle_adapters::{
+		hypercube_evaluate, hypercube_evaluate_partial, hypercube_subcube_partial_high_evals,
+		hypercube_subcube_partial_low_evals, hypercube_zero_pad,
+	},
+	Error, MultilinearExtension, MultilinearPoly, MultilinearQueryRef,
+};
+
+/// A multilinear polynomial with its variables permuted.
+///
+/// Variable `i` of the permuted polynomial is variable `permutation[i]` of the inner polynomial,
+/// that is, $g(X_0, \ldots, X_{n-1}) = f(Y_0, \ldots, Y_{n-1})$ where $Y_{\pi(i)} = X_i$.
+/// Hypercube indices are translated bit by bit, so the permuted evaluations are never
+/// materialized.
+///
+/// Subcubes are delegated to the inner polynomial when the permutation fixes the subcube
+/// variables, in which case the subcube is contiguous in the inner polynomial as well.
+#[derive(Debug, Clone)]
+pub struct PermuteVarsMultilinear<P: PackedField, M> {
+	inner: M,
+	permutation: Vec<usize>,
+	_marker: PhantomData<P>,
+}
+
+impl<P: PackedField, M: MultilinearPoly<P>> PermuteVarsMultilinear<P, M> {
+	/// Permutes the variables of `inner`, where `permutation[i]` is the inner variable presented
+	/// as variable `i`.
+	pub fn new(inner: M, permutation: Vec<usize>) -> Result<Self, Error> {
+		let n_vars = inner.n_vars();
+		let mut seen = vec![false; n_vars];
+		for &var in &permutation {
+			if var >= n_vars || seen[var] {
+				bail!(Error::InvalidVariablePermutation { n_vars });
+			}
+			seen[var] = true;
+		}
+		if permutation.len() != n_vars {
+			bail!(Error::InvalidVariablePermutation { n_vars });
+		}
+
+		Ok(Self {
+			inner,
+			permutation,
+			_marker: PhantomData,
+		})
+	}
+
+	pub const fn inner(&self) -> &M {
+		&self.inner
+	}
+
+	pub fn permutation(&self) -> &[usize] {
+		&self.permutation
+	}
+
+	/// Translates a hypercube index of the permuted polynomial into one of the inner polynomial.
+	fn inner_index(&self, index: usize) -> usize {
+		self.permutation
+			.iter()
+			.enumerate()
+			.fold(0, |acc, (var, &inner_var)| acc | ((index >> var) & 1) << inner_var)
+	}
+
+	/// The number of lowest variables that the permutation leaves in place.
+	fn n_fixed_low_vars(&self) -> usize {
+		self.permutation
+			.iter()
+			.enumerate()
+			.take_while(|&(var, &inner_var)| var == inner_var)
+			.count()
+	}
+}
+
+impl<P, M> MultilinearPoly<P> for PermuteVarsMultilinear<P, M>
+where
+	P: PackedField,
+	M: MultilinearPoly<P>,
+{
+	fn n_vars(&self) -> usize {
+		self.inner.n_vars()
+	}
+
+	fn log_extension_degree(&self) -> usize {
+		// Subfield elements are embedded from consecutive evaluations, which stay consecutive only
+		// over the variables that are not permuted.
+		self.inner
+			.log_extension_degree()
+			.min(self.n_fixed_low_vars())
+	}
+
+	fn evaluate_on_hypercube(&self, index: usize) -> Result<P::Scalar, Error> {
+		if index >= self.size() {
+			bail!(Error::HypercubeIndexOutOfRange { index });
+		}
+		self.inner.evaluate_on_hypercube(self.inner_index(index))
+	}
+
+	fn evaluate_on_hypercube_and_scale(
+		&self,
+		index: usize,
+		scalar: P::Scalar,
+	) -> Result<P::Scalar, Error> {
+		if index >= self.size() {
+			bail!(Error::HypercubeIndexOutOfRange { index });
+		}
+		self.inner
+			.evaluate_on_hypercube_and_scale(self.inner_index(index), scalar)
+	}
+
+	fn evaluate(&self, query: MultilinearQueryRef<P>) -> Result<P::Scalar, Error> {
+		hypercube_evaluate(self, query)
+	}
+
+	fn evaluate_partial_low(
+		&self,
+		query: MultilinearQueryRef<P>,
+	) -> Result<MultilinearExtension<P>, Error> {
+		self.evaluate_partial(query, 0)
+	}
+
+	fn evaluate_partial_high(
+		&self,
+		query: MultilinearQueryRef<P>,
+	) -> Result<MultilinearExtension<P>, Error> {
+		if query.n_vars() > self.n_vars() {
+			bail!(Error::IncorrectQuerySize {
+				expected: self.n_vars()
+			});
+		}
+		self.evaluate_partial(query, self.n_vars() - query.n_vars())
+	}
+
+	fn evaluate_partial(
+		&self,
+		query: MultilinearQueryRef<P>,
+		start_index: usize,
+	) -> Result<MultilinearExtension<P>, Error> {
+		hypercube_evaluate_partial(self, query, start_index)
+	}
+
+	fn zero_pad(
+		&self,
+		n_pad_vars: usize,
+		start_index: usize,
+		nonzero_index: usize,
+	) -> Result<MultilinearExtension<P>, Error> {
+		hypercube_zero_pad(self, n_pad_vars, start_index, nonzero_index)
+	}
+
+	fn subcube_partial_low_evals(
+		&self,
+		query: MultilinearQueryRef<P>,
+		subcube_vars: usize,
+		subcube_index: usize,
+		partial_low_evals: &mut [P],
+	) -> Result<(), Error> {
+		hypercube_subcube_partial_low_evals(
+			self,
+			query,
+			subcube_vars,
+			subcube_index,
+			partial_low_evals,
+		)
+	}
+
+	fn subcube_partial_high_evals(
+		&self,
+		query: MultilinearQueryRef<P>,
+		subcube_vars: usize,
+		subcube_index: usize,
+		partial_high_evals: &mut [P],
+	) -> Result<(), Error> {
+		hypercube_subcube_partial_high_evals(
+			self,
+			query,
+			subcube_vars,
+			subcube_index,
+			partial_high_evals,
+		)
+	}
+
+	fn subcube_evals(
+		&self,
+		subcube_vars: usize,
+		subcube_index: usize,
+		log_embedding_degree: usize,
+		evals: &mut [P],
+	) -> Result<(), Error> {
+		let n_vars = self.n_vars();
+		if subcube_vars > n_vars {
+			bail!(Error::ArgumentRangeError {
+				arg: "subcube_vars".to_string(),
+				range: 0..n_vars + 1,
+			});
+		}
+
+		if log_embedding_degree > self.log_extension_degree() {
+			bail!(Error::LogEmbeddingDegreeTooLarge {
+				log_embedding_degree
+			});
+		}
+
+		let correct_len = 1 << subcube_vars.saturating_sub(log_embedding_degree + P::LOG_WIDTH);
+		if evals.len() != correct_len {
+			bail!(Error::ArgumentRangeError {
+				arg: "evals.len()".to_string(),
+				range: correct_len..correct_len + 1,
+			});
+		}
+
+		let max_index = 1 << (n_vars - subcube_vars);
+		if subcube_index >= max_index {
+			bail!(Error::ArgumentRangeError {
+				arg: "subcube_index".to_string(),
+				range: 0..max_index,
+			});
+		}
+
+		// The subcube is contiguous in the inner polynomial if its variables are not permuted,
+		// which always holds for the embedded variables. Delegate the largest such blocks.
+		let block_vars = subcube_vars.min(self.n_fixed_low_vars());
+		let n_block_scalars = 1 << block_vars.saturating_sub(log_embedding_degree);
+		let mut block_evals =
+			vec![P::zero(); 1 << block_vars.saturating_sub(log_embedding_degree + P::LOG_WIDTH)];
+		let subcube_start = subcube_index << subcube_vars;
+		for block in 0..1 << (subcube_vars - block_vars) {
+			let inner_start = self.inner_index(subcube_start | block << block_vars);
+			self.inner.subcube_evals(
+				block_vars,
+				inner_start >> block_vars,
+				log_embedding_degree,
+				&mut block_evals,
+			)?;
+
+			if block_vars == subcube_vars {
+				evals.copy_from_slice(&block_evals);
+			} else {
+				for i in 0..n_block_scalars {
+					set_packed_slice(
+						evals,
+						block * n_block_scalars + i,
+						get_packed_slice(&block_evals, i),
+					);
+				}
+			}
+		}
+
+		Ok(())
+	}
+
+	fn packed_evals(&self) -> Option<&[P]> {
+		None
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use binius_field::{
+		BinaryField8b, Field, PackedBinaryField16x8b, PackedBinaryField1x128b,
+		PackedBinaryField4x32b,
+	};
+	use proptest::prelude::*;
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+	use crate::{
+		mle_adapters::{
+			multilinear_from_fn,
+			test_utils::{
+				assert_same_multilinear, assert_subcube_evals_match,
+				assert_subcube_partial_evals_match,
+			},
+		},
+		MLEEmbeddingAdapter, MultilinearQuery,
+	};
+
+	type P = PackedBinaryField4x32b;
+	type F = <P as PackedField>::Scalar;
+
+	fn permuted_pair(
+		seed: u64,
+		permutation: &[usize],
+	) -> (PermuteVarsMultilinear<P, MLEEmbeddingAdapter<P, P>>, MLEEmbeddingAdapter<P, P>) {
+		let n_vars = permutation.len();
+		let mut rng = StdRng::seed_from_u64(seed);
+		let evals = repeat_with(|| <F as Field>::random(&mut rng))
+			.take(1 << n_vars)
+			.collect::<Vec<_>>();
+		let inner = multilinear_from_fn::<P>(n_vars, |i| Ok(evals[i]))
+			.unwrap()
+			.specialize::<P>();
+		let permuted = PermuteVarsMultilinear::new(inner, permutation.to_vec()).unwrap();
+
+		// Materialize the permuted evaluations by routing each bit of the index to its variable.
+		let expected = multilinear_from_fn::<P>(n_vars, |i| {
+			let inner_index =
+				(0..n_vars).fold(0, |acc, var| acc | ((i >> var) & 1) << permutation[var]);
+			Ok(evals[inner_index])
+		})
+		.unwrap()
+		.specialize::<P>();
+		(permuted, expected)
+	}
+
+	fn permutation_strategy() -> impl Strategy<Value = Vec<usize>> {
+		(0..=6usize).prop_flat_map(|n_vars| Just((0..n_vars).collect::<Vec<_>>()).prop_shuffle())
+	}
+
+	#[test]
+	fn test_new_rejects_invalid_permutation() {
+		let (permuted, _) = permuted_pair(0, &[0, 1, 2]);
+		let inner = permuted.inner().clone();
+		assert!(PermuteVarsMultilinear::new(inner.clone(), vec![0, 1]).is_err());
+		assert!(PermuteVarsMultilinear::new(inner.clone(), vec![0, 1, 1]).is_err());
+		assert!(PermuteVarsMultilinear::new(inner.clone(), vec![0, 1, 3]).is_err());
+		assert!(PermuteVarsMultilinear::new(inner, vec![2, 0, 1]).is_ok());
+	}
+
+	#[test]
+	fn test_subcube_evals_with_embedding() {
+		type PSub = PackedBinaryField16x8b;
+		type PE = PackedBinaryField1x128b;
+
+		let n_vars = 7;
+		let permutation = vec![0, 1, 2, 5, 3, 6, 4];
+		let mut rng = StdRng::seed_from_u64(0);
+		let evals = repeat_with(|| <BinaryField8b as Field>::random(&mut rng))
+			.take(1 << n_vars)
+			.collect::<Vec<_>>();
+		let to_mle = |f: &dyn Fn(usize) -> BinaryField8b| {
+			multilinear_from_fn::<PSub>(n_vars, |i| Ok(f(i)))
+				.unwrap()
+				.specialize::<PE>()
+		};
+		let permuted =
+			PermuteVarsMultilinear::new(to_mle(&|i| evals[i]), permutation.clone()).unwrap();
+		let expected = to_mle(&|i| {
+			let inner_index =
+				(0..n_vars).fold(0, |acc, var| acc | ((i >> var) & 1) << permutation[var]);
+			evals[inner_index]
+		});
+		assert_eq!(permuted.log_extension_degree(), 3);
+
+		for log_embedding_degree in 0..=3 {
+			for subcube_vars in 0..=n_vars {
+				let len = 1 << subcube_vars.saturating_sub(log_embedding_degree);
+				for subcube_index in 0..1 << (n_vars - subcube_vars) {
+					let mut permuted_evals = vec![PE::zero(); len];
+					let mut expected_evals = vec![PE::zero(); len];
+					permuted
+						.subcube_evals(
+							subcube_vars,
+							subcube_index,
+							log_embedding_degree,
+							&mut permuted_evals,
+						)
+						.unwrap();
+					expected
+						.subcube_evals(
+							subcube_vars,
+							subcube_index,
+							log_embedding_degree,
+							&mut expected_evals,
+						)
+						.unwrap();
+					assert_eq!(permuted_evals, expected_evals);
+				}
+			}
+		}
+
+		let mut evals = vec![PE::zero(); 1 << 3];
+		assert!(permuted.subcube_evals(7, 0, 4, &mut evals).is_err());
+	}
+
+	proptest! {
+		#[test]
+		fn test_permuted_matches_materialized(
+			permutation in permutation_strategy(),
+			seed in any::<u64>(),
+		) {
+			let n_vars = permutation.len();
+			let (permuted, expected) = permuted_pair(seed, &permutation);
+
+			let mut rng = StdRng::seed_from_u64(seed);
+			let point = repeat_with(|| <F as Field>::random(&mut rng))
+				.take(n_vars)
+				.collect::<Vec<_>>();
+			let query = MultilinearQuery::<P>::expand(&point);
+			assert_eq!(
+				permuted.evaluate(query.to_ref()).unwrap(),
+				expected.evaluate(query.to_ref()).unwrap()
+			);
+
+			for query_n_vars in 0..=n_vars {
+				let query = MultilinearQuery::<P>::expand(&point[..query_n_vars]);
+				assert_same_multilinear(
+					&permuted.evaluate_partial_low(query.to_ref()).unwrap(),
+					&expected.evaluate_partial_low(query.to_ref()).unwrap(),
+				);
+				assert_same_multilinear(
+					&permuted.evaluate_partial_high(query.to_ref()).unwrap(),
+					&expected.evaluate_partial_high(query.to_ref()).unwrap(),
+				);
+
+				assert_subcube_partial_evals_match(&permuted, &expected, query.to_ref());
+			}
+
+			assert_subcube_evals_match(&permuted, &expected);
+		}
+	}
+}
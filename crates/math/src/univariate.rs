@@ -1,6 +1,8 @@
 // Copyright 2023-2025 Irreducible Inc.
 // Copyright (c) 2022 The Plonky2 Authors
 
+use std::collections::HashSet;
+
 use auto_impl::auto_impl;
 use binius_field::{
 	packed::mul_by_subfield_scalar, BinaryField, ExtensionField, Field, PackedExtension,
@@ -10,7 +12,7 @@ use binius_utils::bail;
 use itertools::{izip, Either};
 
 use super::{binary_subspace::BinarySubspace, error::Error};
-use crate::Matrix;
+use crate::{reverse_bits, Matrix};
 
 /// A domain that univariate polynomials may be evaluated on.
 ///
@@ -29,6 +31,23 @@ pub struct EvaluationDomain<F: Field> {
 	with_infinity: bool,
 }
 
+/// The order in which [`EvaluationDomain::from_points_ordered`] lays out the finite points.
+///
+/// The ordering only changes which value index corresponds to which point. The polynomial
+/// extrapolated from values at the points does not depend on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PointOrdering {
+	/// The points in the order they are given.
+	#[default]
+	AsGiven,
+	/// The points in ascending order.
+	Sorted,
+	/// The given points permuted by reversing the bits of their indices, matching the output
+	/// layout of an FFT. When the number of points is not a power of two, the indices that reverse
+	/// to positions past the end are skipped.
+	BitReversed,
+}
+
 /// An extended version of `EvaluationDomain` that supports interpolation to monomial form. Takes
 /// longer to construct due to Vandermonde inversion, which has cubic complexity.
 #[derive(Debug, Clone)]
@@ -87,6 +106,42 @@ where
 	}
 }
 
+/// An evaluation domain factory that maps the points of a binary subspace of `FSrc` into the
+/// domain field with an explicit embedding.
+///
+/// This is the same as [`IsomorphicEvaluationDomainFactory`] for fields that are not related by a
+/// `From` implementation. The embedding should be an injective field homomorphism, so that the
+/// domain has the same structure in both fields.
+#[derive(Clone)]
+pub struct ExplicitEmbeddingDomainFactory<FSrc: BinaryField, Embed> {
+	subspace: BinarySubspace<FSrc>,
+	embed: Embed,
+}
+
+impl<FSrc: BinaryField, Embed> ExplicitEmbeddingDomainFactory<FSrc, Embed> {
+	pub fn new(embed: Embed) -> Self {
+		Self {
+			subspace: BinarySubspace::default(),
+			embed,
+		}
+	}
+}
+
+impl<FSrc, FTgt, Embed> EvaluationDomainFactory<FTgt>
+	for ExplicitEmbeddingDomainFactory<FSrc, Embed>
+where
+	FSrc: BinaryField,
+	FTgt: Field,
+	Embed: Fn(FSrc) -> FTgt + Clone + Sync,
+{
+	fn create(&self, size: usize) -> Result<EvaluationDomain<FTgt>, Error> {
+		let with_infinity = size >= 3;
+		let points =
+			make_evaluation_points(&self.subspace, size - if with_infinity { 1 } else { 0 })?;
+		EvaluationDomain::from_points(points.into_iter().map(&self.embed).collect(), with_infinity)
+	}
+}
+
 fn make_evaluation_points<F: BinaryField>(
 	subspace: &BinarySubspace<F>,
 	size: usize,
@@ -130,6 +185,31 @@ impl<F: Field> EvaluationDomain<F> {
 		})
 	}
 
+	/// Creates a domain over the given finite points, laid out in the given order.
+	pub fn from_points_ordered(
+		mut finite_points: Vec<F>,
+		ordering: PointOrdering,
+		with_infinity: bool,
+	) -> Result<Self, Error>
+	where
+		F: Ord,
+	{
+		match ordering {
+			PointOrdering::AsGiven => {}
+			PointOrdering::Sorted => finite_points.sort_unstable(),
+			PointOrdering::BitReversed => {
+				let n = finite_points.len();
+				let log_len = n.next_power_of_two().ilog2() as usize;
+				finite_points = (0..n.next_power_of_two())
+					.map(|i| reverse_bits(i, log_len))
+					.filter(|&i| i < n)
+					.map(|i| finite_points[i])
+					.collect();
+			}
+		}
+		Self::from_points(finite_points, with_infinity)
+	}
+
 	pub fn size(&self) -> usize {
 		self.finite_points.len() + if self.with_infinity { 1 } else { 0 }
 	}
@@ -201,6 +281,69 @@ impl<F: Field> EvaluationDomain<F> {
 
 		Ok(result)
 	}
+
+	/// Evaluate the unique interpolated polynomial at the "infinity" point, for a given set of
+	/// values, in $O(N)$.
+	///
+	/// For a domain of size $d + 1$ this is the coefficient of $X^d$ of the interpolated
+	/// polynomial of degree at most $d$. If the domain includes the infinity point, this is simply
+	/// the value given for it; otherwise the coefficient is the sum of the values scaled by the
+	/// barycentric weights.
+	pub fn extrapolate_at_infinity<PE>(&self, values: &[PE]) -> Result<PE, Error>
+	where
+		PE: PackedField<Scalar: ExtensionField<F>>,
+	{
+		if values.len() != self.size() {
+			bail!(Error::ExtrapolateNumberOfEvaluations);
+		}
+
+		if self.with_infinity {
+			return Ok(*values.last().expect("values length checked above"));
+		}
+
+		let result = izip!(values, &self.weights)
+			.map(|(&value, &weight)| value * PE::Scalar::from(weight))
+			.sum();
+		Ok(result)
+	}
+}
+
+impl<F: BinaryField> EvaluationDomain<F> {
+	/// Returns whether the finite points form an additive subgroup of the field.
+	pub fn is_additive_subgroup(&self) -> bool {
+		self.additive_subgroup().is_some()
+	}
+
+	/// Returns the finite points as a binary subspace, if they form an additive subgroup of the
+	/// field.
+	///
+	/// An additive subgroup of a binary field is an $\mathbb{F}_2$-linear subspace, so it is
+	/// described by a basis rather than by a single generator. The returned subspace contains the
+	/// same points as the domain, although not necessarily in the same order. The infinity point,
+	/// if present, is ignored.
+	pub fn additive_subgroup(&self) -> Option<BinarySubspace<F>> {
+		let n_points = self.finite_points.len();
+		if !n_points.is_power_of_two() {
+			return None;
+		}
+
+		let mut basis = Vec::new();
+		let mut span = HashSet::from([F::ZERO]);
+		for &point in &self.finite_points {
+			if span.contains(&point) {
+				continue;
+			}
+			if span.len() == n_points {
+				return None;
+			}
+			let shifted = span.iter().map(|&x| x + point).collect::<Vec<_>>();
+			span.extend(shifted);
+			basis.push(point);
+		}
+
+		// The points are distinct, so if they all lie in a span of the same size they are the span.
+		(span.len() == n_points).then(|| BinarySubspace::new_unchecked(basis))
+	}
 }
 
 impl<F: Field> InterpolationDomain<F> {
@@ -224,6 +367,13 @@ impl<F: Field> InterpolationDomain<F> {
 		self.evaluation_domain.extrapolate(values, x)
 	}
 
+	pub fn extrapolate_at_infinity<PE: PackedExtension<F>>(
+		&self,
+		values: &[PE],
+	) -> Result<PE, Error> {
+		self.evaluation_domain.extrapolate_at_infinity(values)
+	}
+
 	pub fn interpolate<FE: ExtensionField<F>>(&self, values: &[FE]) -> Result<Vec<FE>, Error> {
 		if values.len() != self.evaluation_domain.size() {
 			bail!(Error::ExtrapolateNumberOfEvaluations);
@@ -233,6 +383,90 @@ impl<F: Field> InterpolationDomain<F> {
 		self.interpolation_matrix.mul_vec_into(values, &mut coeffs);
 		Ok(coeffs)
 	}
+
+	/// Interpolates the values on the domain of a polynomial known to have degree at most
+	/// `max_degree`, returning its `max_degree + 1` lowest coefficients.
+	///
+	/// Only the first `max_degree + 1` finite points are used for interpolation, in $O(d^2)$ for
+	/// $d$ = `max_degree`. The values at the remaining points are checked against the
+	/// interpolated polynomial, and [`Error::DegreeBoundExceeded`] is returned if they do not
+	/// match.
+	pub fn interpolate_degree_bounded<FE: ExtensionField<F>>(
+		&self,
+		values: &[FE],
+		max_degree: usize,
+	) -> Result<Vec<FE>, Error> {
+		if values.len() != self.size() {
+			bail!(Error::ExtrapolateNumberOfEvaluations);
+		}
+		if max_degree >= self.size() {
+			bail!(Error::ArgumentRangeError {
+				arg: "max_degree".to_string(),
+				range: 0..self.size(),
+			});
+		}
+
+		let finite_points = self.finite_points();
+		let n_coeffs = max_degree + 1;
+		if n_coeffs > finite_points.len() {
+			// The domain has no finite points beyond the bound, so the full interpolation applies.
+			return self.interpolate(values);
+		}
+
+		let (points, rest_points) = finite_points.split_at(n_coeffs);
+		let (point_values, rest_values) = values.split_at(n_coeffs);
+		let coeffs = interpolate_monomial(points, point_values)?;
+
+		for (&point, &value) in izip!(rest_points, rest_values) {
+			if evaluate_univariate(&coeffs, FE::from(point)) != value {
+				bail!(Error::DegreeBoundExceeded { max_degree });
+			}
+		}
+		// The value at infinity is the coefficient of the highest degree of the domain, which is
+		// above the bound.
+		if self.with_infinity() && values[values.len() - 1] != FE::ZERO {
+			bail!(Error::DegreeBoundExceeded { max_degree });
+		}
+
+		Ok(coeffs)
+	}
+}
+
+/// Returns the monomial coefficients of the polynomial of degree less than `points.len()` taking
+/// `values` at `points`, by summing the Lagrange basis polynomials in $O(n^2)$.
+fn interpolate_monomial<F: Field, FE: ExtensionField<F>>(
+	points: &[F],
+	values: &[FE],
+) -> Result<Vec<FE>, Error> {
+	let n = points.len();
+	let weights = compute_barycentric_weights(points)?;
+
+	// The coefficients of $\prod_j (X - x_j)$, lowest degree first.
+	let mut vanishing = vec![F::ZERO; n + 1];
+	vanishing[0] = F::ONE;
+	for (i, &point) in points.iter().enumerate() {
+		for j in (1..=i + 1).rev() {
+			vanishing[j] = vanishing[j - 1] - point * vanishing[j];
+		}
+		vanishing[0] *= -point;
+	}
+
+	let mut coeffs = vec![FE::ZERO; n];
+	let mut basis = vec![F::ZERO; n];
+	for (&point, &weight, &value) in izip!(points, &weights, values) {
+		// Divide the vanishing polynomial by $X - x_i$ with synthetic division.
+		let mut carry = F::ZERO;
+		for j in (0..n).rev() {
+			carry = vanishing[j + 1] + carry * point;
+			basis[j] = carry;
+		}
+
+		let scale = value * weight;
+		for (coeff, &basis_coeff) in izip!(&mut coeffs, &basis) {
+			*coeff += scale * basis_coeff;
+		}
+	}
+	Ok(coeffs)
 }
 
 /// Extrapolates lines through a pair of packed fields at a single point from a subfield.
@@ -358,6 +592,61 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn test_domain_factory_explicit_embedding() {
+		let iso_domain_factory = IsomorphicEvaluationDomainFactory::<BinaryField32b>::default();
+		let explicit_domain_factory =
+			ExplicitEmbeddingDomainFactory::new(|x: BinaryField32b| AESTowerField32b::from(x));
+		let domain_1: EvaluationDomain<AESTowerField32b> = iso_domain_factory.create(10).unwrap();
+		let domain_2 = explicit_domain_factory.create(10).unwrap();
+		assert_eq!(domain_1.finite_points, domain_2.finite_points);
+		assert_eq!(domain_1.with_infinity, domain_2.with_infinity);
+
+		let explicit_domain_factory =
+			ExplicitEmbeddingDomainFactory::new(|x: BinaryField8b| BinaryField32b::from(x));
+		assert_matches!(explicit_domain_factory.create(300), Err(Error::DomainSizeTooLarge));
+	}
+
+	#[test]
+	fn test_additive_subgroup() {
+		let domain_factory = DefaultEvaluationDomainFactory::<BinaryField8b>::default();
+		for (size, dim) in [
+			(1, Some(0)),
+			(2, Some(1)),
+			(3, Some(1)),
+			(4, None),
+			(5, Some(2)),
+			(9, Some(3)),
+		] {
+			let domain = domain_factory.create(size).unwrap();
+			let subgroup = domain.additive_subgroup();
+			assert_eq!(subgroup.as_ref().map(|subspace| subspace.dim()), dim);
+			assert_eq!(domain.is_additive_subgroup(), dim.is_some());
+
+			if let Some(subspace) = subgroup {
+				let mut points = domain.finite_points().to_vec();
+				let mut subspace_points = subspace.iter().collect::<Vec<_>>();
+				points.sort_by_key(|point| point.val());
+				subspace_points.sort_by_key(|point| point.val());
+				assert_eq!(points, subspace_points);
+			}
+		}
+
+		// A coset of a subspace is not a subgroup.
+		let domain =
+			EvaluationDomain::from_points([1, 2, 3, 4].map(BinaryField8b::new).to_vec(), false)
+				.unwrap();
+		assert!(!domain.is_additive_subgroup());
+		let domain =
+			EvaluationDomain::from_points([2, 3, 4, 5].map(BinaryField8b::new).to_vec(), false)
+				.unwrap();
+		assert!(!domain.is_additive_subgroup());
+		let domain =
+			EvaluationDomain::from_points([5, 0, 7, 2].map(BinaryField8b::new).to_vec(), false)
+				.unwrap();
+		assert!(domain.is_additive_subgroup());
+	}
+
 	#[test]
 	fn test_new_oversized_domain() {
 		let default_domain_factory = DefaultEvaluationDomainFactory::<BinaryField8b>::default();
@@ -409,6 +698,49 @@ mod tests {
 		assert_eq!(domain.extrapolate(&values, x).unwrap(), expected_y);
 	}
 
+	#[test]
+	fn test_from_points_ordered() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let degree = 5;
+
+		let points = repeat_with(|| <BinaryField32b as Field>::random(&mut rng))
+			.take(degree + 1)
+			.collect::<Vec<_>>();
+		let coeffs = repeat_with(|| <BinaryField32b as Field>::random(&mut rng))
+			.take(degree + 1)
+			.collect::<Vec<_>>();
+		let x = <BinaryField32b as Field>::random(&mut rng);
+		let expected_y = evaluate_univariate(&coeffs, x);
+
+		let sorted =
+			EvaluationDomain::from_points_ordered(points.clone(), PointOrdering::Sorted, false)
+				.unwrap();
+		assert!(sorted.finite_points().is_sorted());
+
+		let bit_reversed = EvaluationDomain::from_points_ordered(
+			points.clone(),
+			PointOrdering::BitReversed,
+			false,
+		)
+		.unwrap();
+		assert_eq!(bit_reversed.finite_points(), [0, 4, 2, 1, 5, 3].map(|i| points[i]).as_slice());
+
+		for ordering in [
+			PointOrdering::AsGiven,
+			PointOrdering::Sorted,
+			PointOrdering::BitReversed,
+		] {
+			let domain =
+				EvaluationDomain::from_points_ordered(points.clone(), ordering, false).unwrap();
+			let values = domain
+				.finite_points()
+				.iter()
+				.map(|&x| evaluate_univariate(&coeffs, x))
+				.collect::<Vec<_>>();
+			assert_eq!(domain.extrapolate(&values, x).unwrap(), expected_y);
+		}
+	}
+
 	#[test]
 	fn test_interpolation() {
 		let mut rng = StdRng::seed_from_u64(0);
@@ -438,6 +770,100 @@ mod tests {
 		assert_eq!(interpolated, coeffs);
 	}
 
+	#[test]
+	fn test_extrapolate_at_infinity() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let degree = 6;
+		let coeffs = repeat_with(|| <BinaryField32b as Field>::random(&mut rng))
+			.take(degree + 1)
+			.collect::<Vec<_>>();
+		let points = repeat_with(|| <BinaryField32b as Field>::random(&mut rng))
+			.take(degree + 1)
+			.collect::<Vec<_>>();
+		let values = points
+			.iter()
+			.map(|&x| evaluate_univariate(&coeffs, x))
+			.collect::<Vec<_>>();
+
+		let domain = EvaluationDomain::from_points(points.clone(), false).unwrap();
+		assert_eq!(domain.extrapolate_at_infinity(&values).unwrap(), coeffs[degree]);
+
+		let domain = EvaluationDomain::from_points(points[..degree].to_vec(), true).unwrap();
+		let mut values = values[..degree].to_vec();
+		values.push(coeffs[degree]);
+		assert_eq!(domain.extrapolate_at_infinity(&values).unwrap(), coeffs[degree]);
+
+		assert_matches!(
+			domain.extrapolate_at_infinity(&values[1..]),
+			Err(Error::ExtrapolateNumberOfEvaluations)
+		);
+	}
+
+	#[test]
+	fn test_interpolate_degree_bounded() {
+		type F = BinaryField8b;
+		type FE = BinaryField32b;
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let domain_size = 6;
+		for with_infinity in [false, true] {
+			let n_finite_points = domain_size - usize::from(with_infinity);
+			let domain = InterpolationDomain::from(
+				EvaluationDomain::from_points(
+					(0..n_finite_points as u8).map(F::new).collect(),
+					with_infinity,
+				)
+				.unwrap(),
+			);
+
+			for degree in 0..domain_size {
+				let coeffs = repeat_with(|| <FE as Field>::random(&mut rng))
+					.take(degree + 1)
+					.collect::<Vec<_>>();
+				let mut values = domain
+					.finite_points()
+					.iter()
+					.map(|&x| evaluate_univariate(&coeffs, FE::from(x)))
+					.collect::<Vec<_>>();
+				if with_infinity {
+					let leading_coeff = if degree == domain_size - 1 {
+						coeffs[degree]
+					} else {
+						FE::ZERO
+					};
+					values.push(leading_coeff);
+				}
+
+				for max_degree in degree..domain_size {
+					let interpolated = domain
+						.interpolate_degree_bounded(&values, max_degree)
+						.unwrap();
+					assert_eq!(interpolated.len(), max_degree + 1);
+					assert_eq!(interpolated[..=degree], coeffs);
+					assert!(interpolated[degree + 1..]
+						.iter()
+						.all(|&coeff| coeff == FE::ZERO));
+				}
+				if degree > 0 {
+					assert_matches!(
+						domain.interpolate_degree_bounded(&values, degree - 1),
+						Err(Error::DegreeBoundExceeded { .. })
+					);
+				}
+			}
+
+			let values = vec![FE::ONE; domain_size];
+			assert_matches!(
+				domain.interpolate_degree_bounded(&values, domain_size),
+				Err(Error::ArgumentRangeError { .. })
+			);
+			assert_matches!(
+				domain.interpolate_degree_bounded(&values[1..], 1),
+				Err(Error::ExtrapolateNumberOfEvaluations)
+			);
+		}
+	}
+
 	#[test]
 	fn test_infinity() {
 		let mut rng = StdRng::seed_from_u64(0);
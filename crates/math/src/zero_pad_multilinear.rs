@@ -0,0 +1,455 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::{
+	packed::{get_packed_slice, set_packed_slice},
+	Field, PackedField,
+};
+use binius_utils::bail;
+
+use crate::{
+	mle_adapters::{
+		hypercube_evaluate, hypercube_evaluate_partial, hypercube_subcube_partial_high_evals,
+		hypercube_subcube_partial_low_evals, hypercube_zero_pad, multilinear_from_fn,
+	},
+	Error, MultilinearExtension, MultilinearPoly, MultilinearQueryRef,
+};
+
+/// A multilinear polynomial padded with a constant to a larger number of variables.
+///
+/// The padded polynomial has `n_pad_vars` more variables than the inner polynomial. Its hypercube
+/// evaluations are those of the inner polynomial at the indices below `2^k`, where `k` is the
+/// number of inner variables, followed by the padding value at all higher indices. Equivalently,
+/// the high `n_pad_vars` variables select between the inner polynomial, when they are all zero,
+/// and the constant. The padded evaluations are never materialized.
+///
+/// Values padded with a nonzero constant are not assumed to lie in a subfield, so the padded
+/// polynomial then reports a [`MultilinearPoly::log_extension_degree`] of 0.
+#[derive(Debug, Clone)]
+pub struct ZeroPadMultilinear<P: PackedField, M> {
+	inner: M,
+	n_pad_vars: usize,
+	pad_value: P::Scalar,
+}
+
+impl<P: PackedField, M: MultilinearPoly<P>> ZeroPadMultilinear<P, M> {
+	/// Pads `inner` with zeros up to `inner.n_vars() + n_pad_vars` variables.
+	pub fn new(inner: M, n_pad_vars: usize) -> Self {
+		Self::with_pad_value(inner, n_pad_vars, P::Scalar::ZERO)
+	}
+
+	/// Pads `inner` with `pad_value` up to `inner.n_vars() + n_pad_vars` variables.
+	pub const fn with_pad_value(inner: M, n_pad_vars: usize, pad_value: P::Scalar) -> Self {
+		Self {
+			inner,
+			n_pad_vars,
+			pad_value,
+		}
+	}
+
+	pub const fn inner(&self) -> &M {
+		&self.inner
+	}
+
+	pub const fn n_pad_vars(&self) -> usize {
+		self.n_pad_vars
+	}
+
+	pub const fn pad_value(&self) -> P::Scalar {
+		self.pad_value
+	}
+
+	/// Evaluates at a hypercube index that is known to be in range.
+	fn eval_at(&self, index: usize) -> Result<P::Scalar, Error> {
+		if index < self.inner.size() {
+			self.inner.evaluate_on_hypercube(index)
+		} else {
+			Ok(self.pad_value)
+		}
+	}
+}
+
+impl<P, M> MultilinearPoly<P> for ZeroPadMultilinear<P, M>
+where
+	P: PackedField,
+	M: MultilinearPoly<P>,
+{
+	fn n_vars(&self) -> usize {
+		self.inner.n_vars() + self.n_pad_vars
+	}
+
+	fn log_extension_degree(&self) -> usize {
+		if self.pad_value == P::Scalar::ZERO {
+			self.inner.log_extension_degree()
+		} else {
+			0
+		}
+	}
+
+	fn evaluate_on_hypercube(&self, index: usize) -> Result<P::Scalar, Error> {
+		if index >= self.size() {
+			bail!(Error::HypercubeIndexOutOfRange { index });
+		}
+		self.eval_at(index)
+	}
+
+	fn evaluate_on_hypercube_and_scale(
+		&self,
+		index: usize,
+		scalar: P::Scalar,
+	) -> Result<P::Scalar, Error> {
+		if index >= self.size() {
+			bail!(Error::HypercubeIndexOutOfRange { index });
+		}
+		if index < self.inner.size() {
+			self.inner.evaluate_on_hypercube_and_scale(index, scalar)
+		} else {
+			Ok(scalar * self.pad_value)
+		}
+	}
+
+	fn evaluate(&self, query: MultilinearQueryRef<P>) -> Result<P::Scalar, Error> {
+		hypercube_evaluate(self, query)
+	}
+
+	fn evaluate_partial_low(
+		&self,
+		query: MultilinearQueryRef<P>,
+	) -> Result<MultilinearExtension<P>, Error> {
+		if query.n_vars() > self.inner.n_vars() {
+			return self.evaluate_partial(query, 0);
+		}
+
+		// The low variables are all inner variables, so the padding stays constant.
+		let inner_partial = self.inner.evaluate_partial_low(query)?;
+		let new_n_vars = self.n_vars() - query.n_vars();
+		multilinear_from_fn(new_n_vars, |i| {
+			if i < inner_partial.size() {
+				inner_partial.evaluate_on_hypercube(i)
+			} else {
+				Ok(self.pad_value)
+			}
+		})
+	}
+
+	fn evaluate_partial_high(
+		&self,
+		query: MultilinearQueryRef<P>,
+	) -> Result<MultilinearExtension<P>, Error> {
+		if query.n_vars() > self.n_vars() {
+			bail!(Error::IncorrectQuerySize {
+				expected: self.n_vars()
+			});
+		}
+		self.evaluate_partial(query, self.n_vars() - query.n_vars())
+	}
+
+	fn evaluate_partial(
+		&self,
+		query: MultilinearQueryRef<P>,
+		start_index: usize,
+	) -> Result<MultilinearExtension<P>, Error> {
+		hypercube_evaluate_partial(self, query, start_index)
+	}
+
+	fn zero_pad(
+		&self,
+		n_pad_vars: usize,
+		start_index: usize,
+		nonzero_index: usize,
+	) -> Result<MultilinearExtension<P>, Error> {
+		hypercube_zero_pad(self, n_pad_vars, start_index, nonzero_index)
+	}
+
+	fn subcube_partial_low_evals(
+		&self,
+		query: MultilinearQueryRef<P>,
+		subcube_vars: usize,
+		subcube_index: usize,
+		partial_low_evals: &mut [P],
+	) -> Result<(), Error> {
+		hypercube_subcube_partial_low_evals(
+			self,
+			query,
+			subcube_vars,
+			subcube_index,
+			partial_low_evals,
+		)
+	}
+
+	fn subcube_partial_high_evals(
+		&self,
+		query: MultilinearQueryRef<P>,
+		subcube_vars: usize,
+		subcube_index: usize,
+		partial_high_evals: &mut [P],
+	) -> Result<(), Error> {
+		hypercube_subcube_partial_high_evals(
+			self,
+			query,
+			subcube_vars,
+			subcube_index,
+			partial_high_evals,
+		)
+	}
+
+	fn subcube_evals(
+		&self,
+		subcube_vars: usize,
+		subcube_index: usize,
+		log_embedding_degree: usize,
+		evals: &mut [P],
+	) -> Result<(), Error> {
+		let n_vars = self.n_vars();
+		if subcube_vars > n_vars {
+			bail!(Error::ArgumentRangeError {
+				arg: "subcube_vars".to_string(),
+				range: 0..n_vars + 1,
+			});
+		}
+
+		if log_embedding_degree > self.log_extension_degree() {
+			bail!(Error::LogEmbeddingDegreeTooLarge {
+				log_embedding_degree
+			});
+		}
+
+		let correct_len = 1 << subcube_vars.saturating_sub(log_embedding_degree + P::LOG_WIDTH);
+		if evals.len() != correct_len {
+			bail!(Error::ArgumentRangeError {
+				arg: "evals.len()".to_string(),
+				range: correct_len..correct_len + 1,
+			});
+		}
+
+		let max_index = 1 << (n_vars - subcube_vars);
+		if subcube_index >= max_index {
+			bail!(Error::ArgumentRangeError {
+				arg: "subcube_index".to_string(),
+				range: 0..max_index,
+			});
+		}
+
+		let inner_n_vars = self.inner.n_vars();
+		let subcube_start = subcube_index << subcube_vars;
+		if subcube_vars <= inner_n_vars && subcube_start < self.inner.size() {
+			// The subcube lies entirely within the inner polynomial.
+			return self.inner.subcube_evals(
+				subcube_vars,
+				subcube_index,
+				log_embedding_degree,
+				evals,
+			);
+		}
+
+		if log_embedding_degree == 0 {
+			for i in 0..1 << subcube_vars {
+				set_packed_slice(evals, i, self.eval_at(subcube_start + i)?);
+			}
+			return Ok(());
+		}
+
+		// A nonzero embedding degree implies zero padding, so only the inner polynomial, which
+		// can only be a prefix of the subcube, contributes nonzero values.
+		evals.fill(P::zero());
+		if subcube_index == 0 {
+			let inner_scalars = 1 << inner_n_vars.saturating_sub(log_embedding_degree);
+			let mut inner_evals = vec![
+				P::zero();
+				1 << inner_n_vars
+					.saturating_sub(log_embedding_degree + P::LOG_WIDTH)
+			];
+			self.inner
+				.subcube_evals(inner_n_vars, 0, log_embedding_degree, &mut inner_evals)?;
+			for i in 0..inner_scalars {
+				set_packed_slice(evals, i, get_packed_slice(&inner_evals, i));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn packed_evals(&self) -> Option<&[P]> {
+		None
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use binius_field::{
+		BinaryField128b, BinaryField8b, PackedBinaryField16x8b, PackedBinaryField1x128b,
+		PackedBinaryField4x32b,
+	};
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+	use crate::{
+		mle_adapters::test_utils::{
+			assert_same_multilinear, assert_subcube_evals_match, assert_subcube_partial_evals_match,
+		},
+		MLEEmbeddingAdapter, MultilinearQuery,
+	};
+
+	type P = PackedBinaryField4x32b;
+	type F = <P as PackedField>::Scalar;
+
+	fn random_padded(
+		rng: &mut StdRng,
+		n_vars: usize,
+		n_pad_vars: usize,
+		pad_value: F,
+	) -> (ZeroPadMultilinear<P, MLEEmbeddingAdapter<P, P>>, MLEEmbeddingAdapter<P, P>) {
+		let inner_evals = repeat_with(|| <F as Field>::random(&mut *rng))
+			.take(1 << n_vars)
+			.collect::<Vec<_>>();
+		let padded_evals = inner_evals
+			.iter()
+			.copied()
+			.chain(std::iter::repeat(pad_value))
+			.take(1 << (n_vars + n_pad_vars))
+			.collect::<Vec<_>>();
+
+		let to_mle = |evals: &[F]| {
+			let n_vars = evals.len().ilog2() as usize;
+			multilinear_from_fn::<P>(n_vars, |i| Ok(evals[i]))
+				.unwrap()
+				.specialize::<P>()
+		};
+		let padded =
+			ZeroPadMultilinear::with_pad_value(to_mle(&inner_evals), n_pad_vars, pad_value);
+		(padded, to_mle(&padded_evals))
+	}
+
+	#[test]
+	fn test_zero_pad_multilinear_matches_materialized() {
+		let mut rng = StdRng::seed_from_u64(0);
+		for (n_vars, n_pad_vars, pad_value) in [
+			(3, 2, F::ZERO),
+			(3, 2, F::new(7)),
+			(1, 4, F::ONE),
+			(4, 0, F::new(3)),
+		] {
+			let (padded, expected) = random_padded(&mut rng, n_vars, n_pad_vars, pad_value);
+			let total_vars = n_vars + n_pad_vars;
+			assert_eq!(padded.n_vars(), total_vars);
+
+			for index in 0..1 << total_vars {
+				assert_eq!(
+					padded.evaluate_on_hypercube(index).unwrap(),
+					expected.evaluate_on_hypercube(index).unwrap()
+				);
+			}
+			assert!(padded.evaluate_on_hypercube(1 << total_vars).is_err());
+
+			let point = repeat_with(|| <F as Field>::random(&mut rng))
+				.take(total_vars)
+				.collect::<Vec<_>>();
+			let query = MultilinearQuery::<P>::expand(&point);
+			assert_eq!(
+				padded.evaluate(query.to_ref()).unwrap(),
+				expected.evaluate(query.to_ref()).unwrap()
+			);
+
+			for query_n_vars in 0..=total_vars {
+				let query = MultilinearQuery::<P>::expand(&point[..query_n_vars]);
+				assert_same_multilinear(
+					&padded.evaluate_partial_low(query.to_ref()).unwrap(),
+					&expected.evaluate_partial_low(query.to_ref()).unwrap(),
+				);
+				assert_same_multilinear(
+					&padded.evaluate_partial_high(query.to_ref()).unwrap(),
+					&expected.evaluate_partial_high(query.to_ref()).unwrap(),
+				);
+				for start_index in 0..=total_vars - query_n_vars {
+					assert_same_multilinear(
+						&padded
+							.evaluate_partial(query.to_ref(), start_index)
+							.unwrap(),
+						&expected
+							.evaluate_partial(query.to_ref(), start_index)
+							.unwrap(),
+					);
+				}
+
+				assert_subcube_partial_evals_match(&padded, &expected, query.to_ref());
+			}
+
+			assert_subcube_evals_match(&padded, &expected);
+
+			// Inserting two variables at index 1 which select the block with index 3.
+			let zero_padded = padded.zero_pad(2, 1, 3).unwrap();
+			assert_eq!(zero_padded.n_vars(), total_vars + 2);
+			for index in 0..1 << (total_vars + 2) {
+				let expected_eval = if (index >> 1) & 3 == 3 {
+					expected
+						.evaluate_on_hypercube(index & 1 | (index >> 3) << 1)
+						.unwrap()
+				} else {
+					F::ZERO
+				};
+				assert_eq!(zero_padded.evaluate_on_hypercube(index).unwrap(), expected_eval);
+			}
+		}
+	}
+
+	#[test]
+	fn test_zero_pad_multilinear_embedding() {
+		type PSub = PackedBinaryField16x8b;
+		type PE = PackedBinaryField1x128b;
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let inner_evals = repeat_with(|| <BinaryField8b as Field>::random(&mut rng))
+			.take(1 << 5)
+			.collect::<Vec<_>>();
+		let padded_evals = inner_evals
+			.iter()
+			.copied()
+			.chain(std::iter::repeat(BinaryField8b::ZERO))
+			.take(1 << 8)
+			.collect::<Vec<_>>();
+		let to_mle = |evals: &[BinaryField8b]| {
+			let n_vars = evals.len().ilog2() as usize;
+			multilinear_from_fn::<PSub>(n_vars, |i| Ok(evals[i]))
+				.unwrap()
+				.specialize::<PE>()
+		};
+
+		let padded = ZeroPadMultilinear::new(to_mle(&inner_evals), 3);
+		let expected = to_mle(&padded_evals);
+		assert_eq!(padded.log_extension_degree(), expected.log_extension_degree());
+
+		for log_embedding_degree in 0..=4 {
+			for subcube_vars in 0..=8usize {
+				let len = 1 << subcube_vars.saturating_sub(log_embedding_degree);
+				for subcube_index in 0..1 << (8 - subcube_vars) {
+					let mut padded_evals = vec![PE::zero(); len];
+					let mut expected_evals = vec![PE::zero(); len];
+					padded
+						.subcube_evals(
+							subcube_vars,
+							subcube_index,
+							log_embedding_degree,
+							&mut padded_evals,
+						)
+						.unwrap();
+					expected
+						.subcube_evals(
+							subcube_vars,
+							subcube_index,
+							log_embedding_degree,
+							&mut expected_evals,
+						)
+						.unwrap();
+					assert_eq!(padded_evals, expected_evals);
+				}
+			}
+		}
+
+		let nonzero_padded =
+			ZeroPadMultilinear::with_pad_value(to_mle(&inner_evals), 3, BinaryField128b::ONE);
+		assert_eq!(nonzero_padded.log_extension_degree(), 0);
+		let mut evals = vec![PE::zero(); 1 << 7];
+		assert!(nonzero_padded.subcube_evals(8, 0, 1, &mut evals).is_err());
+	}
+}
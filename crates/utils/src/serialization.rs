@@ -50,6 +50,14 @@ pub enum SerializationError {
 	InvalidConstruction { name: &'static str },
 	#[error("usize {size} is too large to serialize (max is {max})", max = u32::MAX)]
 	UsizeTooLarge { size: usize },
+	#[error("Invalid magic header for {name}")]
+	InvalidMagic { name: &'static str },
+	#[error("Unsupported {name} format version {got}, expected {expected}")]
+	UnsupportedVersion {
+		name: &'static str,
+		expected: u8,
+		got: u8,
+	},
 }
 
 // Copyright 2025 Irreducible Inc.